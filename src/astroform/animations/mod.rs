@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
+    configatron::get_stable_output,
     error::{ErrorAction, ErrorKind},
     types::Stylitron,
 };
@@ -16,10 +16,12 @@ impl Astroform {
     /// - A `JoinHandle` wrapping a `String` containing all formatted CSS animations.
     pub fn transform_animations(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
         let tab = self.tab.to_owned();
         let space = self.space.to_owned();
         let newline = self.newline.to_owned();
         let is_minified = self.is_minified;
+        let stable_output = get_stable_output();
 
         // Spawn a blocking task to process the animations in a separate thread.
         tokio::task::spawn_blocking(move || {
@@ -28,7 +30,7 @@ impl Astroform {
             let mut formatted_css_animations: Vec<String> = vec![];
 
             // Retrieve the animations map from the global STYLITRON AST.
-            let animations_map = match STYLITRON.get("animations") {
+            let mut animations_map = match stylitron.get("animations") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::Animation(ref animations) => animations.to_owned(),
                     _ => return String::new(),
@@ -45,6 +47,11 @@ impl Astroform {
                 }
             };
 
+            // Keyframes are grouped by context, so the contexts are emitted in name order.
+            if stable_output {
+                animations_map.sort_keys();
+            }
+
             // Iterate over each animation context in the retrieved animations map.
             for (context_name, context_animations) in animations_map {
                 tracing::info!("Processing animations for context: '{}'", context_name);
@@ -186,3 +193,33 @@ impl Astroform {
         stops_rules.join(newline)
     }
 }
+
+#[cfg(test)]
+mod animations_tests {
+    use indexmap::IndexMap;
+
+    use crate::astroform::Astroform;
+
+    #[test]
+    fn grouped_keyframe_selectors_are_emitted_as_is() {
+        let keyframes = IndexMap::from([
+            (
+                "from".to_string(),
+                IndexMap::from([("opacity".to_string(), "0".to_string())]),
+            ),
+            (
+                "30%,50%,70%".to_string(),
+                IndexMap::from([("opacity".to_string(), "0.5".to_string())]),
+            ),
+            (
+                "to".to_string(),
+                IndexMap::from([("opacity".to_string(), "1".to_string())]),
+            ),
+        ]);
+
+        assert_eq!(
+            Astroform::transform_keyframes("", "", "", &keyframes),
+            "from{opacity:0}30%,50%,70%{opacity:0.5}to{opacity:1}"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use crate::configatron::{get_autoprefix, get_browser_targets};
+
+use super::Astroform;
+
+/// The vendor-prefixed properties, along with the browser requiring them and the first version of
+/// that browser supporting the standard property. Infinity marks a prefix that is still required.
+const VENDOR_PREFIXES: [(&str, &str, &str, f64); 22] = [
+    ("transform", "-webkit-transform", "safari", 9.0),
+    ("transform", "-webkit-transform", "chrome", 36.0),
+    (
+        "transform-origin",
+        "-webkit-transform-origin",
+        "safari",
+        9.0,
+    ),
+    (
+        "transform-origin",
+        "-webkit-transform-origin",
+        "chrome",
+        36.0,
+    ),
+    ("transition", "-webkit-transition", "safari", 7.0),
+    ("transition", "-webkit-transition", "chrome", 26.0),
+    ("animation", "-webkit-animation", "safari", 9.0),
+    ("animation", "-webkit-animation", "chrome", 43.0),
+    (
+        "user-select",
+        "-webkit-user-select",
+        "safari",
+        f64::INFINITY,
+    ),
+    ("user-select", "-webkit-user-select", "chrome", 54.0),
+    ("user-select", "-moz-user-select", "firefox", 69.0),
+    ("backdrop-filter", "-webkit-backdrop-filter", "safari", 18.0),
+    ("appearance", "-webkit-appearance", "safari", 15.4),
+    ("appearance", "-webkit-appearance", "chrome", 84.0),
+    ("appearance", "-moz-appearance", "firefox", 80.0),
+    (
+        "text-size-adjust",
+        "-webkit-text-size-adjust",
+        "safari",
+        f64::INFINITY,
+    ),
+    ("mask-image", "-webkit-mask-image", "safari", 15.4),
+    ("mask-image", "-webkit-mask-image", "chrome", 120.0),
+    (
+        "background-clip",
+        "-webkit-background-clip",
+        "chrome",
+        120.0,
+    ),
+    (
+        "box-decoration-break",
+        "-webkit-box-decoration-break",
+        "safari",
+        f64::INFINITY,
+    ),
+    (
+        "box-decoration-break",
+        "-webkit-box-decoration-break",
+        "chrome",
+        130.0,
+    ),
+    ("hyphens", "-webkit-hyphens", "safari", 17.0),
+];
+
+impl Astroform {
+    /// Retrieves the vendor-prefixed properties to emit, which are none unless `autoprefix` is enabled.
+    pub fn get_vendor_prefixes() -> HashMap<String, Vec<String>> {
+        if !get_autoprefix() {
+            return HashMap::new();
+        }
+
+        Self::collect_vendor_prefixes(&get_browser_targets())
+    }
+
+    /// Collects the vendor-prefixed properties to emit alongside each standard property.
+    ///
+    /// A prefix is kept when any of the browser targets is older than the first version supporting
+    /// the standard property. Without any browser target, every known prefix is kept.
+    ///
+    /// # Arguments
+    /// - `browser_targets`: The browsers to support, as a name and the oldest version, e.g. `safari 14`.
+    ///
+    /// # Returns
+    /// - A `HashMap` from each standard property to its prefixed properties, in emission order.
+    pub fn collect_vendor_prefixes(browser_targets: &[String]) -> HashMap<String, Vec<String>> {
+        let targets = browser_targets
+            .iter()
+            .filter_map(|target| {
+                let parsed_target = Self::parse_browser_target(target);
+
+                if parsed_target.is_none() {
+                    tracing::warn!("Ignoring the unrecognized browser target '{}'.", target);
+                }
+
+                parsed_target
+            })
+            .collect::<Vec<(&str, f64)>>();
+
+        let mut vendor_prefixes: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (property, prefixed_property, browser, unprefixed_since) in VENDOR_PREFIXES {
+            let is_required = targets.is_empty()
+                || targets.iter().any(|(target_browser, target_version)| {
+                    *target_browser == browser && *target_version < unprefixed_since
+                });
+
+            if !is_required {
+                continue;
+            }
+
+            let prefixed_properties = vendor_prefixes.entry(property.to_string()).or_default();
+
+            if !prefixed_properties
+                .iter()
+                .any(|prefixed| prefixed == prefixed_property)
+            {
+                prefixed_properties.push(prefixed_property.to_string());
+            }
+        }
+
+        tracing::debug!(
+            "Collected the vendor prefixes of {} properties.",
+            vendor_prefixes.len()
+        );
+
+        vendor_prefixes
+    }
+
+    /// Parses a browser target into the browser name used by the vendor prefixes and its version.
+    /// Chromium-based and iOS browsers share the prefixes of Chrome and Safari.
+    fn parse_browser_target(target: &str) -> Option<(&'static str, f64)> {
+        let (name, version) = target.trim().rsplit_once(char::is_whitespace)?;
+        let version = version.trim().parse::<f64>().ok()?;
+
+        let browser = match name.trim().to_lowercase().as_str() {
+            "chrome" | "edge" | "opera" | "samsung" => "chrome",
+            "safari" | "ios" | "ios_saf" => "safari",
+            "firefox" => "firefox",
+            _ => return None,
+        };
+
+        Some((browser, version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::astroform::Astroform;
+
+    #[test]
+    fn vendor_prefixes_follow_browser_targets() {
+        let vendor_prefixes = Astroform::collect_vendor_prefixes(&[
+            "safari 14".to_string(),
+            "firefox 100".to_string(),
+        ]);
+
+        assert_eq!(
+            vendor_prefixes.get("backdrop-filter"),
+            Some(&vec!["-webkit-backdrop-filter".to_string()])
+        );
+        assert_eq!(
+            vendor_prefixes.get("user-select"),
+            Some(&vec!["-webkit-user-select".to_string()])
+        );
+        assert_eq!(vendor_prefixes.get("transform"), None);
+        assert_eq!(vendor_prefixes.get("appearance").map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn every_vendor_prefix_is_kept_without_targets() {
+        let vendor_prefixes = Astroform::collect_vendor_prefixes(&[]);
+
+        assert_eq!(
+            vendor_prefixes.get("user-select"),
+            Some(&vec![
+                "-webkit-user-select".to_string(),
+                "-moz-user-select".to_string()
+            ])
+        );
+        assert_eq!(
+            vendor_prefixes.get("transform"),
+            Some(&vec!["-webkit-transform".to_string()])
+        );
+    }
+}
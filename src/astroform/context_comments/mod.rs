@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+
+use futures::future::join_all;
+
+use crate::{
+    error::{ErrorAction, ErrorKind},
+    types::Stylitron,
+    utils::context_partitions::collect_context_partitions,
+};
+
+use super::{Astroform, ContextPartition};
+
+impl Astroform {
+    /// Transforms the styles and responsive styles into one block per context, each introduced by
+    /// a comment naming the Nenyr context that produced its rules.
+    ///
+    /// A utility class shared by several contexts is emitted once, in the block of the first one,
+    /// following the central, layouts and modules order. The rules of utility classes no context
+    /// tracks anymore are emitted last, without a comment.
+    ///
+    /// # Returns
+    /// - A `Vec<String>` containing the CSS rules of each block.
+    pub async fn transform_context_blocks(&self) -> Vec<String> {
+        let mut emitted_utilities: HashSet<String> = HashSet::new();
+        let mut context_blocks: Vec<String> = vec![];
+
+        for mut partition in collect_context_partitions(&self.session.clastrack) {
+            partition
+                .utility_names
+                .retain(|utility_name| emitted_utilities.insert(utility_name.to_owned()));
+
+            if partition.utility_names.is_empty() {
+                continue;
+            }
+
+            let context_name = partition.context_name.to_owned();
+            let context_rules = self.transform_partition_styles(partition).await;
+
+            tracing::debug!("Emitting the rules of the `{}` context.", context_name);
+
+            context_blocks.push(format!(
+                "/* ===== Context: {} ===== */{}{}",
+                context_name, self.newline, context_rules
+            ));
+        }
+
+        let untracked_utilities = self
+            .collect_styles_utilities()
+            .difference(&emitted_utilities)
+            .cloned()
+            .collect::<HashSet<String>>();
+
+        if !untracked_utilities.is_empty() {
+            let partition = ContextPartition {
+                context_name: String::new(),
+                include_shared_rules: false,
+                utility_names: untracked_utilities,
+            };
+
+            context_blocks.push(self.transform_partition_styles(partition).await);
+        }
+
+        context_blocks
+    }
+
+    /// Transforms the styles and responsive styles of the utility classes of a partition.
+    async fn transform_partition_styles(&self, partition: ContextPartition) -> String {
+        let mut astroform = self.to_owned();
+        astroform.partition = Some(partition);
+
+        let mut partition_rules: Vec<String> = vec![];
+
+        for task in join_all(vec![
+            astroform.transform_styles(),
+            astroform.transform_responsive_styles(),
+        ])
+        .await
+        {
+            match task {
+                Ok(rules) if !rules.is_empty() => partition_rules.push(rules),
+                Ok(_) => {}
+                Err(err) => {
+                    Self::send_palantir_error_notification(
+                        ErrorKind::TaskFailure,
+                        ErrorAction::Notify,
+                        &err.to_string(),
+                        self.palantir_sender.clone(),
+                    );
+                }
+            }
+        }
+
+        partition_rules.join(&self.newline)
+    }
+
+    /// Collects the unescaped names of every utility class in the styles and responsive styles.
+    fn collect_styles_utilities(&self) -> HashSet<String> {
+        let mut utility_names: HashSet<String> = HashSet::new();
+
+        for node_name in ["styles", "responsive"] {
+            let Some(stylitron_data) = self.session.stylitron.get(node_name) else {
+                continue;
+            };
+
+            let styles_maps = match &*stylitron_data {
+                Stylitron::Styles(styles_map) => vec![styles_map],
+                Stylitron::ResponsiveStyles(responsive_map) => responsive_map.values().collect(),
+                _ => vec![],
+            };
+
+            styles_maps
+                .iter()
+                .flat_map(|styles_map| styles_map.values())
+                .flat_map(|importance_map| importance_map.values())
+                .flat_map(|properties_map| properties_map.values())
+                .flat_map(|utilities_map| utilities_map.keys())
+                .for_each(|utility_name| {
+                    utility_names.insert(utility_name.replace('\\', ""));
+                });
+        }
+
+        utility_names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use tokio::sync::broadcast;
+
+    use crate::{
+        astroform::Astroform,
+        asts::CompilationSession,
+        types::{Clastrack, Stylitron},
+    };
+
+    #[tokio::test]
+    async fn rules_are_grouped_by_context() {
+        let session = CompilationSession::new();
+
+        session.stylitron.insert(
+            "styles".to_string(),
+            Stylitron::Styles(IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([(
+                    "_".to_string(),
+                    IndexMap::from([
+                        (
+                            "color".to_string(),
+                            IndexMap::from([("clr-sEsN".to_string(), "red".to_string())]),
+                        ),
+                        (
+                            "display".to_string(),
+                            IndexMap::from([
+                                ("dpy-S4vd".to_string(), "block".to_string()),
+                                ("dpy-5TuI".to_string(), "flex".to_string()),
+                            ]),
+                        ),
+                    ]),
+                )]),
+            )])),
+        );
+
+        session.clastrack.insert(
+            "central".to_string(),
+            Clastrack::Central(IndexMap::from([(
+                "mainText".to_string(),
+                "clr-sEsN".to_string(),
+            )])),
+        );
+        session.clastrack.insert(
+            "layouts".to_string(),
+            Clastrack::Layouts(IndexMap::from([(
+                "dynamicLayout".to_string(),
+                IndexMap::from([("wrapper".to_string(), "clr-sEsN dpy-S4vd".to_string())]),
+            )])),
+        );
+
+        let (palantir_sender, _) = broadcast::channel(10);
+        let mut astroform = Astroform::new(true, false, palantir_sender);
+        astroform.set_session(session);
+
+        assert_eq!(
+            astroform.transform_context_blocks().await,
+            vec![
+                "/* ===== Context: central ===== */.clr-sEsN{color:red}".to_string(),
+                "/* ===== Context: dynamicLayout ===== */.dpy-S4vd{display:block}".to_string(),
+                ".dpy-5TuI{display:flex}".to_string(),
+            ]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use indexmap::IndexMap;
 
 use super::Astroform;
@@ -58,6 +60,8 @@ impl Astroform {
     /// - `newline`: A newline character for formatting.
     /// - `tab_size`: The level of indentation to apply for the pseudo-selector styles.
     /// - `styles_map`: A map containing pseudo-selectors and their respective styles.
+    /// - `public_selectors`: The public names aliasing each utility class, emitted as additional selectors.
+    /// - `vendor_prefixes`: The vendor-prefixed properties declared before each standard property.
     ///
     /// # Returns
     /// - A `String` containing the formatted CSS rules for pseudo-selectors.
@@ -67,6 +71,8 @@ impl Astroform {
         newline: &str,
         tab_size: usize,
         styles_map: IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+        public_selectors: &HashMap<String, Vec<String>>,
+        vendor_prefixes: &HashMap<String, Vec<String>>,
     ) -> String {
         tracing::info!("Transforming pseudo-selector styles into CSS rules.");
 
@@ -98,19 +104,40 @@ impl Astroform {
                             importance
                         );
 
+                        // The utility class selector, followed by the public names aliasing it.
+                        let selectors = std::iter::once(class_name)
+                            .chain(public_selectors.get(class_name).into_iter().flatten())
+                            .map(|selector_name| format!(".{}{}", selector_name, pseudo_selector))
+                            .collect::<Vec<String>>()
+                            .join(format!(",{}", space).as_str());
+
+                        // The vendor-prefixed declarations come before the standard one, which wins when supported.
+                        let declarations = vendor_prefixes
+                            .get(property)
+                            .into_iter()
+                            .flatten()
+                            .chain(std::iter::once(property))
+                            .map(|property| {
+                                format!(
+                                    "{}{}:{}{}{}",
+                                    tab.repeat(tab_size),
+                                    property,
+                                    space,
+                                    value,
+                                    importance
+                                )
+                            })
+                            .collect::<Vec<String>>()
+                            .join(&format!(";{}", newline));
+
                         // Format each CSS rule and add it to the list of formatted rules.
                         let class = format!(
-                            "{}.{}{}{}{{{}{}{}:{}{}{}{}{}}}",
+                            "{}{}{}{{{}{}{}{}}}",
                             tab_or_empty,
-                            class_name,
-                            pseudo_selector,
+                            selectors,
                             space,
                             newline,
-                            tab.repeat(tab_size),
-                            property,
-                            space,
-                            value,
-                            importance,
+                            declarations,
                             newline,
                             tab_or_empty
                         );
@@ -125,6 +152,80 @@ impl Astroform {
         formatted_css_rules.join(&newline)
     }
 
+    /// Orders the properties of every pseudo-selector and importance group by CSS property name.
+    ///
+    /// Only the textual order of the emitted rules changes: pseudo-selectors and importance groups keep
+    /// their position, and every selector keeps its declarations.
+    ///
+    /// # Arguments
+    /// - `styles_map`: A map containing pseudo-selectors and their respective styles.
+    pub fn sort_styles_by_property(
+        styles_map: &mut IndexMap<
+            String,
+            IndexMap<String, IndexMap<String, IndexMap<String, String>>>,
+        >,
+    ) {
+        tracing::debug!("Ordering the utility rules by CSS property name.");
+
+        styles_map.values_mut().for_each(|importance_map| {
+            importance_map
+                .values_mut()
+                .for_each(|properties_map| properties_map.sort_keys());
+        });
+    }
+
+    /// Orders the properties of every pseudo-selector and importance group, and the utility classes
+    /// of every property, by name, so the emitted rules do not depend on the processing order.
+    ///
+    /// The pseudo-selectors and importance groups keep their position, since their order is fixed
+    /// when the styles node is created and decides which rule wins in the cascade.
+    ///
+    /// # Arguments
+    /// - `styles_map`: A map containing pseudo-selectors and their respective styles.
+    pub fn sort_styles_for_stable_output(
+        styles_map: &mut IndexMap<
+            String,
+            IndexMap<String, IndexMap<String, IndexMap<String, String>>>,
+        >,
+    ) {
+        tracing::debug!("Ordering the utility rules by CSS property and utility class name.");
+
+        styles_map.values_mut().for_each(|importance_map| {
+            importance_map.values_mut().for_each(|properties_map| {
+                properties_map.sort_keys();
+                properties_map
+                    .values_mut()
+                    .for_each(|utilities_map| utilities_map.sort_keys());
+            });
+        });
+    }
+
+    /// Keeps only the utility classes of a context partition, dropping the properties left empty.
+    ///
+    /// # Arguments
+    /// - `styles_map`: A map containing pseudo-selectors and their respective styles.
+    /// - `utility_names`: The unescaped names of the utility classes to keep.
+    pub fn retain_partition_utilities(
+        styles_map: &mut IndexMap<
+            String,
+            IndexMap<String, IndexMap<String, IndexMap<String, String>>>,
+        >,
+        utility_names: &HashSet<String>,
+    ) {
+        styles_map.values_mut().for_each(|importance_map| {
+            importance_map.values_mut().for_each(|properties_map| {
+                properties_map.values_mut().for_each(|utilities_map| {
+                    // The utility names are stored escaped for the CSS selectors.
+                    utilities_map.retain(|utility_name, _| {
+                        utility_names.contains(&utility_name.replace('\\', ""))
+                    });
+                });
+
+                properties_map.retain(|_, utilities_map| !utilities_map.is_empty());
+            });
+        });
+    }
+
     /// Selects either an indentation string or an empty string based on the tab size.
     ///
     /// # Arguments
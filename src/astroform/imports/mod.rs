@@ -1,7 +1,6 @@
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind},
     types::Stylitron,
 };
@@ -19,6 +18,7 @@ impl Astroform {
     /// - A `JoinHandle<String>` that resolves to a string containing all `@import` rules.
     pub fn transform_imports(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
         let newline = self.newline.to_owned();
 
         // Spawn a blocking task to handle the transformation.
@@ -27,7 +27,7 @@ impl Astroform {
 
             let mut import_css_rules: Vec<String> = vec![];
 
-            let imports_map = match STYLITRON.get("imports") {
+            let imports_map = match stylitron.get("imports") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::Imports(ref imports) => imports.to_owned(),
                     _ => return String::new(),
@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use crate::{
+    configatron::get_class_prefix,
+    crealion::Crealion,
+    types::{Classinator, Clastrack, Stylitron},
+};
+
+use super::Astroform;
+
+/// Characters a mangled name may start with, so it never starts with a digit.
+const LEADING_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// Characters a mangled name may continue with.
+const TRAILING_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+/// Keywords a mangled animation name must not match, since they carry a meaning in the `animation` values.
+const RESERVED_NAMES: [&str; 18] = [
+    "auto",
+    "both",
+    "default",
+    "ease",
+    "forwards",
+    "infinite",
+    "inherit",
+    "initial",
+    "linear",
+    "none",
+    "normal",
+    "paused",
+    "revert",
+    "reverse",
+    "running",
+    "unset",
+    "alternate",
+    "backwards",
+];
+
+/// The original generated identifiers mapped to their mangled names.
+#[derive(Default)]
+struct MangledNames {
+    /// The utility class names, in their CSS-selector form.
+    classes: HashMap<String, String>,
+    /// The variable and animation names, which are referenced by the declaration values.
+    references: HashMap<String, String>,
+}
+
+impl Astroform {
+    /// Renames every generated identifier to the shortest unique name, shrinking the stylesheet.
+    ///
+    /// The utility classes, variables and animations are renamed in `STYLITRON`, along with the
+    /// declaration values referencing them, and the utility classes are renamed in `CLASSINATOR`,
+    /// `CLASTRACK` and `ORIGINATOR` as well, so the tracking maps keep matching the generated CSS.
+    pub fn mangle_names(&self) {
+        tracing::info!("Mangling the generated identifiers.");
+
+        let mangled_names = self.collect_mangled_names();
+
+        self.rename_stylitron_names(&mangled_names);
+        self.rename_tracked_utilities(&mangled_names.classes);
+
+        tracing::info!(
+            "Mangled {} utility classes and {} variables and animations.",
+            mangled_names.classes.len(),
+            mangled_names.references.len()
+        );
+    }
+
+    /// Assigns a mangled name to every utility class, variable and animation of the session.
+    fn collect_mangled_names(&self) -> MangledNames {
+        let mut mangled_names = MangledNames::default();
+        let class_prefix = get_class_prefix();
+        let animation_prefix = Crealion::resolve_animation_prefix();
+        let (mut class_index, mut variable_index, mut animation_index) = (0, 0, 0);
+
+        let mut add_class = |class_name: &String| {
+            if !mangled_names.classes.contains_key(class_name) {
+                let mangled_name = format!("{}{}", class_prefix, next_short_name(&mut class_index));
+
+                mangled_names
+                    .classes
+                    .insert(class_name.to_owned(), mangled_name);
+            }
+        };
+
+        for stylitron_data in ["styles", "responsive"]
+            .iter()
+            .filter_map(|section| self.session.stylitron.get(*section))
+        {
+            match &*stylitron_data {
+                Stylitron::Styles(styles_map) => styles_map
+                    .values()
+                    .flat_map(|importance_map| importance_map.values())
+                    .flat_map(|properties_map| properties_map.values())
+                    .flat_map(|class_map| class_map.keys())
+                    .for_each(&mut add_class),
+                Stylitron::ResponsiveStyles(responsive_map) => responsive_map
+                    .values()
+                    .flat_map(|styles_map| styles_map.values())
+                    .flat_map(|importance_map| importance_map.values())
+                    .flat_map(|properties_map| properties_map.values())
+                    .flat_map(|class_map| class_map.keys())
+                    .for_each(&mut add_class),
+                _ => {}
+            }
+        }
+
+        let mut unique_var_names = vec![];
+
+        if let Some(stylitron_data) = self.session.stylitron.get("variables") {
+            if let Stylitron::Variables(ref variables_map) = *stylitron_data {
+                unique_var_names.extend(
+                    variables_map
+                        .values()
+                        .flat_map(|context_variables| context_variables.values())
+                        .filter_map(|variable| variable.first().cloned()),
+                );
+            }
+        }
+
+        if let Some(stylitron_data) = self.session.stylitron.get("themes") {
+            if let Stylitron::Themes(ref themes_map) = *stylitron_data {
+                unique_var_names.extend(
+                    themes_map
+                        .values()
+                        .flat_map(|schemas_map| schemas_map.values())
+                        .flat_map(|schema_variables| schema_variables.values())
+                        .filter_map(|variable| variable.first().cloned()),
+                );
+            }
+        }
+
+        for unique_var_name in unique_var_names {
+            if !mangled_names.references.contains_key(&unique_var_name) {
+                let mangled_name = format!("--{}", next_short_name(&mut variable_index));
+
+                mangled_names
+                    .references
+                    .insert(unique_var_name, mangled_name);
+            }
+        }
+
+        if let Some(stylitron_data) = self.session.stylitron.get("animations") {
+            if let Stylitron::Animation(ref animations_map) = *stylitron_data {
+                let unique_animation_names = animations_map
+                    .values()
+                    .flat_map(|context_animations| context_animations.values())
+                    .flat_map(|animation_map| animation_map.keys());
+
+                for unique_animation_name in unique_animation_names {
+                    if !mangled_names.references.contains_key(unique_animation_name) {
+                        let mangled_name = format!(
+                            "{}{}",
+                            animation_prefix,
+                            next_short_name(&mut animation_index)
+                        );
+
+                        mangled_names
+                            .references
+                            .insert(unique_animation_name.to_owned(), mangled_name);
+                    }
+                }
+            }
+        }
+
+        mangled_names
+    }
+
+    /// Renames the utility classes, variables and animations in `STYLITRON`, rewriting the declaration
+    /// values that reference the renamed variables and animations.
+    fn rename_stylitron_names(&self, mangled_names: &MangledNames) {
+        let rename_class = |class_map: &mut IndexMap<String, String>| {
+            *class_map = class_map
+                .drain(..)
+                .map(|(class_name, value)| {
+                    (
+                        rename(&mangled_names.classes, class_name),
+                        rename_references(&value, &mangled_names.references),
+                    )
+                })
+                .collect();
+        };
+
+        let rename_variable = |variable: &mut Vec<String>| {
+            for (index, value) in variable.iter_mut().enumerate() {
+                *value = match index {
+                    0 => rename(&mangled_names.references, value.to_owned()),
+                    _ => rename_references(value, &mangled_names.references),
+                };
+            }
+        };
+
+        for mut stylitron_data in self.session.stylitron.iter_mut() {
+            match stylitron_data.value_mut() {
+                Stylitron::Styles(styles_map) => styles_map
+                    .values_mut()
+                    .flat_map(|importance_map| importance_map.values_mut())
+                    .flat_map(|properties_map| properties_map.values_mut())
+                    .for_each(rename_class),
+                Stylitron::ResponsiveStyles(responsive_map) => responsive_map
+                    .values_mut()
+                    .flat_map(|styles_map| styles_map.values_mut())
+                    .flat_map(|importance_map| importance_map.values_mut())
+                    .flat_map(|properties_map| properties_map.values_mut())
+                    .for_each(rename_class),
+                Stylitron::Variables(variables_map) => variables_map
+                    .values_mut()
+                    .flat_map(|context_variables| context_variables.values_mut())
+                    .for_each(rename_variable),
+                Stylitron::Themes(themes_map) => themes_map
+                    .values_mut()
+                    .flat_map(|schemas_map| schemas_map.values_mut())
+                    .flat_map(|schema_variables| schema_variables.values_mut())
+                    .for_each(rename_variable),
+                Stylitron::Animation(animations_map) => {
+                    for animation_map in animations_map
+                        .values_mut()
+                        .flat_map(|context_animations| context_animations.values_mut())
+                    {
+                        *animation_map = animation_map
+                            .drain(..)
+                            .map(|(unique_animation_name, mut stops_map)| {
+                                stops_map
+                                    .values_mut()
+                                    .flat_map(|properties_map| properties_map.values_mut())
+                                    .for_each(|value| {
+                                        *value = rename_references(value, &mangled_names.references)
+                                    });
+
+                                (
+                                    rename(&mangled_names.references, unique_animation_name),
+                                    stops_map,
+                                )
+                            })
+                            .collect();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Renames the utility classes tracked by `CLASSINATOR`, `CLASTRACK` and `ORIGINATOR`.
+    fn rename_tracked_utilities(&self, mangled_classes: &HashMap<String, String>) {
+        let rename_utilities = |utility_names: &mut Vec<String>| {
+            *utility_names = utility_names
+                .drain(..)
+                .map(|utility_name| rename(mangled_classes, utility_name))
+                .collect();
+        };
+
+        for mut classinator_data in self.session.classinator.iter_mut() {
+            match classinator_data.value_mut() {
+                Classinator::Central(central_map) => central_map
+                    .values_mut()
+                    .flat_map(|class_map| class_map.values_mut())
+                    .for_each(rename_utilities),
+                Classinator::Layouts(layouts_map) => layouts_map
+                    .values_mut()
+                    .flat_map(|inherited_map| inherited_map.values_mut())
+                    .flat_map(|class_map| class_map.values_mut())
+                    .for_each(rename_utilities),
+                Classinator::Modules(modules_map) => modules_map
+                    .values_mut()
+                    .flat_map(|parent_map| parent_map.values_mut())
+                    .flat_map(|inherited_map| inherited_map.values_mut())
+                    .flat_map(|class_map| class_map.values_mut())
+                    .for_each(rename_utilities),
+            }
+        }
+
+        let rename_joined_utilities = |utility_names: &mut String| {
+            *utility_names = utility_names
+                .split_whitespace()
+                .map(|utility_name| rename(mangled_classes, utility_name.to_string()))
+                .collect::<Vec<_>>()
+                .join(" ");
+        };
+
+        for mut clastrack_data in self.session.clastrack.iter_mut() {
+            match clastrack_data.value_mut() {
+                Clastrack::Central(central_map) => {
+                    central_map.values_mut().for_each(rename_joined_utilities)
+                }
+                Clastrack::Layouts(contexts_map) | Clastrack::Modules(contexts_map) => contexts_map
+                    .values_mut()
+                    .flat_map(|class_map| class_map.values_mut())
+                    .for_each(rename_joined_utilities),
+            }
+        }
+
+        let origins = self
+            .session
+            .originator
+            .iter()
+            .map(|entry| (entry.key().to_owned(), entry.value().to_owned()))
+            .collect::<Vec<_>>();
+
+        self.session.originator.clear();
+
+        for (utility_name, class_origin) in origins {
+            self.session
+                .originator
+                .insert(rename(mangled_classes, utility_name), class_origin);
+        }
+    }
+}
+
+/// Retrieves the mangled name of an identifier, or the identifier itself when it was not renamed.
+fn rename(mangled_names: &HashMap<String, String>, name: String) -> String {
+    match mangled_names.get(&name) {
+        Some(mangled_name) => mangled_name.to_owned(),
+        None => name,
+    }
+}
+
+/// Rewrites the identifiers of a declaration value that were renamed, e.g. the variable in `var(--gW1yAqTMgoH)`.
+///
+/// # Arguments
+/// - `value`: The declaration value.
+/// - `mangled_names`: The renamed variables and animations.
+fn rename_references(value: &str, mangled_names: &HashMap<String, String>) -> String {
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    let mut renamed_value = String::with_capacity(value.len());
+    let mut identifier = String::new();
+
+    for c in value.chars() {
+        if is_identifier_char(c) {
+            identifier.push(c);
+            continue;
+        }
+
+        renamed_value.push_str(mangled_names.get(&identifier).unwrap_or(&identifier));
+        renamed_value.push(c);
+        identifier.clear();
+    }
+
+    renamed_value.push_str(mangled_names.get(&identifier).unwrap_or(&identifier));
+    renamed_value
+}
+
+/// Generates the next shortest name not matching a reserved keyword, advancing the index.
+fn next_short_name(index: &mut usize) -> String {
+    loop {
+        let short_name = generate_short_name(*index);
+        *index += 1;
+
+        if !RESERVED_NAMES.contains(&short_name.as_str()) {
+            return short_name;
+        }
+    }
+}
+
+/// Generates the name at the given index of the sequence of names ordered by length: `a`, ..., `Z`, `aa`, `ba`, ...
+///
+/// The first character is always a letter, while the following ones may also be digits.
+fn generate_short_name(index: usize) -> String {
+    let mut short_name = String::from(LEADING_CHARS[index % LEADING_CHARS.len()] as char);
+    let mut remaining = index / LEADING_CHARS.len();
+
+    while remaining > 0 {
+        remaining -= 1;
+        short_name.push(TRAILING_CHARS[remaining % TRAILING_CHARS.len()] as char);
+        remaining /= TRAILING_CHARS.len();
+    }
+
+    short_name
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{generate_short_name, rename_references};
+
+    #[test]
+    fn short_names_grow_only_once_exhausted() {
+        assert_eq!(generate_short_name(0), "a");
+        assert_eq!(generate_short_name(51), "Z");
+        assert_eq!(generate_short_name(52), "aa");
+        assert_eq!(generate_short_name(53), "ba");
+        assert_eq!(generate_short_name(52 + 52 * 62 - 1), "Z9");
+        assert_eq!(generate_short_name(52 + 52 * 62), "aaa");
+    }
+
+    #[test]
+    fn references_are_renamed_as_whole_identifiers() {
+        let mangled_names = HashMap::from([
+            ("--gW1yAqTMgoH".to_string(), "--a".to_string()),
+            ("giq8HPC3JaYa".to_string(), "b".to_string()),
+        ]);
+
+        assert_eq!(
+            rename_references("var(--gW1yAqTMgoH)", &mangled_names),
+            "var(--a)"
+        );
+        assert_eq!(
+            rename_references("giq8HPC3JaYa 2s ease-in", &mangled_names),
+            "b 2s ease-in"
+        );
+        assert_eq!(
+            rename_references("var(--gW1yAqTMgoHx, #FFF)", &mangled_names),
+            "var(--gW1yAqTMgoHx, #FFF)"
+        );
+    }
+}
@@ -1,23 +1,71 @@
+use std::collections::HashSet;
+
 use chrono::Local;
 use futures::future::join_all;
 use tokio::sync::broadcast;
 
 use crate::{
-    asts::CASCADEX,
+    asts::CompilationSession,
+    configatron::{
+        get_context_comments, get_css_layer, get_emit_at_property, get_emit_charset,
+        get_reset_styles_media, get_respect_reduced_motion, get_source_maps,
+    },
     crealion::CENTRAL_CONTEXT_NAME,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
+    utils::format_media_query::format_media_query,
 };
 
 mod animations;
+mod autoprefix;
+mod context_comments;
 mod handlers;
 mod imports;
+mod mangle_names;
+mod properties;
+mod public_names;
+mod reduced_motion;
 mod responsive;
+mod source_map;
 mod styles;
 mod themes;
 mod typefaces;
 mod variables;
 
+/// Prefix of the `CASCADEX` keys holding the split theme stylesheets.
+pub const THEME_SHEET_KEY_PREFIX: &str = "theme_sheet:";
+
+/// The rule declaring the encoding of the generated stylesheets.
+/// It is only honored as the very first thing in a stylesheet, before any comment or `@import`.
+const CHARSET_RULE: &str = "@charset \"UTF-8\";";
+
+/// Prefix of the `CASCADEX` keys holding the split mobile-first and desktop-first responsive stylesheets.
+pub const RESPONSIVE_SHEET_KEY_PREFIX: &str = "responsive_sheet:";
+
+/// Prefix of the `CASCADEX` keys holding the stylesheets split per context.
+pub const CONTEXT_SHEET_KEY_PREFIX: &str = "context_sheet:";
+
+/// Name of the layer nested in the `cssLayer` one, holding the reset styles.
+const RESET_STYLES_LAYER: &str = "reset";
+
+/// The `CASCADEX` key holding the source map of the global cascading stylesheet.
+pub const SOURCE_MAP_KEY: &str = "source_map";
+
+/// Prefix of the `CASCADEX` keys holding the source maps of the stylesheets split per context.
+pub const CONTEXT_SOURCE_MAP_KEY_PREFIX: &str = "context_source_map:";
+
+/// Restricts a transformation to the rules of a single context.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContextPartition {
+    /// The name of the context the stylesheet is generated for.
+    pub context_name: String,
+    /// Whether the rules shared by every context (imports, typefaces, variables, themes, animations
+    /// and reset styles) are included.
+    pub include_shared_rules: bool,
+    /// The unescaped names of the utility classes whose rules are included.
+    pub utility_names: HashSet<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Astroform {
     /// A `String` representing the tab character(s) used for indentation.
@@ -28,6 +76,16 @@ pub struct Astroform {
     newline: String,
     is_minified: bool,
     set_reset_styles: bool,
+    /// Whether the non-default theme schemas are written apart from the main stylesheet.
+    split_themes: bool,
+    /// Whether the mobile-first and desktop-first responsive styles are written apart from the main stylesheet.
+    split_responsive: bool,
+    /// Whether the generated identifiers are renamed to the shortest unique names before the transformation.
+    names_mangling: bool,
+    /// The context the transformation is restricted to, if any.
+    partition: Option<ContextPartition>,
+    /// The session whose abstract syntax trees are transformed and whose stylesheets are written.
+    session: CompilationSession,
     palantir_sender: broadcast::Sender<GaladrielAlerts>,
 }
 
@@ -53,6 +111,11 @@ impl Astroform {
                 space: "".to_string(),
                 tab: "".to_string(),
                 set_reset_styles,
+                split_themes: false,
+                split_responsive: false,
+                names_mangling: false,
+                partition: None,
+                session: CompilationSession::global(),
                 palantir_sender,
                 is_minified,
             }
@@ -63,12 +126,72 @@ impl Astroform {
                 space: " ".to_string(),
                 tab: "\t".to_string(),
                 set_reset_styles,
+                split_themes: false,
+                split_responsive: false,
+                names_mangling: false,
+                partition: None,
+                session: CompilationSession::global(),
                 palantir_sender,
                 is_minified,
             }
         }
     }
 
+    /// Sets whether the non-default theme schemas are split from the main stylesheet.
+    ///
+    /// When enabled, the default (`light`) theme is emitted inline without its media query,
+    /// while every other schema is stored in `CASCADEX` as its own stylesheet.
+    ///
+    /// # Arguments
+    /// * `split_themes` - A boolean indicating whether the theme schemas should be split.
+    pub fn set_themes_split(&mut self, split_themes: bool) -> &mut Self {
+        self.split_themes = split_themes;
+        self
+    }
+
+    /// Sets whether the mobile-first and desktop-first responsive styles are split from the main stylesheet.
+    ///
+    /// When enabled, the `min-width` and `max-width` media queries are each stored in `CASCADEX` as their
+    /// own stylesheet, while the base styles and any other media query stay in the main one.
+    ///
+    /// # Arguments
+    /// * `split_responsive` - A boolean indicating whether the responsive styles should be split.
+    pub fn set_responsive_split(&mut self, split_responsive: bool) -> &mut Self {
+        self.split_responsive = split_responsive;
+        self
+    }
+
+    /// Sets whether the utility classes, variables and animations are renamed to the shortest unique names.
+    ///
+    /// The renaming is applied to the session ASTs, so it is meant for builds, whose names need not stay stable.
+    ///
+    /// # Arguments
+    /// * `names_mangling` - A boolean indicating whether the generated identifiers should be mangled.
+    pub fn set_names_mangling(&mut self, names_mangling: bool) -> &mut Self {
+        self.names_mangling = names_mangling;
+        self
+    }
+
+    /// Sets the session transformed, instead of the global one.
+    ///
+    /// # Arguments
+    /// * `session` - The session whose styles are transformed and whose stylesheets are written.
+    pub fn set_session(&mut self, session: CompilationSession) -> &mut Self {
+        self.session = session;
+        self
+    }
+
+    /// Restricts the transformation to the rules of a single context.
+    ///
+    /// The resulting stylesheet is stored in `CASCADEX` under its own key, leaving the main stylesheet untouched.
+    ///
+    /// # Arguments
+    /// * `partition` - The context and the utility classes whose rules are transformed.
+    pub fn set_context_partition(&mut self, partition: ContextPartition) -> &mut Self {
+        self.partition = Some(partition);
+        self
+    }
+
     /// Transforms various sections of styles into CSS rules asynchronously.
     ///
     /// This function performs concurrent tasks to convert imports, typefaces, variables, themes,
@@ -83,17 +206,50 @@ impl Astroform {
 
         tracing::info!("Starting the transformation of Galadriel CSS stylesheet into CSS rules.");
 
+        // The identifiers are renamed before any rule is generated, so every rule uses the mangled names.
+        if self.names_mangling {
+            self.mangle_names();
+        }
+
+        let include_shared_rules = self
+            .partition
+            .as_ref()
+            .map_or(true, |partition| partition.include_shared_rules);
+
+        // The styles are grouped per context behind a comment, unless minified, restricted to a single
+        // context or split by responsive approach.
+        let context_comments = get_context_comments()
+            && !self.is_minified
+            && !self.split_responsive
+            && self.partition.is_none();
+
         // Perform concurrent tasks for various sections of the stylesheet.
-        let astroform_tasks = join_all(vec![
-            self.transform_imports(),
-            self.transform_typefaces(),
-            self.transform_variables(),
-            self.transform_themes(),
-            self.transform_animations(),
-            self.transform_styles(),
-            self.transform_responsive_styles(),
-        ])
-        .await;
+        let mut transform_tasks = match (include_shared_rules, context_comments) {
+            (true, true) => vec![
+                self.transform_imports(),
+                self.transform_typefaces(),
+                self.transform_variables(),
+                self.transform_themes(),
+                self.transform_animations(),
+            ],
+            (true, false) => vec![
+                self.transform_imports(),
+                self.transform_typefaces(),
+                self.transform_variables(),
+                self.transform_themes(),
+                self.transform_animations(),
+                self.transform_styles(),
+                self.transform_responsive_styles(),
+            ],
+            (false, _) => vec![self.transform_styles(), self.transform_responsive_styles()],
+        };
+
+        // The `@property` registrations come right after the variables they register.
+        if include_shared_rules && get_emit_at_property() {
+            transform_tasks.insert(3, self.transform_at_properties());
+        }
+
+        let astroform_tasks = join_all(transform_tasks).await;
 
         // Process each task result, handle errors, and accumulate valid CSS rules.
         for task in astroform_tasks {
@@ -112,17 +268,188 @@ impl Astroform {
             }
         }
 
+        if context_comments {
+            css_rules.extend(self.transform_context_blocks().await);
+        }
+
+        // The animated utility classes are overridden last, so the media query wins the cascade.
+        if get_respect_reduced_motion() {
+            match self.transform_reduced_motion().await {
+                Ok(rule) => css_rules.push(rule),
+                Err(err) => {
+                    Self::send_palantir_error_notification(
+                        ErrorKind::TaskFailure,
+                        ErrorAction::Notify,
+                        &err.to_string(),
+                        palantir_sender.clone(),
+                    );
+                }
+            }
+        }
+
         // Optionally include reset styles at the beginning if the flag is set.
-        if self.set_reset_styles {
-            css_rules.insert(0, self.get_reset_styles_rules());
+        let reset_rules = (self.set_reset_styles && include_shared_rules).then(|| {
+            self.scope_reset_styles_rules(self.get_reset_styles_rules(), &get_reset_styles_media())
+        });
+
+        let css_layer = get_css_layer();
+
+        if css_layer.trim().is_empty() {
+            if let Some(reset_rules) = reset_rules {
+                css_rules.insert(0, reset_rules);
+            }
+        } else {
+            css_rules = self.layer_css_rules(css_layer.trim(), reset_rules, css_rules);
+        }
+
+        // The charset rule goes last, so it comes before the reset styles and the imports.
+        if get_emit_charset() {
+            css_rules.insert(0, CHARSET_RULE.to_string());
+        }
+
+        // A context stylesheet, along with its source map, is kept apart from the global cascading stylesheet.
+        let (sheet_key, source_map_key) = match &self.partition {
+            Some(partition) => (
+                format!("{}{}", CONTEXT_SHEET_KEY_PREFIX, partition.context_name),
+                format!(
+                    "{}{}",
+                    CONTEXT_SOURCE_MAP_KEY_PREFIX, partition.context_name
+                ),
+            ),
+            None => ("cascading_sheet".to_string(), SOURCE_MAP_KEY.to_string()),
+        };
+
+        let cascading_sheet = css_rules.join(&self.newline);
+
+        if get_source_maps() {
+            match self.transform_source_map(cascading_sheet.to_owned()).await {
+                Ok(source_map) => {
+                    self.session.cascadex.insert(source_map_key, source_map);
+                }
+                Err(err) => {
+                    Self::send_palantir_error_notification(
+                        ErrorKind::TaskFailure,
+                        ErrorAction::Notify,
+                        &err.to_string(),
+                        palantir_sender.clone(),
+                    );
+                }
+            }
         }
 
         // Insert the generated CSS rules into the global cascading stylesheet.
-        CASCADEX.insert("cascading_sheet".to_string(), css_rules.join(&self.newline));
+        self.session.cascadex.insert(sheet_key, cascading_sheet);
 
         tracing::info!("CSS transformation completed and applied to the global stylesheet.");
     }
 
+    /// Wraps the CSS rules in the named cascade layer, with the reset styles in its nested `reset` layer.
+    ///
+    /// The rules written straight into a layer take precedence over the ones of its nested layers,
+    /// so the utility classes always win over the reset styles. The `@import` rules cannot be nested,
+    /// so they are kept before the layer and assigned to it through their `layer()` function instead.
+    ///
+    /// # Arguments
+    /// * `layer_name` - The name of the cascade layer, e.g. `galadriel`.
+    /// * `reset_rules` - The CSS rules of the reset styles, if they are included.
+    /// * `css_rules` - The CSS rules of the stylesheet.
+    ///
+    /// # Returns
+    /// A `Vec<String>` with the layered `@import` rules, if any, followed by the `@layer` block.
+    fn layer_css_rules(
+        &self,
+        layer_name: &str,
+        reset_rules: Option<String>,
+        css_rules: Vec<String>,
+    ) -> Vec<String> {
+        tracing::debug!("Wrapping the stylesheet in the `{}` layer.", layer_name);
+
+        let (import_rules, css_rules): (Vec<String>, Vec<String>) = css_rules
+            .into_iter()
+            .filter(|rule| !rule.is_empty())
+            .partition(|rule| rule.starts_with("@import"));
+
+        let mut layered_rules = import_rules
+            .iter()
+            .flat_map(|rule| rule.split(';'))
+            .map(|import| import.trim())
+            .filter(|import| !import.is_empty())
+            .map(|import| format!("{} layer({});", import, layer_name))
+            .collect::<Vec<String>>();
+
+        let mut layer_rules = vec![];
+
+        if let Some(reset_rules) = reset_rules {
+            layer_rules.push(format!(
+                "@layer {}{}{{{}{}{}}}",
+                RESET_STYLES_LAYER, self.space, self.newline, reset_rules, self.newline
+            ));
+        }
+
+        layer_rules.extend(css_rules);
+        layered_rules.push(Self::wrap_in_css_layer(
+            layer_name,
+            &self.space,
+            &self.newline,
+            layer_rules.join(&self.newline),
+        ));
+
+        layered_rules
+    }
+
+    /// Wraps a stylesheet in the named cascade layer, leaving it as it is when no layer is named.
+    ///
+    /// # Arguments
+    /// * `layer_name` - The name of the cascade layer, or an empty string.
+    /// * `space` - The space string used for formatting.
+    /// * `newline` - The newline string used for formatting.
+    /// * `rules` - The CSS rules of the stylesheet.
+    fn wrap_in_css_layer(layer_name: &str, space: &str, newline: &str, rules: String) -> String {
+        let layer_name = layer_name.trim();
+
+        if layer_name.is_empty() || rules.is_empty() {
+            return rules;
+        }
+
+        format!(
+            "@layer {}{}{{{}{}{}}}",
+            layer_name, space, newline, rules, newline
+        )
+    }
+
+    /// Scopes the reset styles to the configured media condition.
+    ///
+    /// A condition prefixed with `not ` excludes the reset styles from the matching screens
+    /// instead, while an empty condition leaves them global.
+    ///
+    /// # Arguments
+    /// * `reset_rules` - The CSS rules of the reset styles.
+    /// * `media_condition` - The media condition the reset styles are scoped to or excluded from.
+    ///
+    /// # Returns
+    /// A `String` containing the reset styles, wrapped in a `@media` rule when a condition is given.
+    fn scope_reset_styles_rules(&self, reset_rules: String, media_condition: &str) -> String {
+        let media_condition = media_condition.trim();
+
+        if media_condition.is_empty() {
+            return reset_rules;
+        }
+
+        let media_query = match media_condition.strip_prefix("not ") {
+            Some(excluded_condition) => {
+                format!("@media not screen and ({})", excluded_condition.trim())
+            }
+            None => format_media_query(media_condition),
+        };
+
+        tracing::debug!("Scoping the reset styles to `{}`.", media_query);
+
+        format!(
+            "{}{}{{{}{}{}}}",
+            media_query, self.space, self.newline, reset_rules, self.newline
+        )
+    }
+
     /// Generates the CSS reset styles.
     ///
     /// This function generates a comprehensive set of reset styles for various HTML elements to ensure
@@ -339,13 +666,16 @@ impl Astroform {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{HashMap, HashSet};
+
     use indexmap::IndexMap;
     use tokio::sync::broadcast;
 
     use crate::{
         astroform::Astroform,
-        asts::{CASCADEX, STYLITRON},
+        asts::{CompilationSession, CASCADEX, STYLITRON},
         types::Stylitron,
+        utils::generates_node_styles::generates_node_styles,
     };
 
     #[tokio::test]
@@ -366,6 +696,253 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn independent_session_is_transformed() {
+        let session = CompilationSession::new();
+
+        session.stylitron.insert(
+            "styles".to_string(),
+            Stylitron::Styles(IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([(
+                    "_".to_string(),
+                    IndexMap::from([(
+                        "color".to_string(),
+                        IndexMap::from([("clr-sEsN".to_string(), "red".to_string())]),
+                    )]),
+                )]),
+            )])),
+        );
+
+        let (palantir_sender, _) = broadcast::channel(10);
+
+        Astroform::new(true, false, palantir_sender)
+            .set_session(session.clone())
+            .transform()
+            .await;
+
+        let session_css = session
+            .cascadex
+            .get("cascading_sheet")
+            .map(|sheet| sheet.to_owned())
+            .unwrap_or_default();
+
+        assert!(session_css.contains(".clr-sEsN{color:red}"));
+        assert!(!CASCADEX
+            .get("cascading_sheet")
+            .is_some_and(|sheet| sheet.contains("clr-sEsN")));
+    }
+
+    #[tokio::test]
+    async fn empty_pattern_nodes_emit_no_rules() {
+        let session = CompilationSession::new();
+
+        let mut styles = generates_node_styles();
+        styles[":hover"]["_"].insert(
+            "color".to_string(),
+            IndexMap::from([("clr-sEsN".to_string(), "red".to_string())]),
+        );
+
+        let mut mobile_styles = generates_node_styles();
+        mobile_styles["::before"]["!important"].insert(
+            "display".to_string(),
+            IndexMap::from([("\\!dpy-S4vd".to_string(), "none".to_string())]),
+        );
+
+        session
+            .stylitron
+            .insert("styles".to_string(), Stylitron::Styles(styles));
+        session.stylitron.insert(
+            "responsive".to_string(),
+            Stylitron::ResponsiveStyles(IndexMap::from([
+                ("min-width:740px".to_string(), generates_node_styles()),
+                ("max-width:400px".to_string(), mobile_styles),
+            ])),
+        );
+
+        let (palantir_sender, _) = broadcast::channel(10);
+
+        Astroform::new(false, false, palantir_sender)
+            .set_session(session.clone())
+            .transform()
+            .await;
+
+        let session_css = session
+            .cascadex
+            .get("cascading_sheet")
+            .map(|sheet| sheet.to_owned())
+            .unwrap_or_default();
+        let compact_css = session_css
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+
+        assert!(compact_css.contains(".clr-sEsN:hover{color:red}"));
+        assert!(compact_css.contains(
+            "@mediascreenand(max-width:400px){.\\!dpy-S4vd::before{display:none!important}}"
+        ));
+        assert!(!compact_css.contains("{}"));
+        assert!(!compact_css.contains("min-width:740px"));
+    }
+
+    #[test]
+    fn reset_styles_are_scoped_to_media_condition() {
+        let (palantir_sender, _) = broadcast::channel(10);
+        let astroform = Astroform::new(true, true, palantir_sender);
+        let reset_rules = "body{margin:0}".to_string();
+
+        assert_eq!(
+            astroform.scope_reset_styles_rules(reset_rules.clone(), ""),
+            "body{margin:0}"
+        );
+        assert_eq!(
+            astroform.scope_reset_styles_rules(reset_rules.clone(), "min-width:768px"),
+            "@media screen and (min-width:768px){body{margin:0}}"
+        );
+        assert_eq!(
+            astroform.scope_reset_styles_rules(reset_rules, "not min-width:768px"),
+            "@media not screen and (min-width:768px){body{margin:0}}"
+        );
+    }
+
+    #[test]
+    fn layered_stylesheet_nests_the_reset_styles() {
+        let (palantir_sender, _) = broadcast::channel(10);
+        let astroform = Astroform::new(true, true, palantir_sender);
+
+        let layered_rules = astroform.layer_css_rules(
+            "galadriel",
+            Some("*{margin:0}".to_string()),
+            vec![
+                "@import url(\"a.css\");@import url(\"b.css\");".to_string(),
+                "".to_string(),
+                ".pdg-3KtM{padding:10px}".to_string(),
+                "@media screen and (min-width:740px){.pdg-3KtM{padding:20px}}".to_string(),
+            ],
+        );
+
+        assert_eq!(
+            layered_rules,
+            vec![
+                "@import url(\"a.css\") layer(galadriel);".to_string(),
+                "@import url(\"b.css\") layer(galadriel);".to_string(),
+                "@layer galadriel{@layer reset{*{margin:0}}.pdg-3KtM{padding:10px}@media screen and (min-width:740px){.pdg-3KtM{padding:20px}}}".to_string(),
+            ]
+        );
+        assert_eq!(
+            Astroform::wrap_in_css_layer("", "", "", ".clr-UZ6Q{color:red}".to_string()),
+            ".clr-UZ6Q{color:red}"
+        );
+    }
+
+    #[test]
+    fn styles_are_sorted_by_property() {
+        let mut styles_map = IndexMap::from([(
+            "_".to_string(),
+            IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([
+                    (
+                        "color".to_string(),
+                        IndexMap::from([("clr-a1b2".to_string(), "red".to_string())]),
+                    ),
+                    (
+                        "background".to_string(),
+                        IndexMap::from([("bgd-c3d4".to_string(), "blue".to_string())]),
+                    ),
+                ]),
+            )]),
+        )]);
+
+        Astroform::sort_styles_by_property(&mut styles_map);
+
+        assert_eq!(
+            Astroform::transform_pseudo_selector(
+                "",
+                "",
+                "",
+                1,
+                styles_map,
+                &HashMap::new(),
+                &HashMap::new()
+            ),
+            ".bgd-c3d4{background:blue}.clr-a1b2{color:red}"
+        );
+    }
+
+    #[test]
+    fn vendor_prefixes_share_the_utility_rule() {
+        let styles_map = IndexMap::from([(
+            "_".to_string(),
+            IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([(
+                    "user-select".to_string(),
+                    IndexMap::from([("sr-slt-a1b2".to_string(), "none".to_string())]),
+                )]),
+            )]),
+        )]);
+
+        let vendor_prefixes = HashMap::from([(
+            "user-select".to_string(),
+            vec!["-webkit-user-select".to_string()],
+        )]);
+
+        assert_eq!(
+            Astroform::transform_pseudo_selector(
+                "",
+                "",
+                "",
+                1,
+                styles_map,
+                &HashMap::new(),
+                &vendor_prefixes
+            ),
+            ".sr-slt-a1b2{-webkit-user-select:none;user-select:none}"
+        );
+    }
+
+    #[test]
+    fn partition_keeps_only_its_utilities() {
+        let mut styles_map = IndexMap::from([(
+            "_".to_string(),
+            IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([
+                    (
+                        "color".to_string(),
+                        IndexMap::from([
+                            ("\\!clr-a1b2".to_string(), "red".to_string()),
+                            ("clr-e5f6".to_string(), "green".to_string()),
+                        ]),
+                    ),
+                    (
+                        "background".to_string(),
+                        IndexMap::from([("bgd-c3d4".to_string(), "blue".to_string())]),
+                    ),
+                ]),
+            )]),
+        )]);
+
+        Astroform::retain_partition_utilities(
+            &mut styles_map,
+            &HashSet::from(["!clr-a1b2".to_string()]),
+        );
+
+        assert_eq!(
+            Astroform::transform_pseudo_selector(
+                "",
+                "",
+                "",
+                1,
+                styles_map,
+                &HashMap::new(),
+                &HashMap::new()
+            ),
+            ".\\!clr-a1b2{color:red}"
+        );
+    }
+
     fn mock_stylitron() {
         STYLITRON.insert(
             "imports".to_string(),
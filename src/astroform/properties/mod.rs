@@ -0,0 +1,214 @@
+use tokio::task::JoinHandle;
+
+use crate::{
+    configatron::get_stable_output,
+    error::{ErrorAction, ErrorKind},
+    types::Stylitron,
+};
+
+use super::Astroform;
+
+/// Absolute length units, the only ones accepted by the `initial-value` of a `<length>` registration.
+const ABSOLUTE_LENGTH_UNITS: [&str; 7] = ["px", "cm", "mm", "in", "pt", "pc", "q"];
+/// Angle units, inferred as the `<angle>` syntax.
+const ANGLE_UNITS: [&str; 4] = ["deg", "grad", "rad", "turn"];
+/// Time units, inferred as the `<time>` syntax.
+const TIME_UNITS: [&str; 2] = ["ms", "s"];
+/// Color functions, inferred as the `<color>` syntax.
+const COLOR_FUNCTIONS: [&str; 8] = [
+    "rgb(", "rgba(", "hsl(", "hsla(", "hwb(", "lab(", "lch(", "oklch(",
+];
+
+impl Astroform {
+    /// Transforms the variables from the AST into `@property` rules registering them.
+    ///
+    /// # Returns
+    /// - A `JoinHandle` wrapping a `String` containing the `@property` rules.
+    pub fn transform_at_properties(&self) -> JoinHandle<String> {
+        let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let tab = self.tab.to_owned();
+        let space = self.space.to_owned();
+        let newline = self.newline.to_owned();
+        let stable_output = get_stable_output();
+
+        tokio::task::spawn_blocking(move || {
+            tracing::info!("Starting @property registrations transformation process.");
+
+            let mut variables_map = match stylitron.get("variables") {
+                Some(stylitron_data) => match &*stylitron_data {
+                    Stylitron::Variables(ref variables) => variables.to_owned(),
+                    _ => return String::new(),
+                },
+                None => {
+                    Self::send_palantir_error_notification(
+                        ErrorKind::AccessDeniedToStylitronAST,
+                        ErrorAction::Restart,
+                        "Failed to access the variables section in STYLITRON AST",
+                        palantir_sender.clone(),
+                    );
+
+                    return String::new();
+                }
+            };
+
+            // The contexts are ordered by name, since their processing order varies between runs.
+            if stable_output {
+                variables_map.sort_keys();
+            }
+
+            let at_property_rules = variables_map
+                .values()
+                .flat_map(|context_variables| context_variables.values())
+                .filter_map(|variable| match variable.as_slice() {
+                    [unique_name, value, ..] => Some(Self::format_at_property(
+                        &tab,
+                        &space,
+                        &newline,
+                        unique_name,
+                        value,
+                    )),
+                    _ => None,
+                })
+                .collect::<Vec<String>>();
+
+            tracing::info!(
+                "Completed @property registrations transformation process with {} rules.",
+                at_property_rules.len()
+            );
+
+            at_property_rules.join(&newline)
+        })
+    }
+
+    /// Formats the `@property` rule registering a single variable.
+    ///
+    /// The `initial-value` is left out when the value references other variables,
+    /// since the registration only accepts computationally independent values.
+    ///
+    /// # Arguments
+    /// - `tab`: Indentation string (e.g., tabs or spaces).
+    /// - `space`: A space character for formatting.
+    /// - `newline`: A newline character for formatting.
+    /// - `unique_name`: The unique name of the variable, e.g. `--gW1yAqTMgoH`.
+    /// - `value`: The value of the variable.
+    fn format_at_property(
+        tab: &str,
+        space: &str,
+        newline: &str,
+        unique_name: &str,
+        value: &str,
+    ) -> String {
+        let value = value.trim();
+        let mut descriptors = vec![
+            format!("syntax:{}\"{}\";", space, infer_property_syntax(value)),
+            format!("inherits:{}true;", space),
+        ];
+
+        if !value.is_empty() && !value.contains("var(") {
+            descriptors.push(format!("initial-value:{}{};", space, value));
+        }
+
+        format!(
+            "@property {}{}{{{}{}{}}}",
+            unique_name,
+            space,
+            newline,
+            descriptors
+                .iter()
+                .map(|descriptor| format!("{}{}", tab, descriptor))
+                .collect::<Vec<String>>()
+                .join(newline),
+            newline
+        )
+    }
+}
+
+/// Infers the `@property` syntax of a variable from the format of its value.
+///
+/// # Arguments
+/// - `value`: The value of the variable.
+///
+/// # Returns
+/// - The inferred syntax, e.g. `<color>` or `<length>`, or `*` when the type is unknown.
+fn infer_property_syntax(value: &str) -> &'static str {
+    let value = value.trim().to_lowercase();
+
+    if value.contains("var(") {
+        return "*";
+    }
+
+    if is_hex_color(&value)
+        || COLOR_FUNCTIONS
+            .iter()
+            .any(|function| value.starts_with(function) && value.ends_with(')'))
+    {
+        return "<color>";
+    }
+
+    if value.parse::<i64>().is_ok() {
+        return "<integer>";
+    }
+
+    if value.parse::<f64>().is_ok() {
+        return "<number>";
+    }
+
+    let Some(unit_start) = value.find(|c: char| c.is_ascii_alphabetic() || c == '%') else {
+        return "*";
+    };
+    let (number, unit) = value.split_at(unit_start);
+
+    if number.parse::<f64>().is_err() {
+        return "*";
+    }
+
+    match unit {
+        "%" => "<percentage>",
+        unit if ABSOLUTE_LENGTH_UNITS.contains(&unit) => "<length>",
+        unit if ANGLE_UNITS.contains(&unit) => "<angle>",
+        unit if TIME_UNITS.contains(&unit) => "<time>",
+        _ => "*",
+    }
+}
+
+/// Checks whether the value is a hexadecimal color, e.g. `#FFF` or `#FF6677AA`.
+fn is_hex_color(value: &str) -> bool {
+    value.strip_prefix('#').is_some_and(|digits| {
+        [3, 4, 6, 8].contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::astroform::Astroform;
+
+    use super::infer_property_syntax;
+
+    #[test]
+    fn property_syntax_is_inferred_from_the_value() {
+        assert_eq!(infer_property_syntax("#FF6677"), "<color>");
+        assert_eq!(infer_property_syntax("rgba(0, 0, 0, 0.5)"), "<color>");
+        assert_eq!(infer_property_syntax("12px"), "<length>");
+        assert_eq!(infer_property_syntax("50%"), "<percentage>");
+        assert_eq!(infer_property_syntax("45deg"), "<angle>");
+        assert_eq!(infer_property_syntax("200ms"), "<time>");
+        assert_eq!(infer_property_syntax("2"), "<integer>");
+        assert_eq!(infer_property_syntax("0.5"), "<number>");
+        assert_eq!(infer_property_syntax("1.5rem"), "*");
+        assert_eq!(infer_property_syntax("var(--gW1yAqTMgoH)"), "*");
+        assert_eq!(infer_property_syntax("1px solid red"), "*");
+    }
+
+    #[test]
+    fn variables_referencing_others_have_no_initial_value() {
+        assert_eq!(
+            Astroform::format_at_property("", "", "", "--gW1yAqTMgoH", "#FF6677"),
+            "@property --gW1yAqTMgoH{syntax:\"<color>\";inherits:true;initial-value:#FF6677;}"
+        );
+        assert_eq!(
+            Astroform::format_at_property("", "", "", "--gNKGUE7AAmy", "var(--gW1yAqTMgoH)"),
+            "@property --gNKGUE7AAmy{syntax:\"*\";inherits:true;}"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    configatron::get_public_names,
+    types::Clastrack,
+    utils::serialize_classes_tracking::{
+        get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+    },
+};
+
+use super::Astroform;
+
+lazy_static! {
+    // Matches the names that can be used as a CSS class selector without escaping.
+    static ref PUBLIC_NAME_RE: Regex = Regex::new(r"^-?[_a-zA-Z][_a-zA-Z0-9-]*$").unwrap();
+}
+
+/// Represents the Nenyr class referenced by a `publicNames` key.
+#[derive(Clone, PartialEq, Debug)]
+enum PublicNameTarget {
+    /// A class of the central context (`@class:name`).
+    Central(String),
+    /// A class of a layout context (`@layout:context::name`).
+    Layout(String, String),
+    /// A class of a module context (`@module:context::name`).
+    Module(String, String),
+}
+
+impl Astroform {
+    /// Collects the public names aliasing each utility class.
+    ///
+    /// Every entry of the `publicNames` configuration is resolved against the classes tracking maps,
+    /// so each utility class of the referenced Nenyr class receives the public name as an extra selector.
+    /// The hashed utility class names remain the ones stored in the ASTs.
+    ///
+    /// # Arguments
+    /// - `clastrack`: The classes tracking map the public names are resolved against.
+    ///
+    /// # Returns
+    /// - A `HashMap` keyed by the utility class name, holding the public names aliasing it.
+    pub fn collect_public_selectors(
+        clastrack: &DashMap<String, Clastrack>,
+    ) -> HashMap<String, Vec<String>> {
+        let mut public_selectors: HashMap<String, Vec<String>> = HashMap::new();
+        let public_names = get_public_names();
+
+        if public_names.is_empty() {
+            return public_selectors;
+        }
+
+        let central_map = get_tracking_map_from_central(clastrack);
+        let layouts_map = get_tracking_map_from_layouts(clastrack);
+        let modules_map = get_tracking_map_from_modules(clastrack);
+
+        for (class_reference, public_name) in public_names {
+            if !PUBLIC_NAME_RE.is_match(&public_name) {
+                tracing::warn!(
+                    "Public name '{}' of '{}' is not a valid class selector and was ignored.",
+                    public_name,
+                    class_reference
+                );
+
+                continue;
+            }
+
+            // Find the utility classes of the referenced Nenyr class.
+            let utility_names = match Self::parse_public_name_target(&class_reference) {
+                Some(PublicNameTarget::Central(class_name)) => central_map.get(&class_name),
+                Some(PublicNameTarget::Layout(context_name, class_name)) => layouts_map
+                    .get(&context_name)
+                    .and_then(|classes_map| classes_map.get(&class_name)),
+                Some(PublicNameTarget::Module(context_name, class_name)) => modules_map
+                    .get(&context_name)
+                    .and_then(|classes_map| classes_map.get(&class_name)),
+                None => None,
+            };
+
+            match utility_names {
+                Some(utility_names) => {
+                    for utility_name in utility_names.split_whitespace() {
+                        public_selectors
+                            .entry(utility_name.to_string())
+                            .or_default()
+                            .push(public_name.to_owned());
+                    }
+                }
+                None => {
+                    tracing::warn!(
+                        "Public name '{}' references the unknown class '{}' and was ignored.",
+                        public_name,
+                        class_reference
+                    );
+                }
+            }
+        }
+
+        public_selectors
+    }
+
+    /// Parses a `publicNames` key, written in the markup syntax, into the Nenyr class it references.
+    ///
+    /// # Arguments
+    /// - `class_reference`: The key, e.g. `@class:button` or `@module:card::title`.
+    ///
+    /// # Returns
+    /// - `Some(PublicNameTarget)` if the key is valid, or `None` otherwise.
+    fn parse_public_name_target(class_reference: &str) -> Option<PublicNameTarget> {
+        let (context_type, reference) = class_reference.trim().split_once(':')?;
+
+        match (context_type, reference.split_once("::")) {
+            ("@class", None) if !reference.is_empty() => {
+                Some(PublicNameTarget::Central(reference.to_string()))
+            }
+            ("@layout", Some((context_name, class_name)))
+                if !context_name.is_empty() && !class_name.is_empty() =>
+            {
+                Some(PublicNameTarget::Layout(
+                    context_name.to_string(),
+                    class_name.to_string(),
+                ))
+            }
+            ("@module", Some((context_name, class_name)))
+                if !context_name.is_empty() && !class_name.is_empty() =>
+            {
+                Some(PublicNameTarget::Module(
+                    context_name.to_string(),
+                    class_name.to_string(),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::astroform::{public_names::PublicNameTarget, Astroform};
+
+    #[test]
+    fn public_name_targets_are_parsed() {
+        assert_eq!(
+            Astroform::parse_public_name_target("@class:button"),
+            Some(PublicNameTarget::Central("button".to_string()))
+        );
+        assert_eq!(
+            Astroform::parse_public_name_target("@layout:dashboard::sidebar"),
+            Some(PublicNameTarget::Layout(
+                "dashboard".to_string(),
+                "sidebar".to_string()
+            ))
+        );
+        assert_eq!(
+            Astroform::parse_public_name_target("@module:card::title"),
+            Some(PublicNameTarget::Module(
+                "card".to_string(),
+                "title".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_public_name_targets_are_rejected() {
+        assert_eq!(Astroform::parse_public_name_target("button"), None);
+        assert_eq!(Astroform::parse_public_name_target("@class:"), None);
+        assert_eq!(Astroform::parse_public_name_target("@module:card"), None);
+        assert_eq!(Astroform::parse_public_name_target("@theme:dark::x"), None);
+    }
+}
@@ -0,0 +1,211 @@
+use indexmap::IndexMap;
+use tokio::task::JoinHandle;
+
+use crate::{
+    error::{ErrorAction, ErrorKind},
+    types::Stylitron,
+};
+
+use super::Astroform;
+
+/// The properties of the utility classes turned off for the users requesting less motion.
+const MOTION_PROPERTIES: [&str; 4] = [
+    "animation",
+    "animation-name",
+    "transition",
+    "transition-property",
+];
+
+/// The media query matching the users requesting less motion.
+const REDUCED_MOTION_MEDIA_QUERY: &str = "@media (prefers-reduced-motion: reduce)";
+
+type StylesMap = IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>;
+
+impl Astroform {
+    /// Transforms the animated utility classes into a `prefers-reduced-motion` media query turning
+    /// their animations and transitions off.
+    ///
+    /// Only the utility classes generated for the motion properties are overridden, so the
+    /// animations of any other stylesheet are left as they are.
+    ///
+    /// # Returns
+    /// - A `JoinHandle<String>` resolving to the `@media` rule, or an empty string when no utility class is animated.
+    pub fn transform_reduced_motion(&self) -> JoinHandle<String> {
+        let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let clastrack = self.session.clastrack.clone();
+        let tab = self.tab.to_owned();
+        let space = self.space.to_owned();
+        let newline = self.newline.to_owned();
+        let partition = self.partition.to_owned();
+        let vendor_prefixes = Self::get_vendor_prefixes();
+
+        tokio::task::spawn_blocking(move || {
+            tracing::info!("Starting the transformation of the reduced motion styles.");
+
+            let mut styles_maps: Vec<StylesMap> = vec![];
+
+            match stylitron.get("styles") {
+                Some(stylitron_data) => {
+                    if let Stylitron::Styles(ref styles) = &*stylitron_data {
+                        styles_maps.push(styles.to_owned());
+                    }
+                }
+                None => {
+                    Self::send_palantir_error_notification(
+                        ErrorKind::AccessDeniedToStylitronAST,
+                        ErrorAction::Restart,
+                        "Failed to access the styles section in STYLITRON AST",
+                        palantir_sender.clone(),
+                    );
+
+                    return String::new();
+                }
+            }
+
+            // The responsive utility classes are distinct classes, so they are overridden as well.
+            if let Some(stylitron_data) = stylitron.get("responsive") {
+                if let Stylitron::ResponsiveStyles(ref responsive_styles) = &*stylitron_data {
+                    styles_maps.extend(responsive_styles.values().cloned());
+                }
+            }
+
+            let mut motion_styles = collect_motion_styles(styles_maps);
+
+            if let Some(partition) = &partition {
+                Self::retain_partition_utilities(&mut motion_styles, &partition.utility_names);
+            }
+
+            let style_rules = Self::transform_pseudo_selector(
+                &tab,
+                &space,
+                &newline,
+                2,
+                motion_styles,
+                &Self::collect_public_selectors(&clastrack),
+                &vendor_prefixes,
+            );
+
+            if style_rules.is_empty() {
+                return String::new();
+            }
+
+            format!(
+                "{}{}{{{}{}{}}}",
+                REDUCED_MOTION_MEDIA_QUERY, space, newline, style_rules, newline
+            )
+        })
+    }
+}
+
+/// Collects the utility classes of the motion properties, with their values replaced by `none`.
+///
+/// The pseudo-selectors and importance groups are kept, so an `!important` utility class is
+/// overridden by an `!important` declaration as well.
+///
+/// # Arguments
+/// - `styles_maps`: The styles maps of the main and responsive styles.
+fn collect_motion_styles(styles_maps: Vec<StylesMap>) -> StylesMap {
+    let mut motion_styles: StylesMap = IndexMap::new();
+
+    for styles_map in styles_maps {
+        for (pseudo_selector, importance_map) in styles_map {
+            for (importance, properties_map) in importance_map {
+                for (property, class_map) in properties_map {
+                    if !MOTION_PROPERTIES.contains(&property.as_str()) || class_map.is_empty() {
+                        continue;
+                    }
+
+                    motion_styles
+                        .entry(pseudo_selector.to_owned())
+                        .or_default()
+                        .entry(importance.to_owned())
+                        .or_default()
+                        .entry(property)
+                        .or_default()
+                        .extend(
+                            class_map
+                                .into_keys()
+                                .map(|class_name| (class_name, "none".to_string())),
+                        );
+                }
+            }
+        }
+    }
+
+    motion_styles
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::collect_motion_styles;
+
+    #[test]
+    fn only_the_motion_utilities_are_turned_off() {
+        let styles_map = IndexMap::from([(
+            ":hover".to_string(),
+            IndexMap::from([(
+                "!important".to_string(),
+                IndexMap::from([
+                    (
+                        "animation-name".to_string(),
+                        IndexMap::from([(
+                            "\\!hvr\\.ntn-nm-Y1vH".to_string(),
+                            "gpKLT8POASvU".to_string(),
+                        )]),
+                    ),
+                    (
+                        "color".to_string(),
+                        IndexMap::from([(
+                            "\\!hvr\\.clr-UZ6Q".to_string(),
+                            "var(--gW1yAqTMgoH)".to_string(),
+                        )]),
+                    ),
+                ]),
+            )]),
+        )]);
+        let responsive_map = IndexMap::from([(
+            "_".to_string(),
+            IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([(
+                    "transition".to_string(),
+                    IndexMap::from([("mMd\\.trn-a1Ib".to_string(), "all 0.2s".to_string())]),
+                )]),
+            )]),
+        )]);
+
+        let motion_styles = collect_motion_styles(vec![styles_map, responsive_map]);
+
+        assert_eq!(
+            motion_styles,
+            IndexMap::from([
+                (
+                    ":hover".to_string(),
+                    IndexMap::from([(
+                        "!important".to_string(),
+                        IndexMap::from([(
+                            "animation-name".to_string(),
+                            IndexMap::from([(
+                                "\\!hvr\\.ntn-nm-Y1vH".to_string(),
+                                "none".to_string()
+                            )]),
+                        )]),
+                    )]),
+                ),
+                (
+                    "_".to_string(),
+                    IndexMap::from([(
+                        "_".to_string(),
+                        IndexMap::from([(
+                            "transition".to_string(),
+                            IndexMap::from([("mMd\\.trn-a1Ib".to_string(), "none".to_string())]),
+                        )]),
+                    )]),
+                ),
+            ])
+        );
+    }
+}
@@ -1,12 +1,17 @@
+use dashmap::DashMap;
+use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
+    configatron::{
+        get_css_layer, get_emit_charset, get_sort_output_by_property, get_stable_output,
+    },
     error::{ErrorAction, ErrorKind},
     types::Stylitron,
+    utils::format_media_query::format_media_query,
 };
 
-use super::Astroform;
+use super::{Astroform, CHARSET_RULE, RESPONSIVE_SHEET_KEY_PREFIX};
 
 impl Astroform {
     /// Transforms responsive styles from the AST into CSS media query rules.
@@ -15,21 +20,34 @@ impl Astroform {
     /// and converts it into properly formatted CSS `@media` rules. The function runs
     /// in a separate blocking task to ensure that the async runtime is not affected.
     ///
+    /// When the responsive styles are split, the `min-width` and `max-width` media queries are stored in
+    /// their own stylesheets instead of being returned.
+    ///
     /// # Returns
     /// - A `JoinHandle<String>` that resolves to a string containing all responsive CSS rules.
     pub fn transform_responsive_styles(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let clastrack = self.session.clastrack.clone();
+        let cascadex = self.session.cascadex.clone();
         let tab = self.tab.to_owned();
         let space = self.space.to_owned();
         let newline = self.newline.to_owned();
+        let sort_by_property = get_sort_output_by_property();
+        let stable_output = get_stable_output();
+        let partition = self.partition.to_owned();
+        let vendor_prefixes = Self::get_vendor_prefixes();
+        // A context stylesheet always holds its whole responsive styles.
+        let split_responsive = self.split_responsive && partition.is_none();
 
         // Spawn a blocking task for processing the responsive styles.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting transformation of responsive styles.");
 
             let mut responsive_css_rules: Vec<String> = vec![];
+            let mut split_css_rules: IndexMap<&str, Vec<String>> = IndexMap::new();
 
-            let responsive_styles_map = match STYLITRON.get("responsive") {
+            let responsive_styles_map = match stylitron.get("responsive") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::ResponsiveStyles(ref styles) => styles.to_owned(),
                     _ => return String::new(),
@@ -46,30 +64,282 @@ impl Astroform {
                 }
             };
 
-            // Iterate over the responsive styles map to process each breakpoint and its styles.
-            for (breakpoint, styles_map) in responsive_styles_map {
+            let public_selectors = Self::collect_public_selectors(&clastrack);
+
+            // Group every breakpoint sharing the same media condition, so each condition opens a single `@media` block.
+            let grouped_styles_map = Self::group_responsive_styles(responsive_styles_map);
+
+            // Iterate over the grouped styles map to process each breakpoint and its styles.
+            for (breakpoint, mut styles_map) in grouped_styles_map {
                 tracing::debug!(
                     "Processing responsive styles for breakpoint: {}",
                     breakpoint
                 );
 
+                if let Some(partition) = &partition {
+                    Self::retain_partition_utilities(&mut styles_map, &partition.utility_names);
+                }
+
+                if stable_output {
+                    Self::sort_styles_for_stable_output(&mut styles_map);
+                } else if sort_by_property {
+                    Self::sort_styles_by_property(&mut styles_map);
+                }
+
                 // Transform the styles for the current breakpoint using `transform_pseudo_selector`.
-                let style_rules =
-                    Self::transform_pseudo_selector(&tab, &space, &newline, 2, styles_map);
-
-                if !style_rules.is_empty() {
-                    // Format the rules into a `@media` query and add it to the result vector.
-                    responsive_css_rules.push(format!(
-                        "@media screen and ({}){}{{{}{}{}}}",
-                        breakpoint, space, newline, style_rules, newline
-                    ));
+                let style_rules = Self::transform_pseudo_selector(
+                    &tab,
+                    &space,
+                    &newline,
+                    2,
+                    styles_map,
+                    &public_selectors,
+                    &vendor_prefixes,
+                );
+
+                if style_rules.is_empty() {
+                    continue;
+                }
+
+                // Format the rules into a `@media` query.
+                let media_rule = format!(
+                    "{}{}{{{}{}{}}}",
+                    format_media_query(&breakpoint),
+                    space,
+                    newline,
+                    style_rules,
+                    newline
+                );
+
+                match Self::resolve_responsive_sheet(&breakpoint).filter(|_| split_responsive) {
+                    Some(responsive_sheet) => split_css_rules
+                        .entry(responsive_sheet)
+                        .or_default()
+                        .push(media_rule),
+                    None => responsive_css_rules.push(media_rule),
                 }
             }
 
+            if partition.is_none() {
+                Self::store_responsive_sheets(&cascadex, &space, &newline, split_css_rules);
+            }
+
             tracing::info!("Finished transforming responsive styles into CSS media queries.");
 
             // Join all formatted `@media` rules with the newline character and return.
             responsive_css_rules.join(&newline)
         })
     }
+
+    /// Resolves the split stylesheet of a media condition, from the schema its breakpoint was declared in.
+    ///
+    /// # Arguments
+    /// - `breakpoint`: The normalized media condition, e.g. `min-width:640px`.
+    ///
+    /// # Returns
+    /// - `Some("mobile-first")` for `min-width` conditions, `Some("desktop-first")` for `max-width` ones,
+    ///   and `None` for any other condition, which stays in the main stylesheet.
+    fn resolve_responsive_sheet(breakpoint: &str) -> Option<&'static str> {
+        if breakpoint.starts_with("min-width") {
+            return Some("mobile-first");
+        }
+
+        if breakpoint.starts_with("max-width") {
+            return Some("desktop-first");
+        }
+
+        None
+    }
+
+    /// Replaces the split responsive stylesheets of a previous transformation with the new ones.
+    ///
+    /// # Arguments
+    /// - `cascadex`: The stylesheets of the session being transformed.
+    /// - `space`: The space string used for formatting.
+    /// - `newline`: The newline string used for formatting.
+    /// - `split_css_rules`: The `@media` rules of each split stylesheet.
+    fn store_responsive_sheets(
+        cascadex: &DashMap<String, String>,
+        space: &str,
+        newline: &str,
+        split_css_rules: IndexMap<&str, Vec<String>>,
+    ) {
+        cascadex.retain(|key, _| !key.starts_with(RESPONSIVE_SHEET_KEY_PREFIX));
+
+        for (responsive_sheet, media_rules) in split_css_rules {
+            tracing::debug!(
+                "Splitting the {} responsive styles into their own stylesheet.",
+                responsive_sheet
+            );
+
+            // Each split stylesheet is served on its own, so it declares its own charset, and
+            // it joins the same cascade layer as the main one.
+            let mut sheet_rules = Self::wrap_in_css_layer(
+                &get_css_layer(),
+                space,
+                newline,
+                media_rules.join(newline),
+            );
+
+            if get_emit_charset() {
+                sheet_rules = format!("{}{}{}", CHARSET_RULE, newline, sheet_rules);
+            }
+
+            cascadex.insert(
+                format!("{}{}", RESPONSIVE_SHEET_KEY_PREFIX, responsive_sheet),
+                sheet_rules,
+            );
+        }
+    }
+
+    /// Groups the responsive styles by their normalized media condition.
+    ///
+    /// Breakpoints are defined per context, so the same condition may reach the responsive
+    /// node written in slightly different ways (e.g. `min-width:360px` and `min-width: 360px`).
+    /// Merging them here guarantees that each media condition is emitted only once.
+    ///
+    /// # Arguments
+    /// - `responsive_styles_map`: The responsive styles node, keyed by breakpoint condition.
+    ///
+    /// # Returns
+    /// - An `IndexMap` keyed by the normalized condition, holding the merged styles of every matching breakpoint.
+    fn group_responsive_styles(
+        responsive_styles_map: IndexMap<
+            String,
+            IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+        >,
+    ) -> IndexMap<
+        String,
+        IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+    > {
+        let mut grouped_styles_map: IndexMap<
+            String,
+            IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+        > = IndexMap::new();
+
+        for (breakpoint, styles_map) in responsive_styles_map {
+            // Whitespace and letter case are irrelevant to the media condition itself.
+            let condition: String = breakpoint
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_lowercase();
+
+            tracing::debug!(
+                "Grouping responsive styles of breakpoint '{}' under condition '{}'",
+                breakpoint,
+                condition
+            );
+
+            let grouped_patterns = grouped_styles_map.entry(condition).or_default();
+
+            // Merge pattern -> importance -> property -> class -> value, keeping the first insertion order.
+            for (pattern, importance_map) in styles_map {
+                let grouped_importance = grouped_patterns.entry(pattern).or_default();
+
+                for (importance, properties_map) in importance_map {
+                    let grouped_properties = grouped_importance.entry(importance).or_default();
+
+                    for (property, class_map) in properties_map {
+                        grouped_properties
+                            .entry(property)
+                            .or_default()
+                            .extend(class_map);
+                    }
+                }
+            }
+        }
+
+        grouped_styles_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::astroform::Astroform;
+
+    #[test]
+    fn equivalent_breakpoints_are_grouped() {
+        let styles = |class_name: &str, value: &str| {
+            IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([(
+                    "!important".to_string(),
+                    IndexMap::from([(
+                        "display".to_string(),
+                        IndexMap::from([(class_name.to_string(), value.to_string())]),
+                    )]),
+                )]),
+            )])
+        };
+
+        let grouped = Astroform::group_responsive_styles(IndexMap::from([
+            ("min-width:360px".to_string(), styles("a", "block")),
+            ("max-width:1024px".to_string(), styles("b", "flex")),
+            ("min-width: 360px".to_string(), styles("c", "grid")),
+        ]));
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(
+            format!("{:?}", grouped.get("min-width:360px")),
+            "Some({\"_\": {\"!important\": {\"display\": {\"a\": \"block\", \"c\": \"grid\"}}}})"
+        );
+    }
+
+    #[test]
+    fn stable_output_ignores_insertion_order() {
+        let styles = |entries: Vec<(&str, &str, &str)>| {
+            let mut properties_map: IndexMap<String, IndexMap<String, String>> = IndexMap::new();
+
+            for (property, class_name, value) in entries {
+                properties_map
+                    .entry(property.to_string())
+                    .or_default()
+                    .insert(class_name.to_string(), value.to_string());
+            }
+
+            IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([("_".to_string(), properties_map)]),
+            )])
+        };
+
+        let mut first_styles = styles(vec![
+            ("display", "b", "flex"),
+            ("color", "c", "red"),
+            ("display", "a", "block"),
+        ]);
+        let mut second_styles = styles(vec![
+            ("color", "c", "red"),
+            ("display", "a", "block"),
+            ("display", "b", "flex"),
+        ]);
+
+        Astroform::sort_styles_for_stable_output(&mut first_styles);
+        Astroform::sort_styles_for_stable_output(&mut second_styles);
+
+        assert_eq!(
+            format!("{:?}", first_styles),
+            "{\"_\": {\"_\": {\"color\": {\"c\": \"red\"}, \"display\": {\"a\": \"block\", \"b\": \"flex\"}}}}"
+        );
+        assert_eq!(
+            format!("{:?}", first_styles),
+            format!("{:?}", second_styles)
+        );
+    }
+
+    #[test]
+    fn breakpoints_are_split_by_responsive_approach() {
+        assert_eq!(
+            Astroform::resolve_responsive_sheet("min-width:640px"),
+            Some("mobile-first")
+        );
+        assert_eq!(
+            Astroform::resolve_responsive_sheet("max-width:1024px"),
+            Some("desktop-first")
+        );
+        assert_eq!(Astroform::resolve_responsive_sheet("print"), None);
+    }
 }
@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::json;
+use tokio::task::JoinHandle;
+
+use crate::types::ClassOrigin;
+
+use super::Astroform;
+
+/// The base64 digits of the VLQ encoded mappings.
+const BASE64_DIGITS: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+lazy_static! {
+    // Matches a class selector, including the escaped characters of the utility class names.
+    static ref CLASS_SELECTOR_RE: Regex = Regex::new(r"\.((?:\\.|[\w-])+)").unwrap();
+}
+
+/// Links a class selector of the generated CSS to the declaration of its Nenyr class.
+/// Every line and column is zero-based.
+#[derive(Clone, PartialEq, Debug)]
+struct SourceMapping {
+    generated_line: usize,
+    generated_column: usize,
+    source_index: usize,
+    source_line: usize,
+    source_column: usize,
+    name_index: usize,
+}
+
+impl Astroform {
+    /// Spawns the generation of the source map of a stylesheet.
+    ///
+    /// # Arguments
+    /// - `css`: The stylesheet the source map is generated for.
+    ///
+    /// # Returns
+    /// - A `JoinHandle<String>` containing the source map as a JSON string.
+    pub fn transform_source_map(&self, css: String) -> JoinHandle<String> {
+        let originator = self.session.originator.clone();
+
+        tokio::task::spawn_blocking(move || {
+            tracing::info!("Starting the generation of the source map.");
+
+            Self::generate_source_map(&css, &originator)
+        })
+    }
+
+    /// Generates a Source Map v3 linking every utility class selector of a stylesheet to the
+    /// declaration of the Nenyr class it was generated for.
+    ///
+    /// The selectors whose utility class has no recorded origin are left unmapped, and a class
+    /// declaration that cannot be found in its file is mapped to the start of the file. The `file`
+    /// entry is left out, as it is only known once the build names the stylesheet it writes.
+    ///
+    /// # Arguments
+    /// - `css`: The stylesheet the source map is generated for.
+    /// - `originator`: The Nenyr class each utility class was generated for.
+    ///
+    /// # Returns
+    /// - A `String` containing the source map as JSON.
+    fn generate_source_map(css: &str, originator: &DashMap<String, ClassOrigin>) -> String {
+        let mut sources: Vec<String> = vec![];
+        let mut names: Vec<String> = vec![];
+        let mut mappings: Vec<SourceMapping> = vec![];
+
+        // Every Nenyr file is read once, and every class declaration is searched once.
+        let mut sources_content: HashMap<String, Option<String>> = HashMap::new();
+        let mut declarations: HashMap<(String, String), (usize, usize)> = HashMap::new();
+
+        let mut generated_line = 0;
+        let mut line_start = 0;
+
+        for selector in CLASS_SELECTOR_RE.captures_iter(css) {
+            let (Some(selector_match), Some(class_match)) = (selector.get(0), selector.get(1))
+            else {
+                continue;
+            };
+
+            let Some(origin) = originator.get(class_match.as_str()) else {
+                continue;
+            };
+
+            // Advance the generated line up to the current selector.
+            if let Some(last_newline) = css[line_start..selector_match.start()].rfind('\n') {
+                generated_line += css[line_start..selector_match.start()]
+                    .matches('\n')
+                    .count();
+                line_start += last_newline + 1;
+            }
+
+            let (source_line, source_column) = *declarations
+                .entry((origin.file_path.to_owned(), origin.class_name.to_owned()))
+                .or_insert_with(|| {
+                    sources_content
+                        .entry(origin.file_path.to_owned())
+                        .or_insert_with(|| std::fs::read_to_string(&origin.file_path).ok())
+                        .as_deref()
+                        .and_then(|content| {
+                            Self::find_class_declaration(content, &origin.class_name)
+                        })
+                        .unwrap_or((0, 0))
+                });
+
+            mappings.push(SourceMapping {
+                generated_line,
+                generated_column: css[line_start..selector_match.start()].chars().count(),
+                source_index: Self::position_or_push(&mut sources, &origin.file_path),
+                source_line,
+                source_column,
+                name_index: Self::position_or_push(&mut names, &origin.class_name),
+            });
+        }
+
+        tracing::debug!(
+            "Mapped {} selectors to {} Nenyr files.",
+            mappings.len(),
+            sources.len()
+        );
+
+        json!({
+            "version": 3,
+            "sources": sources,
+            "names": names,
+            "mappings": Self::encode_source_mappings(&mappings),
+        })
+        .to_string()
+    }
+
+    /// Finds the zero-based line and column of a Nenyr class declaration.
+    ///
+    /// # Arguments
+    /// - `content`: The content of the Nenyr file.
+    /// - `class_name`: The name of the declared class.
+    ///
+    /// # Returns
+    /// - `Some((line, column))` pointing to the `Class` keyword, or `None` if it is not found.
+    fn find_class_declaration(content: &str, class_name: &str) -> Option<(usize, usize)> {
+        let declaration_re = Regex::new(&format!(
+            r#"Class\(\s*["']{}["']\s*\)"#,
+            regex::escape(class_name)
+        ))
+        .ok()?;
+
+        let preceding = &content[..declaration_re.find(content)?.start()];
+        let line_start = preceding.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Some((
+            preceding.matches('\n').count(),
+            preceding[line_start..].chars().count(),
+        ))
+    }
+
+    /// Retrieves the index of a value in a list, appending the value when it is missing.
+    fn position_or_push(values: &mut Vec<String>, value: &str) -> usize {
+        match values.iter().position(|current| current == value) {
+            Some(index) => index,
+            None => {
+                values.push(value.to_string());
+                values.len() - 1
+            }
+        }
+    }
+
+    /// Encodes the mappings of a source map, ordered by their generated position.
+    ///
+    /// Lines are separated by `;` and segments by `,`. The generated column is relative to the
+    /// previous segment of the same line, while the source index, source line, source column and
+    /// name index are relative to the previous segment of the whole map.
+    fn encode_source_mappings(mappings: &[SourceMapping]) -> String {
+        let mut encoded = String::new();
+        let mut current_line = 0;
+        let mut is_line_start = true;
+        let mut previous_generated_column = 0;
+        let mut previous_source_index = 0;
+        let mut previous_source_line = 0;
+        let mut previous_source_column = 0;
+        let mut previous_name_index = 0;
+
+        for mapping in mappings {
+            while current_line < mapping.generated_line {
+                encoded.push(';');
+                current_line += 1;
+                is_line_start = true;
+                previous_generated_column = 0;
+            }
+
+            if !is_line_start {
+                encoded.push(',');
+            }
+
+            let fields = [
+                (mapping.generated_column, &mut previous_generated_column),
+                (mapping.source_index, &mut previous_source_index),
+                (mapping.source_line, &mut previous_source_line),
+                (mapping.source_column, &mut previous_source_column),
+                (mapping.name_index, &mut previous_name_index),
+            ];
+
+            for (value, previous) in fields {
+                Self::encode_vlq(value as i64 - *previous as i64, &mut encoded);
+                *previous = value;
+            }
+
+            is_line_start = false;
+        }
+
+        encoded
+    }
+
+    /// Appends a value encoded as a base64 VLQ, as used by the source map mappings.
+    fn encode_vlq(value: i64, encoded: &mut String) {
+        // The sign is moved to the least significant bit.
+        let mut vlq = if value < 0 {
+            ((-value) << 1) | 1
+        } else {
+            value << 1
+        };
+
+        loop {
+            let mut digit = (vlq & 0b11111) as usize;
+            vlq >>= 5;
+
+            // The continuation bit marks that more digits follow.
+            if vlq > 0 {
+                digit |= 0b100000;
+            }
+
+            encoded.push(BASE64_DIGITS[digit] as char);
+
+            if vlq == 0 {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dashmap::DashMap;
+    use serde_json::Value;
+
+    use crate::{astroform::Astroform, types::ClassOrigin};
+
+    #[test]
+    fn values_are_vlq_encoded() {
+        let mut encoded = String::new();
+
+        for value in [0, 1, -1, 16, 123] {
+            Astroform::encode_vlq(value, &mut encoded);
+            encoded.push(' ');
+        }
+
+        assert_eq!(encoded, "A C D gB 2H ");
+    }
+
+    #[test]
+    fn selectors_are_mapped_to_class_declarations() {
+        let originator = DashMap::new();
+
+        originator.insert(
+            "\\!bgd-rTLF".to_string(),
+            ClassOrigin {
+                file_path: "src/crealion/mocks/central.nyr".to_string(),
+                class_name: "celestialHeron".to_string(),
+            },
+        );
+
+        let source_map = Astroform::generate_source_map(
+            ".clr-sEsN{color:red}\n.\\!bgd-rTLF{background:#00FF00!important}",
+            &originator,
+        );
+
+        let source_map: Value = serde_json::from_str(&source_map).unwrap();
+
+        assert_eq!(source_map["version"], 3);
+        assert_eq!(source_map["sources"][0], "src/crealion/mocks/central.nyr");
+        assert_eq!(source_map["names"][0], "celestialHeron");
+        // Only the second line is mapped, to line 131 and column 12 of the Nenyr file.
+        assert_eq!(source_map["mappings"], ";AAkIYA");
+    }
+}
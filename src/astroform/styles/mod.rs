@@ -1,7 +1,7 @@
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
+    configatron::{get_sort_output_by_property, get_stable_output},
     error::{ErrorAction, ErrorKind},
     types::Stylitron,
 };
@@ -20,15 +20,21 @@ impl Astroform {
     /// - A `JoinHandle<String>` containing the resulting CSS rules as a string.
     pub fn transform_styles(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let clastrack = self.session.clastrack.clone();
         let tab = self.tab.to_owned();
         let space = self.space.to_owned();
         let newline = self.newline.to_owned();
+        let sort_by_property = get_sort_output_by_property();
+        let stable_output = get_stable_output();
+        let partition = self.partition.to_owned();
+        let vendor_prefixes = Self::get_vendor_prefixes();
 
         // Spawn a blocking task to process the styles.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting the transformation of styles.");
 
-            let styles_map = match STYLITRON.get("styles") {
+            let mut styles_map = match stylitron.get("styles") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::Styles(ref styles) => styles.to_owned(),
                     _ => return String::new(),
@@ -45,11 +51,29 @@ impl Astroform {
                 }
             };
 
+            if let Some(partition) = &partition {
+                Self::retain_partition_utilities(&mut styles_map, &partition.utility_names);
+            }
+
+            if stable_output {
+                Self::sort_styles_for_stable_output(&mut styles_map);
+            } else if sort_by_property {
+                Self::sort_styles_by_property(&mut styles_map);
+            }
+
             tracing::debug!("Starting to transform styles map into CSS rules using 'transform_pseudo_selector'.");
 
             // Use the `transform_pseudo_selector` helper function to process the styles map into CSS rules.
             // The second argument (1) indicates the level of indentation for the resulting rules.
-            Self::transform_pseudo_selector(&tab, &space, &newline, 1, styles_map)
+            Self::transform_pseudo_selector(
+                &tab,
+                &space,
+                &newline,
+                1,
+                styles_map,
+                &Self::collect_public_selectors(&clastrack),
+                &vendor_prefixes,
+            )
         })
     }
 }
@@ -1,12 +1,23 @@
-use tokio::task::JoinHandle;
+use chrono::Local;
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use tokio::{sync::broadcast, task::JoinHandle};
 
 use crate::{
-    asts::STYLITRON,
+    configatron::{get_css_layer, get_emit_charset, get_stable_output, get_theme_var_fallback},
     error::{ErrorAction, ErrorKind},
+    events::GaladrielAlerts,
     types::Stylitron,
+    utils::send_palantir_notification::send_palantir_notification,
 };
 
-use super::Astroform;
+use super::{Astroform, CHARSET_RULE, THEME_SHEET_KEY_PREFIX};
+
+/// The theme schema kept inline in the main stylesheet when the themes are split.
+const DEFAULT_THEME_SCHEMA: &str = "light";
+
+/// The theme schemas a fallback schema can fill the missing variables of.
+const THEME_SCHEMAS: &[&str] = &["light", "dark"];
 
 impl Astroform {
     /// Transforms the themes from the STYLITRON AST into CSS rules.
@@ -20,16 +31,21 @@ impl Astroform {
     /// - A `JoinHandle<String>` containing the resulting CSS rules for the themes as a string.
     pub fn transform_themes(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let cascadex = self.session.cascadex.clone();
         let tab = self.tab.to_owned();
         let space = self.space.to_owned();
         let newline = self.newline.to_owned();
         let is_minified = self.is_minified;
+        let split_themes = self.split_themes;
+        let fallback_schema = get_theme_var_fallback();
+        let stable_output = get_stable_output();
 
         // Spawn a blocking task to process the themes.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting to transform themes from STYLITRON AST.");
 
-            let themes_map = match STYLITRON.get("themes") {
+            let mut themes_map = match stylitron.get("themes") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::Themes(ref themes) => themes.to_owned(),
                     _ => return String::new(),
@@ -46,53 +62,32 @@ impl Astroform {
                 }
             };
 
-            // Vectors to store the variables for the light and dark themes.
-            let mut light_variables: Vec<String> = vec![];
-            let mut dark_variables: Vec<String> = vec![];
-
-            // Iterate through the themes map to process each context and theme schema.
-            for (context_name, context_variables) in themes_map {
-                tracing::info!("Processing theme context: {}", context_name);
+            // Sorting the contexts keeps the theme variables in the same order on every rebuild.
+            if stable_output {
+                themes_map.sort_keys();
+            }
 
-                for (theme_schema, schema_variables) in context_variables {
-                    tracing::debug!("Processing theme schema: {}", theme_schema);
+            let (light_variables, dark_variables) = Self::collect_themes_variables(
+                &tab,
+                &space,
+                &newline,
+                is_minified,
+                &fallback_schema,
+                themes_map,
+                Some(&palantir_sender),
+            );
 
-                    // Transform the context variables into CSS variables.
-                    let formatted_variables = Self::transform_context_variables(
-                        &tab,
-                        &space,
-                        &newline,
-                        2,
-                        schema_variables,
-                    );
+            // Stylesheets split on a previous transformation are stale from now on.
+            cascadex.retain(|key, _| !key.starts_with(THEME_SHEET_KEY_PREFIX));
 
-                    if formatted_variables.is_empty() {
-                        continue;
-                    }
-
-                    // Check if the theme is "light" or "dark" and add the appropriate variables.
-                    if theme_schema == "light" {
-                        // Add a comment for the light theme if not minified.
-                        Self::add_comment_if_minified(
-                            &tab,
-                            is_minified,
-                            &context_name,
-                            &mut light_variables,
-                        );
-
-                        light_variables.push(formatted_variables);
-                    } else {
-                        // Add a comment for the light theme if not minified.
-                        Self::add_comment_if_minified(
-                            &tab,
-                            is_minified,
-                            &context_name,
-                            &mut dark_variables,
-                        );
-
-                        dark_variables.push(formatted_variables);
-                    }
-                }
+            if split_themes {
+                return Self::split_themes(
+                    &cascadex,
+                    &space,
+                    &newline,
+                    light_variables,
+                    dark_variables,
+                );
             }
 
             // Format the light and dark theme variables into valid CSS and return the result.
@@ -100,6 +95,247 @@ impl Astroform {
         })
     }
 
+    /// Transforms the themes from the STYLITRON AST into a standalone stylesheet.
+    ///
+    /// Unlike `transform_themes`, the schemas are not tied to `prefers-color-scheme`: the light theme is
+    /// declared under `:root` and the dark one under `[data-theme="dark"]`, so the application switches
+    /// between them. The variables keep the unique names of their contexts, so a layout or module theme
+    /// never overrides the central one outside the classes referencing it.
+    ///
+    /// # Returns
+    /// - A `JoinHandle<String>` containing the themes stylesheet.
+    pub fn transform_themes_sheet(&self) -> JoinHandle<String> {
+        let stylitron = self.session.stylitron.clone();
+        let tab = self.tab.to_owned();
+        let space = self.space.to_owned();
+        let newline = self.newline.to_owned();
+        let is_minified = self.is_minified;
+        let fallback_schema = get_theme_var_fallback();
+        let stable_output = get_stable_output();
+
+        tokio::task::spawn_blocking(move || {
+            tracing::info!("Starting to transform themes into a standalone stylesheet.");
+
+            let mut themes_map = match stylitron.get("themes") {
+                Some(stylitron_data) => match &*stylitron_data {
+                    Stylitron::Themes(ref themes) => themes.to_owned(),
+                    _ => return String::new(),
+                },
+                None => return String::new(),
+            };
+
+            if stable_output {
+                themes_map.sort_keys();
+            }
+
+            // The fallbacks were already reported while transforming the main stylesheet.
+            let (light_variables, dark_variables) = Self::collect_themes_variables(
+                &tab,
+                &space,
+                &newline,
+                is_minified,
+                &fallback_schema,
+                themes_map,
+                None,
+            );
+
+            Self::format_themes_sheet(&space, &newline, light_variables, dark_variables)
+        })
+    }
+
+    /// Collects the formatted variables of the light and dark theme schemas of every context.
+    ///
+    /// # Parameters
+    /// - `tab`: The tab string used for indentation.
+    /// - `space`: The space string used for formatting.
+    /// - `newline`: The newline string used for formatting.
+    /// - `is_minified`: A boolean indicating whether the output is minified.
+    /// - `fallback_schema`: The schema providing the variables missing from the other schemas.
+    /// - `themes_map`: The theme schemas of every context.
+    /// - `palantir_sender`: The sender notified of each variable filled from the fallback schema, if any.
+    ///
+    /// # Returns
+    /// The variables of the light theme, followed by the ones of the dark theme.
+    fn collect_themes_variables(
+        tab: &str,
+        space: &str,
+        newline: &str,
+        is_minified: bool,
+        fallback_schema: &str,
+        themes_map: IndexMap<String, IndexMap<String, IndexMap<String, Vec<String>>>>,
+        palantir_sender: Option<&broadcast::Sender<GaladrielAlerts>>,
+    ) -> (Vec<String>, Vec<String>) {
+        // Vectors to store the variables for the light and dark themes.
+        let mut light_variables: Vec<String> = vec![];
+        let mut dark_variables: Vec<String> = vec![];
+
+        // Iterate through the themes map to process each context and theme schema.
+        for (context_name, mut context_variables) in themes_map {
+            tracing::info!("Processing theme context: {}", context_name);
+
+            // Fills the variables omitted by a schema with the values of the fallback schema.
+            for (schema, identifier) in
+                Self::fill_missing_theme_variables(fallback_schema, &mut context_variables)
+            {
+                let Some(palantir_sender) = palantir_sender else {
+                    continue;
+                };
+
+                let notification = GaladrielAlerts::create_information(
+                    Local::now(),
+                    &format!(
+                        "The `{}` variable is missing from the `{}` theme of the `{}` context, so the value of the `{}` theme was used.",
+                        identifier,
+                        schema,
+                        Self::resolve_context_name(&context_name),
+                        fallback_schema
+                    ),
+                );
+
+                send_palantir_notification(notification, palantir_sender.clone());
+            }
+
+            for (theme_schema, schema_variables) in context_variables {
+                tracing::debug!("Processing theme schema: {}", theme_schema);
+
+                // Transform the context variables into CSS variables.
+                let formatted_variables =
+                    Self::transform_context_variables(tab, space, newline, 2, schema_variables);
+
+                if formatted_variables.is_empty() {
+                    continue;
+                }
+
+                // Check if the theme is "light" or "dark" and add the appropriate variables.
+                if theme_schema == "light" {
+                    // Add a comment for the light theme if not minified.
+                    Self::add_comment_if_minified(
+                        tab,
+                        is_minified,
+                        &context_name,
+                        &mut light_variables,
+                    );
+
+                    light_variables.push(formatted_variables);
+                } else {
+                    // Add a comment for the light theme if not minified.
+                    Self::add_comment_if_minified(
+                        tab,
+                        is_minified,
+                        &context_name,
+                        &mut dark_variables,
+                    );
+
+                    dark_variables.push(formatted_variables);
+                }
+            }
+        }
+
+        (light_variables, dark_variables)
+    }
+
+    /// Formats the light and dark theme variables into a standalone stylesheet, with the light theme
+    /// under `:root` and the dark one under `[data-theme="dark"]`.
+    ///
+    /// # Parameters
+    /// - `space`: The space string used for formatting.
+    /// - `newline`: The newline string used for formatting.
+    /// - `light_variables`: The vector containing the variables for the light theme.
+    /// - `dark_variables`: The vector containing the variables for the dark theme.
+    ///
+    /// # Returns
+    /// A string containing the themes stylesheet.
+    fn format_themes_sheet(
+        space: &str,
+        newline: &str,
+        light_variables: Vec<String>,
+        dark_variables: Vec<String>,
+    ) -> String {
+        let mut theme_rules: Vec<String> = vec![];
+
+        if get_emit_charset() {
+            theme_rules.push(CHARSET_RULE.to_string());
+        }
+
+        for (selector, variables) in [
+            (":root", light_variables),
+            ("[data-theme=\"dark\"]", dark_variables),
+        ] {
+            if variables.is_empty() {
+                continue;
+            }
+
+            theme_rules.push(format!(
+                "{}{}{{{}{}{}}}",
+                selector,
+                space,
+                newline,
+                variables.join(newline),
+                newline
+            ));
+        }
+
+        theme_rules.join(newline)
+    }
+
+    /// Fills the variables missing from every theme schema of a context with the ones of the fallback schema.
+    ///
+    /// Only the schemas declared by the context are filled, and each filled variable keeps the unique
+    /// name of its fallback, so the same `var()` resolves in every schema.
+    ///
+    /// # Parameters
+    /// - `fallback_schema`: The schema providing the missing variables, or an empty string to disable the fallback.
+    /// - `context_variables`: The theme schemas of a context and their variables.
+    ///
+    /// # Returns
+    /// The schema and relative name of every filled variable.
+    fn fill_missing_theme_variables(
+        fallback_schema: &str,
+        context_variables: &mut IndexMap<String, IndexMap<String, Vec<String>>>,
+    ) -> Vec<(String, String)> {
+        if fallback_schema.is_empty() {
+            return vec![];
+        }
+
+        if !THEME_SCHEMAS.contains(&fallback_schema) {
+            tracing::warn!(
+                "Theme variables fallback '{}' is not a theme schema and was ignored.",
+                fallback_schema
+            );
+
+            return vec![];
+        }
+
+        let fallback_variables = match context_variables.get(fallback_schema) {
+            Some(fallback_variables) => fallback_variables.to_owned(),
+            None => return vec![],
+        };
+
+        let mut filled_variables: Vec<(String, String)> = vec![];
+
+        for (schema, schema_variables) in context_variables.iter_mut() {
+            if schema == fallback_schema {
+                continue;
+            }
+
+            for (identifier, variable_entry) in &fallback_variables {
+                if !schema_variables.contains_key(identifier) {
+                    tracing::debug!(
+                        "Filling '{}' of the '{}' theme from the '{}' theme.",
+                        identifier,
+                        schema,
+                        fallback_schema
+                    );
+
+                    schema_variables.insert(identifier.to_owned(), variable_entry.to_owned());
+                    filled_variables.push((schema.to_owned(), identifier.to_owned()));
+                }
+            }
+        }
+
+        filled_variables
+    }
+
     /// Formats the light and dark theme variables into valid CSS rules.
     ///
     /// This function takes the variables for both light and dark themes and formats them into valid
@@ -153,6 +389,65 @@ impl Astroform {
         }
     }
 
+    /// Splits the theme schemas, so each one can be loaded on its own.
+    ///
+    /// The default theme is returned to be emitted inline, while every other schema is stored
+    /// in `CASCADEX` under its `theme_sheet:<schema>` key. Both are formatted without the
+    /// `prefers-color-scheme` media query, as the application decides which theme is loaded.
+    ///
+    /// # Parameters
+    /// - `cascadex`: The stylesheets of the session being transformed.
+    /// - `space`: The space string used for formatting.
+    /// - `newline`: The newline string used for formatting.
+    /// - `light_variables`: The vector containing the variables for the light theme.
+    /// - `dark_variables`: The vector containing the variables for the dark theme.
+    ///
+    /// # Returns
+    /// A string containing the CSS rules of the default theme.
+    fn split_themes(
+        cascadex: &DashMap<String, String>,
+        space: &str,
+        newline: &str,
+        light_variables: Vec<String>,
+        dark_variables: Vec<String>,
+    ) -> String {
+        let mut inline_theme = String::new();
+
+        for (schema, variables) in [("light", light_variables), ("dark", dark_variables)] {
+            if variables.is_empty() {
+                continue;
+            }
+
+            let theme_rules = format!(
+                ":root{}{{{}{}{}}}",
+                space,
+                newline,
+                variables.join(newline),
+                newline
+            );
+
+            if schema == DEFAULT_THEME_SCHEMA {
+                inline_theme = theme_rules;
+            } else {
+                tracing::debug!("Splitting the '{}' theme into its own stylesheet.", schema);
+
+                // The split stylesheet joins the same cascade layer as the main one.
+                let theme_rules =
+                    Self::wrap_in_css_layer(&get_css_layer(), space, newline, theme_rules);
+
+                // Each split stylesheet is served on its own, so it declares its own charset.
+                let theme_rules = match get_emit_charset() {
+                    true => format!("{}{}{}", CHARSET_RULE, newline, theme_rules),
+                    false => theme_rules,
+                };
+
+                cascadex.insert(format!("{}{}", THEME_SHEET_KEY_PREFIX, schema), theme_rules);
+            }
+        }
+
+        inline_theme
+    }
+
     /// Formats the theme schema into valid CSS rules with a media query.
     ///
     /// This function takes the theme variables and creates a valid CSS media query
@@ -227,3 +522,89 @@ impl Astroform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::{
+        astroform::{Astroform, THEME_SHEET_KEY_PREFIX},
+        asts::CASCADEX,
+    };
+
+    #[test]
+    fn missing_theme_variables_are_filled_from_the_fallback() {
+        let mut context_variables = IndexMap::from([
+            (
+                "light".to_string(),
+                IndexMap::from([
+                    (
+                        "primaryColor".to_string(),
+                        vec!["--a".to_string(), "#FFFFFF".to_string()],
+                    ),
+                    (
+                        "accentColor".to_string(),
+                        vec!["--b".to_string(), "#FF0000".to_string()],
+                    ),
+                ]),
+            ),
+            (
+                "dark".to_string(),
+                IndexMap::from([(
+                    "primaryColor".to_string(),
+                    vec!["--a".to_string(), "#000000".to_string()],
+                )]),
+            ),
+        ]);
+
+        assert!(Astroform::fill_missing_theme_variables("", &mut context_variables).is_empty());
+        assert_eq!(
+            Astroform::fill_missing_theme_variables("light", &mut context_variables),
+            vec![("dark".to_string(), "accentColor".to_string())]
+        );
+        assert_eq!(
+            context_variables["dark"].get("accentColor"),
+            Some(&vec!["--b".to_string(), "#FF0000".to_string()])
+        );
+        assert_eq!(
+            context_variables["dark"].get("primaryColor"),
+            Some(&vec!["--a".to_string(), "#000000".to_string()])
+        );
+    }
+
+    #[test]
+    fn themes_sheet_selects_the_dark_theme_by_attribute() {
+        assert_eq!(
+            Astroform::format_themes_sheet(
+                "",
+                "",
+                vec!["--a:#FFFFFF;".to_string()],
+                vec!["--a:#000000;".to_string()],
+            ),
+            ":root{--a:#FFFFFF;}[data-theme=\"dark\"]{--a:#000000;}"
+        );
+        assert_eq!(
+            Astroform::format_themes_sheet("", "", vec![], vec!["--a:#000000;".to_string()]),
+            "[data-theme=\"dark\"]{--a:#000000;}"
+        );
+    }
+
+    #[test]
+    fn dark_theme_is_split_from_the_inline_theme() {
+        let inline_theme = Astroform::split_themes(
+            &CASCADEX,
+            "",
+            "",
+            vec!["--a:#FFFFFF;".to_string()],
+            vec!["--a:#000000;".to_string()],
+        );
+
+        assert_eq!(inline_theme, ":root{--a:#FFFFFF;}");
+        assert_eq!(
+            CASCADEX
+                .get(&format!("{}dark", THEME_SHEET_KEY_PREFIX))
+                .map(|sheet| sheet.to_owned()),
+            Some(":root{--a:#000000;}".to_string())
+        );
+    }
+}
@@ -1,7 +1,6 @@
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind},
     types::Stylitron,
 };
@@ -19,6 +18,7 @@ impl Astroform {
     /// A `JoinHandle<String>` which contains the CSS rules as a `String` when the task completes.
     pub fn transform_typefaces(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
         let tab = self.tab.to_owned();
         let space = self.space.to_owned();
         let newline = self.newline.to_owned();
@@ -29,7 +29,7 @@ impl Astroform {
 
             let mut typefaces_css_rules: Vec<String> = vec![];
 
-            let typefaces_map = match STYLITRON.get("typefaces") {
+            let typefaces_map = match stylitron.get("typefaces") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::Typefaces(ref typefaces) => typefaces.to_owned(),
                     _ => return String::new(),
@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
+    configatron::get_stable_output,
     error::{ErrorAction, ErrorKind},
     types::Stylitron,
 };
@@ -16,16 +16,18 @@ impl Astroform {
     /// - A `JoinHandle` wrapping a `String` containing all formatted CSS variables.
     pub fn transform_variables(&self) -> JoinHandle<String> {
         let palantir_sender = self.palantir_sender.clone();
+        let stylitron = self.session.stylitron.clone();
         let tab = self.tab.to_owned();
         let space = self.space.to_owned();
         let newline = self.newline.to_owned();
         let is_minified = self.is_minified;
+        let stable_output = get_stable_output();
 
         // Spawn a blocking task to process variables in a separate thread.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting variables transformation process.");
 
-            let variables_map = match STYLITRON.get("variables") {
+            let mut variables_map = match stylitron.get("variables") {
                 Some(stylitron_data) => match &*stylitron_data {
                     Stylitron::Variables(ref variables) => variables.to_owned(),
                     _ => return String::new(),
@@ -42,6 +44,11 @@ impl Astroform {
                 }
             };
 
+            // The contexts are ordered by name, since their processing order varies between runs.
+            if stable_output {
+                variables_map.sort_keys();
+            }
+
             tracing::info!("Completed variables transformation process");
 
             // Transform the retrieved variables map into CSS rules.
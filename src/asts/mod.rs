@@ -1,14 +1,19 @@
+use std::{collections::BTreeMap, sync::Arc};
+
 use dashmap::DashMap;
-use indexmap::IndexMap;
 use lazy_static::lazy_static;
 
 use crate::{
     configatron::GaladrielConfig,
     events::GaladrielAlerts,
-    types::{Classinator, Clastrack, Stylitron},
-    utils::{generates_node_styles::generates_node_styles, generates_words::generates_words},
+    types::{ClassOrigin, Classinator, Clastrack, Stylitron},
+    utils::generates_words::generates_words,
 };
 
+mod session;
+
+pub use session::CompilationSession;
+
 lazy_static! {
     /// A static reference to a thread-safe `DashMap` that holds a bank of words for name generation.
     ///
@@ -45,6 +50,53 @@ lazy_static! {
     /// - `resetStyles`: A boolean specifying whether to reset default CSS styles.
     /// - `minifiedStyles`: A boolean indicating whether the generated CSS should be minified.
     /// - `port`: A string representing the port for the server.
+    /// - `maxExtensionDepth`: The maximum number of contexts allowed in an extension chain.
+    /// - `relativeErrorPaths`: A boolean indicating whether error alerts display paths relative to the working directory.
+    /// - `generateTypings`: A boolean indicating whether the TypeScript typings of the Nenyr classes are regenerated in development mode.
+    /// - `consolidateShorthand`: A boolean indicating whether complete longhand sets (e.g. `margin-top` to `margin-left`) are consolidated into their shorthand.
+    /// - `reloadThrottleMs`: The window, in milliseconds, in which reload requests to the integration client are coalesced.
+    /// - `publicNames`: A map of Nenyr classes (in markup syntax) to readable names emitted as additional selectors.
+    /// - `contrastChecks`: A list of foreground/background variable pairs whose contrast is checked in every theme schema.
+    /// - `scaleFunction`: The name of the scale function expanded in style values.
+    /// - `scaleBase`: The base unit multiplied by the scale function, disabled when empty.
+    /// - `resolveOnlyContexts`: A list of contexts kept only for resolution, whose classes are never emitted.
+    /// - `classesJson`: A flag indicating whether the classes tracking maps are written to `.galadrielcss/classes.json`.
+    /// - `animationPrefix`: The prefix prepended to the generated animation names.
+    /// - `splitThemes`: The flag for splitting the theme schemas into their own CSS files on build.
+    /// - `classNameHashLength`: The length of the hashed suffix of the utility class names.
+    /// - `resetStylesMedia`: The media condition the reset styles are scoped to or excluded from.
+    /// - `notifyOnError`: The flag for ringing the terminal bell when an error is raised.
+    /// - `frozenCentralNames`: The names of the central definitions that layouts and modules cannot redefine.
+    /// - `sortOutputByProperty`: The flag for ordering the emitted utility rules by CSS property name.
+    /// - `maxClassesPerProperty`: The soft limit of distinct utility classes generated for a single CSS property.
+    /// - `themeVarFallback`: The theme schema filling the variables missing from the other schema.
+    /// - `idleTimeoutMinutes`: The minutes of inactivity after which the development server shuts down.
+    /// - `lenientAliases`: The flag for resolving aliases case-insensitively.
+    /// - `strictVariables`: The properties whose values must reference variables.
+    /// - `emitCharset`: The flag for emitting the `@charset` rule.
+    /// - `buildWebhook`: The URL notified once a build completes.
+    /// - `plainAlerts`: A boolean indicating whether the Nenyr code of error alerts is rendered without syntax highlighting.
+    /// - `stableOutput`: A boolean indicating whether the generated CSS is sorted into a deterministic order.
+    /// - `sourceMaps`: A boolean indicating whether a source map is generated along with the CSS.
+    /// - `lintPseudoElements`: A boolean indicating whether properties unsupported by their pseudo-element are reported.
+    /// - `contextComments`: A boolean indicating whether the rules of each context are introduced by a comment naming it.
+    /// - `autoprefix`: A boolean indicating whether vendor-prefixed declarations are emitted.
+    /// - `browserTargets`: A vector of browsers the vendor prefixes are emitted for.
+    /// - `maxWatchBatch`: The number of Nenyr file events above which a watched batch triggers a full reprocess.
+    /// - `dedupeUtilities`: Whether the utility classes with identical declarations are collapsed into shared ones.
+    /// - `splitResponsive`: Whether the mobile-first and desktop-first responsive styles are split into their own CSS files on build.
+    /// - `classPrefix`: Prefix prepended to every generated utility class name.
+    /// - `featureFlags`: The feature flags the contexts (`contextName`) and classes (`contextName::className`) depend on.
+    /// - `emitAtProperty`: Whether every variable is registered with an `@property` rule.
+    /// - `buildJsonName`: The template of the build JSON file name.
+    /// - `warnUnusedVariables`: Whether the variables that are never referenced are reported.
+    /// - `watchExtra`: Glob patterns of additional files whose changes reprocess the whole project.
+    /// - `contentGlobs`: Glob patterns of the source files scanned for the referenced Nenyr classes.
+    /// - `cssLayer`: Name of the cascade layer the generated stylesheet is wrapped in.
+    /// - `respectReducedMotion`: Determines whether the animations and transitions are turned off for reduced motion.
+    /// - `portFallbackRange`: The number of sequential ports tried when the configured port is in use.
+    /// - `containerBreakpoints`: The breakpoints emitted as `@container` queries, keyed by their name.
+    /// - `mangleNames`: Determines whether the builds rename the generated identifiers to the shortest unique names.
     pub static ref CONFIGATRON: DashMap<String, GaladrielConfig> = {
         let map = DashMap::new();
 
@@ -53,6 +105,53 @@ lazy_static! {
         map.insert("resetStyles".to_string(), GaladrielConfig::ResetStyles(true));
         map.insert("minifiedStyles".to_string(), GaladrielConfig::MinifiedStyles(true));
         map.insert("port".to_string(), GaladrielConfig::Port("0".to_string()));
+        map.insert("maxExtensionDepth".to_string(), GaladrielConfig::MaxExtensionDepth(8));
+        map.insert("relativeErrorPaths".to_string(), GaladrielConfig::RelativeErrorPaths(false));
+        map.insert("generateTypings".to_string(), GaladrielConfig::GenerateTypings(false));
+        map.insert("consolidateShorthand".to_string(), GaladrielConfig::ConsolidateShorthand(false));
+        map.insert("reloadThrottleMs".to_string(), GaladrielConfig::ReloadThrottleMs(100));
+        map.insert("publicNames".to_string(), GaladrielConfig::PublicNames(BTreeMap::new()));
+        map.insert("contrastChecks".to_string(), GaladrielConfig::ContrastChecks(vec![]));
+        map.insert("scaleFunction".to_string(), GaladrielConfig::ScaleFunction("space".to_string()));
+        map.insert("scaleBase".to_string(), GaladrielConfig::ScaleBase(String::new()));
+        map.insert("resolveOnlyContexts".to_string(), GaladrielConfig::ResolveOnlyContexts(vec![]));
+        map.insert("classesJson".to_string(), GaladrielConfig::ClassesJson(false));
+        map.insert("animationPrefix".to_string(), GaladrielConfig::AnimationPrefix(String::new()));
+        map.insert("splitThemes".to_string(), GaladrielConfig::SplitThemes(false));
+        map.insert("classNameHashLength".to_string(), GaladrielConfig::ClassNameHashLength(4));
+        map.insert("resetStylesMedia".to_string(), GaladrielConfig::ResetStylesMedia(String::new()));
+        map.insert("notifyOnError".to_string(), GaladrielConfig::NotifyOnError(false));
+        map.insert("frozenCentralNames".to_string(), GaladrielConfig::FrozenCentralNames(vec![]));
+        map.insert("sortOutputByProperty".to_string(), GaladrielConfig::SortOutputByProperty(false));
+        map.insert("maxClassesPerProperty".to_string(), GaladrielConfig::MaxClassesPerProperty(0));
+        map.insert("themeVarFallback".to_string(), GaladrielConfig::ThemeVarFallback(String::new()));
+        map.insert("idleTimeoutMinutes".to_string(), GaladrielConfig::IdleTimeoutMinutes(0));
+        map.insert("lenientAliases".to_string(), GaladrielConfig::LenientAliases(false));
+        map.insert("strictVariables".to_string(), GaladrielConfig::StrictVariables(vec![]));
+        map.insert("emitCharset".to_string(), GaladrielConfig::EmitCharset(false));
+        map.insert("buildWebhook".to_string(), GaladrielConfig::BuildWebhook(String::new()));
+        map.insert("plainAlerts".to_string(), GaladrielConfig::PlainAlerts(false));
+        map.insert("stableOutput".to_string(), GaladrielConfig::StableOutput(true));
+        map.insert("sourceMaps".to_string(), GaladrielConfig::SourceMaps(false));
+        map.insert("lintPseudoElements".to_string(), GaladrielConfig::LintPseudoElements(false));
+        map.insert("contextComments".to_string(), GaladrielConfig::ContextComments(false));
+        map.insert("autoprefix".to_string(), GaladrielConfig::Autoprefix(false));
+        map.insert("browserTargets".to_string(), GaladrielConfig::BrowserTargets(vec![]));
+        map.insert("maxWatchBatch".to_string(), GaladrielConfig::MaxWatchBatch(0));
+        map.insert("dedupeUtilities".to_string(), GaladrielConfig::DedupeUtilities(false));
+        map.insert("splitResponsive".to_string(), GaladrielConfig::SplitResponsive(false));
+        map.insert("classPrefix".to_string(), GaladrielConfig::ClassPrefix(String::new()));
+        map.insert("featureFlags".to_string(), GaladrielConfig::FeatureFlags(BTreeMap::new()));
+        map.insert("emitAtProperty".to_string(), GaladrielConfig::EmitAtProperty(false));
+        map.insert("buildJsonName".to_string(), GaladrielConfig::BuildJsonName("galadrielcss.json".to_string()));
+        map.insert("warnUnusedVariables".to_string(), GaladrielConfig::WarnUnusedVariables(false));
+        map.insert("watchExtra".to_string(), GaladrielConfig::WatchExtra(vec![]));
+        map.insert("contentGlobs".to_string(), GaladrielConfig::ContentGlobs(vec![]));
+        map.insert("cssLayer".to_string(), GaladrielConfig::CssLayer(String::new()));
+        map.insert("respectReducedMotion".to_string(), GaladrielConfig::RespectReducedMotion(false));
+        map.insert("portFallbackRange".to_string(), GaladrielConfig::PortFallbackRange(10));
+        map.insert("containerBreakpoints".to_string(), GaladrielConfig::ContainerBreakpoints(BTreeMap::new()));
+        map.insert("mangleNames".to_string(), GaladrielConfig::MangleNames(false));
 
         map
     };
@@ -77,14 +176,9 @@ lazy_static! {
     /// generated CSS as a `String`.
     ///
     /// The generated CSS is stored in this map after all transformations and is ready
-    /// to be applied in the final output of the application.
-    pub static ref CASCADEX: DashMap<String, String> = {
-        let dash_map = DashMap::new();
-
-        dash_map.insert("cascading_sheet".to_string(), String::new());
-
-        dash_map
-    };
+    /// to be applied in the final output of the application. When the themes are split,
+    /// each non-default theme schema is stored under its own `theme_sheet:<schema>` key.
+    pub static ref CASCADEX: Arc<DashMap<String, String>> = CompilationSession::global().cascadex;
 
     /// `CLASTRACK` tracks the association between Nenyr classes and CSS utility
     /// class names. This `DashMap` holds mappings where the key is a string identifier
@@ -93,15 +187,7 @@ lazy_static! {
     ///
     /// This map is essential for linking Nenyr-defined classes to the actual CSS utility
     /// classes that will be applied to HTML elements.
-    pub static ref CLASTRACK: DashMap<String, Clastrack> = {
-        let dash_map = DashMap::new();
-
-        dash_map.insert("central".to_string(), Clastrack::Central(IndexMap::new()));
-        dash_map.insert("layouts".to_string(), Clastrack::Layouts(IndexMap::new()));
-        dash_map.insert("modules".to_string(), Clastrack::Modules(IndexMap::new()));
-
-        dash_map
-    };
+    pub static ref CLASTRACK: Arc<DashMap<String, Clastrack>> = CompilationSession::global().clastrack;
 
     /// `INTAKER` stores the names of contexts used in Nenyr classes, with the key
     /// being the path of the context (e.g., "path/context_name") and the value being
@@ -109,7 +195,7 @@ lazy_static! {
     ///
     /// This map helps manage and track the context names applied within the Galadriel CSS framework
     /// for styling purposes.
-    pub static ref INTAKER: DashMap<String, String> = DashMap::new();
+    pub static ref INTAKER: Arc<DashMap<String, String>> = CompilationSession::global().intaker;
 
     /// `GATEKEEPER` tracks relationships between module contexts and the layout contexts
     /// that they receive extension. It is represented as a `DashMap` where the key is a
@@ -118,7 +204,14 @@ lazy_static! {
     ///
     /// This helps ensure the correct application of styles based on the layout contexts
     /// and module contexts within the framework.
-    pub static ref GATEKEEPER: DashMap<String, Vec<String>> = DashMap::new();
+    pub static ref GATEKEEPER: Arc<DashMap<String, Vec<String>>> = CompilationSession::global().gatekeeper;
+
+    /// `ORIGINATOR` records the Nenyr class each utility class was generated for, with the key
+    /// being the escaped utility class name and the value being the file path and name of the
+    /// latest Nenyr class generating it.
+    ///
+    /// It is read when the source map of the generated CSS is produced.
+    pub static ref ORIGINATOR: Arc<DashMap<String, ClassOrigin>> = CompilationSession::global().originator;
 
     /// `CLASSINATOR` tracks the mapping between Nenyr classes and their corresponding
     /// CSS utility classes, including inheritance. This `DashMap` contains keys such as
@@ -127,15 +220,7 @@ lazy_static! {
     ///
     /// The mapping facilitates the creation and inheritance of classes based on their
     /// contextual relationships within the framework.
-    pub static ref CLASSINATOR: DashMap<String, Classinator> = {
-        let map = DashMap::new();
-
-        map.insert("central".to_string(), Classinator::Central(IndexMap::new()));
-        map.insert("layouts".to_string(), Classinator::Layouts(IndexMap::new()));
-        map.insert("modules".to_string(), Classinator::Modules(IndexMap::new()));
-
-        map
-    };
+    pub static ref CLASSINATOR: Arc<DashMap<String, Classinator>> = CompilationSession::global().classinator;
 
     /// `STYLITRON` is the main abstract syntax tree (AST) for the generated styles in
     /// Galadriel CSS. This `DashMap` contains various style categories and their
@@ -146,19 +231,5 @@ lazy_static! {
     /// Each category contains an `IndexMap` or other appropriate data structure that holds
     /// the specific style information, and the styles are generated and populated into the
     /// `STYLITRON` map during the build or dev processes.
-    pub static ref STYLITRON: DashMap<String, Stylitron> = {
-        let map = DashMap::new();
-
-        map.insert("imports".to_string(), Stylitron::Imports(IndexMap::new()));
-        map.insert("aliases".to_string(), Stylitron::Aliases(IndexMap::new()));
-        map.insert("breakpoints".to_string(), Stylitron::Breakpoints(IndexMap::new()));
-        map.insert("typefaces".to_string(), Stylitron::Typefaces(IndexMap::new()));
-        map.insert("variables".to_string(), Stylitron::Variables(IndexMap::new()));
-        map.insert("themes".to_string(), Stylitron::Themes(IndexMap::new()));
-        map.insert("animations".to_string(), Stylitron::Animation(IndexMap::new()));
-        map.insert("styles".to_string(), Stylitron::Styles(generates_node_styles()));
-        map.insert("responsive".to_string(), Stylitron::ResponsiveStyles(IndexMap::new()));
-
-        map
-    };
+    pub static ref STYLITRON: Arc<DashMap<String, Stylitron>> = CompilationSession::global().stylitron;
 }
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use lazy_static::lazy_static;
+
+use crate::{
+    types::{ClassOrigin, Classinator, Clastrack, Stylitron},
+    utils::generates_node_styles::generates_node_styles,
+};
+
+lazy_static! {
+    /// The session the Galadriel CSS runtime compiles into, backing the global abstract syntax trees.
+    static ref GLOBAL_SESSION: CompilationSession = CompilationSession::new();
+}
+
+/// Owns the abstract syntax trees and the generated CSS of a compilation.
+///
+/// Every map is behind an `Arc`, so a cloned session shares its maps with the original one. The
+/// runtime works on the global session, whose maps are the ones behind `STYLITRON`, `CLASSINATOR`,
+/// `CLASTRACK`, `INTAKER`, `GATEKEEPER`, `CASCADEX` and `ORIGINATOR`, while a session created with
+/// `new` is fully independent from it.
+#[derive(Clone, Debug)]
+pub struct CompilationSession {
+    /// The generated styles, as in `STYLITRON`.
+    pub(crate) stylitron: Arc<DashMap<String, Stylitron>>,
+    /// The utility class names of every Nenyr class, before inheritance, as in `CLASSINATOR`.
+    pub(crate) classinator: Arc<DashMap<String, Classinator>>,
+    /// The utility class names of every Nenyr class, after inheritance, as in `CLASTRACK`.
+    pub(crate) clastrack: Arc<DashMap<String, Clastrack>>,
+    /// The names of the contexts used in Nenyr classes, as in `INTAKER`.
+    pub(crate) intaker: Arc<DashMap<String, String>>,
+    /// The modules extending each layout context, as in `GATEKEEPER`.
+    pub(crate) gatekeeper: Arc<DashMap<String, Vec<String>>>,
+    /// The generated stylesheets, as in `CASCADEX`.
+    pub(crate) cascadex: Arc<DashMap<String, String>>,
+    /// The Nenyr class every utility class was generated for, as in `ORIGINATOR`.
+    pub(crate) originator: Arc<DashMap<String, ClassOrigin>>,
+}
+
+impl CompilationSession {
+    /// Creates a session with empty abstract syntax trees, independent from every other session.
+    pub fn new() -> Self {
+        let stylitron = DashMap::new();
+
+        stylitron.insert("imports".to_string(), Stylitron::Imports(IndexMap::new()));
+        stylitron.insert("aliases".to_string(), Stylitron::Aliases(IndexMap::new()));
+        stylitron.insert(
+            "breakpoints".to_string(),
+            Stylitron::Breakpoints(IndexMap::new()),
+        );
+        stylitron.insert(
+            "typefaces".to_string(),
+            Stylitron::Typefaces(IndexMap::new()),
+        );
+        stylitron.insert(
+            "variables".to_string(),
+            Stylitron::Variables(IndexMap::new()),
+        );
+        stylitron.insert("themes".to_string(), Stylitron::Themes(IndexMap::new()));
+        stylitron.insert(
+            "animations".to_string(),
+            Stylitron::Animation(IndexMap::new()),
+        );
+        stylitron.insert(
+            "styles".to_string(),
+            Stylitron::Styles(generates_node_styles()),
+        );
+        stylitron.insert(
+            "responsive".to_string(),
+            Stylitron::ResponsiveStyles(IndexMap::new()),
+        );
+
+        let classinator = DashMap::new();
+
+        classinator.insert("central".to_string(), Classinator::Central(IndexMap::new()));
+        classinator.insert("layouts".to_string(), Classinator::Layouts(IndexMap::new()));
+        classinator.insert("modules".to_string(), Classinator::Modules(IndexMap::new()));
+
+        let clastrack = DashMap::new();
+
+        clastrack.insert("central".to_string(), Clastrack::Central(IndexMap::new()));
+        clastrack.insert("layouts".to_string(), Clastrack::Layouts(IndexMap::new()));
+        clastrack.insert("modules".to_string(), Clastrack::Modules(IndexMap::new()));
+
+        let cascadex = DashMap::new();
+
+        cascadex.insert("cascading_sheet".to_string(), String::new());
+
+        Self {
+            stylitron: Arc::new(stylitron),
+            classinator: Arc::new(classinator),
+            clastrack: Arc::new(clastrack),
+            intaker: Arc::new(DashMap::new()),
+            gatekeeper: Arc::new(DashMap::new()),
+            cascadex: Arc::new(cascadex),
+            originator: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Retrieves the session the Galadriel CSS runtime compiles into.
+    pub fn global() -> Self {
+        GLOBAL_SESSION.clone()
+    }
+}
+
+impl Default for CompilationSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
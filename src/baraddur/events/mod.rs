@@ -14,6 +14,7 @@ pub enum BaraddurEventProcessor {
         path: PathBuf,
     },
     ReloadGaladrielConfigs,
+    ReprocessProject,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -1,4 +1,12 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
 
 use chrono::Local;
 use events::{BaraddurEventProcessor, BaraddurEventProcessorKind, BaraddurRenameEventState};
@@ -18,9 +26,12 @@ use tokio::{
 
 use crate::{
     astroform::Astroform,
+    asts::CompilationSession,
     configatron::{
-        get_auto_naming, get_minified_styles, get_reset_styles, load_galadriel_configs,
-        reconstruct_exclude_matcher,
+        check_duplicated_config_files, construct_watch_extra_matcher, get_auto_naming,
+        get_classes_json, get_emission_config_hash, get_generate_typings, get_max_watch_batch,
+        get_minified_styles, get_reset_styles, get_resolution_config_hash, load_galadriel_configs,
+        reconstruct_exclude_matcher, JSON_CONFIG_FILE_NAME, TOML_CONFIG_FILE_NAME,
     },
     crealion::CrealionContextType,
     error::{ErrorAction, ErrorKind, GaladrielError},
@@ -31,17 +42,28 @@ use crate::{
     synthesizer::Synthesizer,
     trailblazer::Trailblazer,
     utils::{
+        activity_tracker::record_activity, context_partitions::collect_context_partitions,
         file_timestamp_updater::FileTimestampUpdater, inject_names::inject_names,
         is_nenyr_event::is_nenyr_event,
+        restore_abstract_syntax_trees::restore_abstract_syntax_trees,
         send_palantir_error_notification::send_palantir_error_notification,
         send_palantir_notification::send_palantir_notification,
         send_palantir_success_notification::send_palantir_success_notification,
+        serialize_classes_tracking::serialize_classes_tracking,
+        serialize_classes_typings::serialize_classes_typings,
+        warn_crowded_properties::warn_crowded_properties,
+        warn_dangling_derivations::warn_dangling_derivations,
+        warn_important_overrides::warn_important_overrides,
+        warn_unused_variables::warn_unused_variables, write_file::write_file,
     },
     GaladrielResult,
 };
 
 pub mod events;
 
+/// Number of times a Nenyr file is processed by the `BenchFile` command.
+const BENCH_FILE_RUNS: u32 = 10;
+
 /// A struct to observe changes in a directory using an event-driven approach.
 ///
 /// This struct facilitates monitoring a specified directory for events such as
@@ -54,6 +76,9 @@ pub mod events;
 /// - `palantir_sender`: Broadcast sender for sending `GaladrielAlerts`.
 /// - `working_dir`: The directory where files or configurations are monitored for changes.
 /// - `from_millis`: Duration in milliseconds used for debouncing events to minimize redundant notifications.
+/// - `watch_paused`: Whether the debounced events are currently dropped instead of processed.
+/// - `bench_path`: The latest modified Nenyr file, benchmarked on request.
+/// - `dry_run`: Whether the changes are only reported, without writing any file.
 #[derive(Debug)]
 pub struct Baraddur {
     /// Unbounded sender for transmitting system events (`GaladrielEvents`).
@@ -74,6 +99,24 @@ pub struct Baraddur {
     /// Debouncing prevents rapid consecutive notifications for the same event,
     /// ensuring more efficient and meaningful alerts.
     from_millis: u64,
+
+    /// Whether the processing of filesystem events is paused.
+    ///
+    /// Shared with the watcher task, which drops every debounced event while it is set,
+    /// so bulk file changes (e.g. a branch switch) do not trigger repeated reprocessing.
+    watch_paused: Arc<AtomicBool>,
+
+    /// Path of the latest modified Nenyr file, benchmarked by the `BenchFile` command.
+    ///
+    /// Shared with the watcher task, which updates it for every processed modification.
+    bench_path: Arc<Mutex<Option<PathBuf>>>,
+
+    /// Whether the processing of filesystem events runs without writing any file.
+    ///
+    /// The Nenyr contexts are still reprocessed, but instead of updating the CSS cache, the typings,
+    /// the classes JSON and the files timestamps, the reprocessed contexts and the utility classes
+    /// added or removed are reported as information alerts.
+    dry_run: bool,
 }
 
 impl Baraddur {
@@ -108,6 +151,227 @@ impl Baraddur {
             palantir_sender,
             working_dir,
             from_millis,
+            watch_paused: Arc::new(AtomicBool::new(false)),
+            bench_path: Arc::new(Mutex::new(None)),
+            dry_run: false,
+        }
+    }
+
+    /// Sets whether the processing of filesystem events runs without writing any file.
+    ///
+    /// # Arguments
+    /// - `dry_run`: A flag indicating whether the changes are only reported.
+    pub fn set_dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+
+        self
+    }
+
+    /// Checks whether the processing of filesystem events is paused.
+    pub fn is_watch_paused(&self) -> bool {
+        self.watch_paused.load(Ordering::SeqCst)
+    }
+
+    /// Pauses the processing of filesystem events. Every event received while paused is dropped.
+    pub fn pause_watch(&self) {
+        tracing::info!("Pausing the processing of filesystem events.");
+
+        self.watch_paused.store(true, Ordering::SeqCst);
+
+        send_palantir_success_notification(
+            "Barad-dûr closes its eye. File changes are ignored until the watcher is resumed.",
+            Local::now(),
+            self.palantir_sender.clone(),
+        );
+    }
+
+    /// Resumes the processing of filesystem events.
+    ///
+    /// Since the events received while paused were dropped, the configurations are reloaded and
+    /// the whole project is reprocessed once from pristine ASTs.
+    ///
+    /// # Parameters
+    /// - `matcher`: Shared reference to the matcher for excluding or including paths.
+    pub async fn resume_watch(&self, matcher: Arc<RwLock<overrides::Override>>) {
+        tracing::info!("Resuming the processing of filesystem events.");
+
+        let starting_time = Local::now();
+        let palantir_sender = self.palantir_sender.clone();
+
+        self.watch_paused.store(false, Ordering::SeqCst);
+
+        // The configurations may have changed while the watcher was paused.
+        match load_galadriel_configs(&self.working_dir, None).await {
+            Ok(()) => {
+                if let Err(error) =
+                    reconstruct_exclude_matcher(&self.working_dir, Arc::clone(&matcher)).await
+                {
+                    send_palantir_error_notification(error, starting_time, palantir_sender.clone());
+                }
+            }
+            Err(error) => {
+                send_palantir_error_notification(error, starting_time, palantir_sender.clone());
+            }
+        }
+
+        restore_abstract_syntax_trees();
+
+        Synthesizer::new(
+            true,
+            Arc::clone(&matcher),
+            palantir_sender.clone(),
+            CompilationSession::global(),
+        )
+        .set_styles_emission(!self.dry_run)
+        .process(get_minified_styles(), &self.working_dir)
+        .await;
+
+        if !self.dry_run {
+            Self::regenerate_classes_typings(&self.working_dir, palantir_sender.clone()).await;
+            Self::rewrite_classes_tracking(&self.working_dir, palantir_sender.clone()).await;
+
+            // Notify the integration client that the styles have been updated.
+            FileTimestampUpdater::new(palantir_sender.clone())
+                .process_from_folder(true, self.working_dir.to_owned(), matcher)
+                .await;
+        }
+
+        send_palantir_success_notification(
+            "Barad-dûr opens its eye again. The project was reprocessed and file changes are observed once more.",
+            starting_time,
+            palantir_sender,
+        );
+    }
+
+    /// Reloads the configurations on request, as when the configuration file changes.
+    ///
+    /// Some editors save the configuration file through an atomic rename the watcher may miss,
+    /// so this lets the changes be applied without restarting the server.
+    ///
+    /// # Parameters
+    /// - `matcher`: Shared reference to the matcher for excluding or including paths.
+    pub async fn reload_configs(&self, matcher: Arc<RwLock<overrides::Override>>) {
+        tracing::info!("Reloading the Galadriel CSS configurations on request.");
+
+        Self::reload_galadriel_configs(
+            &self.working_dir,
+            matcher,
+            self.dry_run,
+            self.palantir_sender.clone(),
+        )
+        .await;
+    }
+
+    /// Benchmarks the processing of the latest modified Nenyr file.
+    ///
+    /// The file is processed `BENCH_FILE_RUNS` times, and the minimum, average and maximum
+    /// processing times are reported as an information alert. The alerts raised by each run
+    /// are discarded, so the benchmark does not flood the alerts table.
+    pub async fn bench_file(&self) {
+        let bench_path = match self.bench_path.lock() {
+            Ok(bench_path) => bench_path.clone(),
+            Err(_) => None,
+        };
+
+        let Some(bench_path) = bench_path else {
+            let notification = GaladrielAlerts::create_warning(
+                Local::now(),
+                "There is no Nenyr file to benchmark yet. Save the Nenyr file you want to benchmark, then run the benchmark again.",
+            );
+
+            send_palantir_notification(notification, self.palantir_sender.clone());
+
+            return;
+        };
+
+        let stringified_path = bench_path.to_string_lossy().to_string();
+
+        tracing::info!(
+            "Benchmarking the processing of Nenyr file: {:?}",
+            stringified_path
+        );
+
+        // The receiver keeps the channel open while the alerts of each run are discarded.
+        let (bench_sender, _bench_receiver) = sync::broadcast::channel(100);
+        let mut nenyr_parser = NenyrParser::new();
+        let mut durations: Vec<Duration> = vec![];
+
+        for _ in 0..BENCH_FILE_RUNS {
+            let run_start = Instant::now();
+            let (context_type, _) = formera(
+                bench_path.to_owned(),
+                &mut nenyr_parser,
+                Local::now(),
+                bench_sender.clone(),
+                &CompilationSession::global(),
+            )
+            .await;
+
+            // A failed run stops the benchmark, since its time does not reflect a full processing.
+            if context_type.is_none() {
+                let notification = GaladrielAlerts::create_warning(
+                    Local::now(),
+                    &format!("The benchmark of {:?} was stopped because the file could not be processed. Save the file to see its errors.", stringified_path),
+                );
+
+                send_palantir_notification(notification, self.palantir_sender.clone());
+
+                return;
+            }
+
+            durations.push(run_start.elapsed());
+        }
+
+        let min_duration = durations.iter().min().copied().unwrap_or_default();
+        let max_duration = durations.iter().max().copied().unwrap_or_default();
+        let avg_duration = durations.iter().sum::<Duration>() / BENCH_FILE_RUNS;
+
+        let notification = GaladrielAlerts::create_information(
+            Local::now(),
+            &format!(
+                "Processed {:?} {} times. min: {:.2}ms, avg: {:.2}ms, max: {:.2}ms.",
+                stringified_path,
+                BENCH_FILE_RUNS,
+                min_duration.as_secs_f64() * 1000.0,
+                avg_duration.as_secs_f64() * 1000.0,
+                max_duration.as_secs_f64() * 1000.0
+            ),
+        );
+
+        send_palantir_notification(notification, self.palantir_sender.clone());
+    }
+
+    /// Remembers the latest modified Nenyr file of a batch of debounced events, as the file
+    /// benchmarked by the `BenchFile` command.
+    ///
+    /// # Parameters
+    /// - `bench_path`: The shared path of the benchmarked file.
+    /// - `debounced_event_result`: Result containing debounced events or an error.
+    fn remember_bench_path(
+        bench_path: &Mutex<Option<PathBuf>>,
+        debounced_event_result: &Result<
+            Vec<BaraddurEventProcessor>,
+            sync::broadcast::error::RecvError,
+        >,
+    ) {
+        let Ok(debounced_events) = debounced_event_result else {
+            return;
+        };
+
+        let modified_path =
+            debounced_events
+                .iter()
+                .rev()
+                .find_map(|debounced_event| match debounced_event {
+                    BaraddurEventProcessor::ProcessEvent {
+                        kind: BaraddurEventProcessorKind::Modify,
+                        path,
+                    } => Some(path.to_owned()),
+                    _ => None,
+                });
+
+        if let (Some(modified_path), Ok(mut bench_path)) = (modified_path, bench_path.lock()) {
+            *bench_path = Some(modified_path);
         }
     }
 
@@ -174,7 +438,7 @@ impl Baraddur {
                 // Spawn an asynchronous task to process the debouncer events.
                 let debouncer_sender = debouncer_tx.clone();
                 let palantir_sender = palantir_sender.clone();
-                let configuration_path = working_dir.join("galadriel.config.json");
+                let configuration_path = working_dir.join(JSON_CONFIG_FILE_NAME);
                 let matcher = Arc::clone(&matcher);
 
                 handle_view.spawn(async move {
@@ -226,10 +490,23 @@ impl Baraddur {
                 let mut rename_state = BaraddurRenameEventState::None;
                 let matcher = matcher.read().await;
 
+                // The additional watched files are matched from the project root, like the excluded ones.
+                let watch_extra_matcher = construct_watch_extra_matcher(matcher.path())
+                    .unwrap_or_else(|error| {
+                        send_palantir_error_notification(
+                            error,
+                            Local::now(),
+                            palantir_sender.clone(),
+                        );
+
+                        overrides::Override::empty()
+                    });
+
                 // Process each debounced event.
                 Self::process_debounced_events(
                     configuration_path,
                     &matcher,
+                    &watch_extra_matcher,
                     debounced_events,
                     &mut rename_state,
                     &mut processing_events,
@@ -269,21 +546,25 @@ impl Baraddur {
     /// # Arguments
     /// - `configuration_path`: Path to the configuration file to detect related events.
     /// - `matcher`: Matcher for identifying Nenyr-specific events.
+    /// - `watch_extra_matcher`: Matcher for the additional files whose changes reprocess the whole project.
     /// - `debounced_events`: A vector of debounced events captured from the file system watcher.
     /// - `rename_state`: Tracks the state of rename operations to handle multi-step rename events.
     /// - `processing_events`: A mutable vector to collect processed events.
     fn process_debounced_events(
         configuration_path: &PathBuf,
         matcher: &overrides::Override,
+        watch_extra_matcher: &overrides::Override,
         debounced_events: Vec<DebouncedEvent>,
         rename_state: &mut BaraddurRenameEventState,
         processing_events: &mut Vec<BaraddurEventProcessor>,
     ) {
-        // Iterate over each debounced event.
+        // Configuration events are classified first, so the reload token leads the batch.
         debounced_events.iter().for_each(|debounced_event| {
-            // Check paths associated with the event.
-            debounced_event.paths.iter().for_each(|path| {
-                if Self::is_configuration_event(path, configuration_path) {
+            debounced_event
+                .paths
+                .iter()
+                .filter(|path| Self::is_configuration_event(path, configuration_path))
+                .for_each(|path| {
                     tracing::debug!(
                         "Detected configuration event for Galadriel CSS configurations. Path: {:?}",
                         path
@@ -291,21 +572,81 @@ impl Baraddur {
 
                     // Handle events related to the configuration file.
                     Self::process_configuration_event(debounced_event.kind, processing_events);
-                } else if is_nenyr_event(path, matcher) {
-                    tracing::debug!("Detected Nenyr-specific event for path: {:?}", path);
+                });
+        });
 
-                    // Handle events related to Nenyr files.
-                    Self::process_nenyr_event(
-                        path,
-                        debounced_event.kind,
-                        rename_state,
-                        processing_events,
-                    );
-                }
-            });
+        // When the configurations are reloaded in this batch, the current matcher may be outdated.
+        // Nenyr files are then only filtered by extension here and checked again once the matcher is rebuilt.
+        let reloads_configs =
+            processing_events.contains(&BaraddurEventProcessor::ReloadGaladrielConfigs);
+
+        debounced_events.iter().for_each(|debounced_event| {
+            debounced_event
+                .paths
+                .iter()
+                .filter(|path| !Self::is_configuration_event(path, configuration_path))
+                .for_each(|path| {
+                    let is_nenyr_path = match reloads_configs {
+                        true => path.extension().is_some_and(|ext| ext == "nyr"),
+                        false => is_nenyr_event(path, matcher),
+                    };
+
+                    if is_nenyr_path {
+                        tracing::debug!("Detected Nenyr-specific event for path: {:?}", path);
+
+                        // Handle events related to Nenyr files.
+                        Self::process_nenyr_event(
+                            path,
+                            debounced_event.kind,
+                            rename_state,
+                            processing_events,
+                        );
+                    } else if Self::is_watch_extra_event(path, matcher, watch_extra_matcher) {
+                        tracing::debug!("Detected watch extra event for path: {:?}", path);
+
+                        // Handle events related to the additional watched files.
+                        Self::process_watch_extra_event(debounced_event.kind, processing_events);
+                    }
+                });
         });
     }
 
+    /// Checks whether a path matches the `watchExtra` patterns without being excluded.
+    ///
+    /// # Arguments
+    /// - `path`: Path to the file triggering the event.
+    /// - `matcher`: Matcher for the excluded paths.
+    /// - `watch_extra_matcher`: Matcher for the additional watched files.
+    fn is_watch_extra_event(
+        path: &Path,
+        matcher: &overrides::Override,
+        watch_extra_matcher: &overrides::Override,
+    ) -> bool {
+        !matcher.matched(path, false).is_ignore()
+            && watch_extra_matcher.matched(path, false).is_whitelist()
+    }
+
+    /// Processes events related to the additional watched files, which reprocess the whole project.
+    ///
+    /// # Arguments
+    /// - `debounced_event_kind`: The kind of the event (e.g., create, modify, remove).
+    /// - `processing_events`: A mutable vector to collect processed events.
+    fn process_watch_extra_event(
+        debounced_event_kind: EventKind,
+        processing_events: &mut Vec<BaraddurEventProcessor>,
+    ) {
+        // Like the configuration file, only the files created or modified are relevant.
+        if let BaraddurEventProcessorKind::None =
+            Self::process_debounced_events_for_configs(debounced_event_kind)
+        {
+            tracing::trace!("No relevant action for watch extra event.");
+            return;
+        }
+
+        Self::add_event_if_not_exists(BaraddurEventProcessor::ReprocessProject, processing_events);
+        tracing::info!("Added project reprocessing event to processing queue.");
+    }
+
     /// Processes events related to the configuration file.
     ///
     /// # Arguments
@@ -394,16 +735,17 @@ impl Baraddur {
         }
     }
 
-    /// Checks if a given path matches the configuration file path.
+    /// Checks if a given path matches the configuration file path, or the path of its TOML alternative.
     ///
     /// # Arguments
     /// - `path`: Path to the file triggering the event.
-    /// - `configuration_path`: Path to the configuration file.
+    /// - `configuration_path`: Path to the JSON configuration file.
     ///
     /// # Returns
     /// - `true` if the paths match, otherwise `false`.
     fn is_configuration_event(path: &PathBuf, configuration_path: &PathBuf) -> bool {
         path == configuration_path
+            || *path == configuration_path.with_file_name(TOML_CONFIG_FILE_NAME)
     }
 
     /// Determines the type of event for configuration files.
@@ -499,6 +841,9 @@ impl Baraddur {
         let baraddur_sender = self.baraddur_sender.clone(); // Clone the sender for internal use.
         let palantir_sender = self.palantir_sender.clone(); // Clone the Palantir notification sender.
         let working_dir = self.working_dir.clone(); // Clone the working directory path.
+        let watch_paused = Arc::clone(&self.watch_paused); // Share the paused state with the watcher task.
+        let bench_path = Arc::clone(&self.bench_path); // Share the benchmarked path with the watcher task.
+        let dry_run = self.dry_run; // Whether the changes are only reported, without writing any file.
 
         let mut palantir_receiver = palantir_sender.subscribe(); // Subscribe to Palantir notifications.
         let mut debouncer_receiver = debouncer_sender.subscribe(); // Subscribe to debounced events.
@@ -556,10 +901,19 @@ impl Baraddur {
                     debounced_event_result = debouncer_receiver.recv() => {
                         tracing::trace!("Received debounced event result: {:?}", debounced_event_result);
 
+                        // Events received while paused are dropped, the project is reprocessed on resume.
+                        if watch_paused.load(Ordering::SeqCst) {
+                            tracing::debug!("Watcher paused, dropping debounced events.");
+                            continue;
+                        }
+
+                        Self::remember_bench_path(&bench_path, &debounced_event_result);
+
                         Self::match_debounced_result(
                             &working_dir,
                             &mut nenyr_parser,
                             Arc::clone(&matcher),
+                            dry_run,
                             baraddur_sender.clone(),
                             palantir_sender.clone(),
                             &debounced_event_result
@@ -577,12 +931,14 @@ impl Baraddur {
     /// - `working_dir`: Path to the working directory.
     /// - `nenyr_parser`: Reference to the Nenyr parser.
     /// - `matcher`: Shared reference to the matcher for filtering events.
+    /// - `dry_run`: Whether the changes are only reported, without writing any file.
     /// - `palantir_sender`: Sender for Palantir notifications.
     /// - `debounced_event_result`: Result containing debounced events or an error.
     async fn match_debounced_result(
         working_dir: &PathBuf,
         nenyr_parser: &mut NenyrParser,
         matcher: Arc<RwLock<overrides::Override>>,
+        dry_run: bool,
         baraddur_sender: mpsc::UnboundedSender<GaladrielEvents>,
         palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
         debounced_event_result: &Result<
@@ -603,7 +959,68 @@ impl Baraddur {
                     debounced_events.len()
                 );
 
-                for debounced_event in debounced_events {
+                // File changes keep the development server from shutting down for being idle.
+                record_activity();
+
+                // A change to a file watched through `watchExtra` reprocesses the whole project.
+                let reprocesses_project =
+                    debounced_events.contains(&BaraddurEventProcessor::ReprocessProject);
+
+                // Configuration reloads run first, so Nenyr events are evaluated against the rebuilt matcher.
+                let (reload_events, nenyr_events): (Vec<_>, Vec<_>) = debounced_events
+                    .iter()
+                    .filter(|debounced_event| {
+                        **debounced_event != BaraddurEventProcessor::ReprocessProject
+                    })
+                    .partition(|debounced_event| {
+                        **debounced_event == BaraddurEventProcessor::ReloadGaladrielConfigs
+                    });
+                let reloads_configs = !reload_events.is_empty();
+
+                // A batch above the configured size is reprocessed as a whole, which is cheaper than
+                // processing each file of a bulk operation (e.g. a branch switch) one by one.
+                let max_watch_batch = get_max_watch_batch();
+                let exceeds_watch_batch =
+                    max_watch_batch > 0 && nenyr_events.len() > max_watch_batch;
+
+                if reprocesses_project || exceeds_watch_batch {
+                    let reprocess_message = match reprocesses_project {
+                        true => "A file watched through `watchExtra` changed. Galadriel CSS is reprocessing the whole project.".to_string(),
+                        false => format!(
+                            "Received {} Nenyr file changes at once, more than the {} allowed by `maxWatchBatch`. Galadriel CSS is reprocessing the whole project instead of each file.",
+                            nenyr_events.len(),
+                            max_watch_batch
+                        ),
+                    };
+
+                    send_palantir_notification(
+                        GaladrielAlerts::create_information(Local::now(), &reprocess_message),
+                        palantir_sender.clone(),
+                    );
+
+                    if reloads_configs {
+                        Self::reload_galadriel_configs(
+                            working_dir,
+                            Arc::clone(&matcher),
+                            dry_run,
+                            palantir_sender.clone(),
+                        )
+                        .await;
+                    }
+
+                    Self::reprocess_watch_batch(
+                        working_dir,
+                        &nenyr_events,
+                        matcher,
+                        dry_run,
+                        palantir_sender.clone(),
+                    )
+                    .await;
+
+                    return;
+                }
+
+                for debounced_event in reload_events.into_iter().chain(nenyr_events) {
                     match debounced_event {
                         // Handle configuration reload events.
                         BaraddurEventProcessor::ReloadGaladrielConfigs => {
@@ -612,12 +1029,27 @@ impl Baraddur {
                             Self::reload_galadriel_configs(
                                 working_dir,
                                 Arc::clone(&matcher),
+                                dry_run,
                                 palantir_sender.clone(),
                             )
                             .await;
                         }
                         // Handle Nenyr processing events based on their kind and path.
                         BaraddurEventProcessor::ProcessEvent { kind, path } => {
+                            // Skips files excluded by the configurations reloaded in this batch.
+                            // Removals still go through to clean up the state of the removed file.
+                            if reloads_configs
+                                && *kind != BaraddurEventProcessorKind::Remove
+                                && !is_nenyr_event(path, &*matcher.read().await)
+                            {
+                                tracing::debug!(
+                                    "Skipping event for path {:?}, which is excluded by the reloaded configurations.",
+                                    path
+                                );
+
+                                continue;
+                            }
+
                             tracing::info!("Processing event: {:?} for path: {:?}", kind, path);
 
                             Self::match_processing_event_kind(
@@ -626,11 +1058,14 @@ impl Baraddur {
                                 nenyr_parser,
                                 Arc::clone(&matcher),
                                 kind,
+                                dry_run,
                                 baraddur_sender.clone(),
                                 palantir_sender.clone(),
                             )
                             .await;
                         }
+                        // Project reprocessing events were handled above, along with the whole batch.
+                        BaraddurEventProcessor::ReprocessProject => {}
                     }
                 }
             }
@@ -649,15 +1084,98 @@ impl Baraddur {
         }
     }
 
+    /// Reprocesses the whole project once, instead of each Nenyr file of a batch exceeding the
+    /// `maxWatchBatch` configuration or containing a change to a file watched through `watchExtra`.
+    ///
+    /// The removed files are cleaned up first, then the contexts are reprocessed from pristine ASTs.
+    ///
+    /// # Parameters
+    /// - `working_dir`: Path to the working directory containing the Nenyr files.
+    /// - `nenyr_events`: The Nenyr processing events of the batch.
+    /// - `matcher`: Shared reference to the matcher for excluding or including paths.
+    /// - `dry_run`: Whether the changes are only reported, without writing any file.
+    /// - `palantir_sender`: Sender used to broadcast alerts or notifications.
+    async fn reprocess_watch_batch(
+        working_dir: &PathBuf,
+        nenyr_events: &[&BaraddurEventProcessor],
+        matcher: Arc<RwLock<overrides::Override>>,
+        dry_run: bool,
+        palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
+    ) {
+        tracing::info!(
+            "Reprocessing the whole project for a batch of {} Nenyr events.",
+            nenyr_events.len()
+        );
+
+        let starting_time = Local::now();
+
+        for nenyr_event in nenyr_events {
+            if let BaraddurEventProcessor::ProcessEvent {
+                kind: BaraddurEventProcessorKind::Remove,
+                path,
+            } = nenyr_event
+            {
+                let file_path = path.to_string_lossy().to_string();
+
+                remove_path_from_gatekeeper(&file_path);
+                remove_context_from_intaker(&file_path);
+            }
+        }
+
+        let previous_utilities = if dry_run {
+            Self::collect_tracked_utilities()
+        } else {
+            BTreeSet::new()
+        };
+
+        restore_abstract_syntax_trees();
+
+        Synthesizer::new(
+            true,
+            Arc::clone(&matcher),
+            palantir_sender.clone(),
+            CompilationSession::global(),
+        )
+        .set_styles_emission(!dry_run)
+        .process(get_minified_styles(), working_dir)
+        .await;
+
+        if dry_run {
+            Self::send_dry_run_report(
+                &["every Nenyr context".to_string()],
+                &previous_utilities,
+                palantir_sender,
+            );
+
+            return;
+        }
+
+        Self::regenerate_classes_typings(working_dir, palantir_sender.clone()).await;
+        Self::rewrite_classes_tracking(working_dir, palantir_sender.clone()).await;
+
+        // Notify the integration client that the styles have been updated.
+        FileTimestampUpdater::new(palantir_sender.clone())
+            .process_from_folder(true, working_dir.to_owned(), matcher)
+            .await;
+
+        send_palantir_success_notification(
+            "The whole project was reprocessed after the file changes.",
+            starting_time,
+            palantir_sender,
+        );
+    }
+
     /// Reloads the Galadriel CSS configurations and updates the matcher.
     ///
     /// # Parameters
     /// - `working_dir`: Path to the working directory containing the configuration files.
     /// - `matcher`: Shared reference to the matcher for excluding or including paths.
+    /// - `dry_run`: Whether the typings, the classes JSON and the files timestamps are left untouched.
     /// - `palantir_sender`: Sender used to broadcast alerts or notifications.
     async fn reload_galadriel_configs(
         working_dir: &PathBuf,
         matcher: Arc<RwLock<overrides::Override>>,
+        dry_run: bool,
         palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
     ) {
         tracing::info!(
@@ -666,14 +1184,21 @@ impl Baraddur {
         );
 
         let starting_time = Local::now(); // Record the start time for tracking performance.
+        let resolution_hash = get_resolution_config_hash(); // Hash of the configurations used to resolve the ASTs.
+        let emission_hash = get_emission_config_hash(); // Hash of the configurations used to emit the CSS.
 
         // Attempt to load the Galadriel configurations.
-        match load_galadriel_configs(working_dir).await {
+        match load_galadriel_configs(working_dir, None).await {
             Ok(()) => {
                 tracing::info!("Galadriel configurations loaded successfully.");
 
+                // Warn when the TOML configuration file is shadowed by the JSON one.
+                if let Some(notification) = check_duplicated_config_files(working_dir) {
+                    send_palantir_notification(notification, palantir_sender.clone());
+                }
+
                 // If configurations are loaded successfully, reconstruct the exclude matcher.
-                match reconstruct_exclude_matcher(working_dir, matcher).await {
+                match reconstruct_exclude_matcher(working_dir, Arc::clone(&matcher)).await {
                     // Notify Palantir of the successful matcher reconstruction.
                     Ok(notification) => {
                         tracing::info!("Exclude matcher reconstructed successfully.");
@@ -690,6 +1215,23 @@ impl Baraddur {
                     }
                 }
 
+                // Refresh the styles according to the configurations that actually changed.
+                Self::refresh_styles_after_config_change(
+                    working_dir,
+                    resolution_hash,
+                    emission_hash,
+                    matcher,
+                    dry_run,
+                    palantir_sender.clone(),
+                )
+                .await;
+
+                // Keep the typings and the classes JSON in sync, as their flags may have just been enabled.
+                if !dry_run {
+                    Self::regenerate_classes_typings(working_dir, palantir_sender.clone()).await;
+                    Self::rewrite_classes_tracking(working_dir, palantir_sender.clone()).await;
+                }
+
                 tracing::info!("Galadriel CSS configurations updated successfully.");
 
                 // Send a success notification indicating the system has been updated.
@@ -708,12 +1250,78 @@ impl Baraddur {
         }
     }
 
+    /// Refreshes the generated styles after the configurations have been reloaded.
+    ///
+    /// The resolved ASTs only depend on the resolution configurations, so they are rebuilt
+    /// from scratch only when those change. When only emission configurations changed
+    /// (e.g. minification or reset styles), the current ASTs are reused and the CSS is emitted again.
+    ///
+    /// # Parameters
+    /// - `working_dir`: Path to the working directory containing the Nenyr files.
+    /// - `previous_resolution_hash`: The resolution configurations hash before the reload.
+    /// - `previous_emission_hash`: The emission configurations hash before the reload.
+    /// - `matcher`: Shared reference to the matcher for excluding or including paths.
+    /// - `dry_run`: Whether the contexts are reprocessed without updating the CSS cache and the files timestamps.
+    /// - `palantir_sender`: Sender used to broadcast alerts or notifications.
+    async fn refresh_styles_after_config_change(
+        working_dir: &PathBuf,
+        previous_resolution_hash: u64,
+        previous_emission_hash: u64,
+        matcher: Arc<RwLock<overrides::Override>>,
+        dry_run: bool,
+        palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
+    ) {
+        if previous_resolution_hash != get_resolution_config_hash() {
+            tracing::info!("Resolution configurations changed, reprocessing all Nenyr contexts.");
+
+            // Resolution and emission both run again, since the resolved ASTs may be stale.
+            Synthesizer::new(
+                true,
+                Arc::clone(&matcher),
+                palantir_sender.clone(),
+                CompilationSession::global(),
+            )
+            .set_styles_emission(!dry_run)
+            .process(get_minified_styles(), working_dir)
+            .await;
+        } else if previous_emission_hash != get_emission_config_hash() {
+            tracing::info!("Only emission configurations changed, reusing the resolved ASTs.");
+
+            // Only the emission stage runs again, on top of the already resolved ASTs.
+            if !dry_run {
+                Astroform::new(
+                    get_minified_styles(),
+                    get_reset_styles(),
+                    palantir_sender.clone(),
+                )
+                .transform()
+                .await;
+            }
+        } else {
+            tracing::debug!("No style-related configuration changed, skipping styles refresh.");
+
+            return;
+        }
+
+        if dry_run {
+            tracing::debug!("Dry run enabled, skipping the files timestamps update.");
+
+            return;
+        }
+
+        // Notify the integration client that the styles have been updated.
+        FileTimestampUpdater::new(palantir_sender.clone())
+            .process_from_folder(true, working_dir.to_owned(), matcher)
+            .await;
+    }
+
     /// Matches the kind of processing event and performs the appropriate action.
     ///
     /// # Parameters
     /// - `current_path`: Path to the file or resource being processed.
     /// - `processing_event_kind`: Type of the event (e.g., `Modify`, `Remove`).
     /// - `nenyr_parser`: Reference to the Nenyr parser for handling Nenyr files.
+    /// - `dry_run`: Whether the changes are only reported, without writing any file.
     /// - `palantir_sender`: Sender used to broadcast alerts or notifications.
     async fn match_processing_event_kind(
         working_dir: &PathBuf,
@@ -721,6 +1329,7 @@ impl Baraddur {
         nenyr_parser: &mut NenyrParser,
         matcher: Arc<RwLock<overrides::Override>>,
         processing_event_kind: &BaraddurEventProcessorKind,
+        dry_run: bool,
         baraddur_sender: mpsc::UnboundedSender<GaladrielEvents>,
         palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
     ) {
@@ -735,8 +1344,9 @@ impl Baraddur {
             BaraddurEventProcessorKind::Modify => {
                 // Checks if the auto-naming configuration is enabled.
                 // If enabled, it processes the injection of context, class, and animation names
-                // into the Nenyr context for the current path.
-                if get_auto_naming() {
+                // into the Nenyr context for the current path. The names are never injected in a dry run,
+                // since the injection rewrites the Nenyr file.
+                if get_auto_naming() && !dry_run {
                     // Attempts to inject names into the Nenyr context for the provided path.
                     match inject_names(current_path.to_owned()).await {
                         // If names were successfully injected, the operation is complete, and the function returns early.
@@ -766,6 +1376,7 @@ impl Baraddur {
                     working_dir,
                     nenyr_parser,
                     matcher,
+                    dry_run,
                     baraddur_sender,
                     palantir_sender.clone(),
                 )
@@ -789,12 +1400,15 @@ impl Baraddur {
     /// # Parameters
     /// - `current_path`: Path to the Nenyr file being processed.
     /// - `nenyr_parser`: Reference to the Nenyr parser for parsing.
+    /// - `dry_run`: Whether the reprocessed contexts and the utility classes added or removed are
+    ///   reported instead of writing any file.
     /// - `palantir_sender`: Sender used to broadcast alerts or notifications.
     async fn process_nenyr_file(
         current_path: PathBuf,
         working_dir: &PathBuf,
         nenyr_parser: &mut NenyrParser,
         matcher: Arc<RwLock<overrides::Override>>,
+        dry_run: bool,
         baraddur_sender: mpsc::UnboundedSender<GaladrielEvents>,
         palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
     ) {
@@ -814,6 +1428,14 @@ impl Baraddur {
 
         send_palantir_notification(notification, palantir_sender.clone());
 
+        // In a dry run, the utility classes tracked before the processing are compared against the ones tracked after it.
+        let previous_utilities = if dry_run {
+            Self::collect_tracked_utilities()
+        } else {
+            BTreeSet::new()
+        };
+        let mut reprocessed_contexts = vec![stringified_path.to_owned()];
+
         // Parses and processes the current Nenyr context.
         // This function returns a tuple containing:
         // - `context_type`: The type of the context being processed.
@@ -823,6 +1445,7 @@ impl Baraddur {
             nenyr_parser,
             starting_time,
             palantir_sender.clone(),
+            &CompilationSession::global(),
         )
         .await;
 
@@ -848,29 +1471,50 @@ impl Baraddur {
             for module_path in layout_relation {
                 tracing::debug!("Processing related module path: {:?}", module_path);
 
+                reprocessed_contexts.push(module_path.to_owned());
+
                 let _ = formera(
                     PathBuf::from(module_path),
                     nenyr_parser,
                     Local::now(),
                     palantir_sender.clone(),
+                    &CompilationSession::global(),
                 )
                 .await;
             }
         }
 
+        let is_central = context_type == Some(CrealionContextType::Central);
+        // The names of the reprocessed contexts, pushed to the HMR socket once the CSS cache is updated.
+        let updated_contexts = Self::resolve_updated_contexts(&reprocessed_contexts, is_central);
+
         // This method processes the current context type and sends appropriate events to
         // the main runtime. These events are then forwarded to the connected integration
         // client for further handling.
+        if is_central {
+            reprocessed_contexts.push("every Layout and Module context".to_string());
+        }
+
         Self::match_current_context_type_event(
             working_dir,
             &current_path,
             context_type,
+            updated_contexts,
             matcher,
+            dry_run,
             baraddur_sender.clone(),
             palantir_sender.clone(),
         )
         .await;
 
+        if dry_run {
+            Self::send_dry_run_report(
+                &reprocessed_contexts,
+                &previous_utilities,
+                palantir_sender.clone(),
+            );
+        }
+
         tracing::info!("Parsing process completed for: {:?}", current_path);
 
         // Create a notification indicating that the parsing process has been completed.
@@ -892,7 +1536,9 @@ impl Baraddur {
     /// - `working_dir`: The working directory, used for the processing contexts from the root of the application.
     /// - `current_path`: The path to the current context file.
     /// - `context_type`: An optional value representing the current context type (e.g., Layout, Module, or Central).
+    /// - `updated_contexts`: The names of the reprocessed contexts, pushed to the HMR socket clients.
     /// - `matcher`: A reference to a `RwLock` containing an `Override` object for the exclude matching.
+    /// - `dry_run`: Whether the CSS cache, the typings, the classes JSON and the files timestamps are left untouched.
     /// - `baraddur_sender`: A sender used to send events to the main runtime.
     /// - `palantir_sender`: A broadcast sender used to send alerts to Palantir.
     ///
@@ -902,7 +1548,9 @@ impl Baraddur {
         working_dir: &PathBuf,
         current_path: &PathBuf,
         context_type: Option<CrealionContextType>,
+        updated_contexts: Vec<String>,
         matcher: Arc<RwLock<overrides::Override>>,
+        dry_run: bool,
         _baraddur_sender: mpsc::UnboundedSender<GaladrielEvents>,
         palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
     ) {
@@ -916,8 +1564,33 @@ impl Baraddur {
         match context_type {
             // If the context type is Layout or Module, trigger the corresponding layout/module events.
             Some(CrealionContextType::Layout) | Some(CrealionContextType::Module) => {
+                let trailblazer = Trailblazer::default();
+
+                // Collapses the utility classes sharing identical declarations, when enabled.
+                trailblazer.dedupe_utilities(palantir_sender.clone());
+
                 // Applies inheritance for Nenyr classes and their corresponding utility class names.
-                Trailblazer::default().blazer();
+                trailblazer.blazer();
+
+                // Warns about the properties generating more utility classes than the configured limit.
+                warn_crowded_properties(&CompilationSession::global(), palantir_sender.clone());
+
+                // Warns about the derived classes overriding a property inherited with `!important`.
+                warn_important_overrides(&CompilationSession::global(), palantir_sender.clone());
+
+                // Warns about the derived classes whose parent class is not declared.
+                warn_dangling_derivations(&CompilationSession::global(), palantir_sender.clone());
+
+                // Warns about the variables that are never referenced, when enabled.
+                warn_unused_variables(&CompilationSession::global(), palantir_sender.clone());
+
+                // In a dry run, the resolved classes are only compared against the previous ones.
+                if dry_run {
+                    return;
+                }
+
+                // The class mappings are final at this point, so they are written before the CSS is regenerated.
+                Self::rewrite_classes_tracking(working_dir, palantir_sender.clone()).await;
 
                 // Updates the CSS cache by transforming the most up-to-date styles.
                 Astroform::new(
@@ -928,6 +1601,11 @@ impl Baraddur {
                 .transform()
                 .await;
 
+                // Notify the HMR socket clients about the contexts whose styles were updated.
+                let notification =
+                    GaladrielAlerts::create_styles_updated(Local::now(), updated_contexts);
+                send_palantir_notification(notification, palantir_sender.clone());
+
                 if let Some(parent_path) = current_path.parent() {
                     tracing::debug!("Sending refresh event for folder: {:?}", parent_path);
 
@@ -950,9 +1628,24 @@ impl Baraddur {
                 send_palantir_notification(notification, palantir_sender.clone());
 
                 // Reprocess all layout and module contexts from the application, excluding the central context.
-                Synthesizer::new(false, Arc::clone(&matcher), palantir_sender.clone())
-                    .process(get_minified_styles(), working_dir)
-                    .await;
+                Synthesizer::new(
+                    false,
+                    Arc::clone(&matcher),
+                    palantir_sender.clone(),
+                    CompilationSession::global(),
+                )
+                .set_styles_emission(!dry_run)
+                .process(get_minified_styles(), working_dir)
+                .await;
+
+                if dry_run {
+                    return;
+                }
+
+                // Notify the HMR socket clients about the contexts whose styles were updated.
+                let notification =
+                    GaladrielAlerts::create_styles_updated(Local::now(), updated_contexts);
+                send_palantir_notification(notification, palantir_sender.clone());
 
                 tracing::debug!("Synthesizer process completed. Sending refresh event from root.");
 
@@ -960,14 +1653,189 @@ impl Baraddur {
                     .process_from_folder(false, working_dir.to_owned(), Arc::clone(&matcher))
                     .await;
             }
+            None if dry_run => return,
             None => {}
         }
 
+        // Regenerates the TypeScript typings and the classes JSON, as the available Nenyr classes may have changed.
+        Self::regenerate_classes_typings(working_dir, palantir_sender.clone()).await;
+        Self::rewrite_classes_tracking(working_dir, palantir_sender.clone()).await;
+
         FileTimestampUpdater::new(palantir_sender.clone())
             .process_from_folder(true, working_dir.to_owned(), matcher)
             .await;
     }
 
+    /// Regenerates the TypeScript typings of the Nenyr classes when the `generateTypings` configuration is enabled.
+    ///
+    /// The typings are written at `.galadrielcss/galadrielcss.d.ts`, inside the working directory.
+    ///
+    /// # Arguments
+    /// - `working_dir`: The working directory where the `.galadrielcss` folder is located.
+    /// - `palantir_sender`: A broadcast sender used to send alerts to Palantir.
+    pub async fn regenerate_classes_typings(
+        working_dir: &PathBuf,
+        palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
+    ) {
+        if !get_generate_typings() {
+            return;
+        }
+
+        let starting_time = Local::now(); // Record the start time for tracking performance.
+        let folder_path = working_dir.join(".galadrielcss");
+        let typings_path = folder_path.join("galadrielcss.d.ts");
+
+        tracing::info!("Regenerating Nenyr classes typings at: {:?}", typings_path);
+
+        if let Err(error) = write_file(
+            folder_path,
+            typings_path,
+            serialize_classes_typings(),
+            ErrorAction::Notify,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await
+        {
+            tracing::error!("Failed to regenerate Nenyr classes typings: {:?}", error);
+
+            send_palantir_error_notification(error, starting_time, palantir_sender);
+        }
+    }
+
+    /// Rewrites the Nenyr classes tracking maps at `.galadrielcss/classes.json` when the `classesJson`
+    /// configuration is enabled.
+    ///
+    /// The file is only written when the tracking maps differ from its current content, so tooling
+    /// watching it is not notified about unchanged class mappings.
+    ///
+    /// # Arguments
+    /// - `working_dir`: The working directory where the `.galadrielcss` folder is located.
+    /// - `palantir_sender`: A broadcast sender used to send alerts to Palantir.
+    pub async fn rewrite_classes_tracking(
+        working_dir: &PathBuf,
+        palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
+    ) {
+        if !get_classes_json() {
+            return;
+        }
+
+        let starting_time = Local::now(); // Record the start time for tracking performance.
+        let folder_path = working_dir.join(".galadrielcss");
+        let classes_json_path = folder_path.join("classes.json");
+        let tracking = serialize_classes_tracking();
+
+        if let Ok(current_content) = tokio::fs::read_to_string(&classes_json_path).await {
+            if current_content == tracking {
+                tracing::debug!("Nenyr classes tracking maps unchanged, skipping classes JSON.");
+
+                return;
+            }
+        }
+
+        tracing::info!(
+            "Rewriting Nenyr classes tracking maps at: {:?}",
+            classes_json_path
+        );
+
+        if let Err(error) = write_file(
+            folder_path,
+            classes_json_path,
+            tracking,
+            ErrorAction::Notify,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await
+        {
+            tracing::error!("Failed to rewrite Nenyr classes tracking maps: {:?}", error);
+
+            send_palantir_error_notification(error, starting_time, palantir_sender);
+        }
+    }
+
+    /// Resolves the names of the reprocessed contexts from their paths.
+    ///
+    /// # Arguments
+    /// - `reprocessed_paths`: The paths of the reprocessed Nenyr files.
+    /// - `is_central`: Whether the central context was reprocessed, which updates every context.
+    ///
+    /// # Returns
+    /// - The sorted names of the updated contexts, `central` for the central context.
+    fn resolve_updated_contexts(reprocessed_paths: &[String], is_central: bool) -> Vec<String> {
+        let intaker = CompilationSession::global().intaker;
+
+        let context_names = match is_central {
+            true => std::iter::once("central".to_string())
+                .chain(intaker.iter().map(|entry| entry.value().to_owned()))
+                .collect::<BTreeSet<String>>(),
+            false => reprocessed_paths
+                .iter()
+                .filter_map(|path| intaker.get(path).map(|entry| entry.value().to_owned()))
+                .collect::<BTreeSet<String>>(),
+        };
+
+        context_names.into_iter().collect()
+    }
+
+    /// Collects the utility class names used by the Nenyr classes of every tracked context.
+    fn collect_tracked_utilities() -> BTreeSet<String> {
+        collect_context_partitions(&CompilationSession::global().clastrack)
+            .into_iter()
+            .flat_map(|partition| partition.utility_names)
+            .collect()
+    }
+
+    /// Reports the outcome of a dry run as an information alert: the reprocessed contexts, along with
+    /// the utility classes added or removed since the previous processing.
+    ///
+    /// # Arguments
+    /// - `reprocessed_contexts`: The contexts reprocessed by the dry run.
+    /// - `previous_utilities`: The utility class names tracked before the dry run.
+    /// - `palantir_sender`: A broadcast sender used to send alerts to Palantir.
+    fn send_dry_run_report(
+        reprocessed_contexts: &[String],
+        previous_utilities: &BTreeSet<String>,
+        palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
+    ) {
+        let current_utilities = Self::collect_tracked_utilities();
+        let added_utilities = current_utilities
+            .difference(previous_utilities)
+            .cloned()
+            .collect::<Vec<String>>();
+        let removed_utilities = previous_utilities
+            .difference(&current_utilities)
+            .cloned()
+            .collect::<Vec<String>>();
+
+        tracing::info!(
+            "Dry run reprocessed {} contexts: {} utility classes added, {} removed.",
+            reprocessed_contexts.len(),
+            added_utilities.len(),
+            removed_utilities.len()
+        );
+
+        let list_or_none = |names: &Vec<String>| {
+            if names.is_empty() {
+                "none".to_string()
+            } else {
+                names.join(", ")
+            }
+        };
+
+        let notification = GaladrielAlerts::create_information(
+            Local::now(),
+            &format!(
+                "Dry run: no file was written. Reprocessed contexts: {}. Utility classes added: {}. Utility classes removed: {}.",
+                reprocessed_contexts.join(", "),
+                list_or_none(&added_utilities),
+                list_or_none(&removed_utilities)
+            ),
+        );
+
+        send_palantir_notification(notification, palantir_sender);
+    }
+
     /// Sends an event to the main runtime through the Baraddur sender.
     ///
     /// # Arguments
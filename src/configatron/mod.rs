@@ -1,7 +1,14 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use chrono::Local;
+use dashmap::DashMap;
 use ignore::overrides;
+use schemars::{schema_for, JsonSchema};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::json;
 use tokio::sync::RwLock;
@@ -13,10 +20,69 @@ use crate::{
     GaladrielResult,
 };
 
+/// Shortest hashed suffix allowed for the utility class names, below which collisions become likely.
+pub const MIN_CLASS_NAME_HASH_LENGTH: usize = 3;
+/// Longest hashed suffix allowed for the utility class names, as the 64-bit hash rarely yields more base-62 digits.
+pub const MAX_CLASS_NAME_HASH_LENGTH: usize = 10;
+/// Configurations only affecting how the resolved styles are emitted as CSS.
+const EMISSION_CONFIG_NAMES: [&str; 15] = [
+    "minifiedStyles",
+    "resetStyles",
+    "resetStylesMedia",
+    "sortOutputByProperty",
+    "themeVarFallback",
+    "emitCharset",
+    "publicNames",
+    "stableOutput",
+    "sourceMaps",
+    "contextComments",
+    "autoprefix",
+    "browserTargets",
+    "emitAtProperty",
+    "cssLayer",
+    "respectReducedMotion",
+];
+/// Configurations affecting how Nenyr contexts are resolved, or the warnings raised while resolving them.
+const RESOLUTION_CONFIG_NAMES: [&str; 20] = [
+    "exclude",
+    "autoNaming",
+    "maxExtensionDepth",
+    "consolidateShorthand",
+    "contrastChecks",
+    "scaleFunction",
+    "scaleBase",
+    "resolveOnlyContexts",
+    "animationPrefix",
+    "classNameHashLength",
+    "frozenCentralNames",
+    "maxClassesPerProperty",
+    "lenientAliases",
+    "strictVariables",
+    "lintPseudoElements",
+    "dedupeUtilities",
+    "classPrefix",
+    "featureFlags",
+    "warnUnusedVariables",
+    "containerBreakpoints",
+];
+/// Key of the configuration file object holding the named configuration profiles.
+const CONFIG_PROFILES_KEY: &str = "profiles";
+/// Name of the JSON configuration file, preferred whenever it exists.
+pub const JSON_CONFIG_FILE_NAME: &str = "galadriel.config.json";
+/// Name of the TOML configuration file, read only when the JSON one is absent.
+pub const TOML_CONFIG_FILE_NAME: &str = "galadriel.config.toml";
+
+/// The formats the configuration file may be written in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+}
+
 /// Represents configuration settings for the application, deserialized from a JSON file.
 ///
 /// Fields are deserialized using `serde`, with custom default functions specified for each.
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, JsonSchema, Debug)]
 pub struct ConfigurationJson {
     /// List of paths or identifiers to exclude from the process.
     /// Defaults to an empty vector if not provided.
@@ -44,7 +110,326 @@ pub struct ConfigurationJson {
         default = "default_wildcard_port",
         deserialize_with = "normalize_wildcard_port"
     )]
+    #[schemars(with = "String")]
     pub port: String,
+
+    /// Maximum number of contexts allowed in an extension chain (e.g. module → layout → central).
+    /// Renamed in JSON as `maxExtensionDepth` and defaults to `8`.
+    #[serde(rename = "maxExtensionDepth", default = "default_max_extension_depth")]
+    pub max_extension_depth: usize,
+
+    /// Boolean flag indicating whether error alerts should display paths relative to the working directory.
+    /// Renamed in JSON as `relativeErrorPaths` and defaults to `false`.
+    #[serde(rename = "relativeErrorPaths", default = "disenabled_by_default")]
+    pub relative_error_paths: bool,
+
+    /// Boolean flag indicating whether the TypeScript typings of the Nenyr classes should be regenerated in development mode.
+    /// Renamed in JSON as `generateTypings` and defaults to `false`.
+    #[serde(rename = "generateTypings", default = "disenabled_by_default")]
+    pub generate_typings: bool,
+
+    /// Boolean flag indicating whether complete sets of longhand properties sharing a value are consolidated into their shorthand.
+    /// Renamed in JSON as `consolidateShorthand` and defaults to `false`.
+    #[serde(rename = "consolidateShorthand", default = "disenabled_by_default")]
+    pub consolidate_shorthand: bool,
+
+    /// Window, in milliseconds, in which rapid reload requests to the integration client are coalesced into one.
+    /// Renamed in JSON as `reloadThrottleMs` and defaults to `100`. A value of `0` disables the throttle.
+    #[serde(rename = "reloadThrottleMs", default = "default_reload_throttle_ms")]
+    pub reload_throttle_ms: u64,
+
+    /// Readable names emitted as additional selectors of the utility classes of a Nenyr class.
+    /// Keys follow the markup syntax (`@class:name`, `@layout:context::name` or `@module:context::name`).
+    /// Renamed in JSON as `publicNames` and defaults to an empty map.
+    #[serde(rename = "publicNames", default = "empty_map_by_default")]
+    pub public_names: BTreeMap<String, String>,
+
+    /// Foreground/background variable pairs whose contrast ratio is checked in every theme schema.
+    /// Renamed in JSON as `contrastChecks` and defaults to an empty vector.
+    #[serde(
+        rename = "contrastChecks",
+        default = "empty_contrast_checks_by_default"
+    )]
+    pub contrast_checks: Vec<ContrastCheck>,
+
+    /// Name of the scale function expanded in style values, e.g. `space(3)`.
+    /// Renamed in JSON as `scaleFunction` and defaults to `space`.
+    #[serde(rename = "scaleFunction", default = "default_scale_function")]
+    pub scale_function: String,
+
+    /// Base unit multiplied by the scale function, e.g. `4px` or `${spaceUnit}`.
+    /// Renamed in JSON as `scaleBase` and defaults to an empty string, which disables the scale function.
+    #[serde(rename = "scaleBase", default = "default_scale_base")]
+    pub scale_base: String,
+
+    /// Names of the contexts (`central` for the central context) that only provide variables, aliases and animations
+    /// to the contexts extending them, without emitting utility classes of their own.
+    /// Renamed in JSON as `resolveOnlyContexts` and defaults to an empty vector.
+    #[serde(rename = "resolveOnlyContexts", default = "empty_vector_by_default")]
+    pub resolve_only_contexts: Vec<String>,
+
+    /// Boolean flag indicating whether the Nenyr classes tracking maps are also written to `.galadrielcss/classes.json`,
+    /// updated as soon as the classes change and independently of the CSS regeneration.
+    /// Renamed in JSON as `classesJson` and defaults to `false`.
+    #[serde(rename = "classesJson", default = "disenabled_by_default")]
+    pub classes_json: bool,
+
+    /// Prefix prepended to the generated `@keyframes` names and their `animation-name` references,
+    /// namespacing the animations of bundles that coexist on the same page.
+    /// Renamed in JSON as `animationPrefix` and defaults to an empty string.
+    #[serde(
+        rename = "animationPrefix",
+        default = "empty_animation_prefix_by_default"
+    )]
+    pub animation_prefix: String,
+
+    /// Boolean flag indicating whether the build mode writes each non-default theme schema into its own
+    /// `.galadrielcss/themes/<theme>.css` file, leaving the default (`light`) theme inline.
+    /// Renamed in JSON as `splitThemes` and defaults to `false`.
+    #[serde(rename = "splitThemes", default = "disenabled_by_default")]
+    pub split_themes: bool,
+
+    /// Length of the hashed suffix appended to the generated utility class names, trading name length
+    /// against collision headroom. Must be within `MIN_CLASS_NAME_HASH_LENGTH` and `MAX_CLASS_NAME_HASH_LENGTH`.
+    /// Renamed in JSON as `classNameHashLength` and defaults to `4`.
+    #[serde(
+        rename = "classNameHashLength",
+        default = "default_class_name_hash_length"
+    )]
+    pub class_name_hash_length: usize,
+
+    /// Media condition the reset styles are scoped to (e.g. `min-width:768px`), or excluded from
+    /// when prefixed with `not ` (e.g. `not min-width:768px`).
+    /// Renamed in JSON as `resetStylesMedia` and defaults to an empty string, emitting the reset styles globally.
+    #[serde(
+        rename = "resetStylesMedia",
+        default = "empty_reset_styles_media_by_default"
+    )]
+    pub reset_styles_media: String,
+
+    /// Boolean flag indicating whether the development mode rings the terminal bell when an error is raised
+    /// while processing Nenyr files, giving passive awareness of failures during long-running watches.
+    /// Renamed in JSON as `notifyOnError` and defaults to `false`.
+    #[serde(rename = "notifyOnError", default = "disenabled_by_default")]
+    pub notify_on_error: bool,
+
+    /// Names of the central variables, aliases, and animations that layouts and modules are not allowed to redefine.
+    /// A `*` entry freezes every name defined by the central context.
+    /// Renamed in JSON as `frozenCentralNames` and defaults to an empty vector.
+    #[serde(rename = "frozenCentralNames", default = "empty_vector_by_default")]
+    pub frozen_central_names: Vec<String>,
+
+    /// Boolean flag indicating whether the emitted utility rules are ordered by CSS property name, keeping
+    /// the stylesheet scannable. Only the textual order of the rules changes.
+    /// Renamed in JSON as `sortOutputByProperty` and defaults to `false`, preserving the processing order.
+    #[serde(rename = "sortOutputByProperty", default = "disenabled_by_default")]
+    pub sort_output_by_property: bool,
+
+    /// Soft limit of distinct utility classes generated for a single CSS property. Exceeding it raises a warning
+    /// suggesting the use of variables instead of arbitrary values.
+    /// Renamed in JSON as `maxClassesPerProperty` and defaults to `0`, which disables the limit.
+    #[serde(
+        rename = "maxClassesPerProperty",
+        default = "disabled_max_classes_per_property_by_default"
+    )]
+    pub max_classes_per_property: usize,
+
+    /// Theme schema (`light` or `dark`) whose variables fill the ones missing from the other schema of the same context,
+    /// so an omitted theme variable does not leave its `var()` undefined.
+    /// Renamed in JSON as `themeVarFallback` and defaults to an empty string, disabling the fallback.
+    #[serde(
+        rename = "themeVarFallback",
+        default = "empty_theme_var_fallback_by_default"
+    )]
+    pub theme_var_fallback: String,
+
+    /// Minutes without file changes or client requests after which the development server shuts itself down,
+    /// freeing the resources of shared machines.
+    /// Renamed in JSON as `idleTimeoutMinutes` and defaults to `0`, which disables the timeout.
+    #[serde(
+        rename = "idleTimeoutMinutes",
+        default = "disabled_idle_timeout_by_default"
+    )]
+    pub idle_timeout_minutes: u64,
+
+    /// Flag for resolving aliases written with a different casing, such as `BGD` for the `bgd` alias.
+    /// Each mismatched alias raises a warning recommending its canonical casing.
+    /// Renamed in JSON as `lenientAliases` and defaults to `false`, keeping the exact alias matching.
+    #[serde(rename = "lenientAliases", default = "disenabled_by_default")]
+    pub lenient_aliases: bool,
+
+    /// Properties whose values must reference variables, e.g. `color` or `backgroundColor`.
+    /// A literal value such as `#FF6677` in one of these properties raises a warning, enforcing the design tokens.
+    /// Renamed in JSON as `strictVariables` and defaults to an empty vector.
+    #[serde(rename = "strictVariables", default = "empty_vector_by_default")]
+    pub strict_variables: Vec<String>,
+
+    /// Flag for starting the generated stylesheets with an `@charset "UTF-8";` rule,
+    /// for servers and CDNs that do not send the charset in their headers.
+    /// Renamed in JSON as `emitCharset` and defaults to `false`.
+    #[serde(rename = "emitCharset", default = "disenabled_by_default")]
+    pub emit_charset: bool,
+
+    /// URL notified with a `POST` request once a build completes, carrying its outcome, CSS size and class count.
+    /// The request is best-effort: a failing webhook is logged and never fails the build.
+    /// Renamed in JSON as `buildWebhook` and defaults to an empty string, disabling the notification.
+    #[serde(rename = "buildWebhook", default = "empty_build_webhook_by_default")]
+    pub build_webhook: String,
+
+    /// Boolean flag indicating whether the Nenyr code of error alerts is rendered in a single plain color, skipping the syntax highlighting.
+    /// Renamed in JSON as `plainAlerts` and defaults to `false`.
+    #[serde(rename = "plainAlerts", default = "disenabled_by_default")]
+    pub plain_alerts: bool,
+
+    /// Boolean flag indicating whether the emitted utility rules, variables, themes and animations are sorted
+    /// before emission, so identical Nenyr contexts always generate byte-identical CSS regardless of the
+    /// order in which they were processed.
+    /// Renamed in JSON as `stableOutput` and defaults to `true`.
+    #[serde(rename = "stableOutput", default = "enabled_by_default")]
+    pub stable_output: bool,
+
+    /// Boolean flag indicating whether a Source Map v3 is generated along with the CSS, mapping every utility rule
+    /// to the declaration of the Nenyr class it was generated for.
+    /// Renamed in JSON as `sourceMaps` and defaults to `false`.
+    #[serde(rename = "sourceMaps", default = "disenabled_by_default")]
+    pub source_maps: bool,
+
+    /// Boolean flag indicating whether a warning is raised for each property applied to a pseudo-element that
+    /// does not support it, such as layout properties under the `::first-line` pattern.
+    /// Renamed in JSON as `lintPseudoElements` and defaults to `false`.
+    #[serde(rename = "lintPseudoElements", default = "disenabled_by_default")]
+    pub lint_pseudo_elements: bool,
+
+    /// Boolean flag indicating whether the rules of each context are introduced by a comment naming the Nenyr
+    /// context that produced them. The comments are left out of the minified CSS.
+    /// Renamed in JSON as `contextComments` and defaults to `false`.
+    #[serde(rename = "contextComments", default = "disenabled_by_default")]
+    pub context_comments: bool,
+
+    /// Boolean flag indicating whether the vendor-prefixed declarations required by the `browserTargets` are
+    /// emitted alongside the standard declaration of each utility class rule.
+    /// Renamed in JSON as `autoprefix` and defaults to `false`.
+    #[serde(rename = "autoprefix", default = "disenabled_by_default")]
+    pub autoprefix: bool,
+
+    /// Browsers the vendor prefixes are emitted for, as a name and the oldest supported version, e.g. `safari 14`.
+    /// An empty list emits every known vendor prefix.
+    /// Renamed in JSON as `browserTargets` and defaults to an empty vector.
+    #[serde(rename = "browserTargets", default = "empty_vector_by_default")]
+    pub browser_targets: Vec<String>,
+
+    /// Number of Nenyr file events above which a batch of watched events triggers a single full reprocess of
+    /// the project instead of processing each file, keeping the watcher responsive during bulk file operations.
+    /// Renamed in JSON as `maxWatchBatch` and defaults to `0`, which always processes the files one by one.
+    #[serde(
+        rename = "maxWatchBatch",
+        default = "disabled_max_watch_batch_by_default"
+    )]
+    pub max_watch_batch: usize,
+
+    /// Boolean flag indicating whether the utility classes with identical declarations across contexts are collapsed
+    /// into a single shared utility class, comparing the declaration values literally.
+    /// Renamed in JSON as `dedupeUtilities` and defaults to `false`.
+    #[serde(rename = "dedupeUtilities", default = "disenabled_by_default")]
+    pub dedupe_utilities: bool,
+
+    /// Boolean flag indicating whether the mobile-first and desktop-first responsive styles are written into their
+    /// own CSS files on build, leaving the base styles in the main stylesheet.
+    /// Renamed in JSON as `splitResponsive` and defaults to `false`.
+    #[serde(rename = "splitResponsive", default = "disenabled_by_default")]
+    pub split_responsive: bool,
+
+    /// Prefix prepended to every generated utility class name, avoiding collisions with the classes
+    /// of other utility frameworks loaded on the same page.
+    /// Renamed in JSON as `classPrefix` and defaults to an empty string.
+    #[serde(rename = "classPrefix", default = "empty_class_prefix_by_default")]
+    pub class_prefix: String,
+
+    /// Feature flags a context (`contextName`) or one of its classes (`contextName::className`) depends on.
+    /// On build, their classes are only generated when every flag is passed through the `--features` option.
+    /// Renamed in JSON as `featureFlags` and defaults to an empty map.
+    #[serde(rename = "featureFlags", default = "empty_feature_flags_by_default")]
+    pub feature_flags: BTreeMap<String, Vec<String>>,
+
+    /// Boolean flag indicating whether an `@property` rule registers every variable, inferring its `syntax`
+    /// from the value, so the variables can be animated.
+    /// Renamed in JSON as `emitAtProperty` and defaults to `false`.
+    #[serde(rename = "emitAtProperty", default = "disenabled_by_default")]
+    pub emit_at_property: bool,
+
+    /// Template of the file name the build JSON is written to, where `{env}`, `{hash}` and `{date}`
+    /// are replaced when it is written, e.g. `galadrielcss.{env}.json`.
+    /// Renamed in JSON as `buildJsonName` and defaults to `galadrielcss.json`.
+    #[serde(
+        rename = "buildJsonName",
+        default = "galadrielcss_build_json_name_by_default"
+    )]
+    pub build_json_name: String,
+
+    /// Boolean flag indicating whether a warning is raised for each variable that no utility class, animation
+    /// or other variable references once the project is processed.
+    /// Renamed in JSON as `warnUnusedVariables` and defaults to `false`.
+    #[serde(rename = "warnUnusedVariables", default = "disenabled_by_default")]
+    pub warn_unused_variables: bool,
+
+    /// Glob patterns of additional non-Nenyr files, relative to the project root, whose changes reprocess
+    /// the whole project while watching, e.g. the templates of a step generating Nenyr files.
+    /// Renamed in JSON as `watchExtra` and defaults to an empty vector.
+    #[serde(rename = "watchExtra", default = "empty_vector_by_default")]
+    pub watch_extra: Vec<String>,
+
+    /// Glob patterns of the source files, relative to the project root, scanned by the build for the Nenyr
+    /// classes they reference, so the classes never referenced are warned about and listed in the build stats.
+    /// Renamed in JSON as `contentGlobs` and defaults to an empty vector, which disables the scan.
+    #[serde(rename = "contentGlobs", default = "empty_vector_by_default")]
+    pub content_globs: Vec<String>,
+
+    /// Name of the cascade layer the generated stylesheet is wrapped in, e.g. `galadriel`, so its
+    /// precedence against third-party stylesheets is controlled with `@layer`. The reset styles go
+    /// into the nested `reset` layer, losing to the utility classes.
+    /// Renamed in JSON as `cssLayer` and defaults to an empty string, which keeps the stylesheet unlayered.
+    #[serde(rename = "cssLayer", default = "empty_css_layer_by_default")]
+    pub css_layer: String,
+
+    /// Boolean flag indicating whether a `prefers-reduced-motion: reduce` media query is appended, turning off
+    /// the animations and transitions of the generated utility classes for the users requesting less motion.
+    /// Renamed in JSON as `respectReducedMotion` and defaults to `false`.
+    #[serde(rename = "respectReducedMotion", default = "disenabled_by_default")]
+    pub respect_reduced_motion: bool,
+
+    /// Number of sequential ports tried after the configured one when it is already in use, e.g. `3001` to `3010`
+    /// for the `3000` port. Renamed in JSON as `portFallbackRange` and defaults to `10`, with `0` disabling the fallback.
+    #[serde(rename = "portFallbackRange", default = "default_port_fallback_range")]
+    pub port_fallback_range: u16,
+
+    /// Breakpoints emitted as container queries, mapping the breakpoint name used in the `PanoramicViewer` pattern
+    /// to its condition, e.g. `{"cardMd": "min-width:480px"}` for `@container (min-width:480px)`. The breakpoints
+    /// declared in the central context take precedence over them. Renamed in JSON as `containerBreakpoints` and
+    /// defaults to an empty map.
+    #[serde(
+        rename = "containerBreakpoints",
+        default = "empty_container_breakpoints_by_default"
+    )]
+    pub container_breakpoints: BTreeMap<String, String>,
+
+    /// Boolean flag indicating whether the builds rename the generated utility classes, variables and animations
+    /// to the shortest unique names, shrinking the stylesheet. The development mode keeps the original names.
+    /// Renamed in JSON as `mangleNames` and defaults to `false`.
+    #[serde(rename = "mangleNames", default = "disenabled_by_default")]
+    pub mangle_names: bool,
+}
+
+/// A foreground/background variable pair whose contrast ratio is checked in every theme schema.
+#[derive(Clone, PartialEq, Serialize, Deserialize, JsonSchema, Debug)]
+pub struct ContrastCheck {
+    /// Identifier of the variable holding the foreground color.
+    pub foreground: String,
+    /// Identifier of the variable holding the background color.
+    pub background: String,
+    /// Minimum contrast ratio accepted for the pair.
+    /// Renamed in JSON as `minRatio` and defaults to `4.5`.
+    #[serde(rename = "minRatio", default = "default_min_contrast_ratio")]
+    pub min_ratio: f64,
 }
 
 /// Returns `true` as the default value, used for fields requiring an enabled default state.
@@ -75,6 +460,156 @@ fn default_wildcard_port() -> String {
     "0".to_string()
 }
 
+/// Provides `8` as the default maximum extension depth, which comfortably covers the
+/// regular module → layout → central chain while still catching runaway extensions.
+fn default_max_extension_depth() -> usize {
+    tracing::info!("Setting default max extension depth to '8'");
+
+    8
+}
+
+/// Provides `100` milliseconds as the default reload throttle window, short enough to go unnoticed
+/// while still collapsing the bursts of reloads fired by a full reprocess.
+fn default_reload_throttle_ms() -> u64 {
+    tracing::info!("Setting default reload throttle to '100' milliseconds");
+
+    100
+}
+
+/// Returns an empty `BTreeMap<String, String>` as the default, used for the `publicNames` field.
+fn empty_map_by_default() -> BTreeMap<String, String> {
+    tracing::info!("Setting default empty map for public names");
+
+    BTreeMap::new()
+}
+
+/// Returns an empty `Vec<ContrastCheck>` as the default, used for the `contrastChecks` field.
+fn empty_contrast_checks_by_default() -> Vec<ContrastCheck> {
+    tracing::info!("Setting default empty vector for contrast checks");
+
+    vec![]
+}
+
+/// Provides `4.5` as the default minimum contrast ratio, the WCAG AA threshold for normal text.
+fn default_min_contrast_ratio() -> f64 {
+    tracing::info!("Setting default minimum contrast ratio to '4.5'");
+
+    4.5
+}
+
+/// Provides `space` as the default name of the scale function, expanded as `space(n)`.
+fn default_scale_function() -> String {
+    tracing::info!("Setting default scale function to 'space'");
+
+    "space".to_string()
+}
+
+/// Provides an empty scale base as the default, which leaves the scale function disabled.
+fn default_scale_base() -> String {
+    tracing::info!("Setting default empty scale base");
+
+    String::new()
+}
+
+/// Provides an empty animation prefix as the default, which keeps the generated animation names as they are.
+fn empty_animation_prefix_by_default() -> String {
+    tracing::info!("Setting default empty animation prefix");
+
+    String::new()
+}
+
+/// Provides `4` as the default length of the hashed suffix of the utility class names,
+/// which keeps the names short while leaving room for millions of distinct values.
+fn default_class_name_hash_length() -> usize {
+    tracing::info!("Setting default class name hash length to '4'");
+
+    4
+}
+
+/// Provides an empty media condition as the default, which emits the reset styles globally.
+fn empty_reset_styles_media_by_default() -> String {
+    tracing::info!("Setting default empty reset styles media condition");
+
+    String::new()
+}
+
+/// Provides `0` as the default, which disables the per-property utility classes limit.
+fn disabled_max_classes_per_property_by_default() -> usize {
+    tracing::info!("Setting default max classes per property: disabled");
+
+    0
+}
+
+/// Provides an empty theme schema as the default, which disables the theme variables fallback.
+fn empty_theme_var_fallback_by_default() -> String {
+    tracing::info!("Setting default empty theme variables fallback");
+
+    String::new()
+}
+
+/// Provides `0` as the default, which keeps the development server running until it is terminated.
+fn disabled_idle_timeout_by_default() -> u64 {
+    tracing::info!("Setting default idle timeout: disabled");
+
+    0
+}
+
+/// Provides an empty URL as the default, which disables the build webhook.
+fn empty_build_webhook_by_default() -> String {
+    tracing::info!("Setting default build webhook: disabled");
+
+    String::new()
+}
+
+/// Provides `0` as the default, which processes every watched event one by one.
+fn disabled_max_watch_batch_by_default() -> usize {
+    tracing::info!("Setting default max watch batch: disabled");
+
+    0
+}
+
+/// Provides an empty class prefix as the default, which keeps the generated utility class names as they are.
+fn empty_class_prefix_by_default() -> String {
+    tracing::info!("Setting default empty class prefix");
+
+    String::new()
+}
+
+/// Returns an empty `BTreeMap<String, Vec<String>>` as the default, used for the `featureFlags` field.
+fn empty_feature_flags_by_default() -> BTreeMap<String, Vec<String>> {
+    tracing::info!("Setting default empty map for feature flags");
+
+    BTreeMap::new()
+}
+
+/// Provides `galadrielcss.json` as the default build JSON file name.
+fn galadrielcss_build_json_name_by_default() -> String {
+    tracing::info!("Setting default build JSON name: galadrielcss.json");
+
+    "galadrielcss.json".to_string()
+}
+
+/// Provides an empty cascade layer name as the default, which keeps the generated stylesheet unlayered.
+fn empty_css_layer_by_default() -> String {
+    tracing::info!("Setting default empty CSS layer");
+
+    String::new()
+}
+
+/// Provides the default number of sequential ports tried when the configured one is in use.
+fn default_port_fallback_range() -> u16 {
+    tracing::info!("Setting default port fallback range: 10");
+
+    10
+}
+
+/// Returns an empty `BTreeMap<String, String>` as the default, used for the `containerBreakpoints` field.
+fn empty_container_breakpoints_by_default() -> BTreeMap<String, String> {
+    tracing::info!("Setting default empty map for container breakpoints");
+
+    BTreeMap::new()
+}
+
 /// Normalize the received port transforming the "*" into "0" - "0" means to the system look for any available port.
 fn normalize_wildcard_port<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
@@ -106,6 +641,100 @@ pub enum GaladrielConfig {
     MinifiedStyles(bool),
     /// The port to be used by the system.
     Port(String),
+    /// The maximum number of contexts allowed in an extension chain.
+    MaxExtensionDepth(usize),
+    /// Determines whether error alerts display paths relative to the working directory.
+    RelativeErrorPaths(bool),
+    /// Determines whether the TypeScript typings are regenerated in development mode.
+    GenerateTypings(bool),
+    /// Determines whether complete longhand sets are consolidated into their shorthand property.
+    ConsolidateShorthand(bool),
+    /// The window, in milliseconds, in which reload requests are coalesced.
+    ReloadThrottleMs(u64),
+    /// Readable selector names aliasing the utility classes of Nenyr classes.
+    PublicNames(BTreeMap<String, String>),
+    /// Foreground/background variable pairs whose theme contrast is checked.
+    ContrastChecks(Vec<ContrastCheck>),
+    /// Name of the scale function expanded in style values.
+    ScaleFunction(String),
+    /// Base unit multiplied by the scale function.
+    ScaleBase(String),
+    /// Contexts whose classes are never emitted, kept only for resolution.
+    ResolveOnlyContexts(Vec<String>),
+    /// Flag for writing the Nenyr classes tracking maps to a separate JSON file.
+    ClassesJson(bool),
+    /// Prefix prepended to the generated animation names.
+    AnimationPrefix(String),
+    /// Flag for splitting the theme schemas into their own CSS files on build.
+    SplitThemes(bool),
+    /// Length of the hashed suffix of the utility class names.
+    ClassNameHashLength(usize),
+    /// Media condition the reset styles are scoped to or excluded from.
+    ResetStylesMedia(String),
+    /// Flag for ringing the terminal bell when an error is raised.
+    NotifyOnError(bool),
+    /// Names of the central definitions that layouts and modules cannot redefine.
+    FrozenCentralNames(Vec<String>),
+    /// Flag for ordering the emitted utility rules by CSS property name.
+    SortOutputByProperty(bool),
+    /// Soft limit of distinct utility classes generated for a single CSS property.
+    MaxClassesPerProperty(usize),
+    /// Theme schema filling the variables missing from the other schema.
+    ThemeVarFallback(String),
+    /// Minutes of inactivity after which the development server shuts down.
+    IdleTimeoutMinutes(u64),
+    /// Flag for resolving aliases case-insensitively.
+    LenientAliases(bool),
+    /// Properties whose values must reference variables.
+    StrictVariables(Vec<String>),
+    /// Flag for emitting the `@charset` rule.
+    EmitCharset(bool),
+    /// URL notified once a build completes.
+    BuildWebhook(String),
+    /// Determines whether the Nenyr code of error alerts skips the syntax highlighting.
+    PlainAlerts(bool),
+    /// Determines whether the generated CSS is sorted into a deterministic order.
+    StableOutput(bool),
+    /// Determines whether a source map is generated along with the CSS.
+    SourceMaps(bool),
+    /// Determines whether properties unsupported by their pseudo-element are reported.
+    LintPseudoElements(bool),
+    /// Determines whether the rules of each context are introduced by a comment naming it.
+    ContextComments(bool),
+    /// Determines whether vendor-prefixed declarations are emitted.
+    Autoprefix(bool),
+    /// Lists the browsers the vendor prefixes are emitted for.
+    BrowserTargets(Vec<String>),
+    /// Number of Nenyr file events above which a watched batch is reprocessed as a whole.
+    MaxWatchBatch(usize),
+    /// Collapses the utility classes with identical declarations into shared ones.
+    DedupeUtilities(bool),
+    /// Splits the mobile-first and desktop-first responsive styles into their own CSS files on build.
+    SplitResponsive(bool),
+    /// Prefix prepended to every generated utility class name.
+    ClassPrefix(String),
+    /// Feature flags the contexts and classes depend on, keyed by `contextName` or `contextName::className`.
+    FeatureFlags(BTreeMap<String, Vec<String>>),
+    /// Registers every variable with an `@property` rule, so the variables can be animated.
+    EmitAtProperty(bool),
+    /// Template of the file name the build JSON is written to.
+    BuildJsonName(String),
+    /// Raises a warning for each variable that is never referenced.
+    WarnUnusedVariables(bool),
+    /// Glob patterns of additional files whose changes reprocess the whole project.
+    WatchExtra(Vec<String>),
+    /// Glob patterns of the source files scanned for the referenced Nenyr classes.
+    ContentGlobs(Vec<String>),
+    /// Name of the cascade layer the generated stylesheet is wrapped in.
+    CssLayer(String),
+    /// Determines whether the animations and transitions are turned off for reduced motion.
+    RespectReducedMotion(bool),
+    /// Number of sequential ports tried when the configured port is in use.
+    PortFallbackRange(u16),
+    /// Breakpoints emitted as container queries, keyed by their name.
+    ContainerBreakpoints(BTreeMap<String, String>),
+    /// Determines whether the builds rename the generated identifiers to the shortest unique names.
+    MangleNames(bool),
 }
 
 impl GaladrielConfig {
@@ -130,6 +759,20 @@ impl GaladrielConfig {
         }
     }
 
+    /// Toggles the state of the `RelativeErrorPaths` configuration.
+    pub fn switch_relative_error_paths(&mut self) {
+        if let GaladrielConfig::RelativeErrorPaths(ref mut flag) = self {
+            *flag = !*flag;
+        }
+    }
+
+    /// Toggles the state of the `PlainAlerts` configuration.
+    pub fn switch_plain_alerts(&mut self) {
+        if let GaladrielConfig::PlainAlerts(ref mut flag) = self {
+            *flag = !*flag;
+        }
+    }
+
     /// Updates the list of paths to exclude in the `Exclude` configuration.
     pub fn _set_exclude(&mut self, exclude: Vec<String>) {
         if let GaladrielConfig::Exclude(ref mut node) = self {
@@ -188,129 +831,1279 @@ impl GaladrielConfig {
 
         "0".to_string()
     }
-}
 
-/// Updates the global configuration map `CONFIGATRON` with new configuration values.
-///
-/// # Parameters
-/// - `exclude`: Paths to exclude from processing.
-/// - `auto_naming`: Whether to enable auto-naming.
-/// - `reset_styles`: Whether to reset styles to defaults.
-/// - `minified_styles`: Whether styles should be minified.
-/// - `port`: The port to use for the system.
-pub fn set_configatron(
-    exclude: Vec<String>,
-    auto_naming: bool,
-    reset_styles: bool,
-    minified_styles: bool,
-    port: String,
-) {
-    tracing::trace!(
-        "Entering set_configatron with parameters: exclude={:?}, auto_naming={}, reset_styles={}, minified_styles={}, port={}",
-        exclude, auto_naming, reset_styles, minified_styles, port
-    );
+    /// Retrieves the current maximum depth from the `MaxExtensionDepth` configuration.
+    pub fn get_max_extension_depth(&self) -> usize {
+        if let GaladrielConfig::MaxExtensionDepth(ref depth) = self {
+            return *depth;
+        }
 
-    CONFIGATRON.insert("exclude".to_string(), GaladrielConfig::Exclude(exclude));
-    CONFIGATRON.insert(
-        "autoNaming".to_string(),
-        GaladrielConfig::AutoNaming(auto_naming),
-    );
-    CONFIGATRON.insert(
-        "resetStyles".to_string(),
-        GaladrielConfig::ResetStyles(reset_styles),
-    );
-    CONFIGATRON.insert(
-        "minifiedStyles".to_string(),
-        GaladrielConfig::MinifiedStyles(minified_styles),
-    );
-    CONFIGATRON.insert("port".to_string(), GaladrielConfig::Port(port));
+        8
+    }
 
-    tracing::info!("Updated CONFIGATRON with new configuration values.");
-    tracing::debug!("Current CONFIGATRON state: {:?}", *CONFIGATRON);
-}
+    /// Retrieves the current state of the `RelativeErrorPaths` configuration.
+    pub fn get_relative_error_paths(&self) -> bool {
+        if let GaladrielConfig::RelativeErrorPaths(ref flag) = self {
+            return *flag;
+        }
 
-/// Toggles the state of the `AutoNaming` configuration in `CONFIGATRON`.
-pub fn switch_auto_naming() {
-    match CONFIGATRON.get_mut("autoNaming") {
-        Some(ref mut auto_naming) => {
-            auto_naming.switch_auto_naming();
-            tracing::info!("Toggled 'autoNaming' configuration.");
+        false
+    }
+
+    /// Retrieves the current state of the `GenerateTypings` configuration.
+    pub fn get_generate_typings(&self) -> bool {
+        if let GaladrielConfig::GenerateTypings(ref flag) = self {
+            return *flag;
         }
-        None => {}
+
+        false
     }
-}
 
-/// Toggles the state of the `ResetStyles` configuration in `CONFIGATRON`.
-pub fn switch_reset_styles() {
-    match CONFIGATRON.get_mut("resetStyles") {
-        Some(ref mut reset_styles) => {
-            reset_styles.switch_reset_styles();
-            tracing::info!("Toggled 'resetStyles' configuration.");
+    /// Retrieves the current state of the `ConsolidateShorthand` configuration.
+    pub fn get_consolidate_shorthand(&self) -> bool {
+        if let GaladrielConfig::ConsolidateShorthand(ref flag) = self {
+            return *flag;
         }
-        None => {}
+
+        false
     }
-}
 
-/// Toggles the state of the `MinifiedStyles` configuration in `CONFIGATRON`.
-pub fn switch_minified_styles() {
-    match CONFIGATRON.get_mut("minifiedStyles") {
-        Some(ref mut minified_styles) => {
-            minified_styles.switch_minified_styles();
-            tracing::info!("Toggled 'minifiedStyles' configuration.");
+    /// Retrieves the current value of the `ReloadThrottleMs` configuration.
+    pub fn get_reload_throttle_ms(&self) -> u64 {
+        if let GaladrielConfig::ReloadThrottleMs(ref window) = self {
+            return *window;
         }
-        None => {}
+
+        100
     }
-}
 
-/// Retrieves the current state of the `AutoNaming` configuration.
-/// Returns `true` if enabled, or `false` if not found or disabled.
-/// Defaults to `true`.
-pub fn get_auto_naming() -> bool {
-    match CONFIGATRON.get("autoNaming") {
-        Some(ref auto_naming) => auto_naming.get_auto_naming(),
-        None => false,
+    /// Retrieves the current public names from the `PublicNames` configuration.
+    pub fn get_public_names(&self) -> BTreeMap<String, String> {
+        if let GaladrielConfig::PublicNames(ref value) = self {
+            return value.to_owned();
+        }
+
+        BTreeMap::new()
     }
-}
 
-/// Retrieves the current state of the `ResetStyles` configuration.
-/// Returns `true` if enabled, or `false` if not found or disabled.
-/// Defaults to `true`.
-pub fn get_reset_styles() -> bool {
-    match CONFIGATRON.get("resetStyles") {
-        Some(ref reset_styles) => reset_styles.get_reset_styles(),
-        None => true,
+    /// Retrieves the current pairs from the `ContrastChecks` configuration.
+    pub fn get_contrast_checks(&self) -> Vec<ContrastCheck> {
+        if let GaladrielConfig::ContrastChecks(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current value of the `ScaleFunction` configuration.
+    pub fn get_scale_function(&self) -> String {
+        if let GaladrielConfig::ScaleFunction(ref value) = self {
+            return value.to_owned();
+        }
+
+        "space".to_string()
+    }
+
+    /// Retrieves the current value of the `ScaleBase` configuration.
+    pub fn get_scale_base(&self) -> String {
+        if let GaladrielConfig::ScaleBase(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
+    }
+
+    /// Retrieves the current list of contexts from the `ResolveOnlyContexts` configuration.
+    pub fn get_resolve_only_contexts(&self) -> Vec<String> {
+        if let GaladrielConfig::ResolveOnlyContexts(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current state of the `ClassesJson` configuration.
+    pub fn get_classes_json(&self) -> bool {
+        if let GaladrielConfig::ClassesJson(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `AnimationPrefix` configuration.
+    pub fn get_animation_prefix(&self) -> String {
+        if let GaladrielConfig::AnimationPrefix(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
+    }
+
+    /// Retrieves the current state of the `SplitThemes` configuration.
+    pub fn get_split_themes(&self) -> bool {
+        if let GaladrielConfig::SplitThemes(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `ClassNameHashLength` configuration.
+    pub fn get_class_name_hash_length(&self) -> usize {
+        if let GaladrielConfig::ClassNameHashLength(ref value) = self {
+            return *value;
+        }
+
+        4
+    }
+
+    /// Retrieves the current value of the `ResetStylesMedia` configuration.
+    pub fn get_reset_styles_media(&self) -> String {
+        if let GaladrielConfig::ResetStylesMedia(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
+    }
+
+    /// Retrieves the current state of the `NotifyOnError` configuration.
+    pub fn get_notify_on_error(&self) -> bool {
+        if let GaladrielConfig::NotifyOnError(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `FrozenCentralNames` configuration.
+    pub fn get_frozen_central_names(&self) -> Vec<String> {
+        if let GaladrielConfig::FrozenCentralNames(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current state of the `SortOutputByProperty` configuration.
+    pub fn get_sort_output_by_property(&self) -> bool {
+        if let GaladrielConfig::SortOutputByProperty(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `MaxClassesPerProperty` configuration.
+    pub fn get_max_classes_per_property(&self) -> usize {
+        if let GaladrielConfig::MaxClassesPerProperty(ref value) = self {
+            return *value;
+        }
+
+        0
+    }
+
+    /// Retrieves the current value of the `ThemeVarFallback` configuration.
+    pub fn get_theme_var_fallback(&self) -> String {
+        if let GaladrielConfig::ThemeVarFallback(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
     }
+
+    /// Retrieves the current value of the `IdleTimeoutMinutes` configuration.
+    pub fn get_idle_timeout_minutes(&self) -> u64 {
+        if let GaladrielConfig::IdleTimeoutMinutes(ref value) = self {
+            return *value;
+        }
+
+        0
+    }
+
+    /// Retrieves the current state of the `LenientAliases` configuration.
+    pub fn get_lenient_aliases(&self) -> bool {
+        if let GaladrielConfig::LenientAliases(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `StrictVariables` configuration.
+    pub fn get_strict_variables(&self) -> Vec<String> {
+        if let GaladrielConfig::StrictVariables(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current state of the `EmitCharset` configuration.
+    pub fn get_emit_charset(&self) -> bool {
+        if let GaladrielConfig::EmitCharset(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `BuildWebhook` configuration.
+    pub fn get_build_webhook(&self) -> String {
+        if let GaladrielConfig::BuildWebhook(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
+    }
+
+    /// Retrieves the current state of the `PlainAlerts` configuration.
+    pub fn get_plain_alerts(&self) -> bool {
+        if let GaladrielConfig::PlainAlerts(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current state of the `StableOutput` configuration.
+    pub fn get_stable_output(&self) -> bool {
+        if let GaladrielConfig::StableOutput(ref flag) = self {
+            return *flag;
+        }
+
+        true
+    }
+
+    /// Retrieves the current state of the `SourceMaps` configuration.
+    pub fn get_source_maps(&self) -> bool {
+        if let GaladrielConfig::SourceMaps(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current state of the `LintPseudoElements` configuration.
+    pub fn get_lint_pseudo_elements(&self) -> bool {
+        if let GaladrielConfig::LintPseudoElements(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current state of the `ContextComments` configuration.
+    pub fn get_context_comments(&self) -> bool {
+        if let GaladrielConfig::ContextComments(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current state of the `Autoprefix` configuration.
+    pub fn get_autoprefix(&self) -> bool {
+        if let GaladrielConfig::Autoprefix(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `BrowserTargets` configuration.
+    pub fn get_browser_targets(&self) -> Vec<String> {
+        if let GaladrielConfig::BrowserTargets(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current value of the `MaxWatchBatch` configuration.
+    pub fn get_max_watch_batch(&self) -> usize {
+        if let GaladrielConfig::MaxWatchBatch(ref value) = self {
+            return *value;
+        }
+
+        0
+    }
+
+    /// Retrieves the current state of the `DedupeUtilities` configuration.
+    pub fn get_dedupe_utilities(&self) -> bool {
+        if let GaladrielConfig::DedupeUtilities(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current state of the `SplitResponsive` configuration.
+    pub fn get_split_responsive(&self) -> bool {
+        if let GaladrielConfig::SplitResponsive(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `ClassPrefix` configuration.
+    pub fn get_class_prefix(&self) -> String {
+        if let GaladrielConfig::ClassPrefix(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
+    }
+
+    /// Retrieves the current value of the `FeatureFlags` configuration.
+    pub fn get_feature_flags(&self) -> BTreeMap<String, Vec<String>> {
+        if let GaladrielConfig::FeatureFlags(ref value) = self {
+            return value.to_owned();
+        }
+
+        BTreeMap::new()
+    }
+
+    /// Retrieves the current state of the `EmitAtProperty` configuration.
+    pub fn get_emit_at_property(&self) -> bool {
+        if let GaladrielConfig::EmitAtProperty(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `BuildJsonName` configuration.
+    pub fn get_build_json_name(&self) -> String {
+        if let GaladrielConfig::BuildJsonName(ref value) = self {
+            return value.to_owned();
+        }
+
+        "galadrielcss.json".to_string()
+    }
+
+    /// Retrieves the current state of the `WarnUnusedVariables` configuration.
+    pub fn get_warn_unused_variables(&self) -> bool {
+        if let GaladrielConfig::WarnUnusedVariables(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `WatchExtra` configuration.
+    pub fn get_watch_extra(&self) -> Vec<String> {
+        if let GaladrielConfig::WatchExtra(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current value of the `ContentGlobs` configuration.
+    pub fn get_content_globs(&self) -> Vec<String> {
+        if let GaladrielConfig::ContentGlobs(ref value) = self {
+            return value.to_owned();
+        }
+
+        vec![]
+    }
+
+    /// Retrieves the current value of the `CssLayer` configuration.
+    pub fn get_css_layer(&self) -> String {
+        if let GaladrielConfig::CssLayer(ref value) = self {
+            return value.to_owned();
+        }
+
+        String::new()
+    }
+
+    /// Retrieves the current state of the `RespectReducedMotion` configuration.
+    pub fn get_respect_reduced_motion(&self) -> bool {
+        if let GaladrielConfig::RespectReducedMotion(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+
+    /// Retrieves the current value of the `PortFallbackRange` configuration.
+    pub fn get_port_fallback_range(&self) -> u16 {
+        if let GaladrielConfig::PortFallbackRange(ref value) = self {
+            return *value;
+        }
+
+        10
+    }
+
+    /// Retrieves the current value of the `ContainerBreakpoints` configuration.
+    pub fn get_container_breakpoints(&self) -> BTreeMap<String, String> {
+        if let GaladrielConfig::ContainerBreakpoints(ref value) = self {
+            return value.to_owned();
+        }
+
+        BTreeMap::new()
+    }
+
+    /// Retrieves the current state of the `MangleNames` configuration.
+    pub fn get_mangle_names(&self) -> bool {
+        if let GaladrielConfig::MangleNames(ref flag) = self {
+            return *flag;
+        }
+
+        false
+    }
+}
+
+/// Updates the global configuration map `CONFIGATRON` with new configuration values.
+///
+/// # Parameters
+/// - `configs_json`: The configurations to apply, with the defaults filling the missing ones.
+pub fn set_configatron(configs_json: &ConfigurationJson) {
+    tracing::trace!(
+        "Entering set_configatron with configurations: {:?}",
+        configs_json
+    );
+
+    fill_configatron(&CONFIGATRON, configs_json);
+
+    tracing::info!("Updated CONFIGATRON with new configuration values.");
+    tracing::debug!("Current CONFIGATRON state: {:?}", *CONFIGATRON);
+}
+
+/// Inserts every value of a `ConfigurationJson` into a configuration map, under its JSON key.
+///
+/// # Parameters
+/// - `configatron`: The configuration map receiving the values.
+/// - `configs_json`: The configurations to insert.
+fn fill_configatron(
+    configatron: &DashMap<String, GaladrielConfig>,
+    configs_json: &ConfigurationJson,
+) {
+    configatron.insert(
+        "exclude".to_string(),
+        GaladrielConfig::Exclude(configs_json.exclude.to_owned()),
+    );
+    configatron.insert(
+        "autoNaming".to_string(),
+        GaladrielConfig::AutoNaming(configs_json.auto_naming),
+    );
+    configatron.insert(
+        "resetStyles".to_string(),
+        GaladrielConfig::ResetStyles(configs_json.reset_styles),
+    );
+    configatron.insert(
+        "minifiedStyles".to_string(),
+        GaladrielConfig::MinifiedStyles(configs_json.minified_styles),
+    );
+    configatron.insert(
+        "port".to_string(),
+        GaladrielConfig::Port(configs_json.port.to_owned()),
+    );
+    configatron.insert(
+        "maxExtensionDepth".to_string(),
+        GaladrielConfig::MaxExtensionDepth(configs_json.max_extension_depth),
+    );
+    configatron.insert(
+        "relativeErrorPaths".to_string(),
+        GaladrielConfig::RelativeErrorPaths(configs_json.relative_error_paths),
+    );
+    configatron.insert(
+        "generateTypings".to_string(),
+        GaladrielConfig::GenerateTypings(configs_json.generate_typings),
+    );
+    configatron.insert(
+        "consolidateShorthand".to_string(),
+        GaladrielConfig::ConsolidateShorthand(configs_json.consolidate_shorthand),
+    );
+    configatron.insert(
+        "reloadThrottleMs".to_string(),
+        GaladrielConfig::ReloadThrottleMs(configs_json.reload_throttle_ms),
+    );
+    configatron.insert(
+        "publicNames".to_string(),
+        GaladrielConfig::PublicNames(configs_json.public_names.to_owned()),
+    );
+    configatron.insert(
+        "contrastChecks".to_string(),
+        GaladrielConfig::ContrastChecks(configs_json.contrast_checks.to_owned()),
+    );
+    configatron.insert(
+        "scaleFunction".to_string(),
+        GaladrielConfig::ScaleFunction(configs_json.scale_function.to_owned()),
+    );
+    configatron.insert(
+        "scaleBase".to_string(),
+        GaladrielConfig::ScaleBase(configs_json.scale_base.to_owned()),
+    );
+    configatron.insert(
+        "resolveOnlyContexts".to_string(),
+        GaladrielConfig::ResolveOnlyContexts(configs_json.resolve_only_contexts.to_owned()),
+    );
+    configatron.insert(
+        "classesJson".to_string(),
+        GaladrielConfig::ClassesJson(configs_json.classes_json),
+    );
+    configatron.insert(
+        "animationPrefix".to_string(),
+        GaladrielConfig::AnimationPrefix(configs_json.animation_prefix.to_owned()),
+    );
+    configatron.insert(
+        "splitThemes".to_string(),
+        GaladrielConfig::SplitThemes(configs_json.split_themes),
+    );
+    configatron.insert(
+        "classNameHashLength".to_string(),
+        GaladrielConfig::ClassNameHashLength(configs_json.class_name_hash_length),
+    );
+    configatron.insert(
+        "resetStylesMedia".to_string(),
+        GaladrielConfig::ResetStylesMedia(configs_json.reset_styles_media.to_owned()),
+    );
+    configatron.insert(
+        "notifyOnError".to_string(),
+        GaladrielConfig::NotifyOnError(configs_json.notify_on_error),
+    );
+    configatron.insert(
+        "frozenCentralNames".to_string(),
+        GaladrielConfig::FrozenCentralNames(configs_json.frozen_central_names.to_owned()),
+    );
+    configatron.insert(
+        "sortOutputByProperty".to_string(),
+        GaladrielConfig::SortOutputByProperty(configs_json.sort_output_by_property),
+    );
+    configatron.insert(
+        "maxClassesPerProperty".to_string(),
+        GaladrielConfig::MaxClassesPerProperty(configs_json.max_classes_per_property),
+    );
+    configatron.insert(
+        "themeVarFallback".to_string(),
+        GaladrielConfig::ThemeVarFallback(configs_json.theme_var_fallback.to_owned()),
+    );
+    configatron.insert(
+        "idleTimeoutMinutes".to_string(),
+        GaladrielConfig::IdleTimeoutMinutes(configs_json.idle_timeout_minutes),
+    );
+    configatron.insert(
+        "lenientAliases".to_string(),
+        GaladrielConfig::LenientAliases(configs_json.lenient_aliases),
+    );
+    configatron.insert(
+        "strictVariables".to_string(),
+        GaladrielConfig::StrictVariables(configs_json.strict_variables.to_owned()),
+    );
+    configatron.insert(
+        "emitCharset".to_string(),
+        GaladrielConfig::EmitCharset(configs_json.emit_charset),
+    );
+    configatron.insert(
+        "buildWebhook".to_string(),
+        GaladrielConfig::BuildWebhook(configs_json.build_webhook.to_owned()),
+    );
+    configatron.insert(
+        "plainAlerts".to_string(),
+        GaladrielConfig::PlainAlerts(configs_json.plain_alerts),
+    );
+    configatron.insert(
+        "stableOutput".to_string(),
+        GaladrielConfig::StableOutput(configs_json.stable_output),
+    );
+    configatron.insert(
+        "sourceMaps".to_string(),
+        GaladrielConfig::SourceMaps(configs_json.source_maps),
+    );
+    configatron.insert(
+        "lintPseudoElements".to_string(),
+        GaladrielConfig::LintPseudoElements(configs_json.lint_pseudo_elements),
+    );
+    configatron.insert(
+        "contextComments".to_string(),
+        GaladrielConfig::ContextComments(configs_json.context_comments),
+    );
+    configatron.insert(
+        "autoprefix".to_string(),
+        GaladrielConfig::Autoprefix(configs_json.autoprefix),
+    );
+    configatron.insert(
+        "browserTargets".to_string(),
+        GaladrielConfig::BrowserTargets(configs_json.browser_targets.to_owned()),
+    );
+    configatron.insert(
+        "maxWatchBatch".to_string(),
+        GaladrielConfig::MaxWatchBatch(configs_json.max_watch_batch),
+    );
+    configatron.insert(
+        "dedupeUtilities".to_string(),
+        GaladrielConfig::DedupeUtilities(configs_json.dedupe_utilities),
+    );
+    configatron.insert(
+        "splitResponsive".to_string(),
+        GaladrielConfig::SplitResponsive(configs_json.split_responsive),
+    );
+    configatron.insert(
+        "classPrefix".to_string(),
+        GaladrielConfig::ClassPrefix(configs_json.class_prefix.to_owned()),
+    );
+    configatron.insert(
+        "featureFlags".to_string(),
+        GaladrielConfig::FeatureFlags(configs_json.feature_flags.to_owned()),
+    );
+    configatron.insert(
+        "emitAtProperty".to_string(),
+        GaladrielConfig::EmitAtProperty(configs_json.emit_at_property),
+    );
+    configatron.insert(
+        "buildJsonName".to_string(),
+        GaladrielConfig::BuildJsonName(configs_json.build_json_name.to_owned()),
+    );
+    configatron.insert(
+        "warnUnusedVariables".to_string(),
+        GaladrielConfig::WarnUnusedVariables(configs_json.warn_unused_variables),
+    );
+    configatron.insert(
+        "watchExtra".to_string(),
+        GaladrielConfig::WatchExtra(configs_json.watch_extra.to_owned()),
+    );
+    configatron.insert(
+        "contentGlobs".to_string(),
+        GaladrielConfig::ContentGlobs(configs_json.content_globs.to_owned()),
+    );
+    configatron.insert(
+        "cssLayer".to_string(),
+        GaladrielConfig::CssLayer(configs_json.css_layer.to_owned()),
+    );
+    configatron.insert(
+        "respectReducedMotion".to_string(),
+        GaladrielConfig::RespectReducedMotion(configs_json.respect_reduced_motion),
+    );
+    configatron.insert(
+        "portFallbackRange".to_string(),
+        GaladrielConfig::PortFallbackRange(configs_json.port_fallback_range),
+    );
+    configatron.insert(
+        "containerBreakpoints".to_string(),
+        GaladrielConfig::ContainerBreakpoints(configs_json.container_breakpoints.to_owned()),
+    );
+    configatron.insert(
+        "mangleNames".to_string(),
+        GaladrielConfig::MangleNames(configs_json.mangle_names),
+    );
+}
+
+/// Applies every value of a deserialized `ConfigurationJson` to `CONFIGATRON`.
+fn apply_configuration_json(configs_json: ConfigurationJson) {
+    set_configatron(&configs_json);
+}
+
+/// Restores every configuration in `CONFIGATRON` to its default value.
+///
+/// The defaults are the same ones applied to the options missing from the `galadriel.config.json` file.
+pub fn restore_configatron() {
+    tracing::info!("Restoring CONFIGATRON to its default configuration values.");
+
+    match serde_json::from_str::<ConfigurationJson>("{}") {
+        Ok(configs_json) => apply_configuration_json(configs_json),
+        Err(err) => {
+            tracing::error!("Failed to build the default configurations: {}", err);
+        }
+    }
+}
+
+/// Toggles the state of the `AutoNaming` configuration in `CONFIGATRON`.
+pub fn switch_auto_naming() {
+    match CONFIGATRON.get_mut("autoNaming") {
+        Some(ref mut auto_naming) => {
+            auto_naming.switch_auto_naming();
+            tracing::info!("Toggled 'autoNaming' configuration.");
+        }
+        None => {}
+    }
+}
+
+/// Toggles the state of the `ResetStyles` configuration in `CONFIGATRON`.
+pub fn switch_reset_styles() {
+    match CONFIGATRON.get_mut("resetStyles") {
+        Some(ref mut reset_styles) => {
+            reset_styles.switch_reset_styles();
+            tracing::info!("Toggled 'resetStyles' configuration.");
+        }
+        None => {}
+    }
+}
+
+/// Toggles the state of the `MinifiedStyles` configuration in `CONFIGATRON`.
+pub fn switch_minified_styles() {
+    match CONFIGATRON.get_mut("minifiedStyles") {
+        Some(ref mut minified_styles) => {
+            minified_styles.switch_minified_styles();
+            tracing::info!("Toggled 'minifiedStyles' configuration.");
+        }
+        None => {}
+    }
+}
+
+/// Toggles the state of the `RelativeErrorPaths` configuration in `CONFIGATRON`.
+pub fn switch_relative_error_paths() {
+    match CONFIGATRON.get_mut("relativeErrorPaths") {
+        Some(ref mut relative_error_paths) => {
+            relative_error_paths.switch_relative_error_paths();
+            tracing::info!("Toggled 'relativeErrorPaths' configuration.");
+        }
+        None => {}
+    }
+}
+
+/// Toggles the state of the `PlainAlerts` configuration in `CONFIGATRON`.
+pub fn switch_plain_alerts() {
+    match CONFIGATRON.get_mut("plainAlerts") {
+        Some(ref mut plain_alerts) => {
+            plain_alerts.switch_plain_alerts();
+            tracing::info!("Toggled 'plainAlerts' configuration.");
+        }
+        None => {}
+    }
+}
+
+/// Retrieves the current state of the `AutoNaming` configuration.
+/// Returns `true` if enabled, or `false` if not found or disabled.
+/// Defaults to `true`.
+pub fn get_auto_naming() -> bool {
+    match CONFIGATRON.get("autoNaming") {
+        Some(ref auto_naming) => auto_naming.get_auto_naming(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `ResetStyles` configuration.
+/// Returns `true` if enabled, or `false` if not found or disabled.
+/// Defaults to `true`.
+pub fn get_reset_styles() -> bool {
+    match CONFIGATRON.get("resetStyles") {
+        Some(ref reset_styles) => reset_styles.get_reset_styles(),
+        None => true,
+    }
+}
+
+/// Retrieves the current state of the `MinifiedStyles` configuration.
+/// Returns `true` if enabled, or `false` if not found or disabled.
+/// Defaults to `true`.
+pub fn get_minified_styles() -> bool {
+    match CONFIGATRON.get("minifiedStyles") {
+        Some(ref minified_styles) => minified_styles.get_minified_styles(),
+        None => true,
+    }
+}
+
+/// Retrieves the list of excluded paths from the `Exclude` configuration.
+/// Returns a vector of excluded paths or an empty vector if not found.
+pub fn get_exclude() -> Vec<String> {
+    match CONFIGATRON.get("exclude") {
+        Some(ref exclude) => exclude.get_exclude(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the port value from the `Port` configuration.
+/// Returns the port as a string, or "0" if not found.
+pub fn get_port() -> String {
+    match CONFIGATRON.get("port") {
+        Some(ref port) => port.get_port(),
+        None => "0".to_string(),
+    }
+}
+
+/// Retrieves the maximum extension depth from the `MaxExtensionDepth` configuration.
+/// Returns the configured depth, or `8` if not found.
+pub fn get_max_extension_depth() -> usize {
+    match CONFIGATRON.get("maxExtensionDepth") {
+        Some(ref depth) => depth.get_max_extension_depth(),
+        None => 8,
+    }
+}
+
+/// Retrieves the current state of the `RelativeErrorPaths` configuration.
+/// Returns `false` if not found.
+pub fn get_relative_error_paths() -> bool {
+    match CONFIGATRON.get("relativeErrorPaths") {
+        Some(ref relative_error_paths) => relative_error_paths.get_relative_error_paths(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `GenerateTypings` configuration.
+/// Returns `false` if not found.
+pub fn get_generate_typings() -> bool {
+    match CONFIGATRON.get("generateTypings") {
+        Some(ref generate_typings) => generate_typings.get_generate_typings(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `ConsolidateShorthand` configuration.
+/// Returns `false` if not found.
+pub fn get_consolidate_shorthand() -> bool {
+    match CONFIGATRON.get("consolidateShorthand") {
+        Some(ref consolidate_shorthand) => consolidate_shorthand.get_consolidate_shorthand(),
+        None => false,
+    }
+}
+
+/// Retrieves the reload throttle window from the `ReloadThrottleMs` configuration.
+/// Returns the configured window in milliseconds, or `100` if not found.
+pub fn get_reload_throttle_ms() -> u64 {
+    match CONFIGATRON.get("reloadThrottleMs") {
+        Some(ref reload_throttle_ms) => reload_throttle_ms.get_reload_throttle_ms(),
+        None => 100,
+    }
+}
+
+/// Retrieves the public names from the `PublicNames` configuration.
+/// Returns an empty map if not found.
+pub fn get_public_names() -> BTreeMap<String, String> {
+    match CONFIGATRON.get("publicNames") {
+        Some(ref public_names) => public_names.get_public_names(),
+        None => BTreeMap::new(),
+    }
+}
+
+/// Retrieves the variable pairs from the `ContrastChecks` configuration.
+/// Returns an empty vector if not found.
+pub fn get_contrast_checks() -> Vec<ContrastCheck> {
+    match CONFIGATRON.get("contrastChecks") {
+        Some(ref contrast_checks) => contrast_checks.get_contrast_checks(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the scale function name from the `ScaleFunction` configuration.
+/// Returns `space` if not found.
+pub fn get_scale_function() -> String {
+    match CONFIGATRON.get("scaleFunction") {
+        Some(ref scale_function) => scale_function.get_scale_function(),
+        None => "space".to_string(),
+    }
+}
+
+/// Retrieves the scale base from the `ScaleBase` configuration.
+/// Returns an empty string if not found.
+pub fn get_scale_base() -> String {
+    match CONFIGATRON.get("scaleBase") {
+        Some(ref scale_base) => scale_base.get_scale_base(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the resolve-only contexts from the `ResolveOnlyContexts` configuration.
+/// Returns an empty vector if not found.
+pub fn get_resolve_only_contexts() -> Vec<String> {
+    match CONFIGATRON.get("resolveOnlyContexts") {
+        Some(ref resolve_only_contexts) => resolve_only_contexts.get_resolve_only_contexts(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the current state of the `ClassesJson` configuration.
+/// Returns `false` if not found.
+pub fn get_classes_json() -> bool {
+    match CONFIGATRON.get("classesJson") {
+        Some(ref classes_json) => classes_json.get_classes_json(),
+        None => false,
+    }
+}
+
+/// Retrieves the animation prefix from the `AnimationPrefix` configuration.
+/// Returns an empty string if not found.
+pub fn get_animation_prefix() -> String {
+    match CONFIGATRON.get("animationPrefix") {
+        Some(ref animation_prefix) => animation_prefix.get_animation_prefix(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the current state of the `SplitThemes` configuration.
+/// Returns `false` if not found.
+pub fn get_split_themes() -> bool {
+    match CONFIGATRON.get("splitThemes") {
+        Some(ref split_themes) => split_themes.get_split_themes(),
+        None => false,
+    }
+}
+
+/// Retrieves the length of the hashed suffix of the utility class names from the `ClassNameHashLength` configuration.
+/// Returns `4` if not found.
+pub fn get_class_name_hash_length() -> usize {
+    match CONFIGATRON.get("classNameHashLength") {
+        Some(ref class_name_hash_length) => class_name_hash_length.get_class_name_hash_length(),
+        None => 4,
+    }
+}
+
+/// Retrieves the media condition of the reset styles from the `ResetStylesMedia` configuration.
+/// Returns an empty string if not found.
+pub fn get_reset_styles_media() -> String {
+    match CONFIGATRON.get("resetStylesMedia") {
+        Some(ref reset_styles_media) => reset_styles_media.get_reset_styles_media(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the current state of the `NotifyOnError` configuration.
+/// Returns `false` if not found.
+pub fn get_notify_on_error() -> bool {
+    match CONFIGATRON.get("notifyOnError") {
+        Some(ref notify_on_error) => notify_on_error.get_notify_on_error(),
+        None => false,
+    }
+}
+
+/// Retrieves the names of the frozen central definitions from the `FrozenCentralNames` configuration.
+/// Returns an empty vector if not found.
+pub fn get_frozen_central_names() -> Vec<String> {
+    match CONFIGATRON.get("frozenCentralNames") {
+        Some(ref frozen_central_names) => frozen_central_names.get_frozen_central_names(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the current state of the `SortOutputByProperty` configuration.
+/// Returns `false` if not found.
+pub fn get_sort_output_by_property() -> bool {
+    match CONFIGATRON.get("sortOutputByProperty") {
+        Some(ref sort_output_by_property) => sort_output_by_property.get_sort_output_by_property(),
+        None => false,
+    }
+}
+
+/// Retrieves the soft limit of utility classes per property from the `MaxClassesPerProperty` configuration.
+/// Returns `0` if not found.
+pub fn get_max_classes_per_property() -> usize {
+    match CONFIGATRON.get("maxClassesPerProperty") {
+        Some(ref max_classes_per_property) => {
+            max_classes_per_property.get_max_classes_per_property()
+        }
+        None => 0,
+    }
+}
+
+/// Retrieves the fallback theme schema from the `ThemeVarFallback` configuration.
+/// Returns an empty string if not found.
+pub fn get_theme_var_fallback() -> String {
+    match CONFIGATRON.get("themeVarFallback") {
+        Some(ref theme_var_fallback) => theme_var_fallback.get_theme_var_fallback(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the idle timeout of the development server from the `IdleTimeoutMinutes` configuration.
+/// Returns `0` if not found.
+pub fn get_idle_timeout_minutes() -> u64 {
+    match CONFIGATRON.get("idleTimeoutMinutes") {
+        Some(ref idle_timeout_minutes) => idle_timeout_minutes.get_idle_timeout_minutes(),
+        None => 0,
+    }
+}
+
+/// Retrieves the lenient aliases flag from the `LenientAliases` configuration.
+/// Returns `false` if not found.
+pub fn get_lenient_aliases() -> bool {
+    match CONFIGATRON.get("lenientAliases") {
+        Some(ref lenient_aliases) => lenient_aliases.get_lenient_aliases(),
+        None => false,
+    }
+}
+
+/// Retrieves the properties whose values must reference variables from the `StrictVariables` configuration.
+/// Returns an empty vector if not found.
+pub fn get_strict_variables() -> Vec<String> {
+    match CONFIGATRON.get("strictVariables") {
+        Some(ref strict_variables) => strict_variables.get_strict_variables(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the emit charset flag from the `EmitCharset` configuration.
+/// Returns `false` if not found.
+pub fn get_emit_charset() -> bool {
+    match CONFIGATRON.get("emitCharset") {
+        Some(ref emit_charset) => emit_charset.get_emit_charset(),
+        None => false,
+    }
+}
+
+/// Retrieves the build webhook URL from the `BuildWebhook` configuration.
+/// Returns an empty string if not found.
+pub fn get_build_webhook() -> String {
+    match CONFIGATRON.get("buildWebhook") {
+        Some(ref build_webhook) => build_webhook.get_build_webhook(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the current state of the `PlainAlerts` configuration.
+/// Returns `false` if not found.
+pub fn get_plain_alerts() -> bool {
+    match CONFIGATRON.get("plainAlerts") {
+        Some(ref plain_alerts) => plain_alerts.get_plain_alerts(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `StableOutput` configuration.
+/// Returns `true` if not found.
+pub fn get_stable_output() -> bool {
+    match CONFIGATRON.get("stableOutput") {
+        Some(ref stable_output) => stable_output.get_stable_output(),
+        None => true,
+    }
+}
+
+/// Retrieves the current state of the `SourceMaps` configuration.
+/// Returns `false` if not found.
+pub fn get_source_maps() -> bool {
+    match CONFIGATRON.get("sourceMaps") {
+        Some(ref source_maps) => source_maps.get_source_maps(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `LintPseudoElements` configuration.
+/// Returns `false` if not found.
+pub fn get_lint_pseudo_elements() -> bool {
+    match CONFIGATRON.get("lintPseudoElements") {
+        Some(ref lint_pseudo_elements) => lint_pseudo_elements.get_lint_pseudo_elements(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `ContextComments` configuration.
+/// Returns `false` if not found.
+pub fn get_context_comments() -> bool {
+    match CONFIGATRON.get("contextComments") {
+        Some(ref context_comments) => context_comments.get_context_comments(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `Autoprefix` configuration.
+/// Returns `false` if not found.
+pub fn get_autoprefix() -> bool {
+    match CONFIGATRON.get("autoprefix") {
+        Some(ref autoprefix) => autoprefix.get_autoprefix(),
+        None => false,
+    }
+}
+
+/// Retrieves the browsers the vendor prefixes are emitted for from the `BrowserTargets` configuration.
+/// Returns an empty vector if not found.
+pub fn get_browser_targets() -> Vec<String> {
+    match CONFIGATRON.get("browserTargets") {
+        Some(ref browser_targets) => browser_targets.get_browser_targets(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the number of Nenyr file events above which a watched batch triggers a full reprocess,
+/// from the `MaxWatchBatch` configuration. Returns `0` if not found.
+pub fn get_max_watch_batch() -> usize {
+    match CONFIGATRON.get("maxWatchBatch") {
+        Some(ref max_watch_batch) => max_watch_batch.get_max_watch_batch(),
+        None => 0,
+    }
+}
+
+/// Retrieves the current state of the `DedupeUtilities` configuration.
+/// Returns `false` if not found.
+pub fn get_dedupe_utilities() -> bool {
+    match CONFIGATRON.get("dedupeUtilities") {
+        Some(ref dedupe_utilities) => dedupe_utilities.get_dedupe_utilities(),
+        None => false,
+    }
+}
+
+/// Retrieves the current state of the `SplitResponsive` configuration.
+/// Returns `false` if not found.
+pub fn get_split_responsive() -> bool {
+    match CONFIGATRON.get("splitResponsive") {
+        Some(ref split_responsive) => split_responsive.get_split_responsive(),
+        None => false,
+    }
+}
+
+/// Retrieves the current value of the `ClassPrefix` configuration.
+/// Returns an empty string if not found.
+pub fn get_class_prefix() -> String {
+    match CONFIGATRON.get("classPrefix") {
+        Some(ref class_prefix) => class_prefix.get_class_prefix(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the feature flags from the `FeatureFlags` configuration.
+/// Returns an empty map if not found.
+pub fn get_feature_flags() -> BTreeMap<String, Vec<String>> {
+    match CONFIGATRON.get("featureFlags") {
+        Some(ref feature_flags) => feature_flags.get_feature_flags(),
+        None => BTreeMap::new(),
+    }
+}
+
+/// Retrieves the current state of the `EmitAtProperty` configuration.
+/// Returns `false` if not found.
+pub fn get_emit_at_property() -> bool {
+    match CONFIGATRON.get("emitAtProperty") {
+        Some(ref emit_at_property) => emit_at_property.get_emit_at_property(),
+        None => false,
+    }
+}
+
+/// Retrieves the current value of the `BuildJsonName` configuration.
+/// Returns `galadrielcss.json` if not found.
+pub fn get_build_json_name() -> String {
+    match CONFIGATRON.get("buildJsonName") {
+        Some(ref build_json_name) => build_json_name.get_build_json_name(),
+        None => "galadrielcss.json".to_string(),
+    }
+}
+
+/// Retrieves the current state of the `WarnUnusedVariables` configuration.
+/// Returns `false` if not found.
+pub fn get_warn_unused_variables() -> bool {
+    match CONFIGATRON.get("warnUnusedVariables") {
+        Some(ref warn_unused_variables) => warn_unused_variables.get_warn_unused_variables(),
+        None => false,
+    }
+}
+
+/// Retrieves the current value of the `WatchExtra` configuration.
+/// Returns an empty vector if not found.
+pub fn get_watch_extra() -> Vec<String> {
+    match CONFIGATRON.get("watchExtra") {
+        Some(ref watch_extra) => watch_extra.get_watch_extra(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the current value of the `ContentGlobs` configuration.
+/// Returns an empty vector if not found.
+pub fn get_content_globs() -> Vec<String> {
+    match CONFIGATRON.get("contentGlobs") {
+        Some(ref content_globs) => content_globs.get_content_globs(),
+        None => vec![],
+    }
+}
+
+/// Retrieves the current value of the `CssLayer` configuration.
+/// Returns an empty string if not found.
+pub fn get_css_layer() -> String {
+    match CONFIGATRON.get("cssLayer") {
+        Some(ref css_layer) => css_layer.get_css_layer(),
+        None => String::new(),
+    }
+}
+
+/// Retrieves the current state of the `RespectReducedMotion` configuration.
+/// Returns `false` if not found.
+pub fn get_respect_reduced_motion() -> bool {
+    match CONFIGATRON.get("respectReducedMotion") {
+        Some(ref respect_reduced_motion) => respect_reduced_motion.get_respect_reduced_motion(),
+        None => false,
+    }
+}
+
+/// Retrieves the number of sequential ports tried when the configured port is in use.
+/// Returns `10` if not found.
+pub fn get_port_fallback_range() -> u16 {
+    match CONFIGATRON.get("portFallbackRange") {
+        Some(ref port_fallback_range) => port_fallback_range.get_port_fallback_range(),
+        None => 10,
+    }
+}
+
+/// Retrieves the breakpoints emitted as container queries from the `ContainerBreakpoints` configuration.
+/// Returns an empty map if not found.
+pub fn get_container_breakpoints() -> BTreeMap<String, String> {
+    match CONFIGATRON.get("containerBreakpoints") {
+        Some(ref container_breakpoints) => container_breakpoints.get_container_breakpoints(),
+        None => BTreeMap::new(),
+    }
+}
+
+/// Retrieves the current state of the `MangleNames` configuration.
+/// Returns `false` if not found.
+pub fn get_mangle_names() -> bool {
+    match CONFIGATRON.get("mangleNames") {
+        Some(ref mangle_names) => mangle_names.get_mangle_names(),
+        None => false,
+    }
+}
+
+/// Computes a hash of the configurations that only affect the CSS emission stage.
+///
+/// Toggling these options (e.g. minification or reset styles) changes how the STYLITRON AST
+/// is written out, but not how Nenyr contexts are parsed and resolved into it. When only this
+/// hash changes, the resolved ASTs can be reused and just the emission stage has to run again.
+///
+/// # Returns
+/// - A `u64` hash of the emission-only configurations.
+pub fn get_emission_config_hash() -> u64 {
+    hash_emission_configs(&CONFIGATRON)
+}
+
+/// Computes the hash of the emission configurations held by a configuration map.
+///
+/// # Parameters
+/// - `configatron`: The configuration map holding the emission configurations.
+fn hash_emission_configs(configatron: &DashMap<String, GaladrielConfig>) -> u64 {
+    hash_named_configs(configatron, &EMISSION_CONFIG_NAMES)
 }
 
-/// Retrieves the current state of the `MinifiedStyles` configuration.
-/// Returns `true` if enabled, or `false` if not found or disabled.
-/// Defaults to `true`.
-pub fn get_minified_styles() -> bool {
-    match CONFIGATRON.get("minifiedStyles") {
-        Some(ref minified_styles) => minified_styles.get_minified_styles(),
-        None => true,
-    }
+/// Computes a hash of the configurations that affect how Nenyr contexts are resolved.
+///
+/// A change to this hash means the resolved ASTs may be stale, so every context must go
+/// through the parsing and resolution stages again.
+///
+/// # Returns
+/// - A `u64` hash of the resolution configurations.
+pub fn get_resolution_config_hash() -> u64 {
+    hash_resolution_configs(&CONFIGATRON)
 }
 
-/// Retrieves the list of excluded paths from the `Exclude` configuration.
-/// Returns a vector of excluded paths or an empty vector if not found.
-pub fn get_exclude() -> Vec<String> {
-    match CONFIGATRON.get("exclude") {
-        Some(ref exclude) => exclude.get_exclude(),
-        None => vec![],
-    }
+/// Computes the hash of the resolution configurations held by a configuration map.
+///
+/// # Parameters
+/// - `configatron`: The configuration map holding the resolution configurations.
+fn hash_resolution_configs(configatron: &DashMap<String, GaladrielConfig>) -> u64 {
+    hash_named_configs(configatron, &RESOLUTION_CONFIG_NAMES)
 }
 
-/// Retrieves the port value from the `Port` configuration.
-/// Returns the port as a string, or "0" if not found.
-pub fn get_port() -> String {
-    match CONFIGATRON.get("port") {
-        Some(ref port) => port.get_port(),
-        None => "0".to_string(),
+/// Computes the hash of the named configurations held by a configuration map.
+///
+/// # Parameters
+/// - `configatron`: The configuration map holding the configurations.
+/// - `config_names`: The JSON names of the hashed configurations.
+///
+/// Each configuration is hashed through its debug representation, since some of them hold
+/// floating point values (e.g. the minimum ratio of the contrast checks) that cannot be hashed.
+fn hash_named_configs(
+    configatron: &DashMap<String, GaladrielConfig>,
+    config_names: &[&str],
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    for config_name in config_names {
+        config_name.hash(&mut hasher);
+
+        if let Some(config) = configatron.get(config_name) {
+            format!("{:?}", *config).hash(&mut hasher);
+        }
     }
+
+    hasher.finish()
 }
 
-/// Loads Galadriel configurations from the specified `galadriel.config.json` file.
+/// Loads Galadriel configurations from the `galadriel.config.json` file, or from the
+/// `galadriel.config.toml` file when the JSON one is absent.
 ///
 /// # Parameters
 /// - `working_dir`: A reference to the working directory path where the configuration file is located.
@@ -318,9 +2111,12 @@ pub fn get_port() -> String {
 /// # Returns
 /// - `Ok(())`: If the configuration file is successfully loaded and applied.
 /// - `Err(GaladrielError)`: If an error occurs during file reading or parsing.
-pub async fn load_galadriel_configs(working_dir: &PathBuf) -> GaladrielResult<()> {
-    // Construct the full path to the configuration file.
-    let config_path = working_dir.join("galadriel.config.json");
+pub async fn load_galadriel_configs(
+    working_dir: &PathBuf,
+    profile: Option<&str>,
+) -> GaladrielResult<()> {
+    // Resolve the configuration file to load, along with the format it is written in.
+    let (config_path, config_format) = resolve_config_file(working_dir);
 
     tracing::info!(
         "Attempting to load Galadriel configuration file from {:?}",
@@ -338,29 +2134,26 @@ pub async fn load_galadriel_configs(working_dir: &PathBuf) -> GaladrielResult<()
 
                 tracing::info!("Configuration file read successfully. Deserializing content.");
 
-                // Deserialize the JSON string into the ConfigurationJson struct.
+                // Deserialize the raw content, merging the selected profile over the base configurations.
+                let raw_configs = match config_format {
+                    ConfigFormat::Json => serde_json::from_str::<serde_json::Value>(&raw_content)
+                        .map_err(raise_config_parsing_error)?,
+                    ConfigFormat::Toml => toml::from_str::<serde_json::Value>(&raw_content)
+                        .map_err(raise_config_parsing_error)?,
+                };
+                let raw_configs = apply_config_profile(raw_configs, profile)?;
+
+                // Deserialize the merged configurations into the ConfigurationJson struct.
                 let configs_json: ConfigurationJson =
-                    serde_json::from_str(&raw_content).map_err(|err| {
-                        tracing::error!("Error parsing configuration file: {}", err);
+                    serde_json::from_value(raw_configs).map_err(raise_config_parsing_error)?;
 
-                        // Raise an error if deserialization fails.
-                        GaladrielError::raise_general_other_error(
-                            ErrorKind::ConfigFileParsingError,
-                            &format!("Something went wrong while parsing the `galadriel.config.json` file. Err: {}", err.to_string()),
-                            ErrorAction::Notify,
-                        )
-                    })?;
+                // Reject the values that cannot be applied, keeping the current configurations.
+                validate_configuration_json(&configs_json)?;
 
                 tracing::info!("Configuration successfully parsed. Applying settings.");
 
                 // Apply the deserialized configurations to CONFIGATRON.
-                set_configatron(
-                    configs_json.exclude,
-                    configs_json.auto_naming,
-                    configs_json.reset_styles,
-                    configs_json.minified_styles,
-                    configs_json.port,
-                );
+                apply_configuration_json(configs_json);
 
                 tracing::info!("Configuration settings successfully applied.");
             }
@@ -370,7 +2163,7 @@ pub async fn load_galadriel_configs(working_dir: &PathBuf) -> GaladrielResult<()
 
                 return Err(GaladrielError::raise_general_other_error(
                     ErrorKind::ConfigFileReadError,
-                    &format!("Something went wrong while reading the `galadriel.config.json` file. Err: {}", err.to_string()),
+                    &format!("Something went wrong while reading the Galadriel CSS configuration file. Err: {}", err.to_string()),
                     ErrorAction::Notify,
                 ));
             }
@@ -380,6 +2173,227 @@ pub async fn load_galadriel_configs(working_dir: &PathBuf) -> GaladrielResult<()
     Ok(())
 }
 
+/// Raises the error reported when the configuration file cannot be deserialized.
+fn raise_config_parsing_error<E: std::fmt::Display>(err: E) -> GaladrielError {
+    tracing::error!("Error parsing configuration file: {}", err);
+
+    GaladrielError::raise_general_other_error(
+        ErrorKind::ConfigFileParsingError,
+        &format!(
+            "Something went wrong while parsing the Galadriel CSS configuration file. Err: {}",
+            err.to_string()
+        ),
+        ErrorAction::Notify,
+    )
+}
+
+/// Merges a named profile over the base configurations.
+///
+/// The named profiles live in the `profiles` object of the configuration file, each one holding
+/// the configurations that differ from the base ones. The `profiles` object itself is never applied.
+///
+/// # Parameters
+/// - `raw_configs`: The configurations read from the `galadriel.config.json` file.
+/// - `profile`: The name of the selected profile, if any.
+///
+/// # Returns
+/// - `Ok(serde_json::Value)`: The base configurations, with the selected profile merged over them.
+/// - `Err(GaladrielError)`: If the selected profile is not defined.
+fn apply_config_profile(
+    mut raw_configs: serde_json::Value,
+    profile: Option<&str>,
+) -> GaladrielResult<serde_json::Value> {
+    let Some(base_configs) = raw_configs.as_object_mut() else {
+        return Ok(raw_configs);
+    };
+
+    let profiles = base_configs.remove(CONFIG_PROFILES_KEY);
+
+    let Some(profile) = profile else {
+        return Ok(raw_configs);
+    };
+
+    tracing::info!("Applying configuration profile: {}", profile);
+
+    match profiles
+        .as_ref()
+        .and_then(|profiles| profiles.get(profile))
+        .and_then(|profile_configs| profile_configs.as_object())
+    {
+        Some(profile_configs) => {
+            for (key, value) in profile_configs {
+                base_configs.insert(key.to_owned(), value.to_owned());
+            }
+
+            Ok(raw_configs)
+        }
+        None => {
+            tracing::error!("Configuration profile not found: {}", profile);
+
+            Err(GaladrielError::raise_general_other_error(
+                ErrorKind::UnknownConfigProfileError,
+                &format!(
+                    "The `{}` profile is not defined in the `{}` of the `galadriel.config.json` file.",
+                    profile, CONFIG_PROFILES_KEY
+                ),
+                ErrorAction::Notify,
+            ))
+        }
+    }
+}
+
+/// Resolves the configuration file of the working directory, along with its format.
+///
+/// The `galadriel.config.json` file is preferred, falling back to the `galadriel.config.toml` file
+/// only when the JSON one does not exist. Without either, the JSON one is resolved.
+///
+/// # Parameters
+/// - `working_dir`: The directory holding the configuration file.
+///
+/// # Returns
+/// - `(PathBuf, ConfigFormat)`: The path of the configuration file and the format it is written in.
+pub fn resolve_config_file(working_dir: &Path) -> (PathBuf, ConfigFormat) {
+    let json_path = working_dir.join(JSON_CONFIG_FILE_NAME);
+    let toml_path = working_dir.join(TOML_CONFIG_FILE_NAME);
+
+    if !json_path.exists() && toml_path.exists() {
+        return (toml_path, ConfigFormat::Toml);
+    }
+
+    (json_path, ConfigFormat::Json)
+}
+
+/// Checks whether both configuration files exist, in which case the TOML one is ignored.
+///
+/// # Parameters
+/// - `working_dir`: The directory holding the configuration files.
+///
+/// # Returns
+/// - `Option<GaladrielAlerts>`: A warning naming the ignored file, if both files exist.
+pub fn check_duplicated_config_files(working_dir: &Path) -> Option<GaladrielAlerts> {
+    if !working_dir.join(JSON_CONFIG_FILE_NAME).exists()
+        || !working_dir.join(TOML_CONFIG_FILE_NAME).exists()
+    {
+        return None;
+    }
+
+    tracing::warn!("Both configuration files exist, ignoring the TOML one.");
+
+    Some(GaladrielAlerts::create_warning(
+        Local::now(),
+        &format!(
+            "Both `{}` and `{}` exist. The `{}` file is loaded and the `{}` file is ignored.",
+            JSON_CONFIG_FILE_NAME,
+            TOML_CONFIG_FILE_NAME,
+            JSON_CONFIG_FILE_NAME,
+            TOML_CONFIG_FILE_NAME
+        ),
+    ))
+}
+
+/// Carries the named profiles of the previous configuration file over to its serialized replacement,
+/// since they are not part of the applied configurations.
+///
+/// # Parameters
+/// - `previous_configs`: The current content of the `galadriel.config.json` file.
+/// - `serialized_configs`: The serialized configurations replacing it.
+///
+/// # Returns
+/// - `String`: The serialized configurations, along with the previous profiles, if any.
+pub fn carry_config_profiles(previous_configs: &str, serialized_configs: String) -> String {
+    let profiles = serde_json::from_str::<serde_json::Value>(previous_configs)
+        .ok()
+        .and_then(|previous_configs| previous_configs.get(CONFIG_PROFILES_KEY).cloned());
+
+    let (Some(profiles), Ok(mut configs)) = (
+        profiles,
+        serde_json::from_str::<serde_json::Value>(&serialized_configs),
+    ) else {
+        return serialized_configs;
+    };
+
+    match configs.as_object_mut() {
+        Some(configs_map) => {
+            configs_map.insert(CONFIG_PROFILES_KEY.to_string(), profiles);
+
+            serde_json::to_string_pretty(&configs).unwrap_or(serialized_configs)
+        }
+        None => serialized_configs,
+    }
+}
+
+/// Validates the configuration values whose range cannot be expressed by their types.
+///
+/// # Parameters
+/// - `configs_json`: The deserialized configurations.
+///
+/// # Returns
+/// - `Ok(())`: If every value is within its allowed range.
+/// - `Err(GaladrielError)`: If a value is out of its allowed range.
+fn validate_configuration_json(configs_json: &ConfigurationJson) -> GaladrielResult<()> {
+    let hash_length = configs_json.class_name_hash_length;
+
+    if !(MIN_CLASS_NAME_HASH_LENGTH..=MAX_CLASS_NAME_HASH_LENGTH).contains(&hash_length) {
+        tracing::error!("Invalid class name hash length: {}", hash_length);
+
+        return Err(GaladrielError::raise_general_other_error(
+            ErrorKind::ConfigValueOutOfRangeError,
+            &format!(
+                "The `classNameHashLength` configuration must be between {} and {}, but {} was given.",
+                MIN_CLASS_NAME_HASH_LENGTH, MAX_CLASS_NAME_HASH_LENGTH, hash_length
+            ),
+            ErrorAction::Notify,
+        ));
+    }
+
+    let class_prefix = &configs_json.class_prefix;
+
+    if !is_valid_class_prefix(class_prefix) {
+        tracing::error!("Invalid class prefix: {}", class_prefix);
+
+        return Err(GaladrielError::raise_general_other_error(
+            ErrorKind::InvalidClassPrefixError,
+            &format!(
+                "The `classPrefix` configuration must start a valid CSS class name, using only letters, digits, `-` and `_`, but `{}` was given.",
+                class_prefix
+            ),
+            ErrorAction::Notify,
+        ));
+    }
+
+    let build_json_name = configs_json.build_json_name.trim();
+
+    if build_json_name.is_empty() || build_json_name.contains(['/', '\\']) {
+        tracing::error!("Invalid build JSON name: {}", build_json_name);
+
+        return Err(GaladrielError::raise_general_other_error(
+            ErrorKind::InvalidBuildJsonNameError,
+            &format!(
+                "The `buildJsonName` configuration must be a file name without path separators, but `{}` was given.",
+                build_json_name
+            ),
+            ErrorAction::Notify,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks whether the class prefix can start a CSS class name without being escaped.
+/// An empty prefix is valid, as it keeps the generated utility class names untouched.
+fn is_valid_class_prefix(class_prefix: &str) -> bool {
+    let is_valid_start = class_prefix
+        .strip_prefix('-')
+        .unwrap_or(class_prefix)
+        .starts_with(|c: char| c.is_ascii_alphabetic() || c == '_');
+
+    class_prefix.is_empty()
+        || is_valid_start
+            && class_prefix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 /// Constructs an exclude matcher based on the configuration's exclude patterns.
 ///
 /// # Parameters
@@ -433,6 +2447,99 @@ pub fn construct_exclude_matcher(working_dir: &PathBuf) -> GaladrielResult<overr
     })
 }
 
+/// Constructs a matcher for the additional files watched through the `watchExtra` configuration.
+///
+/// Unlike the exclude matcher, the patterns are whitelisted, so a watched file is matched as such.
+///
+/// # Parameters
+/// - `working_dir`: A reference to the working directory path.
+///
+/// # Returns
+/// - `GaladrielResult<overrides::Override>`: The built matcher or an error if construction fails.
+pub fn construct_watch_extra_matcher(working_dir: &Path) -> GaladrielResult<overrides::Override> {
+    tracing::info!("Constructing watch extra matcher using patterns from configuration.");
+
+    construct_whitelist_matcher(
+        working_dir,
+        "watchExtra",
+        &get_watch_extra(),
+        ErrorKind::WatchExtraMatcherCreationError,
+        ErrorKind::WatchExtraMatcherBuildFailed,
+    )
+}
+
+/// Constructs a matcher for the source files scanned through the `contentGlobs` configuration.
+///
+/// # Parameters
+/// - `working_dir`: A reference to the working directory path.
+///
+/// # Returns
+/// - `GaladrielResult<overrides::Override>`: The built matcher, whitelisting the scanned files, or an error if construction fails.
+pub fn construct_content_matcher(working_dir: &Path) -> GaladrielResult<overrides::Override> {
+    tracing::info!("Constructing content matcher using patterns from configuration.");
+
+    construct_whitelist_matcher(
+        working_dir,
+        "contentGlobs",
+        &get_content_globs(),
+        ErrorKind::ContentMatcherCreationError,
+        ErrorKind::ContentMatcherBuildFailed,
+    )
+}
+
+/// Constructs a matcher whitelisting the files of the given glob patterns, anchored at the working directory.
+///
+/// # Parameters
+/// - `working_dir`: A reference to the working directory path.
+/// - `config_name`: The name of the configuration holding the patterns, reported by the errors.
+/// - `patterns`: The glob patterns to whitelist.
+/// - `creation_error`: The error kind raised when a pattern is invalid.
+/// - `build_error`: The error kind raised when the matcher cannot be built.
+fn construct_whitelist_matcher(
+    working_dir: &Path,
+    config_name: &str,
+    patterns: &[String],
+    creation_error: ErrorKind,
+    build_error: ErrorKind,
+) -> GaladrielResult<overrides::Override> {
+    let mut overrides = overrides::OverrideBuilder::new(working_dir);
+
+    for pattern in patterns {
+        tracing::info!("Adding {} pattern: {}", config_name, pattern);
+
+        overrides
+            .add(&format!("/{}", pattern.trim_start_matches("/")))
+            .map_err(|err| {
+                tracing::error!("Error adding {} pattern: {}", config_name, err);
+
+                GaladrielError::raise_general_other_error(
+                    creation_error.clone(),
+                    &format!(
+                        "Something went wrong while adding the `{}` pattern of the `{}` configuration. Err: {}",
+                        pattern,
+                        config_name,
+                        err.to_string()
+                    ),
+                    ErrorAction::Notify,
+                )
+            })?;
+    }
+
+    overrides.build().map_err(|err| {
+        tracing::error!("Error building {} matcher: {}", config_name, err);
+
+        GaladrielError::raise_general_other_error(
+            build_error,
+            &format!(
+                "Something went wrong while constructing the matcher of the `{}` configuration. Err: {}",
+                config_name,
+                err.to_string()
+            ),
+            ErrorAction::Notify,
+        )
+    })
+}
+
 /// Reconstructs the exclude matcher by replacing the existing matcher.
 ///
 /// # Parameters
@@ -470,9 +2577,22 @@ pub async fn reconstruct_exclude_matcher(
 pub fn transform_configatron_to_json() -> GaladrielResult<String> {
     tracing::info!("Converting current configuration (CONFIGATRON) to pretty-printed JSON.");
 
-    // Serialize the CONFIGATRON key-value pairs into a JSON object.
+    transform_configs_to_json(&CONFIGATRON)
+}
+
+/// Converts a configuration map into a pretty-printed JSON string.
+///
+/// # Parameters
+/// - `configatron`: The configuration map to convert.
+///
+/// # Returns
+/// - `GaladrielResult<String>`: The JSON representation of the configuration, or an error if serialization fails.
+fn transform_configs_to_json(
+    configatron: &DashMap<String, GaladrielConfig>,
+) -> GaladrielResult<String> {
+    // Serialize the configuration key-value pairs into a JSON object.
     serde_json::to_string_pretty(
-        &CONFIGATRON
+        &configatron
             .iter()
             .map(|entry| {
                 // Match each configuration entry type and serialize its value to JSON.
@@ -482,6 +2602,53 @@ pub fn transform_configatron_to_json() -> GaladrielResult<String> {
                     GaladrielConfig::ResetStyles(value) => json!(value),
                     GaladrielConfig::MinifiedStyles(value) => json!(value),
                     GaladrielConfig::Port(value) => json!(value),
+                    GaladrielConfig::MaxExtensionDepth(value) => json!(value),
+                    GaladrielConfig::RelativeErrorPaths(value) => json!(value),
+                    GaladrielConfig::GenerateTypings(value) => json!(value),
+                    GaladrielConfig::ConsolidateShorthand(value) => json!(value),
+                    GaladrielConfig::ReloadThrottleMs(value) => json!(value),
+                    GaladrielConfig::PublicNames(value) => json!(value),
+                    GaladrielConfig::ContrastChecks(value) => json!(value),
+                    GaladrielConfig::ScaleFunction(value) => json!(value),
+                    GaladrielConfig::ScaleBase(value) => json!(value),
+                    GaladrielConfig::ResolveOnlyContexts(value) => json!(value),
+                    GaladrielConfig::ClassesJson(value) => json!(value),
+                    GaladrielConfig::AnimationPrefix(value) => json!(value),
+                    GaladrielConfig::SplitThemes(value) => json!(value),
+                    GaladrielConfig::ClassNameHashLength(value) => json!(value),
+                    GaladrielConfig::ResetStylesMedia(value) => json!(value),
+                    GaladrielConfig::NotifyOnError(value) => json!(value),
+                    GaladrielConfig::FrozenCentralNames(value) => json!(value),
+                    GaladrielConfig::SortOutputByProperty(value) => json!(value),
+                    GaladrielConfig::MaxClassesPerProperty(value) => json!(value),
+                    GaladrielConfig::ThemeVarFallback(value) => json!(value),
+                    GaladrielConfig::IdleTimeoutMinutes(value) => json!(value),
+                    GaladrielConfig::LenientAliases(value) => json!(value),
+                    GaladrielConfig::StrictVariables(value) => json!(value),
+                    GaladrielConfig::EmitCharset(value) => json!(value),
+                    GaladrielConfig::BuildWebhook(value) => json!(value),
+                    GaladrielConfig::PlainAlerts(value) => json!(value),
+                    GaladrielConfig::StableOutput(value) => json!(value),
+                    GaladrielConfig::SourceMaps(value) => json!(value),
+                    GaladrielConfig::LintPseudoElements(value) => json!(value),
+                    GaladrielConfig::ContextComments(value) => json!(value),
+                    GaladrielConfig::Autoprefix(value) => json!(value),
+                    GaladrielConfig::BrowserTargets(value) => json!(value),
+                    GaladrielConfig::MaxWatchBatch(value) => json!(value),
+                    GaladrielConfig::DedupeUtilities(value) => json!(value),
+                    GaladrielConfig::SplitResponsive(value) => json!(value),
+                    GaladrielConfig::ClassPrefix(value) => json!(value),
+                    GaladrielConfig::FeatureFlags(value) => json!(value),
+                    GaladrielConfig::EmitAtProperty(value) => json!(value),
+                    GaladrielConfig::BuildJsonName(value) => json!(value),
+                    GaladrielConfig::WarnUnusedVariables(value) => json!(value),
+                    GaladrielConfig::WatchExtra(value) => json!(value),
+                    GaladrielConfig::ContentGlobs(value) => json!(value),
+                    GaladrielConfig::CssLayer(value) => json!(value),
+                    GaladrielConfig::RespectReducedMotion(value) => json!(value),
+                    GaladrielConfig::PortFallbackRange(value) => json!(value),
+                    GaladrielConfig::ContainerBreakpoints(value) => json!(value),
+                    GaladrielConfig::MangleNames(value) => json!(value),
                 };
 
                 // Return the key-value pair for the serialized configuration entry.
@@ -501,9 +2668,147 @@ pub fn transform_configatron_to_json() -> GaladrielResult<String> {
     })
 }
 
+/// Converts the current `CONFIGATRON` configuration into a TOML string, carrying over the named
+/// profiles of the previous TOML configuration file.
+///
+/// # Parameters
+/// - `previous_configs`: The current content of the `galadriel.config.toml` file.
+///
+/// # Returns
+/// - `GaladrielResult<String>`: The TOML representation of the configuration, or an error if serialization fails.
+pub fn transform_configatron_to_toml(previous_configs: &str) -> GaladrielResult<String> {
+    tracing::info!("Converting current configuration (CONFIGATRON) to TOML.");
+
+    let raise_toml_serialization_error = |err: String| {
+        tracing::error!("Error serializing configuration to TOML: {}", err);
+
+        GaladrielError::raise_general_other_error(
+            ErrorKind::GaladrielConfigSerdeSerializationError,
+            &format!("Something went wrong while transforming the current Galadriel CSS configurations into TOML. Err: {}", err),
+            ErrorAction::Notify,
+        )
+    };
+
+    let mut configs = serde_json::from_str::<serde_json::Value>(&transform_configatron_to_json()?)
+        .map_err(|err| raise_toml_serialization_error(err.to_string()))?;
+
+    let profiles = toml::from_str::<serde_json::Value>(previous_configs)
+        .ok()
+        .and_then(|previous_configs| previous_configs.get(CONFIG_PROFILES_KEY).cloned());
+
+    if let (Some(profiles), Some(configs_map)) = (profiles, configs.as_object_mut()) {
+        configs_map.insert(CONFIG_PROFILES_KEY.to_string(), profiles);
+    }
+
+    toml::to_string_pretty(&configs).map_err(|err| raise_toml_serialization_error(err.to_string()))
+}
+
+/// Compares the current `CONFIGATRON` configuration against the built-in defaults.
+///
+/// # Returns
+/// - `GaladrielResult<Vec<(String, serde_json::Value, serde_json::Value)>>`: The name, default value and
+///   current value of every configuration that differs from its default, sorted by name.
+pub fn diff_configatron_from_defaults(
+) -> GaladrielResult<Vec<(String, serde_json::Value, serde_json::Value)>> {
+    tracing::info!("Comparing current configuration (CONFIGATRON) against the defaults.");
+
+    diff_configs_from_defaults(&CONFIGATRON)
+}
+
+/// Compares a configuration map against the built-in defaults.
+///
+/// # Parameters
+/// - `configatron`: The configuration map to compare.
+///
+/// # Returns
+/// - `GaladrielResult<Vec<(String, serde_json::Value, serde_json::Value)>>`: The name, default value and
+///   current value of every configuration that differs from its default, sorted by name.
+fn diff_configs_from_defaults(
+    configatron: &DashMap<String, GaladrielConfig>,
+) -> GaladrielResult<Vec<(String, serde_json::Value, serde_json::Value)>> {
+    let map_serde_error = |err: serde_json::Error| {
+        tracing::error!("Error serializing configuration to JSON: {}", err);
+
+        GaladrielError::raise_general_other_error(
+            ErrorKind::GaladrielConfigSerdeSerializationError,
+            &format!("Something went wrong while comparing the current Galadriel CSS configurations against the defaults. Err: {}", err.to_string()),
+            ErrorAction::Notify,
+        )
+    };
+
+    // The defaults are the configurations deserialized from an empty `galadriel.config.json` file.
+    let default_configs = serde_json::from_str::<ConfigurationJson>("{}")
+        .and_then(serde_json::to_value)
+        .map_err(map_serde_error)?;
+    let current_configs: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&transform_configs_to_json(configatron)?).map_err(map_serde_error)?;
+
+    let mut differences = match default_configs {
+        serde_json::Value::Object(default_configs) => default_configs
+            .into_iter()
+            .filter_map(|(config_name, default_value)| {
+                let current_value = current_configs.get(&config_name)?.to_owned();
+
+                (current_value != default_value).then_some((
+                    config_name,
+                    default_value,
+                    current_value,
+                ))
+            })
+            .collect::<Vec<_>>(),
+        _ => vec![],
+    };
+
+    differences.sort_by(|(first_name, _, _), (second_name, _, _)| first_name.cmp(second_name));
+
+    Ok(differences)
+}
+
+/// Generates the JSON Schema of the `galadriel.config.json` file, derived from `ConfigurationJson`.
+///
+/// The schema can be referenced through the `$schema` key of the configuration file, so editors
+/// validate and autocomplete it.
+///
+/// # Returns
+/// - `GaladrielResult<String>`: The pretty-printed JSON Schema, or an error if its serialization fails.
+pub fn generate_configuration_schema() -> GaladrielResult<String> {
+    tracing::info!("Generating the JSON Schema of the Galadriel CSS configurations.");
+
+    let mut schema = schema_for!(ConfigurationJson);
+
+    if let Some(metadata) = schema.schema.metadata.as_mut() {
+        metadata.title = Some("Galadriel CSS configurations".to_string());
+        metadata.description = Some(format!(
+            "The configurations of the `{}` file. Named profiles may be declared under the `{}` key, each overriding any of these configurations.",
+            JSON_CONFIG_FILE_NAME, CONFIG_PROFILES_KEY
+        ));
+    }
+
+    serde_json::to_string_pretty(&schema).map_err(|err| {
+        tracing::error!("Error serializing the configurations JSON Schema: {}", err);
+
+        GaladrielError::raise_general_other_error(
+            ErrorKind::GaladrielConfigSerdeSerializationError,
+            &format!(
+                "Something went wrong while generating the JSON Schema of the Galadriel CSS configurations. Err: {}",
+                err.to_string()
+            ),
+            ErrorAction::Exit,
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::configatron::ConfigurationJson;
+    use std::collections::BTreeMap;
+
+    use dashmap::DashMap;
+
+    use crate::configatron::{
+        apply_config_profile, carry_config_profiles, diff_configs_from_defaults, fill_configatron,
+        generate_configuration_schema, hash_emission_configs, hash_resolution_configs,
+        validate_configuration_json, ConfigurationJson, RESOLUTION_CONFIG_NAMES,
+    };
 
     #[test]
     fn test_default_configuration() {
@@ -516,6 +2821,53 @@ mod tests {
         assert!(config.reset_styles);
         assert!(config.minified_styles);
         assert_eq!(config.port, "0");
+        assert_eq!(config.max_extension_depth, 8);
+        assert!(!config.relative_error_paths);
+        assert!(!config.generate_typings);
+        assert!(!config.consolidate_shorthand);
+        assert_eq!(config.reload_throttle_ms, 100);
+        assert!(config.public_names.is_empty());
+        assert!(config.contrast_checks.is_empty());
+        assert_eq!(config.scale_function, "space");
+        assert!(config.scale_base.is_empty());
+        assert!(config.resolve_only_contexts.is_empty());
+        assert!(!config.classes_json);
+        assert!(config.animation_prefix.is_empty());
+        assert!(!config.split_themes);
+        assert_eq!(config.class_name_hash_length, 4);
+        assert!(config.reset_styles_media.is_empty());
+        assert!(!config.notify_on_error);
+        assert!(config.frozen_central_names.is_empty());
+        assert!(!config.sort_output_by_property);
+        assert_eq!(config.max_classes_per_property, 0);
+        assert!(config.theme_var_fallback.is_empty());
+        assert_eq!(config.idle_timeout_minutes, 0);
+        assert!(!config.lenient_aliases);
+        assert!(config.strict_variables.is_empty());
+        assert!(!config.emit_charset);
+        assert_eq!(config.build_webhook, "");
+        assert!(!config.plain_alerts);
+        assert!(config.stable_output);
+        assert!(!config.source_maps);
+        assert!(!config.lint_pseudo_elements);
+        assert!(!config.context_comments);
+        assert!(!config.autoprefix);
+        assert!(config.browser_targets.is_empty());
+        assert_eq!(config.max_watch_batch, 0);
+        assert!(!config.dedupe_utilities);
+        assert!(!config.split_responsive);
+        assert!(config.class_prefix.is_empty());
+        assert!(config.feature_flags.is_empty());
+        assert!(!config.emit_at_property);
+        assert_eq!(config.build_json_name, "galadrielcss.json");
+        assert!(!config.warn_unused_variables);
+        assert!(config.watch_extra.is_empty());
+        assert!(config.content_globs.is_empty());
+        assert_eq!(config.css_layer, "");
+        assert!(!config.respect_reduced_motion);
+        assert_eq!(config.port_fallback_range, 10);
+        assert!(config.container_breakpoints.is_empty());
+        assert!(!config.mangle_names);
     }
 
     #[test]
@@ -527,6 +2879,53 @@ mod tests {
             "resetStyles": true,
             "minifiedStyles": false,
             "port": "*",
+            "maxExtensionDepth": 4,
+            "relativeErrorPaths": true,
+            "generateTypings": true,
+            "consolidateShorthand": true,
+            "reloadThrottleMs": 250,
+            "publicNames": { "@module:card::title": "card-title" },
+            "contrastChecks": [{ "foreground": "textColor", "background": "surfaceColor" }],
+            "scaleFunction": "gap",
+            "scaleBase": "4px",
+            "resolveOnlyContexts": ["tokens"],
+            "classesJson": true,
+            "animationPrefix": "app-",
+            "splitThemes": true,
+            "classNameHashLength": 6,
+            "resetStylesMedia": "min-width:768px",
+            "notifyOnError": true,
+            "frozenCentralNames": ["primaryColor", "*"],
+            "sortOutputByProperty": true,
+            "maxClassesPerProperty": 50,
+            "themeVarFallback": "light",
+            "idleTimeoutMinutes": 30,
+            "lenientAliases": true,
+            "strictVariables": ["color", "backgroundColor"],
+            "emitCharset": true,
+            "buildWebhook": "https://ci.example.com/hooks/css",
+            "plainAlerts": true,
+            "stableOutput": false,
+            "sourceMaps": true,
+            "lintPseudoElements": true,
+            "contextComments": true,
+            "autoprefix": true,
+            "browserTargets": ["safari 14"],
+            "maxWatchBatch": 25,
+            "dedupeUtilities": true,
+            "splitResponsive": true,
+            "classPrefix": "gd-",
+            "featureFlags": { "checkoutLayout": ["newCheckout"] },
+            "emitAtProperty": true,
+            "buildJsonName": "galadrielcss.{env}.json",
+            "warnUnusedVariables": true,
+            "watchExtra": ["templates/**/*.txt"],
+            "contentGlobs": ["src/**/*.tsx"],
+            "cssLayer": "galadriel",
+            "respectReducedMotion": true,
+            "portFallbackRange": 3,
+            "containerBreakpoints": {"cardMd": "min-width:480px"},
+            "mangleNames": true,
             "version": "1.0.0"
         }"#;
 
@@ -538,6 +2937,65 @@ mod tests {
         assert!(config.reset_styles);
         assert!(!config.minified_styles);
         assert_eq!(config.port, "0"); // normalize_wildcard_port should convert "*" to "0"
+        assert_eq!(config.max_extension_depth, 4);
+        assert!(config.relative_error_paths);
+        assert!(config.generate_typings);
+        assert!(config.consolidate_shorthand);
+        assert_eq!(config.reload_throttle_ms, 250);
+        assert_eq!(
+            config.public_names.get("@module:card::title").unwrap(),
+            "card-title"
+        );
+        assert_eq!(config.contrast_checks[0].min_ratio, 4.5);
+        assert_eq!(config.scale_function, "gap");
+        assert_eq!(config.scale_base, "4px");
+        assert_eq!(config.resolve_only_contexts, vec!["tokens"]);
+        assert!(config.classes_json);
+        assert_eq!(config.animation_prefix, "app-");
+        assert!(config.split_themes);
+        assert_eq!(config.class_name_hash_length, 6);
+        assert_eq!(config.reset_styles_media, "min-width:768px");
+        assert!(config.notify_on_error);
+        assert_eq!(config.frozen_central_names, vec!["primaryColor", "*"]);
+        assert!(config.sort_output_by_property);
+        assert_eq!(config.max_classes_per_property, 50);
+        assert_eq!(config.theme_var_fallback, "light");
+        assert_eq!(config.idle_timeout_minutes, 30);
+        assert!(config.lenient_aliases);
+        assert_eq!(
+            config.strict_variables,
+            vec!["color".to_string(), "backgroundColor".to_string()]
+        );
+        assert!(config.emit_charset);
+        assert_eq!(config.build_webhook, "https://ci.example.com/hooks/css");
+        assert!(config.plain_alerts);
+        assert!(!config.stable_output);
+        assert!(config.source_maps);
+        assert!(config.lint_pseudo_elements);
+        assert!(config.context_comments);
+        assert!(config.autoprefix);
+        assert_eq!(config.browser_targets, vec!["safari 14".to_string()]);
+        assert_eq!(config.max_watch_batch, 25);
+        assert!(config.dedupe_utilities);
+        assert!(config.split_responsive);
+        assert_eq!(config.class_prefix, "gd-");
+        assert_eq!(
+            config.feature_flags.get("checkoutLayout").unwrap(),
+            &vec!["newCheckout".to_string()]
+        );
+        assert!(config.emit_at_property);
+        assert_eq!(config.build_json_name, "galadrielcss.{env}.json");
+        assert!(config.warn_unused_variables);
+        assert_eq!(config.watch_extra, vec!["templates/**/*.txt".to_string()]);
+        assert_eq!(config.content_globs, vec!["src/**/*.tsx".to_string()]);
+        assert_eq!(config.css_layer, "galadriel");
+        assert!(config.respect_reduced_motion);
+        assert_eq!(config.port_fallback_range, 3);
+        assert_eq!(
+            config.container_breakpoints.get("cardMd").unwrap(),
+            "min-width:480px"
+        );
+        assert!(config.mangle_names);
     }
 
     #[test]
@@ -548,6 +3006,53 @@ mod tests {
             reset_styles: false,
             minified_styles: true,
             port: "8080".to_string(),
+            max_extension_depth: 8,
+            relative_error_paths: true,
+            generate_typings: false,
+            consolidate_shorthand: true,
+            reload_throttle_ms: 0,
+            public_names: BTreeMap::from([("@class:button".to_string(), "button".to_string())]),
+            contrast_checks: vec![],
+            scale_function: "space".to_string(),
+            scale_base: String::new(),
+            resolve_only_contexts: vec![],
+            classes_json: false,
+            animation_prefix: String::new(),
+            split_themes: false,
+            class_name_hash_length: 4,
+            reset_styles_media: String::new(),
+            notify_on_error: false,
+            frozen_central_names: vec![],
+            sort_output_by_property: false,
+            max_classes_per_property: 0,
+            theme_var_fallback: String::new(),
+            idle_timeout_minutes: 0,
+            lenient_aliases: false,
+            strict_variables: vec![],
+            emit_charset: false,
+            build_webhook: String::new(),
+            plain_alerts: true,
+            stable_output: false,
+            source_maps: true,
+            lint_pseudo_elements: true,
+            context_comments: true,
+            autoprefix: true,
+            browser_targets: vec!["chrome 80".to_string()],
+            max_watch_batch: 0,
+            dedupe_utilities: false,
+            split_responsive: false,
+            class_prefix: String::new(),
+            feature_flags: BTreeMap::new(),
+            emit_at_property: false,
+            build_json_name: "galadrielcss.json".to_string(),
+            warn_unused_variables: false,
+            watch_extra: vec![],
+            content_globs: vec![],
+            css_layer: String::new(),
+            respect_reduced_motion: false,
+            port_fallback_range: 10,
+            container_breakpoints: BTreeMap::new(),
+            mangle_names: false,
         };
 
         // Verify initialization
@@ -556,5 +3061,257 @@ mod tests {
         assert!(!config.reset_styles);
         assert!(config.minified_styles);
         assert_eq!(config.port, "8080");
+        assert_eq!(config.max_extension_depth, 8);
+        assert!(config.relative_error_paths);
+        assert!(!config.generate_typings);
+        assert!(config.consolidate_shorthand);
+        assert_eq!(config.reload_throttle_ms, 0);
+        assert_eq!(config.public_names.len(), 1);
+        assert!(config.contrast_checks.is_empty());
+        assert_eq!(config.scale_function, "space");
+        assert!(config.scale_base.is_empty());
+        assert!(config.resolve_only_contexts.is_empty());
+        assert!(!config.classes_json);
+        assert!(config.animation_prefix.is_empty());
+        assert!(!config.split_themes);
+        assert_eq!(config.class_name_hash_length, 4);
+        assert!(config.reset_styles_media.is_empty());
+        assert!(!config.notify_on_error);
+        assert!(config.frozen_central_names.is_empty());
+        assert!(!config.sort_output_by_property);
+        assert_eq!(config.max_classes_per_property, 0);
+        assert!(config.theme_var_fallback.is_empty());
+        assert_eq!(config.idle_timeout_minutes, 0);
+        assert!(!config.lenient_aliases);
+        assert!(config.strict_variables.is_empty());
+        assert!(!config.emit_charset);
+        assert_eq!(config.build_webhook, "");
+        assert!(config.plain_alerts);
+        assert!(!config.stable_output);
+        assert!(config.source_maps);
+        assert!(config.lint_pseudo_elements);
+        assert!(config.context_comments);
+        assert!(config.autoprefix);
+        assert_eq!(config.browser_targets, vec!["chrome 80".to_string()]);
+        assert_eq!(config.max_watch_batch, 0);
+        assert!(!config.dedupe_utilities);
+        assert!(!config.split_responsive);
+        assert!(config.class_prefix.is_empty());
+        assert!(config.feature_flags.is_empty());
+        assert!(!config.emit_at_property);
+        assert_eq!(config.build_json_name, "galadrielcss.json");
+        assert!(!config.warn_unused_variables);
+        assert!(config.watch_extra.is_empty());
+        assert!(config.content_globs.is_empty());
+        assert_eq!(config.css_layer, "");
+        assert!(!config.respect_reduced_motion);
+        assert_eq!(config.port_fallback_range, 10);
+        assert!(config.container_breakpoints.is_empty());
+        assert!(!config.mangle_names);
+    }
+
+    #[test]
+    fn toggling_output_config_keeps_resolution_hash() {
+        let default_configs: ConfigurationJson = serde_json::from_str("{}").unwrap();
+        let toggled_configs: ConfigurationJson =
+            serde_json::from_str(r#"{ "minifiedStyles": false }"#).unwrap();
+        let default_configatron = DashMap::new();
+        let toggled_configatron = DashMap::new();
+
+        fill_configatron(&default_configatron, &default_configs);
+        fill_configatron(&toggled_configatron, &toggled_configs);
+
+        assert_eq!(
+            hash_resolution_configs(&toggled_configatron),
+            hash_resolution_configs(&default_configatron)
+        );
+        assert_ne!(
+            hash_emission_configs(&toggled_configatron),
+            hash_emission_configs(&default_configatron)
+        );
+    }
+
+    #[test]
+    fn toggling_resolution_config_changes_resolution_hash() {
+        let default_configs: ConfigurationJson = serde_json::from_str("{}").unwrap();
+        let default_configatron = DashMap::new();
+
+        fill_configatron(&default_configatron, &default_configs);
+
+        let resolution_hash = hash_resolution_configs(&default_configatron);
+
+        let toggled_configs = [
+            r#"{ "exclude": ["src/legacy"] }"#,
+            r#"{ "autoNaming": true }"#,
+            r#"{ "maxExtensionDepth": 2 }"#,
+            r#"{ "consolidateShorthand": true }"#,
+            r#"{ "contrastChecks": [{ "foreground": "text", "background": "surface" }] }"#,
+            r#"{ "scaleFunction": "gap" }"#,
+            r#"{ "scaleBase": "0.5rem" }"#,
+            r#"{ "resolveOnlyContexts": ["central"] }"#,
+            r#"{ "animationPrefix": "app" }"#,
+            r#"{ "classNameHashLength": 8 }"#,
+            r#"{ "frozenCentralNames": ["primaryColor"] }"#,
+            r#"{ "maxClassesPerProperty": 10 }"#,
+            r#"{ "lenientAliases": true }"#,
+            r#"{ "strictVariables": ["color"] }"#,
+            r#"{ "lintPseudoElements": true }"#,
+            r#"{ "dedupeUtilities": true }"#,
+            r#"{ "classPrefix": "app-" }"#,
+            r#"{ "featureFlags": { "beta": ["dashboard"] } }"#,
+            r#"{ "warnUnusedVariables": true }"#,
+            r#"{ "containerBreakpoints": { "card": "min-width:400px" } }"#,
+        ];
+
+        assert_eq!(toggled_configs.len(), RESOLUTION_CONFIG_NAMES.len());
+
+        for toggled_config in toggled_configs {
+            let configs_json: ConfigurationJson = serde_json::from_str(toggled_config).unwrap();
+            let configatron = DashMap::new();
+
+            fill_configatron(&configatron, &configs_json);
+
+            assert_ne!(
+                hash_resolution_configs(&configatron),
+                resolution_hash,
+                "Toggling {} kept the resolution hash",
+                toggled_config
+            );
+        }
+    }
+
+    #[test]
+    fn diff_lists_only_customized_configs() {
+        let configs_json: ConfigurationJson =
+            serde_json::from_str(r#"{ "scaleFunction": "gap" }"#).unwrap();
+        let configatron = DashMap::new();
+
+        fill_configatron(&configatron, &configs_json);
+
+        let differences = diff_configs_from_defaults(&configatron).unwrap();
+
+        assert!(differences.contains(&(
+            "scaleFunction".to_string(),
+            serde_json::json!("space"),
+            serde_json::json!("gap")
+        )));
+        assert!(!differences
+            .iter()
+            .any(|(config_name, _, _)| config_name == "maxExtensionDepth"));
+    }
+
+    #[test]
+    fn class_name_hash_length_is_validated() {
+        let too_short: ConfigurationJson =
+            serde_json::from_str(r#"{"classNameHashLength": 2}"#).unwrap();
+        let too_long: ConfigurationJson =
+            serde_json::from_str(r#"{"classNameHashLength": 11}"#).unwrap();
+        let custom: ConfigurationJson =
+            serde_json::from_str(r#"{"classNameHashLength": 6}"#).unwrap();
+
+        assert!(validate_configuration_json(&too_short).is_err());
+        assert!(validate_configuration_json(&too_long).is_err());
+        assert!(validate_configuration_json(&custom).is_ok());
+    }
+
+    #[test]
+    fn class_prefix_is_validated() {
+        let empty: ConfigurationJson = serde_json::from_str(r#"{"classPrefix": ""}"#).unwrap();
+        let custom: ConfigurationJson = serde_json::from_str(r#"{"classPrefix": "gd-"}"#).unwrap();
+        let digit_start: ConfigurationJson =
+            serde_json::from_str(r#"{"classPrefix": "9gd-"}"#).unwrap();
+        let with_dot: ConfigurationJson =
+            serde_json::from_str(r#"{"classPrefix": "gd."}"#).unwrap();
+
+        assert!(validate_configuration_json(&empty).is_ok());
+        assert!(validate_configuration_json(&custom).is_ok());
+        assert!(validate_configuration_json(&digit_start).is_err());
+        assert!(validate_configuration_json(&with_dot).is_err());
+    }
+
+    #[test]
+    fn build_json_name_is_validated() {
+        let templated: ConfigurationJson =
+            serde_json::from_str(r#"{"buildJsonName": "galadrielcss.{env}.{date}.json"}"#).unwrap();
+        let empty: ConfigurationJson = serde_json::from_str(r#"{"buildJsonName": " "}"#).unwrap();
+        let nested: ConfigurationJson =
+            serde_json::from_str(r#"{"buildJsonName": "builds/galadrielcss.json"}"#).unwrap();
+
+        assert!(validate_configuration_json(&templated).is_ok());
+        assert!(validate_configuration_json(&empty).is_err());
+        assert!(validate_configuration_json(&nested).is_err());
+    }
+
+    #[test]
+    fn selected_profile_is_merged_over_the_base() {
+        let raw_configs = serde_json::json!({
+            "minifiedStyles": false,
+            "port": "3000",
+            "profiles": {
+                "prod": { "minifiedStyles": true }
+            }
+        });
+
+        let merged = apply_config_profile(raw_configs.clone(), Some("prod")).unwrap();
+        let config: ConfigurationJson = serde_json::from_value(merged).unwrap();
+
+        assert!(config.minified_styles);
+        assert_eq!(config.port, "3000");
+
+        let base = apply_config_profile(raw_configs.clone(), None).unwrap();
+
+        assert_eq!(base.get("profiles"), None);
+        assert_eq!(base.get("minifiedStyles"), Some(&serde_json::json!(false)));
+        assert!(apply_config_profile(raw_configs, Some("staging")).is_err());
+    }
+
+    #[test]
+    fn profiles_are_carried_to_the_replaced_configurations() {
+        let previous_configs = r#"{ "port": "3000", "profiles": { "dev": { "port": "8080" } } }"#;
+        let carried = carry_config_profiles(previous_configs, r#"{ "port": "4000" }"#.to_string());
+        let carried = serde_json::from_str::<serde_json::Value>(&carried).unwrap();
+
+        assert_eq!(carried.get("port"), Some(&serde_json::json!("4000")));
+        assert_eq!(
+            carried.get("profiles"),
+            Some(&serde_json::json!({ "dev": { "port": "8080" } }))
+        );
+    }
+    #[test]
+    fn toml_configuration_is_deserialized_like_json() {
+        let toml_data = r##"
+            exclude = ["path/to/exclude"]
+            minifiedStyles = false
+            port = "8080"
+            maxExtensionDepth = 4
+
+            [[contrastChecks]]
+            foreground = "#fff"
+            background = "#000"
+        "##;
+        let raw_configs = toml::from_str::<serde_json::Value>(toml_data).unwrap();
+        let config: ConfigurationJson = serde_json::from_value(raw_configs).unwrap();
+
+        assert_eq!(config.exclude, vec!["path/to/exclude".to_string()]);
+        assert!(!config.minified_styles);
+        assert_eq!(config.port, "8080");
+        assert_eq!(config.max_extension_depth, 4);
+        assert_eq!(config.contrast_checks.len(), 1);
+    }
+
+    #[test]
+    fn configuration_schema_describes_the_configuration_fields() {
+        let schema =
+            serde_json::from_str::<serde_json::Value>(&generate_configuration_schema().unwrap())
+                .unwrap();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["resetStyles"]["type"], "boolean");
+        assert_eq!(properties["resetStyles"]["default"], true);
+        assert_eq!(properties["port"]["type"], "string");
+        assert_eq!(properties["exclude"]["type"], "array");
+        assert_eq!(properties["exclude"]["items"]["type"], "string");
+        assert!(properties.get("contrastChecks").is_some());
+        assert!(properties.get("auto_naming").is_none());
     }
 }
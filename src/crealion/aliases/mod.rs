@@ -3,7 +3,6 @@ use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Stylitron,
@@ -28,13 +27,14 @@ impl Crealion {
         aliases_data: IndexMap<String, String>,
     ) -> JoinHandle<()> {
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
 
         // Spawn a blocking task to safely update the STYLITRON AST.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting to apply aliases for context: {}", context_name);
 
             // Attempt to retrieve the `aliases` section of the STYLITRON AST.
-            let mut stylitron_data = match STYLITRON.get_mut("aliases") {
+            let mut stylitron_data = match stylitron.get_mut("aliases") {
                 Some(data) => {
                     tracing::debug!("Successfully accessed the aliases section in STYLITRON AST.");
                     data
@@ -103,7 +103,9 @@ mod tests {
     use nenyr::types::{ast::NenyrAst, central::CentralContext};
     use tokio::sync::broadcast;
 
-    use crate::{asts::STYLITRON, crealion::Crealion, events::GaladrielAlerts, types::Stylitron};
+    use crate::{
+        asts::CompilationSession, crealion::Crealion, events::GaladrielAlerts, types::Stylitron,
+    };
 
     fn mock_aliases() -> IndexMap<String, String> {
         IndexMap::from([
@@ -116,26 +118,32 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_aliases_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_aliases_to_stylitron("myContextName1".to_string(), mock_aliases())
             .await;
 
-        let result = STYLITRON
-            .get("aliases")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Aliases(aliases_definitions) => aliases_definitions
-                    .get("myContextName1")
-                    .and_then(|context_aliases| Some(context_aliases.to_owned())),
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("aliases")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Aliases(aliases_definitions) => aliases_definitions
+                        .get("myContextName1")
+                        .and_then(|context_aliases| Some(context_aliases.to_owned())),
+                    _ => None,
+                });
 
         assert!(result.is_some());
 
@@ -146,7 +154,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_aliases_to_existing_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
@@ -156,26 +164,32 @@ mod tests {
             IndexMap::from([("animeName".to_string(), "animation-name".to_string())]),
         )]);
 
-        STYLITRON.insert("aliases".to_string(), Stylitron::Aliases(initial_data));
+        session
+            .stylitron
+            .insert("aliases".to_string(), Stylitron::Aliases(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_aliases_to_stylitron("myContextName3".to_string(), mock_aliases())
             .await;
 
-        let result = STYLITRON
-            .get("aliases")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Aliases(aliases_definitions) => {
-                    aliases_definitions.get("myContextName3").cloned()
-                }
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("aliases")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Aliases(aliases_definitions) => {
+                        aliases_definitions.get("myContextName3").cloned()
+                    }
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let aliases = result.unwrap();
@@ -186,32 +200,38 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_aliases_to_new_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert("aliases".to_string(), Stylitron::Aliases(initial_data));
+        session
+            .stylitron
+            .insert("aliases".to_string(), Stylitron::Aliases(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_aliases_to_stylitron("newContextName".to_string(), mock_aliases())
             .await;
 
-        let result = STYLITRON
-            .get("aliases")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Aliases(aliases_definitions) => {
-                    aliases_definitions.get("newContextName").cloned()
-                }
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("aliases")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Aliases(aliases_definitions) => {
+                        aliases_definitions.get("newContextName").cloned()
+                    }
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let aliases = result.unwrap();
@@ -222,29 +242,33 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_aliases_with_empty_aliases_data() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let empty_aliases: IndexMap<String, String> = IndexMap::new();
         let _ = crealion
             .apply_aliases_to_stylitron("emptyAliasesContext".to_string(), empty_aliases.clone())
             .await;
 
-        let result = STYLITRON
-            .get("aliases")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Aliases(aliases_definitions) => {
-                    aliases_definitions.get("emptyAliasesContext").cloned()
-                }
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("aliases")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Aliases(aliases_definitions) => {
+                        aliases_definitions.get("emptyAliasesContext").cloned()
+                    }
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let aliases = result.unwrap();
@@ -255,19 +279,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_aliases_no_aliases_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("aliases");
+        session.stylitron.remove("aliases");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_aliases_to_stylitron("noAliasSection".to_string(), mock_aliases())
             .await;
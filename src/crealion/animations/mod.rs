@@ -3,7 +3,7 @@ use indexmap::IndexMap;
 use nenyr::types::animations::{NenyrAnimation, NenyrAnimationKind, NenyrKeyframe};
 
 use crate::{
-    asts::STYLITRON,
+    configatron::{get_animation_prefix, get_lenient_aliases},
     crealion::{processors::variables::VariablesOption, utils::camelify::camelify},
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
@@ -11,7 +11,10 @@ use crate::{
 };
 
 use super::{
-    processors::{aliases::resolve_alias_identifier, variables::resolve_variable_from_str},
+    processors::{
+        aliases::{resolve_alias_identifier, resolve_alias_ignoring_case},
+        variables::resolve_variable_from_str,
+    },
     utils::generates_variable_or_animation_name::generates_variable_or_animation_name,
     Crealion,
 };
@@ -166,6 +169,23 @@ impl Crealion {
                         .collect::<Vec<_>>()
                         .join(",");
 
+                    // Malformed offsets would produce an invalid `@keyframes` rule, so the keyframe is skipped.
+                    if !is_valid_keyframe_selector(&fraction_stops) {
+                        tracing::warn!(
+                            "Skipped keyframe with malformed stops `{}` for animation `{}` in context `{}`.",
+                            fraction_stops,
+                            animation_name,
+                            context_name,
+                        );
+
+                        self.raise_warning(&format!(
+                            "The `{}` stops in the `{}` animation of the `{}` context are not valid keyframe offsets. Each offset must be a percentage between 0% and 100%. As a result, the keyframe was not created.",
+                            fraction_stops, animation_name, self.transform_context_name(context_name)
+                        ));
+
+                        return None;
+                    }
+
                     let processed_properties = self.process_animation_properties(
                         animation_name,
                         context_name,
@@ -190,7 +210,10 @@ impl Crealion {
 
                 None
             })
-            .collect::<IndexMap<String, IndexMap<String, String>>>();
+            .collect::<Vec<_>>();
+
+        let transformed_keyframes =
+            self.collect_keyframes(animation_name, context_name, transformed_keyframes);
 
         // Apply the transformed keyframes to Stylitron.
         self.apply_animation_to_stylitron(animation_name, context_name, transformed_keyframes);
@@ -368,7 +391,10 @@ impl Crealion {
 
                 None
             })
-            .collect::<IndexMap<String, IndexMap<String, String>>>();
+            .collect::<Vec<_>>();
+
+        let transformed_keyframes =
+            self.collect_keyframes(animation_name, context_name, transformed_keyframes);
 
         // Apply the transformed animation keyframes to the STYLITRON AST.
         self.apply_animation_to_stylitron(animation_name, context_name, transformed_keyframes);
@@ -379,6 +405,47 @@ impl Crealion {
         );
     }
 
+    /// Collects the keyframes of an animation by their offsets, raising a warning for every offset
+    /// defined more than once. As before, the last definition of a duplicated offset is kept.
+    ///
+    /// # Arguments
+    /// - `animation_name` - The name of the animation.
+    /// - `context_name` - The name of the current context.
+    /// - `keyframes` - The offsets and properties of the keyframes, in their declaration order.
+    ///
+    /// # Returns
+    /// An `IndexMap` of the keyframe properties keyed by their offsets.
+    fn collect_keyframes(
+        &self,
+        animation_name: &str,
+        context_name: &str,
+        keyframes: Vec<(String, IndexMap<String, String>)>,
+    ) -> IndexMap<String, IndexMap<String, String>> {
+        let mut collected_keyframes: IndexMap<String, IndexMap<String, String>> = IndexMap::new();
+
+        for (offset, properties) in keyframes {
+            if collected_keyframes.contains_key(&offset) {
+                let context_name = self.transform_context_name(context_name);
+
+                tracing::warn!(
+                    "Duplicate keyframe offset `{}` for animation `{}` in context `{}`.",
+                    offset,
+                    animation_name,
+                    context_name,
+                );
+
+                self.raise_warning(&format!(
+                    "The `{}` offset is defined more than once in the `{}` animation of the `{}` context. Only its last definition is kept, overwriting the previous ones. Please merge or remove the duplicated keyframes.",
+                    offset, animation_name, context_name
+                ));
+            }
+
+            collected_keyframes.insert(offset, properties);
+        }
+
+        collected_keyframes
+    }
+
     /// Processes the properties of an animation keyframe by resolving aliases
     /// and variables based on inherited contexts.
     ///
@@ -408,10 +475,20 @@ impl Crealion {
             .into_iter()
             .filter_map(|(identifier, value)| {
                 // Attempt to resolve the property alias using inherited contexts.
-                match resolve_alias_identifier(&identifier, inherited_contexts) {
+                let resolved_property = resolve_alias_identifier(&self.session.stylitron, &identifier, inherited_contexts)
+                    .or_else(|| {
+                        self.resolve_lenient_animation_alias(
+                            &identifier,
+                            animation_name,
+                            context_name,
+                            inherited_contexts,
+                        )
+                    });
+
+                match resolved_property {
                     Some(property) => {
                         // Resolve the variable value using inherited contexts.
-                        match resolve_variable_from_str(value, false, inherited_contexts) {
+                        match resolve_variable_from_str(&self.session.stylitron, value, false, inherited_contexts) {
                             VariablesOption::Some(resolved_value) => {
                                 tracing::debug!(
                                     "Resolved property '{}' with value '{}' for animation '{}'",
@@ -460,6 +537,49 @@ impl Crealion {
             .collect()
     }
 
+    /// Resolves an alias written with a different casing when the `lenientAliases` configuration is
+    /// enabled, raising a warning that recommends the canonical casing of the alias.
+    ///
+    /// # Arguments
+    /// - `identifier` - The property identifier, possibly an alias.
+    /// - `animation_name` - The name of the animation the property belongs to.
+    /// - `context_name` - The name of the context the animation belongs to.
+    /// - `inherited_contexts` - The contexts used to resolve the alias.
+    ///
+    /// # Returns
+    /// - `Option<String>` with the resolved property, or `None` if lenient aliases are disabled or no alias matches.
+    fn resolve_lenient_animation_alias(
+        &self,
+        identifier: &str,
+        animation_name: &str,
+        context_name: &str,
+        inherited_contexts: &Vec<String>,
+    ) -> Option<String> {
+        if !get_lenient_aliases() {
+            return None;
+        }
+
+        let (canonical_alias, property) =
+            resolve_alias_ignoring_case(&self.session.stylitron, identifier, inherited_contexts)?;
+        let alias = identifier.trim_start_matches("nickname;");
+        let context_name = self.transform_context_name(context_name);
+
+        tracing::warn!(
+            "Alias '{}' for animation '{}' in context '{}' resolved as '{}'.",
+            alias,
+            animation_name,
+            context_name,
+            canonical_alias
+        );
+
+        self.raise_warning(&format!(
+            "The `{}` alias in the `{}` animation of the `{}` context does not match the casing of the `{}` alias it was resolved to. Please write it as `{}` to keep the alias usage consistent.",
+            alias, animation_name, context_name, canonical_alias, canonical_alias
+        ));
+
+        Some(property)
+    }
+
     /// Raises a warning by creating and sending a notification.
     ///
     /// # Arguments
@@ -474,6 +594,32 @@ impl Crealion {
         }
     }
 
+    /// Retrieves the configured animation prefix, as long as it can start a CSS identifier.
+    ///
+    /// # Returns
+    /// - `String`: The animation prefix, or an empty string when none (or an invalid one) is configured.
+    pub(crate) fn resolve_animation_prefix() -> String {
+        let animation_prefix = get_animation_prefix();
+        let is_valid_prefix = animation_prefix
+            .strip_prefix('-')
+            .unwrap_or(&animation_prefix)
+            .starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && animation_prefix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        if animation_prefix.is_empty() || is_valid_prefix {
+            return animation_prefix;
+        }
+
+        tracing::warn!(
+            "Animation prefix '{}' is not a valid CSS identifier start and was ignored.",
+            animation_prefix
+        );
+
+        String::new()
+    }
+
     /// Applies the transformed animation keyframes to the STYLITRON AST.
     ///
     /// # Arguments
@@ -495,7 +641,7 @@ impl Crealion {
         );
 
         // Attempt to retrieve the `animations` section of the STYLITRON AST.
-        let mut stylitron_data = match STYLITRON.get_mut("animations") {
+        let mut stylitron_data = match self.session.stylitron.get_mut("animations") {
             Some(data) => {
                 tracing::debug!("Successfully accessed the animations section in STYLITRON AST.");
                 data
@@ -527,8 +673,11 @@ impl Crealion {
             }
         };
 
-        let animation_unique_name =
-            generates_variable_or_animation_name(context_name, animation_name, false);
+        let animation_unique_name = format!(
+            "{}{}",
+            Self::resolve_animation_prefix(),
+            generates_variable_or_animation_name(context_name, animation_name, false)
+        );
 
         // Check if the retrieved data matches the `Animations` variant.
         match *stylitron_data {
@@ -567,6 +716,27 @@ impl Crealion {
     }
 }
 
+/// Checks whether a keyframe selector is made only of valid offsets.
+///
+/// A selector may group several comma-separated offsets (e.g. `30%,50%,70%`), each of them being
+/// `from`, `to`, or a percentage between `0%` and `100%`.
+///
+/// # Arguments
+/// - `selector` - The keyframe selector to validate.
+///
+/// # Returns
+/// - `true` if every offset of the selector is valid, otherwise `false`.
+fn is_valid_keyframe_selector(selector: &str) -> bool {
+    !selector.trim().is_empty()
+        && selector.split(',').all(|offset| match offset.trim() {
+            "from" | "to" => true,
+            offset => offset
+                .strip_suffix('%')
+                .and_then(|percentage| percentage.parse::<f64>().ok())
+                .is_some_and(|percentage| (0.0..=100.0).contains(&percentage)),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use indexmap::IndexMap;
@@ -578,8 +748,9 @@ mod tests {
     use tokio::sync::broadcast;
 
     use crate::{
-        asts::STYLITRON,
+        asts::CompilationSession,
         crealion::{
+            animations::is_valid_keyframe_selector,
             utils::generates_variable_or_animation_name::generates_variable_or_animation_name,
             Crealion,
         },
@@ -634,26 +805,30 @@ mod tests {
 
     #[test]
     fn test_apply_animations_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let inherits = vec!["myAnimationContextOne".to_string()];
         let _ = crealion.process_animations("myAnimationContextOne", &inherits, mock_animations());
 
-        let result =
-            STYLITRON
-                .get("animations")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Animation(animations_definitions) => animations_definitions
-                        .get("myAnimationContextOne")
-                        .and_then(|context_animations| Some(context_animations.to_owned())),
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("animations")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Animation(animations_definitions) => animations_definitions
+                    .get("myAnimationContextOne")
+                    .and_then(|context_animations| Some(context_animations.to_owned())),
+                _ => None,
+            });
 
         assert!(result.is_some());
 
@@ -665,6 +840,8 @@ mod tests {
 
     #[test]
     fn test_apply_animations_to_existing_context() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
         // Pre-populate the STYLITRON AST with existing data.
@@ -682,26 +859,30 @@ mod tests {
             )]),
         )]);
 
-        STYLITRON.insert("animations".to_string(), Stylitron::Animation(initial_data));
+        session
+            .stylitron
+            .insert("animations".to_string(), Stylitron::Animation(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let inherits = vec!["myAnimationContextTwo".to_string()];
         let _ = crealion.process_animations("myAnimationContextTwo", &inherits, mock_animations());
 
-        let result =
-            STYLITRON
-                .get("animations")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Animation(animations_definitions) => {
-                        animations_definitions.get("myAnimationContextTwo").cloned()
-                    }
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("animations")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Animation(animations_definitions) => {
+                    animations_definitions.get("myAnimationContextTwo").cloned()
+                }
+                _ => None,
+            });
 
         assert!(result.is_some());
         let animations = result.unwrap();
@@ -713,31 +894,37 @@ mod tests {
 
     #[test]
     fn test_apply_animations_to_new_context() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert("animations".to_string(), Stylitron::Animation(initial_data));
+        session
+            .stylitron
+            .insert("animations".to_string(), Stylitron::Animation(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let inherits = vec!["myAnimationContextThree".to_string()];
         let _ =
             crealion.process_animations("myAnimationContextThree", &inherits, mock_animations());
 
-        let result =
-            STYLITRON
-                .get("animations")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Animation(animations_definitions) => animations_definitions
-                        .get("myAnimationContextThree")
-                        .cloned(),
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("animations")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Animation(animations_definitions) => animations_definitions
+                    .get("myAnimationContextThree")
+                    .cloned(),
+                _ => None,
+            });
 
         assert!(result.is_some());
         let animations = result.unwrap();
@@ -749,14 +936,18 @@ mod tests {
 
     #[test]
     fn test_apply_animations_with_empty_animations_data() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let empty_animations: IndexMap<String, NenyrAnimation> = IndexMap::new();
         let inherits = vec!["emptyAnimationContext".to_string()];
         let _ = crealion.process_animations(
@@ -765,34 +956,36 @@ mod tests {
             empty_animations.clone(),
         );
 
-        let result =
-            STYLITRON
-                .get("animations")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Animation(animations_definitions) => {
-                        animations_definitions.get("emptyAnimationContext").cloned()
-                    }
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("animations")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Animation(animations_definitions) => {
+                    animations_definitions.get("emptyAnimationContext").cloned()
+                }
+                _ => None,
+            });
 
         assert!(result.is_none());
     }
 
     #[tokio::test]
     async fn test_apply_animations_no_animations_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("animations");
+        session.stylitron.remove("animations");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let inherits = vec!["noAnimationsSection".to_string()];
         let _ = crealion.process_animations("noAnimationsSection", &inherits, mock_animations());
 
@@ -812,4 +1005,223 @@ mod tests {
             panic!("Expected an error notification, but none was received.");
         }
     }
+
+    #[test]
+    fn keyframe_selectors_are_validated() {
+        assert!(is_valid_keyframe_selector("from"));
+        assert!(is_valid_keyframe_selector("to"));
+        assert!(is_valid_keyframe_selector("0%"));
+        assert!(is_valid_keyframe_selector("12.5%"));
+        assert!(is_valid_keyframe_selector("30%,50%,70%"));
+        assert!(is_valid_keyframe_selector("from, 50%, to"));
+
+        assert!(!is_valid_keyframe_selector(""));
+        assert!(!is_valid_keyframe_selector("50"));
+        assert!(!is_valid_keyframe_selector("120%"));
+        assert!(!is_valid_keyframe_selector("-10%"));
+        assert!(!is_valid_keyframe_selector("30%,,70%"));
+        assert!(!is_valid_keyframe_selector("halfway"));
+    }
+
+    #[test]
+    fn malformed_fraction_stops_are_skipped() {
+        let session = CompilationSession::new();
+
+        let (sender, _) = broadcast::channel(10);
+
+        let mut crealion = Crealion::new(
+            sender,
+            NenyrAst::CentralContext(CentralContext::new()),
+            "".to_string(),
+        );
+
+        crealion.set_session(session.clone());
+
+        let animations = IndexMap::from([(
+            "groupedAnimation".to_string(),
+            NenyrAnimation {
+                animation_name: "groupedAnimation".to_string(),
+                kind: Some(NenyrAnimationKind::Fraction),
+                progressive_count: None,
+                keyframe: vec![
+                    NenyrKeyframe::Fraction {
+                        stops: vec![30.0, 50.0, 70.0],
+                        properties: IndexMap::from([(
+                            "border-color".to_string(),
+                            "red".to_string(),
+                        )]),
+                    },
+                    NenyrKeyframe::Fraction {
+                        stops: vec![150.0],
+                        properties: IndexMap::from([(
+                            "border-color".to_string(),
+                            "green".to_string(),
+                        )]),
+                    },
+                ],
+            },
+        )]);
+
+        let inherits = vec!["groupedStopsContext".to_string()];
+        crealion.process_animations("groupedStopsContext", &inherits, animations);
+
+        let keyframes = session
+            .stylitron
+            .get("animations")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Animation(animations_definitions) => animations_definitions
+                    .get("groupedStopsContext")
+                    .and_then(|context_animations| context_animations.get("groupedAnimation"))
+                    .and_then(|animation| animation.values().next().cloned()),
+                _ => None,
+            });
+
+        assert_eq!(
+            keyframes.map(|keyframes| keyframes.keys().cloned().collect::<Vec<_>>()),
+            Some(vec!["30%,50%,70%".to_string()])
+        );
+    }
+
+    #[test]
+    fn keyframe_variables_are_resolved_against_the_inherited_contexts() {
+        let session = CompilationSession::new();
+
+        let (sender, mut receiver) = broadcast::channel(10);
+
+        session.stylitron.insert(
+            "variables".to_string(),
+            Stylitron::Variables(IndexMap::from([(
+                "timingVariablesParent".to_string(),
+                IndexMap::from([
+                    (
+                        "easeOutQuint".to_string(),
+                        vec![
+                            "--gEaseOutQnt".to_string(),
+                            "cubic-bezier(0.22, 1, 0.36, 1)".to_string(),
+                        ],
+                    ),
+                    (
+                        "shortDelay".to_string(),
+                        vec!["--gShortDelay".to_string(), "120ms".to_string()],
+                    ),
+                ]),
+            )])),
+        );
+
+        let mut crealion = Crealion::new(
+            sender,
+            NenyrAst::CentralContext(CentralContext::new()),
+            "".to_string(),
+        );
+
+        crealion.set_session(session.clone());
+
+        let animations = IndexMap::from([(
+            "slideIn".to_string(),
+            NenyrAnimation {
+                animation_name: "slideIn".to_string(),
+                kind: Some(NenyrAnimationKind::Transitive),
+                progressive_count: None,
+                keyframe: vec![
+                    NenyrKeyframe::From(IndexMap::from([
+                        (
+                            "animation-timing-function".to_string(),
+                            "${easeOutQuint}".to_string(),
+                        ),
+                        ("animation-delay".to_string(), "${shortDelay}".to_string()),
+                    ])),
+                    NenyrKeyframe::To(IndexMap::from([
+                        ("opacity".to_string(), "1".to_string()),
+                        (
+                            "animation-timing-function".to_string(),
+                            "${undeclaredEasing}".to_string(),
+                        ),
+                    ])),
+                ],
+            },
+        )]);
+
+        let inherits = vec![
+            "timingVariablesContext".to_string(),
+            "timingVariablesParent".to_string(),
+        ];
+        crealion.process_animations("timingVariablesContext", &inherits, animations);
+
+        let keyframes = session
+            .stylitron
+            .get("animations")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Animation(animations_definitions) => animations_definitions
+                    .get("timingVariablesContext")
+                    .and_then(|context_animations| context_animations.get("slideIn"))
+                    .and_then(|animation| animation.values().next().cloned()),
+                _ => None,
+            })
+            .expect("The animation should be applied to the STYLITRON AST.");
+
+        assert_eq!(
+            keyframes.get("0%"),
+            Some(&IndexMap::from([
+                (
+                    "animation-timing-function".to_string(),
+                    "var(--gEaseOutQnt)".to_string()
+                ),
+                (
+                    "animation-delay".to_string(),
+                    "var(--gShortDelay)".to_string()
+                ),
+            ]))
+        );
+        assert_eq!(
+            keyframes.get("100%"),
+            Some(&IndexMap::from([("opacity".to_string(), "1".to_string())]))
+        );
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(GaladrielAlerts::Warning { .. })
+        ));
+    }
+
+    #[test]
+    fn duplicate_keyframe_offsets_are_reported() {
+        let (sender, mut receiver) = broadcast::channel(10);
+
+        let crealion = Crealion::new(
+            sender,
+            NenyrAst::CentralContext(CentralContext::new()),
+            "".to_string(),
+        );
+
+        let keyframes = crealion.collect_keyframes(
+            "duplicatedAnimation",
+            "duplicatedStopsContext",
+            vec![
+                (
+                    "20%".to_string(),
+                    IndexMap::from([("opacity".to_string(), "0".to_string())]),
+                ),
+                (
+                    "100%".to_string(),
+                    IndexMap::from([("opacity".to_string(), "1".to_string())]),
+                ),
+                (
+                    "20%".to_string(),
+                    IndexMap::from([("opacity".to_string(), "0.5".to_string())]),
+                ),
+            ],
+        );
+
+        assert_eq!(
+            keyframes.keys().cloned().collect::<Vec<_>>(),
+            vec!["20%".to_string(), "100%".to_string()]
+        );
+        assert_eq!(
+            keyframes
+                .get("20%")
+                .and_then(|properties| properties.get("opacity")),
+            Some(&"0.5".to_string())
+        );
+        assert!(receiver.try_recv().is_ok());
+        assert!(receiver.try_recv().is_err());
+    }
 }
@@ -1,9 +1,9 @@
 use chrono::Local;
+use dashmap::DashMap;
 use indexmap::IndexMap;
 use tokio::{sync::broadcast, task::JoinHandle};
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Stylitron,
@@ -34,13 +34,14 @@ impl Crealion {
         desktop_data: Option<IndexMap<String, String>>,
     ) -> JoinHandle<()> {
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
 
         // Spawn a blocking task to process the variables.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting the process to apply breakpoints to the STYLITRON AST.");
 
             // Attempt to retrieve the "variables" section from the STYLITRON AST.
-            let mut stylitron_data = match STYLITRON.get_mut("breakpoints") {
+            let mut stylitron_data = match stylitron.get_mut("breakpoints") {
                 Some(data) => {
                     tracing::debug!(
                         "Successfully accessed the `breakpoints` section in STYLITRON AST."
@@ -62,8 +63,12 @@ impl Crealion {
             let desktop_definitions =
                 Self::process_breakpoint(desktop_data, BreakpointType::DesktopFirst);
 
+            // Breakpoints sharing a condition are merged into a single media query, but are likely a mistake.
+            Self::warn_duplicate_breakpoints(&mobile_definitions, &desktop_definitions, &sender);
+
             // Apply the processed breakpoint definitions to the responsive node in STYLITRON.
             if let Err(_) = Self::apply_definitions_to_responsive_node(
+                &stylitron,
                 mobile_definitions.to_owned(),
                 desktop_definitions.to_owned(),
                 sender.clone(),
@@ -128,6 +133,81 @@ impl Crealion {
             .collect()
     }
 
+    /// Raises a warning for every group of breakpoints resolving to the same media condition.
+    ///
+    /// Conditions are compared ignoring whitespace and letter case, the same way their
+    /// responsive styles are grouped into a single `@media` query when the CSS is emitted.
+    ///
+    /// # Arguments
+    /// - `mobile_definitions`: The formatted mobile-first breakpoint definitions.
+    /// - `desktop_definitions`: The formatted desktop-first breakpoint definitions.
+    /// - `sender`: The sender used to send the warnings.
+    fn warn_duplicate_breakpoints(
+        mobile_definitions: &IndexMap<String, String>,
+        desktop_definitions: &IndexMap<String, String>,
+        sender: &broadcast::Sender<GaladrielAlerts>,
+    ) {
+        for (condition, identifiers) in
+            Self::find_duplicate_breakpoints(mobile_definitions, desktop_definitions)
+        {
+            tracing::warn!(
+                "Breakpoints {:?} resolve to the same condition '{}'.",
+                identifiers,
+                condition
+            );
+
+            let notification = GaladrielAlerts::create_warning(
+                Local::now(),
+                &format!(
+                    "The {} breakpoints resolve to the same `{}` condition. Their styles are merged into a single media query, so keeping only one of these breakpoints is recommended.",
+                    identifiers
+                        .iter()
+                        .map(|identifier| format!("`{}`", identifier))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    condition
+                ),
+            );
+
+            if let Err(err) = sender.send(notification) {
+                tracing::error!("Failed to send warning notification: {:?}", err);
+            }
+        }
+    }
+
+    /// Groups the breakpoint identifiers by their normalized media condition.
+    ///
+    /// # Arguments
+    /// - `mobile_definitions`: The formatted mobile-first breakpoint definitions.
+    /// - `desktop_definitions`: The formatted desktop-first breakpoint definitions.
+    ///
+    /// # Returns
+    /// - The normalized conditions shared by more than one breakpoint, each with the identifiers resolving to it.
+    fn find_duplicate_breakpoints(
+        mobile_definitions: &IndexMap<String, String>,
+        desktop_definitions: &IndexMap<String, String>,
+    ) -> Vec<(String, Vec<String>)> {
+        let mut conditions: IndexMap<String, Vec<String>> = IndexMap::new();
+
+        for (identifier, definition) in mobile_definitions.iter().chain(desktop_definitions) {
+            let condition: String = definition
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_lowercase();
+
+            conditions
+                .entry(condition)
+                .or_default()
+                .push(identifier.to_owned());
+        }
+
+        conditions
+            .into_iter()
+            .filter(|(_, identifiers)| identifiers.len() > 1)
+            .collect()
+    }
+
     /// Applies the provided mobile and desktop breakpoints definitions to the responsive node in the STYLITRON AST.
     ///
     /// This function accesses the `responsive` section in the STYLITRON Abstract Syntax Tree (AST),
@@ -135,6 +215,7 @@ impl Crealion {
     /// into the existing responsive styles.
     ///
     /// # Arguments
+    /// - `stylitron` (`&DashMap<String, Stylitron>`): The abstract syntax tree holding the responsive node.
     /// - `mobile_definitions` (`IndexMap<String, String>`): A collection of mobile-first breakpoint definitions.
     /// - `desktop_definitions` (`IndexMap<String, String>`): A collection of desktop-first breakpoints definitions.
     /// - `sender` (`broadcast::Sender<GaladrielAlerts>`): The sender used to send alerts, such as error notifications.
@@ -143,12 +224,13 @@ impl Crealion {
     /// - `Ok(())`: If the definitions are successfully applied.
     /// - `Err(())`: If there was an error accessing the `responsive` section in the STYLITRON AST.
     fn apply_definitions_to_responsive_node(
+        stylitron: &DashMap<String, Stylitron>,
         mobile_definitions: IndexMap<String, String>,
         desktop_definitions: IndexMap<String, String>,
         sender: broadcast::Sender<GaladrielAlerts>,
     ) -> Result<(), ()> {
         // Attempt to access the "responsive" section in the STYLITRON AST.
-        let mut stylitron_data = match STYLITRON.get_mut("responsive") {
+        let mut stylitron_data = match stylitron.get_mut("responsive") {
             Some(data) => {
                 tracing::debug!("Successfully accessed the `responsive` section in STYLITRON AST.");
 
@@ -236,7 +318,9 @@ mod tests {
     use nenyr::types::{ast::NenyrAst, central::CentralContext};
     use tokio::sync::broadcast;
 
-    use crate::{asts::STYLITRON, crealion::Crealion, events::GaladrielAlerts, types::Stylitron};
+    use crate::{
+        asts::CompilationSession, crealion::Crealion, events::GaladrielAlerts, types::Stylitron,
+    };
 
     use super::BreakpointType;
 
@@ -275,28 +359,32 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_breakpoints_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let (mobile_data, desktop_data) = mock_breakpoints();
         let _ = crealion
             .process_breakpoints(Some(mobile_data.clone()), Some(desktop_data.clone()))
             .await;
 
-        let result =
-            STYLITRON
-                .get("breakpoints")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Breakpoints(breakpoints_definitions) => {
-                        Some(breakpoints_definitions.to_owned())
-                    }
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("breakpoints")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Breakpoints(breakpoints_definitions) => {
+                    Some(breakpoints_definitions.to_owned())
+                }
+                _ => None,
+            });
 
         assert!(result.is_some());
 
@@ -317,7 +405,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_breakpoints_to_existing_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
@@ -327,31 +415,33 @@ mod tests {
             IndexMap::from([("myFakeBreakpoint".to_string(), "1024px".to_string())]),
         )]);
 
-        STYLITRON.insert(
+        session.stylitron.insert(
             "breakpoints".to_string(),
             Stylitron::Breakpoints(initial_data),
         );
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let (mobile_data, desktop_data) = mock_breakpoints();
         let _ = crealion
             .process_breakpoints(Some(mobile_data.clone()), Some(desktop_data.clone()))
             .await;
 
-        let result =
-            STYLITRON
-                .get("breakpoints")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Breakpoints(breakpoints_definitions) => {
-                        Some(breakpoints_definitions.clone())
-                    }
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("breakpoints")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Breakpoints(breakpoints_definitions) => {
+                    Some(breakpoints_definitions.clone())
+                }
+                _ => None,
+            });
 
         assert!(result.is_some());
         let breakpoints = result.unwrap();
@@ -372,37 +462,39 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_breakpoints_to_new_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert(
+        session.stylitron.insert(
             "breakpoints".to_string(),
             Stylitron::Breakpoints(initial_data),
         );
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let (mobile_data, desktop_data) = mock_breakpoints();
         let _ = crealion
             .process_breakpoints(Some(mobile_data.clone()), Some(desktop_data.clone()))
             .await;
 
-        let result =
-            STYLITRON
-                .get("breakpoints")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Breakpoints(breakpoints_definitions) => {
-                        Some(breakpoints_definitions.clone())
-                    }
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("breakpoints")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Breakpoints(breakpoints_definitions) => {
+                    Some(breakpoints_definitions.clone())
+                }
+                _ => None,
+            });
 
         assert!(result.is_some());
         let breakpoints = result.unwrap();
@@ -423,16 +515,18 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_breakpoints_with_empty_breakpoints_data() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let empty_breakpoints: IndexMap<String, String> = IndexMap::new();
         let _ = crealion
             .process_breakpoints(
@@ -441,15 +535,15 @@ mod tests {
             )
             .await;
 
-        let result =
-            STYLITRON
-                .get("breakpoints")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::Breakpoints(breakpoints_definitions) => {
-                        Some(breakpoints_definitions.clone())
-                    }
-                    _ => None,
-                });
+        let result = session
+            .stylitron
+            .get("breakpoints")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Breakpoints(breakpoints_definitions) => {
+                    Some(breakpoints_definitions.clone())
+                }
+                _ => None,
+            });
 
         assert!(result.is_some());
         let breakpoints = result.unwrap();
@@ -464,19 +558,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_breakpoints_no_breakpoints_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("breakpoints");
+        session.stylitron.remove("breakpoints");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let (mobile_data, desktop_data) = mock_breakpoints();
         let _ = crealion
             .process_breakpoints(Some(mobile_data), Some(desktop_data))
@@ -498,4 +594,31 @@ mod tests {
             panic!("Expected an error notification, but none was received.");
         }
     }
+
+    #[test]
+    fn duplicate_breakpoints_are_found() {
+        let mobile_definitions = format_breakpoints(
+            IndexMap::from([
+                ("md".to_string(), "768px".to_string()),
+                ("tablet".to_string(), " 768PX".to_string()),
+                ("xl".to_string(), "1280px".to_string()),
+            ]),
+            BreakpointType::MobileFirst,
+        );
+        let desktop_definitions = format_breakpoints(
+            IndexMap::from([("md".to_string(), "768px".to_string())]),
+            BreakpointType::DesktopFirst,
+        );
+
+        let duplicates =
+            Crealion::find_duplicate_breakpoints(&mobile_definitions, &desktop_definitions);
+
+        assert_eq!(
+            duplicates,
+            vec![(
+                "min-width:768px".to_string(),
+                vec!["md".to_string(), "tablet".to_string()]
+            )]
+        );
+    }
 }
@@ -1,23 +1,31 @@
 use chrono::Local;
+use dashmap::DashMap;
 use futures::future::join_all;
 use indexmap::IndexMap;
 use nenyr::types::class::NenyrStyleClass;
 use tokio::{sync::broadcast, task::JoinHandle};
 
 use crate::{
-    asts::STYLITRON,
+    configatron::{
+        get_consolidate_shorthand, get_lenient_aliases, get_lint_pseudo_elements,
+        get_resolve_only_contexts, get_scale_base, get_scale_function, get_strict_variables,
+    },
     crealion::utils::{camelify::camelify, pascalify::pascalify},
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
-    types::Stylitron,
+    types::{ClassOrigin, Stylitron},
 };
 
 use super::{
+    features::is_flagged_out,
     processors::{
-        aliases::resolve_alias_identifier,
+        aliases::{resolve_alias_identifier, resolve_alias_ignoring_case},
         breakpoints::resolve_breakpoint_identifier,
+        scales::expand_scale_functions,
+        shorthands::consolidate_shorthand_properties,
         variables::{resolve_variable_from_str, VariablesOption},
     },
+    profiler::{is_classes_profiling_enabled, record_class_timing},
     utils::generate_utility_class_name::generate_utility_class_name,
     Crealion,
 };
@@ -44,8 +52,46 @@ impl Crealion {
             "Starting to process classes for context"
         );
 
+        // Resolve-only contexts feed the contexts extending them, so their own classes are never emitted.
+        if get_resolve_only_contexts().contains(&self.transform_context_name(&context_name)) {
+            tracing::info!(
+                "Skipping {} classes of the resolve-only context '{}'.",
+                classes_data.len(),
+                context_name
+            );
+
+            return;
+        }
+
+        // Contexts depending on a feature flag left disabled for the build ship no classes.
+        if is_flagged_out(&self.transform_context_name(&context_name), None) {
+            tracing::info!(
+                "Skipping {} classes of the context '{}', as one of its feature flags is disabled.",
+                classes_data.len(),
+                context_name
+            );
+
+            return;
+        }
+
+        // Timing every class only pays off when a profile was requested.
+        let is_profiling = is_classes_profiling_enabled();
+
         // Iterate over all style classes in the provided data.
         for class in classes_data.into_values() {
+            if is_flagged_out(
+                &self.transform_context_name(&context_name),
+                Some(&class.class_name),
+            ) {
+                tracing::info!(
+                    "Skipping the class '{}', as one of its feature flags is disabled.",
+                    class.class_name
+                );
+
+                continue;
+            }
+
+            let started_at = is_profiling.then(std::time::Instant::now);
             let class_name = class.class_name;
             let derived_from = class.deriving_from.unwrap_or("_".to_string());
             let is_important = class.is_important.unwrap_or(false);
@@ -107,6 +153,26 @@ impl Crealion {
                 "Finished processing styles for class"
             );
 
+            // Record how long the class took to resolve, when profiling.
+            if let Some(started_at) = started_at {
+                record_class_timing(
+                    &class_name,
+                    &self.transform_context_name(&context_name),
+                    started_at.elapsed(),
+                );
+            }
+
+            // Record the class as the origin of its utility classes, for the source maps.
+            for utility_cls_name in &tracking_cls_names {
+                self.session.originator.insert(
+                    utility_cls_name.to_owned(),
+                    ClassOrigin {
+                        file_path: self.path.to_owned(),
+                        class_name: class_name.to_owned(),
+                    },
+                );
+            }
+
             // Retrieve or initialize the mapping for the parent context (`derived_from`) in the `tracking_map`.
             // If no entry exists for `derived_from`, a default value is created.
             //
@@ -148,6 +214,8 @@ impl Crealion {
         // Transform the context name to be used in alerts and clone the sender.
         let transformed_context_name = self.transform_context_name(&context_name);
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let class_prefix = self.class_prefix.to_owned();
 
         tokio::task::spawn_blocking(move || {
             let mut tracking_cls_names: Vec<String> = vec![];
@@ -161,6 +229,8 @@ impl Crealion {
                 );
 
                 Self::process_patterns(
+                    &stylitron,
+                    &class_prefix,
                     class_name.to_owned(),
                     is_important,
                     context_name,
@@ -207,6 +277,8 @@ impl Crealion {
         // Transform the context name to be used in alerts and clone the sender.
         let transformed_context_name = self.transform_context_name(&context_name);
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
+        let class_prefix = self.class_prefix.to_owned();
 
         tracing::info!(
             "Spawning task to process responsive styles for class '{}'. Context: '{}', Important: {}, Inherited contexts: {:?}",
@@ -228,6 +300,7 @@ impl Crealion {
                     .into_iter()
                     .for_each(
                         |(breakpoint_name, patterns_map)| match resolve_breakpoint_identifier(
+                            &stylitron,
                             &breakpoint_name,
                         ) {
                             Some(breakpoint) => {
@@ -239,6 +312,8 @@ impl Crealion {
 
                                 // Process patterns for the given breakpoint.
                                 Self::process_patterns(
+                                    &stylitron,
+                                    &class_prefix,
                                     class_name.to_owned(),
                                     is_important,
                                     context_name.to_owned(),
@@ -279,12 +354,14 @@ impl Crealion {
     /// Processes style patterns for a class, generating and resolving properties and values
     /// while tracking utility class names for further processing.
     fn process_patterns(
-        class_name: String,                         // Name of the class being processed.
-        is_important: bool,                         // Whether the class is marked as important.
-        context_name: String, // Name of the context to which the class belongs.
-        breakpoint: Option<String>, // Breakpoint value, if applicable.
-        breakpoint_name: Option<String>, // Name of the breakpoint, if applicable.
-        inherited_contexts: Vec<String>, // Contexts inherited by the current context.
+        stylitron: &DashMap<String, Stylitron>, // The abstract syntax tree the utility classes are applied to.
+        class_prefix: &str,                     // Prefix prepended to the utility class names.
+        class_name: String,                     // Name of the class being processed.
+        is_important: bool,                     // Whether the class is marked as important.
+        context_name: String,                   // Name of the context to which the class belongs.
+        breakpoint: Option<String>,             // Breakpoint value, if applicable.
+        breakpoint_name: Option<String>,        // Name of the breakpoint, if applicable.
+        inherited_contexts: Vec<String>,        // Contexts inherited by the current context.
         transformed_context_name: String, // Transformed name of the current context, to be used in alerts.
         tracking_cls_names: &mut Vec<String>, // Vector to track generated utility class names.
         sender: broadcast::Sender<GaladrielAlerts>, // Channel to send warnings and alerts.
@@ -295,8 +372,17 @@ impl Crealion {
             class_name, context_name, breakpoint_name, transformed_context_name
         );
 
+        let consolidate_shorthand = get_consolidate_shorthand();
+
         // Iterate over each pattern and its associated properties.
         styles_map.iter().for_each(|(pattern_name, properties)| {
+            // Replace complete sets of longhands sharing the same value with their shorthand, when enabled.
+            let properties = if consolidate_shorthand {
+                consolidate_shorthand_properties(stylitron, properties, &inherited_contexts)
+            } else {
+                properties.to_owned()
+            };
+
             tracing::debug!(
                 "Processing pattern '{}' with {} properties for class '{}'.",
                 pattern_name,
@@ -307,6 +393,8 @@ impl Crealion {
             // Iterate over properties and resolve each property-value pair.
             properties.iter().for_each(|(property, value)| {
                 Self::resolve_property(
+                    stylitron,
+                    class_prefix,
                     property,
                     value,
                     &class_name,
@@ -327,9 +415,11 @@ impl Crealion {
     /// Resolves a single property of a style pattern, checking for aliases and raising warnings
     /// if the alias cannot be resolved.
     fn resolve_property(
-        property: &str,                             // The property to resolve.
-        value: &str,                                // Value associated with the property.
-        class_name: &str,                           // Name of the class being processed.
+        stylitron: &DashMap<String, Stylitron>, // The abstract syntax tree used to resolve the property.
+        class_prefix: &str,                     // Prefix prepended to the utility class names.
+        property: &str,                         // The property to resolve.
+        value: &str,                            // Value associated with the property.
+        class_name: &str,                       // Name of the class being processed.
         pattern_name: &str, // Name of the pattern to which the property belongs.
         is_important: bool, // Whether the class is marked as important.
         context_name: &str, // Name of the context to which the class belongs.
@@ -341,7 +431,20 @@ impl Crealion {
         sender: broadcast::Sender<GaladrielAlerts>, // Channel to send warnings and alerts.
     ) {
         // Attempt to resolve the property alias using inherited contexts.
-        match resolve_alias_identifier(property, inherited_contexts) {
+        let resolved_property = resolve_alias_identifier(stylitron, property, inherited_contexts)
+            .or_else(|| {
+                Self::resolve_lenient_class_alias(
+                    stylitron,
+                    property,
+                    class_name,
+                    pattern_name,
+                    inherited_contexts,
+                    transformed_context_name,
+                    sender.clone(),
+                )
+            });
+
+        match resolved_property {
             Some(resolved_property) => {
                 tracing::debug!(
                     "Resolved alias '{}' to '{}' for class '{}'.",
@@ -350,8 +453,20 @@ impl Crealion {
                     class_name
                 );
 
+                // Properties the pseudo-element ignores are reported, but still generated.
+                Self::check_pseudo_element_property(
+                    &resolved_property,
+                    property,
+                    class_name,
+                    pattern_name,
+                    transformed_context_name,
+                    sender.clone(),
+                );
+
                 // If resolved, process the property value further.
                 Self::resolve_value(
+                    stylitron,
+                    class_prefix,
                     &resolved_property,
                     property,
                     value,
@@ -391,13 +506,55 @@ impl Crealion {
         }
     }
 
+    /// Resolves an alias written with a different casing when the `lenientAliases` configuration is
+    /// enabled, raising a warning that recommends the canonical casing of the alias.
+    fn resolve_lenient_class_alias(
+        stylitron: &DashMap<String, Stylitron>, // The abstract syntax tree used to resolve the alias.
+        property: &str,                         // The property to resolve.
+        class_name: &str,                       // Name of the class being processed.
+        pattern_name: &str, // Name of the pattern to which the property belongs.
+        inherited_contexts: &Vec<String>, // Contexts inherited by the current context.
+        transformed_context_name: &str, // Transformed name of the current context.
+        sender: broadcast::Sender<GaladrielAlerts>, // Channel to send warnings and alerts.
+    ) -> Option<String> {
+        if !get_lenient_aliases() {
+            return None;
+        }
+
+        let (canonical_alias, resolved_property) =
+            resolve_alias_ignoring_case(stylitron, property, inherited_contexts)?;
+        let alias = property.trim_start_matches("nickname;");
+        let pattern_name = pascalify(pattern_name);
+
+        tracing::warn!(
+            "Alias '{}' of `{}` pattern for class '{}' in context '{}' resolved as '{}'.",
+            alias,
+            pattern_name,
+            class_name,
+            transformed_context_name,
+            canonical_alias
+        );
+
+        Self::raise_class_warning(
+            &format!(
+                "The `{}` alias of `{}` pattern in the `{}` class of the `{}` context does not match the casing of the `{}` alias it was resolved to. Please write it as `{}` to keep the alias usage consistent.",
+                alias, pattern_name, class_name, transformed_context_name, canonical_alias, canonical_alias
+            ),
+            sender,
+        );
+
+        Some(resolved_property)
+    }
+
     /// Resolves the value of a property, checking for variables and generating the utility class name
     /// if successful, or raising warnings if unresolved.
     fn resolve_value(
-        resolved_property: &str,                    // Resolved property name.
-        property: &str,                             // Original property name.
-        value: &str,                                // Value associated with the property.
-        class_name: &str,                           // Name of the class being processed.
+        stylitron: &DashMap<String, Stylitron>, // The abstract syntax tree used to resolve the value.
+        class_prefix: &str,                     // Prefix prepended to the utility class names.
+        resolved_property: &str,                // Resolved property name.
+        property: &str,                         // Original property name.
+        value: &str,                            // Value associated with the property.
+        class_name: &str,                       // Name of the class being processed.
         pattern_name: &str, // Name of the pattern to which the property belongs.
         is_important: bool, // Whether the class is marked as important.
         context_name: &str, // Name of the context to which the class belongs.
@@ -408,8 +565,33 @@ impl Crealion {
         tracking_cls_names: &mut Vec<String>, // Vector to track generated utility class names.
         sender: broadcast::Sender<GaladrielAlerts>, // Channel to send warnings and alerts.
     ) {
+        // Expand the scale function calls before the variables, so a variable used as the base is resolved too.
+        let value = expand_scale_functions(value, &get_scale_function(), &get_scale_base());
+
         // Resolve variable values using the provided string and inherited contexts.
-        match resolve_variable_from_str(value.to_owned(), true, inherited_contexts) {
+        match resolve_variable_from_str(stylitron, value.to_owned(), true, inherited_contexts) {
+            // An empty value would produce a broken `property: ;` declaration, so the utility class is skipped.
+            VariablesOption::Some(resolved_value) if resolved_value.trim().is_empty() => {
+                let property = property.trim_start_matches("nickname;");
+                let property = camelify(property);
+                let pattern_name = pascalify(pattern_name);
+
+                tracing::warn!(
+                    "Empty value in property '{}' of `{}` pattern for class '{}' in context '{}'.",
+                    property,
+                    pattern_name,
+                    class_name,
+                    transformed_context_name
+                );
+
+                Self::raise_class_warning(
+                    &format!(
+                        "The `{}` property of `{}` pattern in the `{}` class of the `{}` context has an empty value. As a result, the style corresponding to the `{}` property was not created. Please provide a valid value for the property.",
+                        property, pattern_name, class_name, transformed_context_name, property
+                    ),
+                    sender.clone()
+                );
+            }
             VariablesOption::Some(resolved_value) => {
                 tracing::info!(
                     "Variable resolved successfully for property '{}'. Resolved value: '{}'.",
@@ -417,8 +599,21 @@ impl Crealion {
                     resolved_value
                 );
 
+                // Literal values in the strict variables properties are reported, but still generated.
+                Self::check_strict_variables(
+                    resolved_property,
+                    property,
+                    &value,
+                    class_name,
+                    pattern_name,
+                    transformed_context_name,
+                    sender.clone(),
+                );
+
                 // Generate a utility class name if the variable resolves successfully.
                 Self::generate_utility_class_name(
+                    stylitron,
+                    class_prefix,
                     resolved_property,
                     &resolved_value,
                     pattern_name,
@@ -452,10 +647,87 @@ impl Crealion {
         }
     }
 
+    /// Raises a warning when a property listed in the `strictVariables` configuration holds a literal
+    /// value, i.e. one that references no variable and is not a CSS-wide keyword.
+    fn check_strict_variables(
+        resolved_property: &str,                    // Resolved property name.
+        property: &str,                             // Original property name.
+        value: &str, // Value associated with the property, before resolving variables.
+        class_name: &str, // Name of the class being processed.
+        pattern_name: &str, // Name of the pattern to which the property belongs.
+        transformed_context_name: &str, // Transformed name of the current context, to be used in alerts.
+        sender: broadcast::Sender<GaladrielAlerts>, // Channel to send warnings and alerts.
+    ) {
+        let strict_variables = get_strict_variables();
+        let normalized_property = normalize_property_name(resolved_property);
+
+        let is_strict_property = strict_variables
+            .iter()
+            .any(|strict_property| normalize_property_name(strict_property) == normalized_property);
+
+        if !is_strict_property || !is_literal_value(value) {
+            return;
+        }
+
+        let property = camelify(property.trim_start_matches("nickname;"));
+        let pattern_name = pascalify(pattern_name);
+
+        tracing::warn!(
+            "Literal value '{}' in strict variables property '{}' of `{}` pattern for class '{}' in context '{}'.",
+            value, property, pattern_name, class_name, transformed_context_name
+        );
+
+        Self::raise_class_warning(
+            &format!(
+                "The `{}` property of `{}` pattern in the `{}` class of the `{}` context uses the literal value `{}`, but it is configured to require variables. Please replace the hardcoded value with a variable from the design tokens.",
+                property, pattern_name, class_name, transformed_context_name, value.trim()
+            ),
+            sender
+        );
+    }
+
+    /// Raises a warning when the `lintPseudoElements` configuration is enabled and a property is
+    /// applied to a pseudo-element that does not support it, as its declaration would be dead CSS.
+    fn check_pseudo_element_property(
+        resolved_property: &str,                    // Resolved property name.
+        property: &str,                             // Original property name.
+        class_name: &str,                           // Name of the class being processed.
+        pattern_name: &str, // Name of the pattern to which the property belongs.
+        transformed_context_name: &str, // Transformed name of the current context, to be used in alerts.
+        sender: broadcast::Sender<GaladrielAlerts>, // Channel to send warnings and alerts.
+    ) {
+        if !get_lint_pseudo_elements()
+            || is_supported_by_pseudo_element(pattern_name, resolved_property)
+        {
+            return;
+        }
+
+        let property = camelify(property.trim_start_matches("nickname;"));
+        let pattern_name = pascalify(pattern_name);
+
+        tracing::warn!(
+            "Property '{}' is not supported by the `{}` pattern for class '{}' in context '{}'.",
+            property,
+            pattern_name,
+            class_name,
+            transformed_context_name
+        );
+
+        Self::raise_class_warning(
+            &format!(
+                "The `{}` property of `{}` pattern in the `{}` class of the `{}` context is not supported by its pseudo-element, so browsers ignore the generated style. Please move the `{}` property to a pattern that supports it.",
+                property, pattern_name, class_name, transformed_context_name, property
+            ),
+            sender,
+        );
+    }
+
     /// Generates a utility class name based on the resolved property and value.
     /// The generated class name is added to the Stylitron AST and tracked.
     ///
     /// # Arguments
+    /// - `stylitron`: The abstract syntax tree the utility class is applied to.
+    /// - `class_prefix`: The prefix prepended to the utility class name.
     /// - `resolved_property`: The property that was resolved.
     /// - `resolved_value`: The value associated with the resolved property.
     /// - `pattern_name`: The pattern name where the property belongs.
@@ -466,6 +738,8 @@ impl Crealion {
     /// - `tracking_cls_names`: A mutable reference to the vector tracking generated class names.
     /// - `sender`: A sender to communicate alerts.
     fn generate_utility_class_name(
+        stylitron: &DashMap<String, Stylitron>,
+        class_prefix: &str,
         resolved_property: &str,
         resolved_value: &str,
         pattern_name: &str,
@@ -480,6 +754,7 @@ impl Crealion {
         let pattern_name = pattern_name.trim_end_matches("stylesheet");
         // Generate the utility class name using the resolved data and breakpoint name.
         let utility_cls_name = generate_utility_class_name(
+            class_prefix,
             &breakpoint_name,
             is_important,
             pattern_name,
@@ -494,6 +769,7 @@ impl Crealion {
 
         // Apply the utility class to the Stylitron AST.
         Self::apply_utility_class_to_stylitron(
+            stylitron,
             &utility_cls_name,
             resolved_property,
             resolved_value,
@@ -521,6 +797,7 @@ impl Crealion {
     /// updates the relevant node with the utility class and its associated styles.
     ///
     /// # Arguments
+    /// - `stylitron`: The abstract syntax tree the utility class is applied to.
     /// - `utility_cls_name`: The name of the utility class to apply.
     /// - `resolved_property`: The CSS property being applied.
     /// - `resolved_value`: The resolved value of the CSS property.
@@ -530,6 +807,7 @@ impl Crealion {
     /// - `breakpoint`: An optional breakpoint for responsive styles.
     /// - `sender`: A channel sender used to send alerts or notifications.
     fn apply_utility_class_to_stylitron(
+        stylitron: &DashMap<String, Stylitron>,
         utility_cls_name: &str,
         resolved_property: &str,
         resolved_value: &str,
@@ -546,7 +824,7 @@ impl Crealion {
         };
 
         // Attempt to access the desired node in the Stylitron AST.
-        let mut stylitron_data = match STYLITRON.get_mut(stylitron_node_name) {
+        let mut stylitron_data = match stylitron.get_mut(stylitron_node_name) {
             Some(data) => {
                 tracing::debug!("Successfully accessed the styles section in STYLITRON AST.");
                 data
@@ -704,6 +982,97 @@ impl Crealion {
     }
 }
 
+/// CSS-wide keywords, which are accepted in place of a variable.
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// Normalizes a property name so its kebab-case and camelCase forms are compared equally.
+fn normalize_property_name(property: &str) -> String {
+    property
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Checks whether a value references no variable and is not a CSS-wide keyword.
+fn is_literal_value(value: &str) -> bool {
+    let value = value.trim();
+
+    !(value.contains("${")
+        || value.contains("var(")
+        || CSS_WIDE_KEYWORDS
+            .iter()
+            .any(|keyword| value.eq_ignore_ascii_case(keyword)))
+}
+
+/// The properties supported by the pseudo-elements that only accept a subset of them.
+/// Each entry also covers its longhands, e.g. `font` covers `font-size`.
+const PSEUDO_ELEMENT_PROPERTIES: [(&str, &[&str]); 2] = [
+    (
+        "::first-line",
+        &[
+            "font",
+            "background",
+            "color",
+            "word-spacing",
+            "letter-spacing",
+            "line-height",
+            "text-decoration",
+            "text-emphasis",
+            "text-shadow",
+            "text-transform",
+            "vertical-align",
+            "ruby-position",
+        ],
+    ),
+    (
+        "::first-letter",
+        &[
+            "font",
+            "background",
+            "color",
+            "margin",
+            "padding",
+            "border",
+            "box-shadow",
+            "float",
+            "initial-letter",
+            "word-spacing",
+            "letter-spacing",
+            "line-height",
+            "text-decoration",
+            "text-emphasis",
+            "text-shadow",
+            "text-transform",
+            "vertical-align",
+        ],
+    ),
+];
+
+/// Checks whether a property is supported by the pseudo-element of a pattern.
+/// Patterns without a restricted pseudo-element, as well as custom properties, support every property.
+fn is_supported_by_pseudo_element(pattern_name: &str, property: &str) -> bool {
+    let property = property.trim().to_ascii_lowercase();
+
+    if property.starts_with("--") {
+        return true;
+    }
+
+    let Some((_, supported_properties)) = PSEUDO_ELEMENT_PROPERTIES
+        .iter()
+        .find(|(pseudo_element, _)| *pseudo_element == pattern_name)
+    else {
+        return true;
+    };
+
+    supported_properties.iter().any(|supported| {
+        property == *supported
+            || property
+                .strip_prefix(supported)
+                .is_some_and(|longhand| longhand.starts_with('-'))
+    })
+}
+
 #[cfg(test)]
 mod classes_tests {
     use indexmap::IndexMap;
@@ -711,18 +1080,30 @@ mod classes_tests {
     use tokio::sync::broadcast;
 
     use crate::{
-        asts::STYLITRON, crealion::Crealion, types::Stylitron,
-        utils::generates_node_styles::generates_node_styles,
+        asts::CompilationSession,
+        crealion::{
+            classes::{is_literal_value, is_supported_by_pseudo_element, normalize_property_name},
+            Crealion, CrealionContextType,
+        },
+        events::GaladrielAlerts,
+        trailblazer::Trailblazer,
+        types::Stylitron,
+        utils::{
+            generates_node_styles::generates_node_styles,
+            serialize_classes_tracking::serialize_classes_tracking_from,
+        },
     };
 
-    fn mock_breakpoints() {
+    fn mock_breakpoints(session: &CompilationSession) {
         let map = IndexMap::from([(
             "mobile-first".to_string(),
             IndexMap::from([("mobMd".to_string(), "min-width:740px".to_string())]),
         )]);
 
-        STYLITRON.insert("breakpoints".to_string(), Stylitron::Breakpoints(map));
-        STYLITRON.insert(
+        session
+            .stylitron
+            .insert("breakpoints".to_string(), Stylitron::Breakpoints(map));
+        session.stylitron.insert(
             "responsive".to_string(),
             Stylitron::ResponsiveStyles(IndexMap::from([(
                 "min-width:740px".to_string(),
@@ -782,19 +1163,21 @@ mod classes_tests {
 
     #[tokio::test]
     async fn classes_exists_in_ast() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+        let session = CompilationSession::new();
 
-        mock_breakpoints();
+        mock_breakpoints(&session);
 
         let (sender, _) = broadcast::channel(10);
         let mut tracking_map: IndexMap<String, IndexMap<String, Vec<String>>> = IndexMap::new();
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .process_classes(
                 "firstClassContextName".to_string(),
@@ -805,7 +1188,8 @@ mod classes_tests {
             .await;
 
         let first_non_responsive_cls =
-            STYLITRON
+            session
+                .stylitron
                 .get("styles")
                 .and_then(|stylitron_data| match &*stylitron_data {
                     Stylitron::Styles(ref styles_defs) => {
@@ -832,31 +1216,29 @@ mod classes_tests {
             "\\!bgd-clr-NmXB".to_string()
         );
 
-        let first_responsive_cls =
-            STYLITRON
-                .get("responsive")
-                .and_then(|stylitron_data| match &*stylitron_data {
-                    Stylitron::ResponsiveStyles(ref styles_defs) => styles_defs
-                        .get("min-width:740px")
-                        .and_then(|breakpoint_styles| {
-                            breakpoint_styles.get("_").and_then(|pattern_styles| {
-                                pattern_styles
-                                    .get("!important")
-                                    .and_then(|importance_styles| {
-                                        importance_styles.get("background-color").and_then(
-                                            |property_styles| {
-                                                property_styles.get_index(0).and_then(
-                                                    |(utility_name, _)| {
-                                                        Some(utility_name.to_owned())
-                                                    },
-                                                )
-                                            },
-                                        )
-                                    })
-                            })
-                        }),
-                    _ => None,
-                });
+        let first_responsive_cls = session
+            .stylitron
+            .get("responsive")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::ResponsiveStyles(ref styles_defs) => styles_defs
+                    .get("min-width:740px")
+                    .and_then(|breakpoint_styles| {
+                        breakpoint_styles.get("_").and_then(|pattern_styles| {
+                            pattern_styles
+                                .get("!important")
+                                .and_then(|importance_styles| {
+                                    importance_styles.get("background-color").and_then(
+                                        |property_styles| {
+                                            property_styles.get_index(0).and_then(
+                                                |(utility_name, _)| Some(utility_name.to_owned()),
+                                            )
+                                        },
+                                    )
+                                })
+                        })
+                    }),
+                _ => None,
+            });
 
         assert!(first_responsive_cls.is_some());
         assert_eq!(
@@ -865,7 +1247,8 @@ mod classes_tests {
         );
 
         let second_non_responsive_cls =
-            STYLITRON
+            session
+                .stylitron
                 .get("styles")
                 .and_then(|stylitron_data| match &*stylitron_data {
                     Stylitron::Styles(ref styles_defs) => {
@@ -891,7 +1274,8 @@ mod classes_tests {
         );
 
         let second_responsive_cls =
-            STYLITRON
+            session
+                .stylitron
                 .get("responsive")
                 .and_then(|stylitron_data| match &*stylitron_data {
                     Stylitron::ResponsiveStyles(ref styles_defs) => styles_defs
@@ -918,4 +1302,180 @@ mod classes_tests {
             "mMd\\.hvr\\.bgd-clr-fWgf".to_string()
         );
     }
+
+    #[test]
+    fn empty_value_skips_utility_class() {
+        let session = CompilationSession::new();
+
+        let (sender, mut receiver) = broadcast::channel(10);
+        let mut tracking_cls_names: Vec<String> = vec![];
+
+        Crealion::resolve_value(
+            &session.stylitron,
+            "",
+            "background-color",
+            "background-color",
+            "   ",
+            "emptyValueClass",
+            "_stylesheet",
+            false,
+            "emptyValueContextName",
+            &None,
+            &None,
+            &vec!["emptyValueContextName".to_string()],
+            "emptyValueContextName",
+            &mut tracking_cls_names,
+            sender,
+        );
+
+        assert!(tracking_cls_names.is_empty());
+
+        match receiver.try_recv() {
+            Ok(GaladrielAlerts::Warning { message, .. }) => {
+                assert!(message.contains("`emptyValueClass` class"));
+                assert!(message.contains("`backgroundColor` property"));
+            }
+            other => panic!("Expected a warning alert, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn class_prefix_is_applied_to_utility_names() {
+        let session = CompilationSession::new();
+
+        mock_breakpoints(&session);
+
+        let (sender, _) = broadcast::channel(10);
+        let mut tracking_map: IndexMap<String, IndexMap<String, Vec<String>>> = IndexMap::new();
+
+        let mut crealion = Crealion::new(
+            sender,
+            NenyrAst::CentralContext(CentralContext::new()),
+            "".to_string(),
+        );
+
+        crealion
+            .set_session(session.clone())
+            .set_class_prefix("gd-".to_string());
+
+        crealion
+            .process_classes(
+                "prefixedClassContextName".to_string(),
+                vec!["prefixedClassContextName".to_string()],
+                mock_classes(),
+                &mut tracking_map,
+            )
+            .await;
+
+        crealion.apply_tracking_map_to_classinator(
+            "central".to_string(),
+            None,
+            CrealionContextType::Central,
+            tracking_map,
+        );
+
+        Trailblazer::default().set_session(session.clone()).blazer();
+
+        let utility_name = session
+            .stylitron
+            .get("styles")
+            .and_then(|stylitron_data| match &*stylitron_data {
+                Stylitron::Styles(ref styles_defs) => styles_defs
+                    .get("_")
+                    .and_then(|pattern_styles| pattern_styles.get("!important"))
+                    .and_then(|importance_styles| importance_styles.get("background-color"))
+                    .and_then(|property_styles| property_styles.get_index(0))
+                    .map(|(utility_name, _)| utility_name.to_owned()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(utility_name, "gd-\\!bgd-clr-NmXB");
+
+        let tracking = serialize_classes_tracking_from(&session.clastrack);
+
+        assert!(tracking.contains(&utility_name.replace('\\', "\\\\")));
+        assert!(tracking.contains(&utility_name.replace('\\', "")));
+    }
+
+    #[tokio::test]
+    async fn undefined_breakpoint_raises_warning() {
+        let (sender, mut receiver) = broadcast::channel(10);
+
+        let crealion = Crealion::new(
+            sender,
+            NenyrAst::CentralContext(CentralContext::new()),
+            "".to_string(),
+        );
+
+        let tracking_cls_names = crealion
+            .process_responsive_styles(
+                "typoBreakpointClass".to_string(),
+                false,
+                "typoBreakpointContextName".to_string(),
+                vec!["typoBreakpointContextName".to_string()],
+                Some(IndexMap::from([(
+                    "unknownBreakpoint".to_string(),
+                    IndexMap::from([(
+                        "_stylesheet".to_string(),
+                        IndexMap::from([("color".to_string(), "#FF0000".to_string())]),
+                    )]),
+                )])),
+            )
+            .await
+            .unwrap();
+
+        assert!(tracking_cls_names.is_empty());
+
+        match receiver.try_recv() {
+            Ok(GaladrielAlerts::Warning { message, .. }) => {
+                assert!(message.contains("`unknownBreakpoint` breakpoint"));
+                assert!(message.contains("`typoBreakpointClass` class"));
+            }
+            other => panic!("Expected a warning alert, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn literal_values_are_detected() {
+        assert!(is_literal_value("#FF6677"));
+        assert!(is_literal_value("12px"));
+        assert!(!is_literal_value("${primaryColor}"));
+        assert!(!is_literal_value("1px solid ${borderColor}"));
+        assert!(!is_literal_value("var(--primary-color)"));
+        assert!(!is_literal_value(" inherit "));
+
+        assert_eq!(
+            normalize_property_name("background-color"),
+            normalize_property_name("backgroundColor")
+        );
+    }
+
+    #[test]
+    fn pseudo_element_properties_are_checked() {
+        assert!(is_supported_by_pseudo_element("::first-line", "font-size"));
+        assert!(is_supported_by_pseudo_element("::first-line", "color"));
+        assert!(!is_supported_by_pseudo_element("::first-line", "display"));
+        assert!(!is_supported_by_pseudo_element(
+            "::first-line",
+            "margin-top"
+        ));
+        assert!(!is_supported_by_pseudo_element("::first-line", "fontsize"));
+
+        assert!(is_supported_by_pseudo_element(
+            "::first-letter",
+            "margin-top"
+        ));
+        assert!(!is_supported_by_pseudo_element(
+            "::first-letter",
+            "position"
+        ));
+
+        assert!(is_supported_by_pseudo_element(
+            "::first-line",
+            "--accent-color"
+        ));
+        assert!(is_supported_by_pseudo_element("::after", "display"));
+        assert!(is_supported_by_pseudo_element("_stylesheet", "display"));
+    }
 }
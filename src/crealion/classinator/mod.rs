@@ -2,7 +2,6 @@ use chrono::Local;
 use indexmap::IndexMap;
 
 use crate::{
-    asts::CLASSINATOR,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Classinator,
@@ -43,7 +42,7 @@ impl Crealion {
         };
 
         // Attempt to access the Classinator's section corresponding to the context node name.
-        let mut classinator_data = match CLASSINATOR.get_mut(context_node_name) {
+        let mut classinator_data = match self.session.classinator.get_mut(context_node_name) {
             Some(data) => {
                 tracing::debug!(
                     "Successfully accessed the {context_node_name} section in CLASSINATOR AST."
@@ -174,21 +173,25 @@ mod classinator_tests {
     use tokio::sync::broadcast;
 
     use crate::{
-        asts::CLASSINATOR,
+        asts::CompilationSession,
         crealion::{Crealion, CrealionContextType},
         types::Classinator,
     };
 
     #[test]
     fn central_map_should_exists_in_ast() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         crealion.apply_tracking_map_to_classinator(
             "central".to_string(),
             None,
@@ -206,16 +209,16 @@ mod classinator_tests {
             )]),
         );
 
-        let cls_map =
-            CLASSINATOR
-                .get("central")
-                .and_then(|classinator_data| match &*classinator_data {
-                    Classinator::Central(ref central_map) => central_map.get("_").and_then(|map| {
-                        map.get("myTestClassinatorClass")
-                            .and_then(|cls_map| Some(cls_map.to_owned()))
-                    }),
-                    _ => None,
-                });
+        let cls_map = session
+            .classinator
+            .get("central")
+            .and_then(|classinator_data| match &*classinator_data {
+                Classinator::Central(ref central_map) => central_map.get("_").and_then(|map| {
+                    map.get("myTestClassinatorClass")
+                        .and_then(|cls_map| Some(cls_map.to_owned()))
+                }),
+                _ => None,
+            });
 
         assert!(cls_map.is_some());
         assert_eq!(
@@ -230,14 +233,18 @@ mod classinator_tests {
 
     #[test]
     fn layout_map_should_exists_in_ast() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         crealion.apply_tracking_map_to_classinator(
             "classinatorLayoutContextName".to_string(),
             None,
@@ -255,20 +262,20 @@ mod classinator_tests {
             )]),
         );
 
-        let cls_map =
-            CLASSINATOR
-                .get("layouts")
-                .and_then(|classinator_data| match &*classinator_data {
-                    Classinator::Layouts(ref layouts_map) => layouts_map
-                        .get("classinatorLayoutContextName")
-                        .and_then(|context_map| {
-                            context_map.get("_").and_then(|map| {
-                                map.get("myTestClassinatorClass")
-                                    .and_then(|cls_map| Some(cls_map.to_owned()))
-                            })
-                        }),
-                    _ => None,
-                });
+        let cls_map = session
+            .classinator
+            .get("layouts")
+            .and_then(|classinator_data| match &*classinator_data {
+                Classinator::Layouts(ref layouts_map) => layouts_map
+                    .get("classinatorLayoutContextName")
+                    .and_then(|context_map| {
+                        context_map.get("_").and_then(|map| {
+                            map.get("myTestClassinatorClass")
+                                .and_then(|cls_map| Some(cls_map.to_owned()))
+                        })
+                    }),
+                _ => None,
+            });
 
         assert!(cls_map.is_some());
         assert_eq!(
@@ -283,14 +290,18 @@ mod classinator_tests {
 
     #[test]
     fn module_map_should_exists_in_ast() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         crealion.apply_tracking_map_to_classinator(
             "classinatorModuleContextName".to_string(),
             None,
@@ -308,24 +319,24 @@ mod classinator_tests {
             )]),
         );
 
-        let cls_map =
-            CLASSINATOR
-                .get("modules")
-                .and_then(|classinator_data| match &*classinator_data {
-                    Classinator::Modules(ref modules_map) => {
-                        modules_map.get("_").and_then(|no_parent_map| {
-                            no_parent_map.get("classinatorModuleContextName").and_then(
-                                |context_map| {
-                                    context_map.get("_").and_then(|map| {
-                                        map.get("myTestClassinatorClass")
-                                            .and_then(|cls_map| Some(cls_map.to_owned()))
-                                    })
-                                },
-                            )
-                        })
-                    }
-                    _ => None,
-                });
+        let cls_map = session
+            .classinator
+            .get("modules")
+            .and_then(|classinator_data| match &*classinator_data {
+                Classinator::Modules(ref modules_map) => {
+                    modules_map.get("_").and_then(|no_parent_map| {
+                        no_parent_map
+                            .get("classinatorModuleContextName")
+                            .and_then(|context_map| {
+                                context_map.get("_").and_then(|map| {
+                                    map.get("myTestClassinatorClass")
+                                        .and_then(|cls_map| Some(cls_map.to_owned()))
+                                })
+                            })
+                    })
+                }
+                _ => None,
+            });
 
         assert!(cls_map.is_some());
         assert_eq!(
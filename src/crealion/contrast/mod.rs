@@ -0,0 +1,358 @@
+use chrono::Local;
+use dashmap::DashMap;
+
+use crate::{
+    configatron::{get_contrast_checks, ContrastCheck},
+    events::GaladrielAlerts,
+    types::Stylitron,
+};
+
+use super::Crealion;
+
+/// Schemas in which the contrast of the configured variable pairs is checked.
+const THEMES_SCHEMA: &[&str] = &["light", "dark"];
+
+impl Crealion {
+    /// Checks the contrast ratio of the configured foreground/background variable pairs
+    /// in every theme schema of a context, raising a warning for each pair below its minimum.
+    ///
+    /// A pair is only checked in a context that declares at least one of its variables in the
+    /// checked theme schema, while the other one may come from any inherited context.
+    ///
+    /// # Arguments
+    /// - `context_name` - The name of the context whose themes are checked.
+    /// - `inherited_contexts` - The contexts used to resolve the variables, closest first.
+    pub fn check_themes_contrast(&self, context_name: &str, inherited_contexts: &Vec<String>) {
+        let contrast_checks = get_contrast_checks();
+
+        if contrast_checks.is_empty() {
+            return;
+        }
+
+        tracing::info!(
+            "Checking the contrast of {} variable pairs in the themes of context: '{}'.",
+            contrast_checks.len(),
+            context_name
+        );
+
+        let transformed_context_name = self.transform_context_name(context_name);
+
+        for check in &contrast_checks {
+            for schema in THEMES_SCHEMA {
+                if let Some(ratio) = Self::find_failing_contrast(
+                    &self.session.stylitron,
+                    check,
+                    schema,
+                    context_name,
+                    inherited_contexts,
+                ) {
+                    let notification = GaladrielAlerts::create_warning(
+                        Local::now(),
+                        &format!(
+                            "The `{}` and `{}` variables of the `{}` theme in the `{}` context have a contrast ratio of {:.2}:1, below the minimum of {}:1. Please review these colors to keep them accessible.",
+                            check.foreground, check.background, schema, transformed_context_name, ratio, check.min_ratio
+                        ),
+                    );
+
+                    if let Err(err) = self.sender.send(notification) {
+                        tracing::error!("Failed to send warning notification: {:?}", err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Computes the contrast ratio of a variable pair in a theme schema of a context.
+    ///
+    /// # Arguments
+    /// - `stylitron` - The abstract syntax tree holding the themes and variables.
+    /// - `check` - The variable pair and its minimum contrast ratio.
+    /// - `schema` - The theme schema being checked (`light` or `dark`).
+    /// - `context_name` - The name of the context whose theme is checked.
+    /// - `inherited_contexts` - The contexts used to resolve the variables, closest first.
+    ///
+    /// # Returns
+    /// - `Some(f64)` with the contrast ratio when the pair is below its minimum, or `None` when the
+    ///   pair passes, does not belong to the context theme, or its colors cannot be parsed.
+    fn find_failing_contrast(
+        stylitron: &DashMap<String, Stylitron>,
+        check: &ContrastCheck,
+        schema: &str,
+        context_name: &str,
+        inherited_contexts: &Vec<String>,
+    ) -> Option<f64> {
+        // Pairs entirely inherited from another context are checked by that context.
+        let declares_pair = [&check.foreground, &check.background]
+            .iter()
+            .any(|identifier| {
+                Self::get_theme_value(stylitron, context_name, schema, identifier).is_some()
+            });
+
+        if !declares_pair {
+            return None;
+        }
+
+        let foreground =
+            Self::resolve_contrast_color(stylitron, &check.foreground, schema, inherited_contexts)?;
+        let background =
+            Self::resolve_contrast_color(stylitron, &check.background, schema, inherited_contexts)?;
+        let ratio = contrast_ratio(foreground, background);
+
+        tracing::debug!(
+            "Contrast ratio of '{}' and '{}' in the '{}' theme of context '{}': {:.2}.",
+            check.foreground,
+            check.background,
+            schema,
+            context_name,
+            ratio
+        );
+
+        (ratio < check.min_ratio).then_some(ratio)
+    }
+
+    /// Resolves the color of a variable in a theme schema, falling back to the plain variables.
+    ///
+    /// # Arguments
+    /// - `stylitron` - The abstract syntax tree holding the themes and variables.
+    /// - `identifier` - The relative name of the variable.
+    /// - `schema` - The theme schema being checked (`light` or `dark`).
+    /// - `inherited_contexts` - The contexts used to resolve the variable, closest first.
+    ///
+    /// # Returns
+    /// - `Option<(u8, u8, u8)>` with the RGB channels of the color, if it is found and parsed.
+    fn resolve_contrast_color(
+        stylitron: &DashMap<String, Stylitron>,
+        identifier: &str,
+        schema: &str,
+        inherited_contexts: &Vec<String>,
+    ) -> Option<(u8, u8, u8)> {
+        let value = inherited_contexts.iter().find_map(|context_name| {
+            Self::get_theme_value(stylitron, context_name, schema, identifier)
+                .or_else(|| Self::get_variable_value(stylitron, context_name, identifier))
+        })?;
+
+        let color = parse_color(&value);
+
+        if color.is_none() {
+            tracing::debug!(
+                "Variable '{}' holds '{}', which is not a color that can be checked.",
+                identifier,
+                value
+            );
+        }
+
+        color
+    }
+
+    /// Retrieves the value of a variable declared in a theme schema of a context.
+    fn get_theme_value(
+        stylitron: &DashMap<String, Stylitron>,
+        context_name: &str,
+        schema: &str,
+        identifier: &str,
+    ) -> Option<String> {
+        let stylitron_data = stylitron.get("themes")?;
+
+        match &*stylitron_data {
+            Stylitron::Themes(ref themes_definitions) => themes_definitions
+                .get(context_name)
+                .and_then(|context_themes| context_themes.get(schema))
+                .and_then(|schema_variables| schema_variables.get(identifier))
+                .and_then(|variable_entry| variable_entry.get(1).cloned()),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the value of a plain variable declared in a context.
+    fn get_variable_value(
+        stylitron: &DashMap<String, Stylitron>,
+        context_name: &str,
+        identifier: &str,
+    ) -> Option<String> {
+        let stylitron_data = stylitron.get("variables")?;
+
+        match &*stylitron_data {
+            Stylitron::Variables(ref variables_definitions) => variables_definitions
+                .get(context_name)
+                .and_then(|context_variables| context_variables.get(identifier))
+                .and_then(|variable_entry| variable_entry.get(1).cloned()),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a hexadecimal (`#RGB`, `#RGBA`, `#RRGGBB`, `#RRGGBBAA`) or `rgb()`/`rgba()` color.
+/// The alpha channel is ignored.
+///
+/// # Arguments
+/// - `value` - The color value.
+///
+/// # Returns
+/// - `Option<(u8, u8, u8)>` with the RGB channels, or `None` if the value is not a supported color.
+fn parse_color(value: &str) -> Option<(u8, u8, u8)> {
+    let value = value.trim().to_lowercase();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let hex = match hex.len() {
+            3 | 4 => hex.chars().take(3).flat_map(|c| [c, c]).collect::<String>(),
+            6 | 8 => hex.get(..6)?.to_string(),
+            _ => return None,
+        };
+
+        let channel = |idx: usize| u8::from_str_radix(hex.get(idx..idx + 2)?, 16).ok();
+
+        return Some((channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    let arguments = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+
+    let channels = arguments
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|channel| !channel.is_empty())
+        .take(3)
+        .map(|channel| match channel.strip_suffix('%') {
+            Some(percentage) => percentage
+                .parse::<f64>()
+                .ok()
+                .map(|p| (p.clamp(0.0, 100.0) * 2.55).round() as u8),
+            None => channel
+                .parse::<f64>()
+                .ok()
+                .map(|c| c.clamp(0.0, 255.0).round() as u8),
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    match channels.as_slice() {
+        [red, green, blue] => Some((*red, *green, *blue)),
+        _ => None,
+    }
+}
+
+/// Computes the WCAG relative luminance of a color.
+fn relative_luminance((red, green, blue): (u8, u8, u8)) -> f64 {
+    let linearize = |channel: u8| {
+        let channel = channel as f64 / 255.0;
+
+        if channel <= 0.03928 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linearize(red) + 0.7152 * linearize(green) + 0.0722 * linearize(blue)
+}
+
+/// Computes the WCAG contrast ratio between two colors, ranging from `1.0` to `21.0`.
+fn contrast_ratio(first_color: (u8, u8, u8), second_color: (u8, u8, u8)) -> f64 {
+    let first_luminance = relative_luminance(first_color);
+    let second_luminance = relative_luminance(second_color);
+    let (lighter, darker) = if first_luminance > second_luminance {
+        (first_luminance, second_luminance)
+    } else {
+        (second_luminance, first_luminance)
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+#[cfg(test)]
+mod contrast_tests {
+    use indexmap::IndexMap;
+
+    use crate::{
+        asts::STYLITRON,
+        configatron::ContrastCheck,
+        crealion::{
+            contrast::{contrast_ratio, parse_color},
+            Crealion,
+        },
+        types::Stylitron,
+    };
+
+    #[test]
+    fn colors_are_parsed() {
+        assert_eq!(parse_color("#FFF"), Some((255, 255, 255)));
+        assert_eq!(parse_color("#00000080"), Some((0, 0, 0)));
+        assert_eq!(parse_color("rgb(255, 0, 128)"), Some((255, 0, 128)));
+        assert_eq!(parse_color("rgba(0 128 255 / 50%)"), Some((0, 128, 255)));
+        assert_eq!(parse_color("var(--someColor)"), None);
+        assert_eq!(parse_color("#GGGGGG"), None);
+    }
+
+    #[test]
+    fn contrast_ratio_matches_wcag() {
+        let ratio = contrast_ratio((0, 0, 0), (255, 255, 255));
+
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert!((contrast_ratio((119, 119, 119), (255, 255, 255)) - 4.48).abs() < 0.01);
+    }
+
+    #[test]
+    fn failing_pair_is_reported_only_in_its_theme() {
+        let context_name = "contrastCheckContextName".to_string();
+
+        if let Some(mut stylitron_data) = STYLITRON.get_mut("themes") {
+            if let Stylitron::Themes(ref mut themes_definitions) = *stylitron_data {
+                themes_definitions.insert(
+                    context_name.to_owned(),
+                    IndexMap::from([
+                        (
+                            "light".to_string(),
+                            IndexMap::from([
+                                (
+                                    "textColor".to_string(),
+                                    vec!["--a".to_string(), "#777777".to_string()],
+                                ),
+                                (
+                                    "surfaceColor".to_string(),
+                                    vec!["--b".to_string(), "#FFFFFF".to_string()],
+                                ),
+                            ]),
+                        ),
+                        (
+                            "dark".to_string(),
+                            IndexMap::from([
+                                (
+                                    "textColor".to_string(),
+                                    vec!["--a".to_string(), "#FFFFFF".to_string()],
+                                ),
+                                (
+                                    "surfaceColor".to_string(),
+                                    vec!["--b".to_string(), "#000000".to_string()],
+                                ),
+                            ]),
+                        ),
+                    ]),
+                );
+            }
+        }
+
+        let check = ContrastCheck {
+            foreground: "textColor".to_string(),
+            background: "surfaceColor".to_string(),
+            min_ratio: 4.5,
+        };
+        let inherited_contexts = vec![context_name.to_owned()];
+
+        assert!(Crealion::find_failing_contrast(
+            &STYLITRON,
+            &check,
+            "light",
+            &context_name,
+            &inherited_contexts
+        )
+        .is_some());
+        assert!(Crealion::find_failing_contrast(
+            &STYLITRON,
+            &check,
+            "dark",
+            &context_name,
+            &inherited_contexts
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use lazy_static::lazy_static;
+
+use crate::configatron::get_feature_flags;
+
+lazy_static! {
+    /// Stores the feature flags enabled for the build.
+    /// `None` means the filtering is disabled and every context and class is generated.
+    static ref ENABLED_FEATURES: Mutex<Option<Vec<String>>> = Mutex::new(None);
+}
+
+/// Enables the filtering of the flagged contexts and classes, keeping only the ones whose
+/// feature flags are all among the given ones.
+///
+/// # Arguments
+/// - `features`: The feature flags enabled for the build.
+pub fn enable_feature_filtering(features: Vec<String>) {
+    if let Ok(mut enabled_features) = ENABLED_FEATURES.lock() {
+        *enabled_features = Some(features);
+    }
+}
+
+/// Checks whether a class is excluded from the output by the feature flags configured for
+/// its context or for the class itself.
+///
+/// # Arguments
+/// - `context_name`: The name of the context the class belongs to.
+/// - `class_name`: The name of the class, or `None` to check the context as a whole.
+///
+/// # Returns
+/// - `bool`: `true` if a required feature flag is not enabled, `false` otherwise.
+pub fn is_flagged_out(context_name: &str, class_name: Option<&str>) -> bool {
+    let enabled_features = match ENABLED_FEATURES.lock() {
+        Ok(enabled_features) => match enabled_features.as_ref() {
+            Some(enabled_features) => enabled_features.to_owned(),
+            None => return false,
+        },
+        Err(_) => return false,
+    };
+
+    has_disabled_flag(
+        &get_feature_flags(),
+        &enabled_features,
+        context_name,
+        class_name,
+    )
+}
+
+/// Checks whether any feature flag required by a context or by one of its classes is missing
+/// from the enabled feature flags.
+///
+/// # Arguments
+/// - `feature_flags`: The feature flags keyed by `contextName` or `contextName::className`.
+/// - `enabled_features`: The feature flags enabled for the build.
+/// - `context_name`: The name of the context.
+/// - `class_name`: The name of the class, or `None` to check the context as a whole.
+fn has_disabled_flag(
+    feature_flags: &BTreeMap<String, Vec<String>>,
+    enabled_features: &[String],
+    context_name: &str,
+    class_name: Option<&str>,
+) -> bool {
+    let flag_key = match class_name {
+        Some(class_name) => format!("{}::{}", context_name, class_name),
+        None => context_name.to_string(),
+    };
+
+    feature_flags.get(&flag_key).is_some_and(|required_flags| {
+        required_flags
+            .iter()
+            .any(|flag| !enabled_features.contains(flag))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::has_disabled_flag;
+
+    #[test]
+    fn flagged_styles_require_every_flag() {
+        let feature_flags = BTreeMap::from([
+            (
+                "checkoutLayout".to_string(),
+                vec!["newCheckout".to_string(), "payments".to_string()],
+            ),
+            ("card::promoTitle".to_string(), vec!["promo".to_string()]),
+        ]);
+        let enabled_features = vec!["newCheckout".to_string(), "promo".to_string()];
+
+        assert!(has_disabled_flag(
+            &feature_flags,
+            &enabled_features,
+            "checkoutLayout",
+            None
+        ));
+        assert!(!has_disabled_flag(
+            &feature_flags,
+            &enabled_features,
+            "card",
+            Some("promoTitle")
+        ));
+        assert!(has_disabled_flag(
+            &feature_flags,
+            &[],
+            "card",
+            Some("promoTitle")
+        ));
+        assert!(!has_disabled_flag(&feature_flags, &[], "card", None));
+        assert!(!has_disabled_flag(
+            &feature_flags,
+            &[],
+            "card",
+            Some("title")
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+use dashmap::DashMap;
+
+use crate::{
+    configatron::get_frozen_central_names,
+    error::{ErrorAction, ErrorKind, GaladrielError},
+    types::Stylitron,
+    GaladrielResult,
+};
+
+use super::{Crealion, CENTRAL_CONTEXT_NAME};
+
+impl Crealion {
+    /// Validates that a layout or module context does not redefine a frozen central definition.
+    ///
+    /// A central variable, alias, or animation is frozen when its name is listed in the `frozenCentralNames`
+    /// configuration, or when the configuration holds a `*` entry. Redefining it in another context would
+    /// silently shadow the central value, so an error is raised instead.
+    ///
+    /// # Arguments
+    /// * `context_name` - The name of the layout or module context being processed.
+    /// * `definitions` - The kind of each group of definitions (e.g. `variable`) and the names it defines.
+    ///
+    /// # Returns
+    /// * `Ok(())` if no frozen central name is redefined.
+    /// * An error of type `GaladrielError` naming the first redefined frozen definition.
+    pub fn validates_frozen_central_names(
+        &self,
+        context_name: &str,
+        definitions: &[(&str, Vec<String>)],
+    ) -> GaladrielResult<()> {
+        let frozen_names = get_frozen_central_names();
+
+        if frozen_names.is_empty() {
+            return Ok(());
+        }
+
+        match Self::find_frozen_definition(&self.session.stylitron, &frozen_names, definitions) {
+            Some((kind, name)) => {
+                tracing::error!(
+                    "The frozen central {} '{}' is redefined in context '{}' at '{}'.",
+                    kind,
+                    name,
+                    context_name,
+                    self.path
+                );
+
+                Err(GaladrielError::raise_general_other_error(
+                    ErrorKind::FrozenCentralNameRedefinition,
+                    &format!(
+                        "The `{}` {} cannot be redefined in the `{}` context because it is frozen in the central context. Please rename it or remove it from the `frozenCentralNames` configuration.",
+                        name, kind, context_name
+                    ),
+                    ErrorAction::Notify,
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Finds the first definition whose name is frozen and also defined by the central context.
+    ///
+    /// # Arguments
+    /// * `stylitron` - The abstract syntax tree holding the central definitions.
+    /// * `frozen_names` - The frozen names, where `*` freezes every central name.
+    /// * `definitions` - The kind of each group of definitions and the names it defines.
+    ///
+    /// # Returns
+    /// * `Option<(String, String)>` with the kind and name of the redefined frozen definition, if any.
+    fn find_frozen_definition(
+        stylitron: &DashMap<String, Stylitron>,
+        frozen_names: &[String],
+        definitions: &[(&str, Vec<String>)],
+    ) -> Option<(String, String)> {
+        let freezes_all = frozen_names.iter().any(|name| name == "*");
+
+        definitions.iter().find_map(|(kind, names)| {
+            let central_names = Self::get_central_names(stylitron, kind);
+
+            names
+                .iter()
+                .find(|name| {
+                    (freezes_all || frozen_names.contains(name)) && central_names.contains(name)
+                })
+                .map(|name| (kind.to_string(), name.to_owned()))
+        })
+    }
+
+    /// Retrieves the names of the central definitions of the given kind from the `STYLITRON` AST.
+    ///
+    /// # Arguments
+    /// * `stylitron` - The abstract syntax tree holding the central definitions.
+    /// * `kind` - The kind of the definitions: `variable`, `alias`, or `animation`.
+    fn get_central_names(stylitron: &DashMap<String, Stylitron>, kind: &str) -> Vec<String> {
+        let stylitron_key = match kind {
+            "variable" => "variables",
+            "alias" => "aliases",
+            "animation" => "animations",
+            _ => return vec![],
+        };
+
+        let stylitron_data = match stylitron.get(stylitron_key) {
+            Some(stylitron_data) => stylitron_data,
+            None => return vec![],
+        };
+
+        match &*stylitron_data {
+            Stylitron::Variables(ref definitions) => definitions
+                .get(CENTRAL_CONTEXT_NAME)
+                .map(|names| names.keys().cloned().collect())
+                .unwrap_or_default(),
+            Stylitron::Aliases(ref definitions) => definitions
+                .get(CENTRAL_CONTEXT_NAME)
+                .map(|names| names.keys().cloned().collect())
+                .unwrap_or_default(),
+            Stylitron::Animation(ref definitions) => definitions
+                .get(CENTRAL_CONTEXT_NAME)
+                .map(|names| names.keys().cloned().collect())
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod frozen_tests {
+    use indexmap::IndexMap;
+
+    use crate::{
+        asts::STYLITRON,
+        crealion::{Crealion, CENTRAL_CONTEXT_NAME},
+        types::Stylitron,
+    };
+
+    fn define_central_variable(name: &str) {
+        if let Some(mut stylitron_data) = STYLITRON.get_mut("variables") {
+            if let Stylitron::Variables(ref mut variables_definitions) = *stylitron_data {
+                variables_definitions
+                    .entry(CENTRAL_CONTEXT_NAME.to_string())
+                    .or_insert_with(IndexMap::new)
+                    .insert(
+                        name.to_string(),
+                        vec![format!("--{}", name), "#FFF".to_string()],
+                    );
+            }
+        }
+    }
+
+    #[test]
+    fn frozen_central_variable_is_found() {
+        define_central_variable("frozenTestPrimaryColor");
+
+        let definitions = vec![(
+            "variable",
+            vec![
+                "frozenTestLocalColor".to_string(),
+                "frozenTestPrimaryColor".to_string(),
+            ],
+        )];
+
+        assert_eq!(
+            Crealion::find_frozen_definition(
+                &STYLITRON,
+                &["frozenTestPrimaryColor".to_string()],
+                &definitions
+            ),
+            Some(("variable".to_string(), "frozenTestPrimaryColor".to_string()))
+        );
+        assert!(Crealion::find_frozen_definition(
+            &STYLITRON,
+            &["frozenTestOtherColor".to_string()],
+            &definitions
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn wildcard_only_freezes_central_names() {
+        define_central_variable("frozenTestWildcardColor");
+
+        let frozen_names = vec!["*".to_string()];
+
+        assert!(Crealion::find_frozen_definition(
+            &STYLITRON,
+            &frozen_names,
+            &[("variable", vec!["frozenTestWildcardColor".to_string()])]
+        )
+        .is_some());
+        assert!(Crealion::find_frozen_definition(
+            &STYLITRON,
+            &frozen_names,
+            &[
+                ("variable", vec!["frozenTestUndefinedColor".to_string()]),
+                ("alias", vec!["frozenTestWildcardColor".to_string()]),
+            ]
+        )
+        .is_none());
+    }
+}
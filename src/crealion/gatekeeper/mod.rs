@@ -1,5 +1,3 @@
-use crate::{asts::GATEKEEPER, gatekeeper::remove_path_from_gatekeeper};
-
 use super::Crealion;
 
 impl Crealion {
@@ -28,7 +26,9 @@ impl Crealion {
         );
 
         // Remove the module path from the gatekeeper registry before registering it for the layout.
-        remove_path_from_gatekeeper(&module_path);
+        self.session.gatekeeper.iter_mut().for_each(|mut entry| {
+            entry.value_mut().retain(|path| path != &module_path);
+        });
 
         tracing::debug!(
             "Inserting module path '{}' into the GATEKEEPER registry for layout '{}'.",
@@ -38,7 +38,8 @@ impl Crealion {
 
         // Insert the module path into the GATEKEEPER registry for the given layout name.
         // If the layout name doesn't exist, it creates a new entry with a Vec::new.
-        GATEKEEPER
+        self.session
+            .gatekeeper
             .entry(layout_name)
             .or_insert_with(Vec::new)
             .push(module_path);
@@ -60,7 +61,8 @@ impl Crealion {
         tracing::debug!("Retrieving module paths for layout '{}'.", layout_name);
 
         // Retrieve the entry for the layout name from the GATEKEEPER registry and clone the vector.
-        GATEKEEPER
+        self.session
+            .gatekeeper
             .get(layout_name)
             .map(|entry| entry.value().to_vec())
     }
@@ -71,24 +73,29 @@ mod tests {
     use nenyr::types::{ast::NenyrAst, central::CentralContext};
     use tokio::sync::broadcast;
 
-    use crate::{asts::GATEKEEPER, crealion::Crealion};
+    use crate::{asts::CompilationSession, crealion::Crealion};
 
     #[test]
     fn registering_context_with_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         crealion.register_module_layout_relationship(
             "myRegisteringLayoutName".to_string(),
             "path/to/module_03.nyr".to_string(),
         );
 
-        let ctx = GATEKEEPER
+        let ctx = session
+            .gatekeeper
             .get("myRegisteringLayoutName")
             .map(|entry| entry.value().to_owned());
 
@@ -98,20 +105,25 @@ mod tests {
 
     #[test]
     fn retrieving_context_with_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         crealion.register_module_layout_relationship(
             "myRetrievingLayoutName".to_string(),
             "path/to/module_04.nyr".to_string(),
         );
 
-        let ctx = GATEKEEPER
+        let ctx = session
+            .gatekeeper
             .get("myRetrievingLayoutName")
             .map(|entry| entry.value().to_owned());
 
@@ -3,7 +3,6 @@ use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Stylitron,
@@ -21,13 +20,14 @@ impl Crealion {
     /// - A `JoinHandle` representing the spawned task. The task updates the STYLITRON AST in a separate thread.
     pub fn apply_imports_to_stylitron(&self, imports_data: IndexMap<String, ()>) -> JoinHandle<()> {
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
 
         // Spawn a blocking task to apply the imports to the STYLITRON AST.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting the process to apply imports to the STYLITRON AST.");
 
             // Attempt to access the `imports` section of the STYLITRON AST.
-            let mut stylitron_data = match STYLITRON.get_mut("imports") {
+            let mut stylitron_data = match stylitron.get_mut("imports") {
                 Some(data) => {
                     tracing::debug!(
                         "Successfully accessed the `imports` section in STYLITRON AST."
@@ -85,7 +85,9 @@ mod tests {
     use nenyr::types::{ast::NenyrAst, central::CentralContext};
     use tokio::sync::broadcast;
 
-    use crate::{asts::STYLITRON, crealion::Crealion, events::GaladrielAlerts, types::Stylitron};
+    use crate::{
+        asts::CompilationSession, crealion::Crealion, events::GaladrielAlerts, types::Stylitron,
+    };
 
     fn mock_imports() -> IndexMap<String, ()> {
         IndexMap::from([
@@ -98,22 +100,28 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_imports_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion.apply_imports_to_stylitron(mock_imports()).await;
 
-        let result = STYLITRON
-            .get("imports")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Imports(imports_definitions) => Some(imports_definitions.to_owned()),
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("imports")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Imports(imports_definitions) => Some(imports_definitions.to_owned()),
+                    _ => None,
+                });
 
         assert!(result.is_some());
 
@@ -124,29 +132,35 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_imports_to_existing_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Pre-populate the STYLITRON AST with existing data.
         let initial_data = IndexMap::from([("animeName".to_string(), ())]);
 
-        STYLITRON.insert("imports".to_string(), Stylitron::Imports(initial_data));
+        session
+            .stylitron
+            .insert("imports".to_string(), Stylitron::Imports(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion.apply_imports_to_stylitron(mock_imports()).await;
 
-        let result = STYLITRON
-            .get("imports")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Imports(imports_definitions) => Some(imports_definitions.clone()),
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("imports")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Imports(imports_definitions) => Some(imports_definitions.clone()),
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let imports = result.unwrap();
@@ -157,28 +171,34 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_imports_to_new_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert("imports".to_string(), Stylitron::Imports(initial_data));
+        session
+            .stylitron
+            .insert("imports".to_string(), Stylitron::Imports(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion.apply_imports_to_stylitron(mock_imports()).await;
 
-        let result = STYLITRON
-            .get("imports")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Imports(imports_definitions) => Some(imports_definitions.clone()),
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("imports")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Imports(imports_definitions) => Some(imports_definitions.clone()),
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let imports = result.unwrap();
@@ -189,27 +209,31 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_imports_with_empty_imports_data() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let empty_imports: IndexMap<String, ()> = IndexMap::new();
         let _ = crealion
             .apply_imports_to_stylitron(empty_imports.clone())
             .await;
 
-        let result = STYLITRON
-            .get("imports")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Imports(imports_definitions) => Some(imports_definitions.clone()),
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("imports")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Imports(imports_definitions) => Some(imports_definitions.clone()),
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let imports = result.unwrap();
@@ -220,19 +244,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_imports_no_imports_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("imports");
+        session.stylitron.remove("imports");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion.apply_imports_to_stylitron(mock_imports()).await;
 
         // Verify that an error notification was sent.
@@ -0,0 +1,182 @@
+use crate::{
+    configatron::get_max_extension_depth,
+    error::{ErrorAction, ErrorKind, GaladrielError},
+    GaladrielResult,
+};
+
+use super::Crealion;
+
+impl Crealion {
+    /// Builds the list of inherited contexts for the current context from its extension chain.
+    ///
+    /// The chain is expected to start with the current context, followed by the contexts it
+    /// extends from, ending with the central context. Placeholder entries (`"_"`) are dropped,
+    /// and the remaining chain is validated against cyclic extensions and the configured
+    /// `maxExtensionDepth` before being returned.
+    ///
+    /// # Arguments
+    /// * `extension_chain` - The ordered list of context names composing the extension chain.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` containing the validated inherited contexts.
+    /// * An error of type `GaladrielError` if the chain is cyclic or deeper than allowed.
+    pub fn build_inherited_contexts(
+        &self,
+        extension_chain: Vec<String>,
+    ) -> GaladrielResult<Vec<String>> {
+        // Remove empty context names from the list to ensure only valid entries remain.
+        let inherited_contexts: Vec<String> = extension_chain
+            .into_iter()
+            .filter(|context_name| context_name != "_")
+            .collect();
+
+        tracing::debug!(
+            "Validating extension chain for inherited contexts: {:?}",
+            inherited_contexts
+        );
+
+        // A context appearing twice in the chain means it ends up extending itself.
+        if let Some((idx, context_name)) = inherited_contexts
+            .iter()
+            .enumerate()
+            .find(|(idx, context_name)| inherited_contexts[..*idx].contains(*context_name))
+        {
+            tracing::error!(
+                "Cyclic extension detected for context '{}' at position {} of the chain {:?}",
+                context_name,
+                idx,
+                inherited_contexts
+            );
+
+            return Err(GaladrielError::raise_general_other_error(
+                ErrorKind::CyclicContextExtension,
+                &format!(
+                    "The context `{}` appears more than once in the extension chain `{}` of `{}`. A context cannot extend from itself, directly or indirectly.",
+                    self.transform_context_name(context_name),
+                    self.format_extension_chain(&inherited_contexts),
+                    self.path
+                ),
+                ErrorAction::Notify,
+            ));
+        }
+
+        let max_depth = get_max_extension_depth();
+
+        // Reject chains deeper than the configured limit before they reach the resolution stage.
+        if inherited_contexts.len() > max_depth {
+            tracing::error!(
+                "Extension chain {:?} exceeds the maximum depth of {}",
+                inherited_contexts,
+                max_depth
+            );
+
+            return Err(GaladrielError::raise_general_other_error(
+                ErrorKind::ContextExtensionDepthExceeded,
+                &format!(
+                    "The extension chain `{}` of `{}` has a depth of {}, which exceeds the maximum allowed depth of {}. Review the context extensions or raise `maxExtensionDepth` in the `galadriel.config.json` file.",
+                    self.format_extension_chain(&inherited_contexts),
+                    self.path,
+                    inherited_contexts.len(),
+                    max_depth
+                ),
+                ErrorAction::Notify,
+            ));
+        }
+
+        Ok(inherited_contexts)
+    }
+
+    /// Formats an extension chain into a readable `a -> b -> central` representation.
+    fn format_extension_chain(&self, inherited_contexts: &[String]) -> String {
+        inherited_contexts
+            .iter()
+            .map(|context_name| self.transform_context_name(context_name))
+            .collect::<Vec<String>>()
+            .join(" -> ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nenyr::types::{ast::NenyrAst, central::CentralContext};
+    use tokio::sync::broadcast;
+
+    use crate::{
+        crealion::{Crealion, CENTRAL_CONTEXT_NAME},
+        error::ErrorKind,
+    };
+
+    fn mock_crealion() -> Crealion {
+        let (sender, _) = broadcast::channel(10);
+
+        Crealion::new(
+            sender,
+            NenyrAst::CentralContext(CentralContext::new()),
+            "path/to/context.nyr".to_string(),
+        )
+    }
+
+    #[test]
+    fn regular_extension_chain_is_valid() {
+        let result = mock_crealion().build_inherited_contexts(vec![
+            "myModule".to_string(),
+            "myLayout".to_string(),
+            CENTRAL_CONTEXT_NAME.to_string(),
+        ]);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                "myModule".to_string(),
+                "myLayout".to_string(),
+                CENTRAL_CONTEXT_NAME.to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholder_contexts_are_removed() {
+        let result = mock_crealion().build_inherited_contexts(vec![
+            "myModule".to_string(),
+            "_".to_string(),
+            CENTRAL_CONTEXT_NAME.to_string(),
+        ]);
+
+        assert_eq!(
+            result.unwrap(),
+            vec!["myModule".to_string(), CENTRAL_CONTEXT_NAME.to_string()]
+        );
+    }
+
+    #[test]
+    fn cyclic_extension_chain_is_rejected() {
+        let result = mock_crealion().build_inherited_contexts(vec![
+            "myModule".to_string(),
+            "myModule".to_string(),
+            CENTRAL_CONTEXT_NAME.to_string(),
+        ]);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().get_kind(),
+            ErrorKind::CyclicContextExtension
+        );
+    }
+
+    #[test]
+    fn deep_extension_chain_is_rejected() {
+        let mut extension_chain: Vec<String> =
+            (0..16).map(|idx| format!("myContext{}", idx)).collect();
+
+        extension_chain.push(CENTRAL_CONTEXT_NAME.to_string());
+
+        let result = mock_crealion().build_inherited_contexts(extension_chain);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().get_kind(),
+            ErrorKind::ContextExtensionDepthExceeded
+        );
+    }
+}
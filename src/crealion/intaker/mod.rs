@@ -1,5 +1,4 @@
 use crate::{
-    asts::INTAKER,
     error::{ErrorAction, ErrorKind, GaladrielError},
     GaladrielResult,
 };
@@ -35,7 +34,9 @@ impl Crealion {
         );
 
         // Attempt to find a conflicting context name already associated with a different file path
-        let conflicting_context_entry = INTAKER
+        let conflicting_context_entry = self
+            .session
+            .intaker
             .iter()
             .find(|entry| entry.key() != &file_path && entry.value() == &context_name);
 
@@ -63,7 +64,9 @@ impl Crealion {
             }
             None => {
                 // If no conflict is found, insert the context name and file path into the registry
-                INTAKER.insert(file_path.to_owned(), context_name.to_owned());
+                self.session
+                    .intaker
+                    .insert(file_path.to_owned(), context_name.to_owned());
 
                 tracing::info!(
                     "Context name '{}' successfully validated and added for '{}'",
@@ -82,7 +85,7 @@ mod tests {
     use nenyr::types::{ast::NenyrAst, central::CentralContext};
     use tokio::sync::broadcast;
 
-    use crate::{asts::INTAKER, crealion::Crealion};
+    use crate::{asts::CompilationSession, crealion::Crealion};
 
     #[test]
     fn context_name_is_valid() {
@@ -104,19 +107,23 @@ mod tests {
 
     #[test]
     fn context_name_is_not_valid() {
-        INTAKER.insert(
+        let session = CompilationSession::new();
+
+        session.intaker.insert(
             "path/to/context_1.nyr".to_string(),
             "newContextName".to_string(),
         );
 
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let result = crealion.validates_context_name(
             "newContextName".to_string(),
             "path/to/context_2.nyr".to_string(),
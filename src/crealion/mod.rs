@@ -7,6 +7,8 @@ use nenyr::types::{
 use tokio::{sync::broadcast, task::JoinError};
 
 use crate::{
+    asts::CompilationSession,
+    configatron::get_class_prefix,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     GaladrielResult,
@@ -17,10 +19,15 @@ mod animations;
 mod breakpoints;
 mod classes;
 mod classinator;
+mod contrast;
+pub mod features;
+mod frozen;
 mod gatekeeper;
 mod imports;
+mod inheritance;
 mod intaker;
 mod processors;
+pub mod profiler;
 mod themes;
 mod typefaces;
 mod utils;
@@ -44,6 +51,10 @@ pub struct Crealion {
     central_context_identifier: String,
     parsed_ast: NenyrAst,
     path: String,
+    /// The session the resolved contexts are written into.
+    session: CompilationSession,
+    /// The prefix prepended to the generated utility class names.
+    class_prefix: String,
 }
 
 impl Crealion {
@@ -63,9 +74,29 @@ impl Crealion {
             parsed_ast,
             path,
             sender,
+            session: CompilationSession::global(),
+            class_prefix: get_class_prefix(),
         }
     }
 
+    /// Sets the session the resolved contexts are written into, instead of the global one.
+    ///
+    /// # Parameters
+    /// - `session`: The session whose abstract syntax trees are updated.
+    pub fn set_session(&mut self, session: CompilationSession) -> &mut Self {
+        self.session = session;
+        self
+    }
+
+    /// Sets the prefix prepended to the generated utility class names, instead of the configured one.
+    ///
+    /// # Parameters
+    /// - `class_prefix`: The prefix of the utility class names.
+    pub fn set_class_prefix(&mut self, class_prefix: String) -> &mut Self {
+        self.class_prefix = class_prefix;
+        self
+    }
+
     /// Creates the appropriate collector based on the parsed AST type.
     ///
     /// This method identifies the context type (`CentralContext`, `LayoutContext`, or `ModuleContext`)
@@ -181,13 +212,16 @@ impl Crealion {
         });
 
         // Maintain a list of inherited contexts for animation and class processing.
-        let inherited_contexts = vec![context_name.to_owned()];
+        let inherited_contexts = self.build_inherited_contexts(vec![context_name.to_owned()])?;
 
         tracing::debug!(
             "Inherited contexts prepared for animations and classes: {:?}",
             inherited_contexts
         );
 
+        // Check the contrast of the configured variable pairs now that the themes are in place.
+        self.check_themes_contrast(&context_name, &inherited_contexts);
+
         // Extract animations or use a default empty map.
         let animations_data = self.get_value(
             context.animations.as_ref().map(|v| v.to_owned()),
@@ -290,6 +324,23 @@ impl Crealion {
 
         tracing::debug!("Extracted aliases: {:?}", aliases_data);
 
+        // Ensures that the layout does not shadow any frozen central definition.
+        self.validates_frozen_central_names(
+            &context_name,
+            &[
+                ("variable", variables_data.keys().cloned().collect()),
+                ("alias", aliases_data.keys().cloned().collect()),
+                (
+                    "animation",
+                    context
+                        .animations
+                        .as_ref()
+                        .map(|animations| animations.keys().cloned().collect())
+                        .unwrap_or_default(),
+                ),
+            ],
+        )?;
+
         // Process variables, themes, and aliases concurrently.
         join_all(vec![
             self.process_variables(context_name.to_owned(), variables_data),
@@ -309,16 +360,19 @@ impl Crealion {
         });
 
         // Create a list of inherited contexts for use in subsequent processing, including this context, and the central context.
-        let inherited_contexts = vec![
+        let inherited_contexts = self.build_inherited_contexts(vec![
             context_name.to_owned(),
             self.central_context_identifier.to_owned(),
-        ];
+        ])?;
 
         tracing::debug!(
             "Inherited contexts prepared for animations and classes: {:?}",
             inherited_contexts
         );
 
+        // Check the contrast of the configured variable pairs now that the themes are in place.
+        self.check_themes_contrast(&context_name, &inherited_contexts);
+
         // Retrieve animation definitions from the context, if available.
         let animations_data = self.get_value(
             context.animations.as_ref().map(|v| v.to_owned()),
@@ -416,6 +470,23 @@ impl Crealion {
 
         tracing::debug!("Extracted aliases: {:?}", aliases_data);
 
+        // Ensures that the module does not shadow any frozen central definition.
+        self.validates_frozen_central_names(
+            &context_name,
+            &[
+                ("variable", variables_data.keys().cloned().collect()),
+                ("alias", aliases_data.keys().cloned().collect()),
+                (
+                    "animation",
+                    context
+                        .animations
+                        .as_ref()
+                        .map(|animations| animations.keys().cloned().collect())
+                        .unwrap_or_default(),
+                ),
+            ],
+        )?;
+
         // Process variables and aliases concurrently.
         join_all(vec![
             self.process_variables(context_name.to_owned(), variables_data),
@@ -434,14 +505,12 @@ impl Crealion {
         });
 
         // Build the list of inherited contexts, including this context, its parent, and the central context.
-        let mut inherited_contexts = vec![
+        // Empty context names are dropped, and the chain is checked against cycles and the maximum depth.
+        let inherited_contexts = self.build_inherited_contexts(vec![
             context_name.to_owned(),
             extended_from.to_owned(),
             self.central_context_identifier.to_owned(),
-        ];
-
-        // Remove empty context names from the list to ensure only valid entries remain.
-        inherited_contexts.retain(|v| v != "_");
+        ])?;
 
         tracing::debug!(
             "Inherited contexts prepared for animations and classes: {:?}",
@@ -576,59 +645,22 @@ impl Crealion {
 
 #[cfg(test)]
 mod tests {
-    use indexmap::IndexMap;
     use nenyr::NenyrParser;
     use tokio::sync::broadcast;
 
-    use crate::{
-        asts::STYLITRON, types::Stylitron, utils::generates_node_styles::generates_node_styles,
-    };
+    use crate::asts::CompilationSession;
 
     use super::{Crealion, CrealionContextType};
 
-    fn reset_stylitron() {
-        STYLITRON.clear();
-
-        STYLITRON.insert("imports".to_string(), Stylitron::Imports(IndexMap::new()));
-        STYLITRON.insert("aliases".to_string(), Stylitron::Aliases(IndexMap::new()));
-        STYLITRON.insert(
-            "breakpoints".to_string(),
-            Stylitron::Breakpoints(IndexMap::new()),
-        );
-        STYLITRON.insert(
-            "typefaces".to_string(),
-            Stylitron::Typefaces(IndexMap::new()),
-        );
-        STYLITRON.insert(
-            "variables".to_string(),
-            Stylitron::Variables(IndexMap::new()),
-        );
-        STYLITRON.insert("themes".to_string(), Stylitron::Themes(IndexMap::new()));
-        STYLITRON.insert(
-            "animations".to_string(),
-            Stylitron::Animation(IndexMap::new()),
-        );
-        STYLITRON.insert(
-            "styles".to_string(),
-            Stylitron::Styles(generates_node_styles()),
-        );
-        STYLITRON.insert(
-            "responsive".to_string(),
-            Stylitron::ResponsiveStyles(IndexMap::new()),
-        );
-    }
-
     #[tokio::test]
     async fn central_context_created_with_success() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(25)).await;
-
         match std::fs::read_to_string("src/crealion/mocks/central.nyr") {
             Ok(raw_nenyr) => {
                 let mut parser = NenyrParser::new();
 
                 match parser.parse(raw_nenyr, "src/crealion/mocks/central.nyr".to_string()) {
                     Ok(parsed_ast) => {
-                        reset_stylitron();
+                        let session = CompilationSession::new();
 
                         let (sender, _) = broadcast::channel(10);
 
@@ -638,44 +670,46 @@ mod tests {
                             "src/crealion/mocks/central.nyr".to_string(),
                         );
 
+                        crealion.set_session(session.clone());
+
                         let result = crealion.create().await;
 
                         assert!(result.is_ok());
                         assert_eq!(result.unwrap(), (CrealionContextType::Central, None));
                         assert_eq!(
-                            STYLITRON.get("breakpoints").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("breakpoints").map(|v| format!("{:?}", &*v)),
                             Some("Breakpoints({\"mobile-first\": {\"onMobXs\": \"min-width:360px\"}, \"desktop-first\": {\"onDeskSmall\": \"max-width:1024px\"}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("typefaces").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("typefaces").map(|v| format!("{:?}", &*v)),
                             Some("Typefaces({\"roseMartin\": \"./typefaces/rosemartin.regular.otf\"})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("imports").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("imports").map(|v| format!("{:?}", &*v)),
                             Some("Imports({\"https://fonts.googleapis.com/css2?family=Matemasie&display=swap\": ()})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("themes").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("themes").map(|v| format!("{:?}", &*v)),
                             Some("Themes({\"gCtxCen_8Xq4ZJ\": {\"light\": {\"primaryColor\": [\"--gNKGUE7AAmy\", \"#FFFFFF\"]}, \"dark\": {\"primaryColor\": [\"--gNKGUE7AAmy\", \"#1E1E1E\"]}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("aliases").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("aliases").map(|v| format!("{:?}", &*v)),
                             Some("Aliases({\"gCtxCen_8Xq4ZJ\": {\"bgd\": \"background\", \"dp\": \"display\", \"transf\": \"transform\", \"pdg\": \"padding\", \"wd\": \"width\", \"hgt\": \"height\", \"flexDir\": \"flex-direction\"}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("variables").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("variables").map(|v| format!("{:?}", &*v)),
                             Some("Variables({\"gCtxCen_8Xq4ZJ\": {\"myColor\": [\"--gW1yAqTMgoH\", \"#FF6677\"]}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("animations").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("animations").map(|v| format!("{:?}", &*v)),
                             Some("Animation({\"gCtxCen_8Xq4ZJ\": {\"slideScale\": {\"giq8HPC3JaYa\": {\"20%\": {\"transform\": \"translateX(10%) scale(1.1)\"}, \"40%,60%\": {\"transform\": \"translateX(30%) scale(1.2)\"}, \"80%\": {\"transform\": \"translateX(50%) scale(0.9)\"}, \"100%\": {\"transform\": \"translateX(0) scale(1)\"}}}, \"borderFlash\": {\"gpKLT8POASvU\": {\"10%\": {\"border-color\": \"var(--gW1yAqTMgoH)\", \"border-width\": \"1px\"}, \"30%,50%,70%\": {\"border-color\": \"red\", \"border-width\": \"3px\"}, \"90%\": {\"border-color\": \"green\", \"border-width\": \"2px\"}, \"100%\": {\"border-color\": \"var(--gW1yAqTMgoH)\", \"border-width\": \"1px\"}}}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("styles").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("styles").map(|v| format!("{:?}", &*v)),
                             Some("Styles({\"_\": {\"!important\": {\"background\": {\"\\\\!bgd-kobF\": \"var(--gNKGUE7AAmy)\"}, \"color\": {\"\\\\!clr-UZ6Q\": \"var(--gW1yAqTMgoH)\"}, \"padding\": {\"\\\\!pdg-3KtM\": \"10px\"}, \"display\": {\"\\\\!dpy-5TuI\": \"flex\"}, \"align-items\": {\"\\\\!lgn-tms-sLJ6\": \"center\"}}, \"_\": {}}, \"::after\": {\"!important\": {\"content\": {\"\\\\!ftr\\\\.ctt-WT3W\": \"' '\"}, \"display\": {\"\\\\!ftr\\\\.dpy-S4vd\": \"block\"}, \"width\": {\"\\\\!ftr\\\\.wth-YYq9\": \"100%\"}, \"height\": {\"\\\\!ftr\\\\.hht-9X8O\": \"2px\"}, \"background\": {\"\\\\!ftr\\\\.bgd-kobF\": \"var(--gNKGUE7AAmy)\"}}, \"_\": {}}, \"::before\": {\"!important\": {}, \"_\": {}}, \"::first-line\": {\"!important\": {}, \"_\": {}}, \"::first-letter\": {\"!important\": {}, \"_\": {}}, \":hover\": {\"!important\": {\"color\": {\"\\\\!hvr\\\\.clr-UZ6Q\": \"var(--gW1yAqTMgoH)\"}, \"border\": {\"\\\\!hvr\\\\.bdr-Csem\": \"2px solid var(--gNKGUE7AAmy)\"}, \"animation-name\": {\"\\\\!hvr\\\\.ntn-nm-Y1vH\": \"gpKLT8POASvU\"}}, \"_\": {}}, \":active\": {\"!important\": {}, \"_\": {}}, \":focus\": {\"!important\": {}, \"_\": {}}, \":first-child\": {\"!important\": {}, \"_\": {}}, \":last-child\": {\"!important\": {}, \"_\": {}}, \":first-of-type\": {\"!important\": {}, \"_\": {}}, \":last-of-type\": {\"!important\": {}, \"_\": {}}, \":only-child\": {\"!important\": {}, \"_\": {}}, \":only-of-type\": {\"!important\": {}, \"_\": {}}, \":target\": {\"!important\": {}, \"_\": {}}, \":visited\": {\"!important\": {}, \"_\": {}}, \":checked\": {\"!important\": {}, \"_\": {}}, \":disabled\": {\"!important\": {}, \"_\": {}}, \":enabled\": {\"!important\": {}, \"_\": {}}, \":read-only\": {\"!important\": {}, \"_\": {}}, \":read-write\": {\"!important\": {}, \"_\": {}}, \":placeholder-shown\": {\"!important\": {}, \"_\": {}}, \":valid\": {\"!important\": {}, \"_\": {}}, \":invalid\": {\"!important\": {}, \"_\": {}}, \":required\": {\"!important\": {}, \"_\": {}}, \":optional\": {\"!important\": {}, \"_\": {}}, \":fullscreen\": {\"!important\": {}, \"_\": {}}, \":focus-within\": {\"!important\": {}, \"_\": {}}, \":out-of-range\": {\"!important\": {}, \"_\": {}}, \":root\": {\"!important\": {}, \"_\": {}}, \":empty\": {\"!important\": {}, \"_\": {}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("responsive").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("responsive").map(|v| format!("{:?}", &*v)),
                             Some("ResponsiveStyles({\"min-width:360px\": {\"_\": {\"!important\": {\"display\": {\"nbs\\\\.\\\\!dpy-S4vd\": \"block\"}, \"flex-direction\": {\"nbs\\\\.\\\\!flx-dcn-w5ZN\": \"column\"}, \"padding\": {\"nbs\\\\.\\\\!pdg-3JDd\": \"15px\"}}, \"_\": {}}, \"::after\": {\"!important\": {}, \"_\": {}}, \"::before\": {\"!important\": {}, \"_\": {}}, \"::first-line\": {\"!important\": {}, \"_\": {}}, \"::first-letter\": {\"!important\": {}, \"_\": {}}, \":hover\": {\"!important\": {}, \"_\": {}}, \":active\": {\"!important\": {}, \"_\": {}}, \":focus\": {\"!important\": {}, \"_\": {}}, \":first-child\": {\"!important\": {}, \"_\": {}}, \":last-child\": {\"!important\": {}, \"_\": {}}, \":first-of-type\": {\"!important\": {}, \"_\": {}}, \":last-of-type\": {\"!important\": {}, \"_\": {}}, \":only-child\": {\"!important\": {}, \"_\": {}}, \":only-of-type\": {\"!important\": {}, \"_\": {}}, \":target\": {\"!important\": {}, \"_\": {}}, \":visited\": {\"!important\": {}, \"_\": {}}, \":checked\": {\"!important\": {}, \"_\": {}}, \":disabled\": {\"!important\": {}, \"_\": {}}, \":enabled\": {\"!important\": {}, \"_\": {}}, \":read-only\": {\"!important\": {}, \"_\": {}}, \":read-write\": {\"!important\": {}, \"_\": {}}, \":placeholder-shown\": {\"!important\": {}, \"_\": {}}, \":valid\": {\"!important\": {}, \"_\": {}}, \":invalid\": {\"!important\": {}, \"_\": {}}, \":required\": {\"!important\": {}, \"_\": {}}, \":optional\": {\"!important\": {}, \"_\": {}}, \":fullscreen\": {\"!important\": {}, \"_\": {}}, \":focus-within\": {\"!important\": {}, \"_\": {}}, \":out-of-range\": {\"!important\": {}, \"_\": {}}, \":root\": {\"!important\": {}, \"_\": {}}, \":empty\": {\"!important\": {}, \"_\": {}}}, \"max-width:1024px\": {\"_\": {\"!important\": {}, \"_\": {}}, \"::after\": {\"!important\": {}, \"_\": {}}, \"::before\": {\"!important\": {}, \"_\": {}}, \"::first-line\": {\"!important\": {}, \"_\": {}}, \"::first-letter\": {\"!important\": {}, \"_\": {}}, \":hover\": {\"!important\": {\"background\": {\"nSl\\\\.\\\\!hvr\\\\.bgd-kobF\": \"var(--gNKGUE7AAmy)\"}, \"padding\": {\"nSl\\\\.\\\\!hvr\\\\.pdg-3Kvn\": \"20px\"}}, \"_\": {}}, \":active\": {\"!important\": {}, \"_\": {}}, \":focus\": {\"!important\": {}, \"_\": {}}, \":first-child\": {\"!important\": {}, \"_\": {}}, \":last-child\": {\"!important\": {}, \"_\": {}}, \":first-of-type\": {\"!important\": {}, \"_\": {}}, \":last-of-type\": {\"!important\": {}, \"_\": {}}, \":only-child\": {\"!important\": {}, \"_\": {}}, \":only-of-type\": {\"!important\": {}, \"_\": {}}, \":target\": {\"!important\": {}, \"_\": {}}, \":visited\": {\"!important\": {}, \"_\": {}}, \":checked\": {\"!important\": {}, \"_\": {}}, \":disabled\": {\"!important\": {}, \"_\": {}}, \":enabled\": {\"!important\": {}, \"_\": {}}, \":read-only\": {\"!important\": {}, \"_\": {}}, \":read-write\": {\"!important\": {}, \"_\": {}}, \":placeholder-shown\": {\"!important\": {}, \"_\": {}}, \":valid\": {\"!important\": {}, \"_\": {}}, \":invalid\": {\"!important\": {}, \"_\": {}}, \":required\": {\"!important\": {}, \"_\": {}}, \":optional\": {\"!important\": {}, \"_\": {}}, \":fullscreen\": {\"!important\": {}, \"_\": {}}, \":focus-within\": {\"!important\": {}, \"_\": {}}, \":out-of-range\": {\"!important\": {}, \"_\": {}}, \":root\": {\"!important\": {}, \"_\": {}}, \":empty\": {\"!important\": {}, \"_\": {}}}})".to_string())
                         );
                     }
@@ -692,15 +726,13 @@ mod tests {
 
     #[tokio::test]
     async fn layout_context_created_with_success() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
-
         match std::fs::read_to_string("src/crealion/mocks/layout.nyr") {
             Ok(raw_nenyr) => {
                 let mut parser = NenyrParser::new();
 
                 match parser.parse(raw_nenyr, "src/crealion/mocks/layout.nyr".to_string()) {
                     Ok(parsed_ast) => {
-                        reset_stylitron();
+                        let session = CompilationSession::new();
 
                         let (sender, _) = broadcast::channel(10);
 
@@ -710,32 +742,37 @@ mod tests {
                             "src/crealion/mocks/layout.nyr".to_string(),
                         );
 
+                        crealion.set_session(session.clone());
+
                         let result = crealion.create().await;
 
                         assert!(result.is_ok());
                         assert_eq!(result.unwrap(), (CrealionContextType::Layout, None));
                         assert_eq!(
-                            STYLITRON.get("themes").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("themes").map(|v| format!("{:?}", &*v)),
                             Some("Themes({\"dynamicLayout\": {\"light\": {\"primaryColor\": [\"--gNDnNldHTaq\", \"#FFFFFF\"]}, \"dark\": {\"primaryColor\": [\"--gNDnNldHTaq\", \"#1E1E1E\"]}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("aliases").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("aliases").map(|v| format!("{:?}", &*v)),
                             Some("Aliases({\"dynamicLayout\": {\"bgd\": \"background\", \"dp\": \"display\", \"transf\": \"transform\", \"pdg\": \"padding\", \"wd\": \"width\", \"hgt\": \"height\", \"flexDir\": \"flex-direction\"}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("variables").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("variables").map(|v| format!("{:?}", &*v)),
                             Some("Variables({\"dynamicLayout\": {\"myColor\": [\"--gUcAVe3Ho2h\", \"#FF6677\"]}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("animations").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("animations").map(|v| format!("{:?}", &*v)),
                             Some("Animation({\"dynamicLayout\": {\"borderFlash\": {\"gmLUMBMQvsEE\": {\"10%\": {\"border-color\": \"var(--gUcAVe3Ho2h)\", \"border-width\": \"1px\"}, \"30%,50%,70%\": {\"border-color\": \"red\", \"border-width\": \"3px\"}, \"90%\": {\"border-color\": \"green\", \"border-width\": \"2px\"}, \"100%\": {\"border-color\": \"var(--gUcAVe3Ho2h)\", \"border-width\": \"1px\"}}}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("styles").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("styles").map(|v| format!("{:?}", &*v)),
                             Some("Styles({\"_\": {\"!important\": {\"background\": {\"\\\\!bgd-zwSc\": \"var(--gNDnNldHTaq)\"}, \"color\": {\"\\\\!clr-4W4E\": \"var(--gUcAVe3Ho2h)\"}, \"padding\": {\"\\\\!pdg-3KtM\": \"10px\"}, \"display\": {\"\\\\!dpy-5TuI\": \"flex\"}, \"align-items\": {\"\\\\!lgn-tms-sLJ6\": \"center\"}}, \"_\": {}}, \"::after\": {\"!important\": {\"content\": {\"\\\\!ftr\\\\.ctt-WT3W\": \"' '\"}, \"display\": {\"\\\\!ftr\\\\.dpy-S4vd\": \"block\"}, \"width\": {\"\\\\!ftr\\\\.wth-YYq9\": \"100%\"}, \"height\": {\"\\\\!ftr\\\\.hht-9X8O\": \"2px\"}, \"background\": {\"\\\\!ftr\\\\.bgd-zwSc\": \"var(--gNDnNldHTaq)\"}}, \"_\": {}}, \"::before\": {\"!important\": {}, \"_\": {}}, \"::first-line\": {\"!important\": {}, \"_\": {}}, \"::first-letter\": {\"!important\": {}, \"_\": {}}, \":hover\": {\"!important\": {\"color\": {\"\\\\!hvr\\\\.clr-4W4E\": \"var(--gUcAVe3Ho2h)\"}, \"border\": {\"\\\\!hvr\\\\.bdr-akTf\": \"2px solid var(--gNDnNldHTaq)\"}, \"animation-name\": {\"\\\\!hvr\\\\.ntn-nm-wVho\": \"gmLUMBMQvsEE\"}}, \"_\": {}}, \":active\": {\"!important\": {}, \"_\": {}}, \":focus\": {\"!important\": {}, \"_\": {}}, \":first-child\": {\"!important\": {}, \"_\": {}}, \":last-child\": {\"!important\": {}, \"_\": {}}, \":first-of-type\": {\"!important\": {}, \"_\": {}}, \":last-of-type\": {\"!important\": {}, \"_\": {}}, \":only-child\": {\"!important\": {}, \"_\": {}}, \":only-of-type\": {\"!important\": {}, \"_\": {}}, \":target\": {\"!important\": {}, \"_\": {}}, \":visited\": {\"!important\": {}, \"_\": {}}, \":checked\": {\"!important\": {}, \"_\": {}}, \":disabled\": {\"!important\": {}, \"_\": {}}, \":enabled\": {\"!important\": {}, \"_\": {}}, \":read-only\": {\"!important\": {}, \"_\": {}}, \":read-write\": {\"!important\": {}, \"_\": {}}, \":placeholder-shown\": {\"!important\": {}, \"_\": {}}, \":valid\": {\"!important\": {}, \"_\": {}}, \":invalid\": {\"!important\": {}, \"_\": {}}, \":required\": {\"!important\": {}, \"_\": {}}, \":optional\": {\"!important\": {}, \"_\": {}}, \":fullscreen\": {\"!important\": {}, \"_\": {}}, \":focus-within\": {\"!important\": {}, \"_\": {}}, \":out-of-range\": {\"!important\": {}, \"_\": {}}, \":root\": {\"!important\": {}, \"_\": {}}, \":empty\": {\"!important\": {}, \"_\": {}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("responsive").map(|v| format!("{:?}", &*v)),
+                            session
+                                .stylitron
+                                .get("responsive")
+                                .map(|v| format!("{:?}", &*v)),
                             Some("ResponsiveStyles({})".to_string())
                         );
                     }
@@ -752,15 +789,13 @@ mod tests {
 
     #[tokio::test]
     async fn module_context_created_with_success() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(35)).await;
-
         match std::fs::read_to_string("src/crealion/mocks/module.nyr") {
             Ok(raw_nenyr) => {
                 let mut parser = NenyrParser::new();
 
                 match parser.parse(raw_nenyr, "src/crealion/mocks/module.nyr".to_string()) {
                     Ok(parsed_ast) => {
-                        reset_stylitron();
+                        let session = CompilationSession::new();
 
                         let (sender, _) = broadcast::channel(10);
 
@@ -770,28 +805,33 @@ mod tests {
                             "src/crealion/mocks/module.nyr".to_string(),
                         );
 
+                        crealion.set_session(session.clone());
+
                         let result = crealion.create().await;
 
                         assert!(result.is_ok());
                         assert_eq!(result.unwrap(), (CrealionContextType::Module, None));
                         assert_eq!(
-                            STYLITRON.get("aliases").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("aliases").map(|v| format!("{:?}", &*v)),
                             Some("Aliases({\"modernCanvas\": {\"bgd\": \"background\", \"dp\": \"display\", \"transf\": \"transform\", \"pdg\": \"padding\", \"wd\": \"width\", \"hgt\": \"height\", \"flexDir\": \"flex-direction\"}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("variables").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("variables").map(|v| format!("{:?}", &*v)),
                             Some("Variables({\"modernCanvas\": {\"myColor\": [\"--gdixceenEK6\", \"#FF6677\"]}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("animations").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("animations").map(|v| format!("{:?}", &*v)),
                             Some("Animation({\"modernCanvas\": {\"borderFlash\": {\"gvd4g8WU1iS7\": {\"10%\": {\"border-color\": \"var(--gdixceenEK6)\", \"border-width\": \"1px\"}, \"30%,50%,70%\": {\"border-color\": \"red\", \"border-width\": \"3px\"}, \"90%\": {\"border-color\": \"green\", \"border-width\": \"2px\"}, \"100%\": {\"border-color\": \"var(--gdixceenEK6)\", \"border-width\": \"1px\"}}}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("styles").map(|v| format!("{:?}", &*v)),
+                            session.stylitron.get("styles").map(|v| format!("{:?}", &*v)),
                             Some("Styles({\"_\": {\"!important\": {\"color\": {\"\\\\!clr-nyD2\": \"var(--gdixceenEK6)\"}, \"padding\": {\"\\\\!pdg-3KtM\": \"10px\"}, \"display\": {\"\\\\!dpy-5TuI\": \"flex\"}, \"align-items\": {\"\\\\!lgn-tms-sLJ6\": \"center\"}}, \"_\": {}}, \"::after\": {\"!important\": {\"content\": {\"\\\\!ftr\\\\.ctt-WT3W\": \"' '\"}, \"display\": {\"\\\\!ftr\\\\.dpy-S4vd\": \"block\"}, \"width\": {\"\\\\!ftr\\\\.wth-YYq9\": \"100%\"}, \"height\": {\"\\\\!ftr\\\\.hht-9X8O\": \"2px\"}}, \"_\": {}}, \"::before\": {\"!important\": {}, \"_\": {}}, \"::first-line\": {\"!important\": {}, \"_\": {}}, \"::first-letter\": {\"!important\": {}, \"_\": {}}, \":hover\": {\"!important\": {\"color\": {\"\\\\!hvr\\\\.clr-nyD2\": \"var(--gdixceenEK6)\"}, \"animation-name\": {\"\\\\!hvr\\\\.ntn-nm-xyuz\": \"gvd4g8WU1iS7\"}}, \"_\": {}}, \":active\": {\"!important\": {}, \"_\": {}}, \":focus\": {\"!important\": {}, \"_\": {}}, \":first-child\": {\"!important\": {}, \"_\": {}}, \":last-child\": {\"!important\": {}, \"_\": {}}, \":first-of-type\": {\"!important\": {}, \"_\": {}}, \":last-of-type\": {\"!important\": {}, \"_\": {}}, \":only-child\": {\"!important\": {}, \"_\": {}}, \":only-of-type\": {\"!important\": {}, \"_\": {}}, \":target\": {\"!important\": {}, \"_\": {}}, \":visited\": {\"!important\": {}, \"_\": {}}, \":checked\": {\"!important\": {}, \"_\": {}}, \":disabled\": {\"!important\": {}, \"_\": {}}, \":enabled\": {\"!important\": {}, \"_\": {}}, \":read-only\": {\"!important\": {}, \"_\": {}}, \":read-write\": {\"!important\": {}, \"_\": {}}, \":placeholder-shown\": {\"!important\": {}, \"_\": {}}, \":valid\": {\"!important\": {}, \"_\": {}}, \":invalid\": {\"!important\": {}, \"_\": {}}, \":required\": {\"!important\": {}, \"_\": {}}, \":optional\": {\"!important\": {}, \"_\": {}}, \":fullscreen\": {\"!important\": {}, \"_\": {}}, \":focus-within\": {\"!important\": {}, \"_\": {}}, \":out-of-range\": {\"!important\": {}, \"_\": {}}, \":root\": {\"!important\": {}, \"_\": {}}, \":empty\": {\"!important\": {}, \"_\": {}}})".to_string())
                         );
                         assert_eq!(
-                            STYLITRON.get("responsive").map(|v| format!("{:?}", &*v)),
+                            session
+                                .stylitron
+                                .get("responsive")
+                                .map(|v| format!("{:?}", &*v)),
                             Some("ResponsiveStyles({})".to_string())
                         );
                     }
@@ -1,4 +1,6 @@
-use crate::{asts::STYLITRON, types::Stylitron};
+use dashmap::DashMap;
+
+use crate::types::Stylitron;
 
 /// Resolves an alias identifier based on a given `identifier` string and inherited contexts.
 ///
@@ -8,12 +10,14 @@ use crate::{asts::STYLITRON, types::Stylitron};
 /// simply returns the original `identifier` as a string.
 ///
 /// # Parameters
+/// - `stylitron`: The abstract syntax tree holding the aliases definitions.
 /// - `identifier`: A string slice that represents the identifier to be resolved.
 /// - `inherited_contexts`: A vector of strings representing the contexts from which the alias should be resolved.
 ///
 /// # Returns
 /// - `Option<String>`: The resolved alias if found, or the original identifier if no alias is found.
 pub fn resolve_alias_identifier(
+    stylitron: &DashMap<String, Stylitron>,
     identifier: &str,
     inherited_contexts: &Vec<String>,
 ) -> Option<String> {
@@ -26,7 +30,7 @@ pub fn resolve_alias_identifier(
             "Found 'nickname;' prefix, attempting alias resolution"
         );
 
-        return STYLITRON
+        return stylitron
             .get("aliases")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Aliases(ref aliases_definitions) => {
@@ -53,12 +57,59 @@ pub fn resolve_alias_identifier(
     Some(identifier.to_string())
 }
 
+/// Resolves an alias identifier while ignoring the casing of the alias, e.g. `nickname;BGD` for the `bgd` alias.
+///
+/// The aliases of each inherited context are compared case-insensitively, closest context first, and
+/// the first match is returned along with its canonical casing so the caller can recommend it.
+///
+/// # Parameters
+/// - `stylitron`: The abstract syntax tree holding the aliases definitions.
+/// - `identifier`: A string slice that represents the identifier to be resolved.
+/// - `inherited_contexts`: A vector of strings representing the contexts from which the alias should be resolved.
+///
+/// # Returns
+/// - `Option<(String, String)>`: The canonical alias and its resolved value, or `None` if the identifier is not
+///   an alias or no alias matches it.
+pub fn resolve_alias_ignoring_case(
+    stylitron: &DashMap<String, Stylitron>,
+    identifier: &str,
+    inherited_contexts: &Vec<String>,
+) -> Option<(String, String)> {
+    let alias = identifier.strip_prefix("nickname;")?;
+
+    tracing::debug!(alias, "Attempting case-insensitive alias resolution");
+
+    stylitron
+        .get("aliases")
+        .and_then(|stylitron_data| match &*stylitron_data {
+            Stylitron::Aliases(ref aliases_definitions) => {
+                inherited_contexts.iter().find_map(|context_name| {
+                    aliases_definitions
+                        .get(context_name)
+                        .and_then(|context_aliases| {
+                            context_aliases
+                                .iter()
+                                .find(|(canonical_alias, _)| {
+                                    canonical_alias.eq_ignore_ascii_case(alias)
+                                })
+                                .map(|(canonical_alias, alias_entry)| {
+                                    (canonical_alias.to_owned(), alias_entry.to_owned())
+                                })
+                        })
+                })
+            }
+            _ => None,
+        })
+}
+
 #[cfg(test)]
 mod alias_test {
     use indexmap::IndexMap;
 
     use crate::{
-        asts::STYLITRON, crealion::processors::aliases::resolve_alias_identifier, types::Stylitron,
+        asts::STYLITRON,
+        crealion::processors::aliases::{resolve_alias_identifier, resolve_alias_ignoring_case},
+        types::Stylitron,
     };
 
     fn mock_aliases() {
@@ -81,7 +132,7 @@ mod alias_test {
         let input = "nickname;bgd";
         let inherits = vec!["myAliasesContext".to_string()];
 
-        let resolved_input = resolve_alias_identifier(input, &inherits);
+        let resolved_input = resolve_alias_identifier(&STYLITRON, input, &inherits);
         let expected_result = "background".to_string();
 
         assert!(resolved_input.is_some());
@@ -95,7 +146,7 @@ mod alias_test {
         let input = "nickname;dsp";
         let inherits = vec!["myAliasesContext".to_string()];
 
-        let resolved_input = resolve_alias_identifier(input, &inherits);
+        let resolved_input = resolve_alias_identifier(&STYLITRON, input, &inherits);
         let expected_result = "display".to_string();
 
         assert!(resolved_input.is_some());
@@ -109,10 +160,32 @@ mod alias_test {
         let input = "nickname;br";
         let inherits = vec!["myAliasesContext".to_string()];
 
-        let resolved_input = resolve_alias_identifier(input, &inherits);
+        let resolved_input = resolve_alias_identifier(&STYLITRON, input, &inherits);
         let expected_result = "border-radius".to_string();
 
         assert!(resolved_input.is_some());
         assert_eq!(resolved_input, Some(expected_result));
     }
+
+    #[test]
+    fn mismatched_case_alias_resolves_only_ignoring_case() {
+        mock_aliases();
+
+        let input = "nickname;BGD";
+        let inherits = vec!["myAliasesContext".to_string()];
+
+        assert_eq!(resolve_alias_identifier(&STYLITRON, input, &inherits), None);
+        assert_eq!(
+            resolve_alias_ignoring_case(&STYLITRON, input, &inherits),
+            Some(("bgd".to_string(), "background".to_string()))
+        );
+        assert_eq!(
+            resolve_alias_ignoring_case(&STYLITRON, "nickname;Bgx", &inherits),
+            None
+        );
+        assert_eq!(
+            resolve_alias_ignoring_case(&STYLITRON, "background", &inherits),
+            None
+        );
+    }
 }
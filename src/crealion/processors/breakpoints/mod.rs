@@ -1,7 +1,18 @@
-use crate::{asts::STYLITRON, types::Stylitron};
+use std::collections::BTreeMap;
+
+use dashmap::DashMap;
+
+use crate::{
+    configatron::get_container_breakpoints,
+    types::Stylitron,
+    utils::format_media_query::{CONTAINER_CONDITION_PREFIX, PRINT_MEDIA_CONDITION},
+};
 
 const SCHEMA_TYPES: &[&str] = &["mobile-first", "desktop-first"];
 
+/// The reserved breakpoint name whose styles are emitted inside `@media print`.
+pub const PRINT_BREAKPOINT_NAME: &str = "print";
+
 /// Resolves a breakpoint identifier based on the given `identifier` string.
 ///
 /// This function searches for a breakpoint associated with the provided `identifier` in the
@@ -10,16 +21,27 @@ const SCHEMA_TYPES: &[&str] = &["mobile-first", "desktop-first"];
 /// matching breakpoint is found, it returns the resolved breakpoint as a `String`. If no
 /// matching breakpoint is found, `None` is returned.
 ///
+/// An identifier missing from both schemas is then looked up in the `containerBreakpoints`
+/// configuration, resolving to its condition prefixed by `CONTAINER_CONDITION_PREFIX`, so its
+/// styles are emitted inside a `@container` rule.
+///
+/// The reserved `print` identifier resolves to the print media condition, unless a breakpoint
+/// with the same name has been defined, in which case the defined one takes precedence.
+///
 /// # Parameters
+/// - `stylitron`: The abstract syntax tree holding the breakpoints definitions.
 /// - `identifier`: A string slice representing the identifier of the breakpoint to resolve.
 ///
 /// # Returns
 /// - `Option<String>`: The resolved breakpoint if found, otherwise `None`.
-pub fn resolve_breakpoint_identifier(identifier: &str) -> Option<String> {
+pub fn resolve_breakpoint_identifier(
+    stylitron: &DashMap<String, Stylitron>,
+    identifier: &str,
+) -> Option<String> {
     tracing::info!(identifier, "Resolving breakpoint identifier");
 
     // Attempt to retrieve the "breakpoints" data from the STYLITRON structure.
-    STYLITRON
+    stylitron
         .get("breakpoints")
         .and_then(|stylitron_data| match &*stylitron_data {
             Stylitron::Breakpoints(ref breakpoints_definitions) => {
@@ -39,15 +61,54 @@ pub fn resolve_breakpoint_identifier(identifier: &str) -> Option<String> {
             }
             _ => None,
         })
+        .or_else(|| resolve_container_breakpoint(&get_container_breakpoints(), identifier))
+        .or_else(|| {
+            (identifier == PRINT_BREAKPOINT_NAME).then(|| {
+                tracing::info!(identifier, "Breakpoint resolved to the print media type");
+
+                PRINT_MEDIA_CONDITION.to_string()
+            })
+        })
+}
+
+/// Resolves a breakpoint identifier against the breakpoints emitted as container queries.
+///
+/// # Parameters
+/// - `container_breakpoints`: The conditions of the container breakpoints, keyed by their name.
+/// - `identifier`: A string slice representing the identifier of the breakpoint to resolve.
+///
+/// # Returns
+/// - `Option<String>`: The condition prefixed by `CONTAINER_CONDITION_PREFIX` if found, otherwise `None`.
+fn resolve_container_breakpoint(
+    container_breakpoints: &BTreeMap<String, String>,
+    identifier: &str,
+) -> Option<String> {
+    container_breakpoints
+        .get(identifier)
+        .map(|container_condition| {
+            tracing::info!(identifier, "Breakpoint resolved to a container query");
+
+            format!(
+                "{}{}",
+                CONTAINER_CONDITION_PREFIX,
+                container_condition.trim()
+            )
+        })
 }
 
 #[cfg(test)]
 mod breakpoints_test {
+    use std::collections::BTreeMap;
+
     use indexmap::IndexMap;
 
     use crate::{
-        asts::STYLITRON, crealion::processors::breakpoints::resolve_breakpoint_identifier,
+        asts::STYLITRON,
+        crealion::processors::breakpoints::{
+            resolve_breakpoint_identifier, resolve_container_breakpoint,
+        },
         types::Stylitron,
+        utils::format_media_query::format_media_query,
     };
 
     fn mock_breakpoints() {
@@ -77,7 +138,7 @@ mod breakpoints_test {
 
         let input = "mobSm";
 
-        let resolved_input = resolve_breakpoint_identifier(input);
+        let resolved_input = resolve_breakpoint_identifier(&STYLITRON, input);
         let expected_result = "min-width:320px".to_string();
 
         assert!(resolved_input.is_some());
@@ -90,7 +151,7 @@ mod breakpoints_test {
 
         let input = "mobMd";
 
-        let resolved_input = resolve_breakpoint_identifier(input);
+        let resolved_input = resolve_breakpoint_identifier(&STYLITRON, input);
         let expected_result = "min-width:740px".to_string();
 
         assert!(resolved_input.is_some());
@@ -103,7 +164,7 @@ mod breakpoints_test {
 
         let input = "deskSm";
 
-        let resolved_input = resolve_breakpoint_identifier(input);
+        let resolved_input = resolve_breakpoint_identifier(&STYLITRON, input);
         let expected_result = "max-width:320px".to_string();
 
         assert!(resolved_input.is_some());
@@ -116,10 +177,52 @@ mod breakpoints_test {
 
         let input = "deskMd";
 
-        let resolved_input = resolve_breakpoint_identifier(input);
+        let resolved_input = resolve_breakpoint_identifier(&STYLITRON, input);
         let expected_result = "max-width:740px".to_string();
 
         assert!(resolved_input.is_some());
         assert_eq!(resolved_input, Some(expected_result));
     }
+
+    #[test]
+    fn print_breakpoint_resolves_to_print_media() {
+        mock_breakpoints();
+
+        let resolved_input = resolve_breakpoint_identifier(&STYLITRON, "print");
+
+        assert_eq!(resolved_input, Some("print".to_string()));
+        assert_eq!(format_media_query("print"), "@media print");
+        assert_eq!(
+            format_media_query("min-width:740px"),
+            "@media screen and (min-width:740px)"
+        );
+    }
+
+    #[test]
+    fn container_breakpoint_resolves_to_container_query() {
+        let container_breakpoints =
+            BTreeMap::from([("cardMd".to_string(), "min-width:480px".to_string())]);
+
+        let container_breakpoint = resolve_container_breakpoint(&container_breakpoints, "cardMd");
+
+        assert_eq!(
+            container_breakpoint,
+            Some("container:min-width:480px".to_string())
+        );
+        assert_eq!(
+            format_media_query("container:min-width:480px"),
+            "@container (min-width:480px)"
+        );
+        assert_eq!(
+            resolve_container_breakpoint(&container_breakpoints, "cardLg"),
+            None
+        );
+    }
+
+    #[test]
+    fn undefined_breakpoint_is_not_resolved() {
+        mock_breakpoints();
+
+        assert_eq!(resolve_breakpoint_identifier(&STYLITRON, "mobXl"), None);
+    }
 }
@@ -1,3 +1,5 @@
 pub mod aliases;
 pub mod breakpoints;
+pub mod scales;
+pub mod shorthands;
 pub mod variables;
@@ -0,0 +1,143 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Lazy-static regex to match a base made of a number and an optional unit, e.g. `4px` or `0.25rem`.
+    static ref NUMERIC_BASE_RE: Regex = Regex::new(r"^(-?\d*\.?\d+)([a-zA-Z%]*)$").unwrap();
+}
+
+/// Expands every call of the scale function within a value into its computed value.
+///
+/// A call like `space(3)` is multiplied by the base: a numeric base such as `4px` is computed
+/// directly into `12px`, while any other base (e.g. `${spaceUnit}` or `var(--space-unit)`) is
+/// expanded into a `calc()` expression. Calls whose argument is not a number are left untouched.
+///
+/// # Parameters
+/// - `value`: The property value that may contain scale function calls.
+/// - `function_name`: The name of the scale function.
+/// - `base`: The base unit multiplied by the scale function. An empty base disables the expansion.
+///
+/// # Returns
+/// - `String`: The value with every scale function call expanded.
+pub fn expand_scale_functions(value: &str, function_name: &str, base: &str) -> String {
+    let base = base.trim();
+    let call_prefix = format!("{}(", function_name.trim());
+
+    if base.is_empty() || call_prefix.len() == 1 || !value.contains(&call_prefix) {
+        return value.to_string();
+    }
+
+    let mut expanded_value = String::new();
+    let mut remaining_value = value;
+
+    while let Some(call_idx) = remaining_value.find(&call_prefix) {
+        let (preceding, call) = remaining_value.split_at(call_idx);
+        let arguments = &call[call_prefix.len()..];
+
+        expanded_value.push_str(preceding);
+
+        // The function name must not be the tail of a longer identifier, e.g. `myspace(3)`.
+        let is_standalone = !preceding
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        let expansion = arguments.find(')').and_then(|close_idx| {
+            let multiplier = arguments[..close_idx].trim().parse::<f64>().ok()?;
+
+            is_standalone.then(|| (scale_value(base, multiplier), close_idx))
+        });
+
+        match expansion {
+            Some((scaled_value, close_idx)) => {
+                tracing::debug!(
+                    "Expanded scale function call `{}{})` into '{}'.",
+                    call_prefix,
+                    &arguments[..close_idx],
+                    scaled_value
+                );
+
+                expanded_value.push_str(&scaled_value);
+                remaining_value = &arguments[close_idx + 1..];
+            }
+            None => {
+                expanded_value.push_str(&call_prefix);
+                remaining_value = arguments;
+            }
+        }
+    }
+
+    expanded_value.push_str(remaining_value);
+    expanded_value
+}
+
+/// Multiplies the base by the given multiplier.
+///
+/// # Parameters
+/// - `base`: The base unit, either numeric (e.g. `4px`) or any other expression.
+/// - `multiplier`: The scale step.
+///
+/// # Returns
+/// - `String`: The computed value, or a `calc()` expression for non-numeric bases.
+fn scale_value(base: &str, multiplier: f64) -> String {
+    match NUMERIC_BASE_RE.captures(base).and_then(|caps| {
+        let number = caps.get(1)?.as_str().parse::<f64>().ok()?;
+
+        Some((number, caps.get(2)?.as_str()))
+    }) {
+        Some((number, unit)) => {
+            // Rounding avoids floating point noise such as `0.30000000000000004rem`.
+            let scaled_number = (number * multiplier * 10_000.0).round() / 10_000.0;
+
+            match scaled_number == 0.0 {
+                true => "0".to_string(),
+                false => format!("{}{}", scaled_number, unit),
+            }
+        }
+        None => format!("calc({} * {})", base, multiplier),
+    }
+}
+
+#[cfg(test)]
+mod scales_test {
+    use crate::crealion::processors::scales::expand_scale_functions;
+
+    #[test]
+    fn numeric_base_is_computed() {
+        assert_eq!(expand_scale_functions("space(3)", "space", "4px"), "12px");
+        assert_eq!(
+            expand_scale_functions("space(1) space(1.5)", "space", "0.25rem"),
+            "0.25rem 0.375rem"
+        );
+        assert_eq!(expand_scale_functions("space(0)", "space", "4px"), "0");
+    }
+
+    #[test]
+    fn non_numeric_base_becomes_calc() {
+        assert_eq!(
+            expand_scale_functions("space(3) auto", "space", "var(--space-unit)"),
+            "calc(var(--space-unit) * 3) auto"
+        );
+        assert_eq!(
+            expand_scale_functions("gap(2)", "gap", "${spaceUnit}"),
+            "calc(${spaceUnit} * 2)"
+        );
+    }
+
+    #[test]
+    fn unrelated_values_are_kept() {
+        assert_eq!(expand_scale_functions("space(3)", "space", ""), "space(3)");
+        assert_eq!(
+            expand_scale_functions("myspace(3)", "space", "4px"),
+            "myspace(3)"
+        );
+        assert_eq!(
+            expand_scale_functions("space(x)", "space", "4px"),
+            "space(x)"
+        );
+        assert_eq!(
+            expand_scale_functions("space-between", "space", "4px"),
+            "space-between"
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use dashmap::DashMap;
+use indexmap::IndexMap;
+
+use crate::{configatron::get_lenient_aliases, types::Stylitron};
+
+use super::aliases::{resolve_alias_identifier, resolve_alias_ignoring_case};
+
+/// Shorthand properties paired with the complete set of longhands they replace.
+const SHORTHAND_SETS: [(&str, &[&str]); 16] = [
+    (
+        "margin",
+        &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+    ),
+    (
+        "padding",
+        &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+    ),
+    ("inset", &["top", "right", "bottom", "left"]),
+    (
+        "border-width",
+        &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+    ),
+    (
+        "border-style",
+        &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+    ),
+    (
+        "border-color",
+        &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    ),
+    (
+        "border-radius",
+        &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+    ),
+    (
+        "margin-inline",
+        &["margin-inline-start", "margin-inline-end"],
+    ),
+    ("margin-block", &["margin-block-start", "margin-block-end"]),
+    (
+        "padding-inline",
+        &["padding-inline-start", "padding-inline-end"],
+    ),
+    (
+        "padding-block",
+        &["padding-block-start", "padding-block-end"],
+    ),
+    ("inset-inline", &["inset-inline-start", "inset-inline-end"]),
+    ("inset-block", &["inset-block-start", "inset-block-end"]),
+    ("gap", &["row-gap", "column-gap"]),
+    ("overflow", &["overflow-x", "overflow-y"]),
+    (
+        "overscroll-behavior",
+        &["overscroll-behavior-x", "overscroll-behavior-y"],
+    ),
+];
+
+/// Consolidates complete sets of longhand properties into their shorthand property.
+///
+/// A set is only consolidated when every longhand of the set is present with the same value
+/// and no other declaration of the set conflicts with it (i.e. the shorthand itself is not
+/// already declared). Properties given through aliases are resolved before being compared, and
+/// the shorthand takes the position of the first longhand it replaces.
+///
+/// # Parameters
+/// - `stylitron`: The abstract syntax tree holding the aliases definitions.
+/// - `properties`: A map of the pattern properties and their values.
+/// - `inherited_contexts`: The contexts used to resolve property aliases.
+///
+/// # Returns
+/// - `IndexMap<String, String>`: The properties with every consolidatable set replaced by its shorthand.
+pub fn consolidate_shorthand_properties(
+    stylitron: &DashMap<String, Stylitron>,
+    properties: &IndexMap<String, String>,
+    inherited_contexts: &Vec<String>,
+) -> IndexMap<String, String> {
+    // Map each resolved property name to the original key and its value.
+    let resolved_properties: IndexMap<String, (&String, &String)> = properties
+        .iter()
+        .map(|(property, value)| {
+            // Mismatched alias casings are reported when the class itself is resolved.
+            let resolved_property =
+                resolve_alias_identifier(stylitron, property, inherited_contexts)
+                    .or_else(|| {
+                        get_lenient_aliases()
+                            .then(|| {
+                                resolve_alias_ignoring_case(stylitron, property, inherited_contexts)
+                            })
+                            .flatten()
+                            .map(|(_, resolved_property)| resolved_property)
+                    })
+                    .unwrap_or_else(|| property.to_owned());
+
+            (resolved_property, (property, value))
+        })
+        .collect();
+
+    // The original keys replaced by each consolidated shorthand, along with the shared value.
+    let mut consolidated_sets: Vec<(&str, Vec<&String>, &String)> = vec![];
+
+    for (shorthand, longhands) in SHORTHAND_SETS {
+        // Declaring the shorthand alongside its longhands is a conflict, so the set is left as is.
+        if resolved_properties.contains_key(shorthand) {
+            continue;
+        }
+
+        let set_entries: Vec<(&String, &String)> = longhands
+            .iter()
+            .filter_map(|longhand| resolved_properties.get(*longhand).copied())
+            .collect();
+
+        // Only a complete set of longhands can be consolidated.
+        if set_entries.len() != longhands.len() {
+            continue;
+        }
+
+        let (_, first_value) = set_entries[0];
+
+        if first_value.trim().is_empty()
+            || set_entries
+                .iter()
+                .any(|(_, value)| value.trim() != first_value.trim())
+        {
+            continue;
+        }
+
+        tracing::debug!(
+            "Consolidating {:?} longhands into the `{}` shorthand with value '{}'.",
+            longhands,
+            shorthand,
+            first_value
+        );
+
+        consolidated_sets.push((
+            shorthand,
+            set_entries.iter().map(|(property, _)| *property).collect(),
+            first_value,
+        ));
+    }
+
+    if consolidated_sets.is_empty() {
+        return properties.to_owned();
+    }
+
+    let mut consolidated_properties: IndexMap<String, String> = IndexMap::new();
+
+    for (property, value) in properties {
+        match consolidated_sets
+            .iter()
+            .find(|(_, replaced_properties, _)| replaced_properties.contains(&property))
+        {
+            // The shorthand is placed where the first longhand of its set was declared.
+            Some((shorthand, _, shorthand_value)) => {
+                if !consolidated_properties.contains_key(*shorthand) {
+                    consolidated_properties
+                        .insert(shorthand.to_string(), shorthand_value.to_string());
+                }
+            }
+            None => {
+                consolidated_properties.insert(property.to_owned(), value.to_owned());
+            }
+        }
+    }
+
+    consolidated_properties
+}
+
+#[cfg(test)]
+mod shorthands_test {
+    use dashmap::DashMap;
+    use indexmap::IndexMap;
+
+    use crate::crealion::processors::shorthands::consolidate_shorthand_properties;
+
+    fn mock_properties(entries: &[(&str, &str)]) -> IndexMap<String, String> {
+        entries
+            .iter()
+            .map(|(property, value)| (property.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn complete_set_is_consolidated() {
+        let properties = mock_properties(&[
+            ("color", "red"),
+            ("margin-top", "8px"),
+            ("margin-right", "8px"),
+            ("margin-bottom", "8px"),
+            ("margin-left", "8px"),
+        ]);
+
+        let result = consolidate_shorthand_properties(&DashMap::new(), &properties, &vec![]);
+
+        assert_eq!(
+            result,
+            mock_properties(&[("color", "red"), ("margin", "8px")])
+        );
+    }
+
+    #[test]
+    fn incomplete_set_is_kept() {
+        let properties = mock_properties(&[
+            ("padding-top", "4px"),
+            ("padding-right", "4px"),
+            ("padding-bottom", "4px"),
+        ]);
+
+        let result = consolidate_shorthand_properties(&DashMap::new(), &properties, &vec![]);
+
+        assert_eq!(result, properties);
+    }
+
+    #[test]
+    fn different_values_are_kept() {
+        let properties =
+            mock_properties(&[("margin-inline-start", "4px"), ("margin-inline-end", "2px")]);
+
+        let result = consolidate_shorthand_properties(&DashMap::new(), &properties, &vec![]);
+
+        assert_eq!(result, properties);
+    }
+
+    #[test]
+    fn conflicting_shorthand_is_kept() {
+        let properties =
+            mock_properties(&[("gap", "1rem"), ("row-gap", "2rem"), ("column-gap", "2rem")]);
+
+        let result = consolidate_shorthand_properties(&DashMap::new(), &properties, &vec![]);
+
+        assert_eq!(result, properties);
+    }
+}
@@ -1,7 +1,8 @@
+use dashmap::DashMap;
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use crate::{asts::STYLITRON, types::Stylitron};
+use crate::types::Stylitron;
 
 /// Schema for themes, defining the available theme types.
 const THEMES_SCHEMA: &[&str] = &["light", "dark"];
@@ -38,6 +39,7 @@ impl<T> VariablesOption<T> {
 /// replaced by their resolved values.
 ///
 /// # Parameters
+/// - `stylitron`: The abstract syntax tree holding the variables, themes, and animations definitions.
 /// - `input`: A `String` representing the input that may contain variables to be resolved.
 /// - `use_animation`: A `bool` indicating whether to attempt resolving variables from animation nodes.
 /// - `inherited_contexts`: A reference to a `Vec<String>` containing the inherited contexts to search for variables.
@@ -45,6 +47,7 @@ impl<T> VariablesOption<T> {
 /// # Returns
 /// - `Option<String>`: The resolved string with variables replaced, or `None` if a variable could not be resolved.
 pub fn resolve_variable_from_str(
+    stylitron: &DashMap<String, Stylitron>,
     input: String,
     use_animation: bool,
     inherited_contexts: &Vec<String>,
@@ -63,7 +66,7 @@ pub fn resolve_variable_from_str(
             tracing::info!("Processing capture: {}", relative_name);
 
             // First, attempt to resolve the variable from the themes node.
-            match resolve_from_themes_node(relative_name, inherited_contexts) {
+            match resolve_from_themes_node(stylitron, relative_name, inherited_contexts) {
                 Some(resolved_name) => {
                     tracing::info!("Resolved '{}' from variables node", resolved_name);
 
@@ -73,7 +76,7 @@ pub fn resolve_variable_from_str(
             }
 
             // Second attempt to resolve the variable from the variables node.
-            match resolve_from_variables_node(relative_name, inherited_contexts) {
+            match resolve_from_variables_node(stylitron, relative_name, inherited_contexts) {
                 Some(resolved_name) => {
                     tracing::info!("Resolved '{}' from variables node", resolved_name);
 
@@ -84,7 +87,7 @@ pub fn resolve_variable_from_str(
 
             // If using animation, attempt to resolve the variable from animation nodes.
             if use_animation {
-                match resolve_from_animations_node(relative_name, inherited_contexts) {
+                match resolve_from_animations_node(stylitron, relative_name, inherited_contexts) {
                     Some(resolved_name) => {
                         tracing::info!("Resolved '{}' from animations node", resolved_name);
 
@@ -116,6 +119,7 @@ pub fn resolve_variable_from_str(
 /// Resolves a variable from the "variables" node in the STYLITRON.
 ///
 /// # Parameters
+/// - `stylitron`: The abstract syntax tree to search.
 /// - `relative_name`: The variable name to resolve.
 /// - `inherited_contexts`: Contexts to search for the variable.
 ///
@@ -123,10 +127,11 @@ pub fn resolve_variable_from_str(
 /// - `Some(String)` if the variable is resolved.
 /// - `None` if the variable cannot be found.
 fn resolve_from_variables_node(
+    stylitron: &DashMap<String, Stylitron>,
     relative_name: &str,
     inherited_contexts: &Vec<String>,
 ) -> Option<String> {
-    STYLITRON
+    stylitron
         .get("variables")
         .and_then(|stylitron_data| match &*stylitron_data {
             Stylitron::Variables(ref variables_definitions) => {
@@ -147,6 +152,7 @@ fn resolve_from_variables_node(
 /// Resolves a variable from the "animations" node in the STYLITRON.
 ///
 /// # Parameters
+/// - `stylitron`: The abstract syntax tree to search.
 /// - `relative_name`: The animation name to resolve.
 /// - `inherited_contexts`: Contexts to search for the animation.
 ///
@@ -154,10 +160,11 @@ fn resolve_from_variables_node(
 /// - `Some(String)` if the animation is resolved.
 /// - `None` if the animation cannot be found.
 fn resolve_from_animations_node(
+    stylitron: &DashMap<String, Stylitron>,
     relative_name: &str,
     inherited_contexts: &Vec<String>,
 ) -> Option<String> {
-    STYLITRON
+    stylitron
         .get("animations")
         .and_then(|stylitron_data| match &*stylitron_data {
             Stylitron::Animation(ref animation_definitions) => {
@@ -182,6 +189,7 @@ fn resolve_from_animations_node(
 /// Resolves a variable from the "themes" node in the STYLITRON.
 ///
 /// # Parameters
+/// - `stylitron`: The abstract syntax tree to search.
 /// - `relative_name`: The theme variable name to resolve.
 /// - `inherited_contexts`: Contexts to search for the theme variable.
 ///
@@ -189,10 +197,11 @@ fn resolve_from_animations_node(
 /// - `Some(String)` if the theme variable is resolved.
 /// - `None` if the theme variable cannot be found.
 fn resolve_from_themes_node(
+    stylitron: &DashMap<String, Stylitron>,
     relative_name: &str,
     inherited_contexts: &Vec<String>,
 ) -> Option<String> {
-    STYLITRON
+    stylitron
         .get("themes")
         .and_then(|stylitron_data| match &*stylitron_data {
             Stylitron::Themes(ref themes_definitions) => {
@@ -401,7 +410,7 @@ mod processors_tests {
             "oneExtraContext".to_string(),
         ];
 
-        let resolved_input = resolve_variable_from_str(input, false, &inherits);
+        let resolved_input = resolve_variable_from_str(&STYLITRON, input, false, &inherits);
         let expected_result = "var(--y7637dj35e) var(--jd5dj3h4e7) var(--o34s54e83e)".to_string();
 
         assert!(resolved_input.is_some());
@@ -418,7 +427,7 @@ mod processors_tests {
             "oneExtraContext".to_string(),
         ];
 
-        let resolved_input = resolve_variable_from_str(input, false, &inherits);
+        let resolved_input = resolve_variable_from_str(&STYLITRON, input, false, &inherits);
         let expected_result = "var(--jd5dj3h4e7) var(--ywd5drj73h)".to_string();
 
         assert!(resolved_input.is_some());
@@ -435,7 +444,7 @@ mod processors_tests {
             "animationsContextTwo".to_string(),
         ];
 
-        let resolved_input = resolve_variable_from_str(input, true, &inherits);
+        let resolved_input = resolve_variable_from_str(&STYLITRON, input, true, &inherits);
         let expected_result = "g4duf74dju3 g39jd4dkh3k7".to_string();
 
         assert!(resolved_input.is_some());
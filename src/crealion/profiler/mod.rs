@@ -0,0 +1,75 @@
+use std::{sync::Mutex, time::Duration};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Stores the resolution time of every Nenyr class while class profiling is enabled.
+    /// `None` means profiling is disabled and no timing is recorded.
+    static ref CLASSES_TIMINGS: Mutex<Option<Vec<ClassTiming>>> = Mutex::new(None);
+}
+
+/// The time spent resolving a single Nenyr class.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClassTiming {
+    /// The name of the Nenyr class.
+    pub class_name: String,
+    /// The name of the context the class belongs to.
+    pub context_name: String,
+    /// The time spent resolving the class styles.
+    pub duration: Duration,
+}
+
+/// Enables the class profiling, discarding any previously recorded timing.
+pub fn enable_classes_profiling() {
+    if let Ok(mut classes_timings) = CLASSES_TIMINGS.lock() {
+        *classes_timings = Some(vec![]);
+    }
+}
+
+/// Checks whether the class profiling is enabled.
+pub fn is_classes_profiling_enabled() -> bool {
+    CLASSES_TIMINGS
+        .lock()
+        .map(|classes_timings| classes_timings.is_some())
+        .unwrap_or(false)
+}
+
+/// Records the resolution time of a Nenyr class, if the class profiling is enabled.
+///
+/// # Arguments
+/// - `class_name`: The name of the Nenyr class.
+/// - `context_name`: The name of the context the class belongs to.
+/// - `duration`: The time spent resolving the class styles.
+pub fn record_class_timing(class_name: &str, context_name: &str, duration: Duration) {
+    if let Ok(mut classes_timings) = CLASSES_TIMINGS.lock() {
+        if let Some(classes_timings) = classes_timings.as_mut() {
+            classes_timings.push(ClassTiming {
+                class_name: class_name.to_string(),
+                context_name: context_name.to_string(),
+                duration,
+            });
+        }
+    }
+}
+
+/// Takes the slowest Nenyr classes recorded so far, clearing the recorded timings.
+///
+/// # Arguments
+/// - `limit`: The maximum number of classes returned.
+///
+/// # Returns
+/// - `Vec<ClassTiming>`: The slowest classes, sorted from the slowest one.
+pub fn take_slowest_classes(limit: usize) -> Vec<ClassTiming> {
+    let mut classes_timings = match CLASSES_TIMINGS.lock() {
+        Ok(mut classes_timings) => match classes_timings.as_mut() {
+            Some(classes_timings) => std::mem::take(classes_timings),
+            None => return vec![],
+        },
+        Err(_) => return vec![],
+    };
+
+    classes_timings.sort_by(|first, second| second.duration.cmp(&first.duration));
+    classes_timings.truncate(limit);
+
+    classes_timings
+}
@@ -4,7 +4,6 @@ use nenyr::types::variables::NenyrVariables;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Stylitron,
@@ -31,6 +30,7 @@ impl Crealion {
         dark_data: Option<NenyrVariables>,
     ) -> JoinHandle<()> {
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
 
         // Spawn a blocking task to process the themes.
         tokio::spawn(async move {
@@ -40,7 +40,7 @@ impl Crealion {
             );
 
             // Attempt to retrieve the "themes" section from the STYLITRON AST.
-            let mut stylitron_data = match STYLITRON.get_mut("themes") {
+            let mut stylitron_data = match stylitron.get_mut("themes") {
                 Some(data) => {
                     tracing::debug!("Successfully accessed the `themes` section in STYLITRON AST.");
                     data
@@ -140,7 +140,7 @@ mod tests {
     use tokio::sync::broadcast;
 
     use crate::{
-        asts::STYLITRON,
+        asts::CompilationSession,
         crealion::{
             utils::generates_variable_or_animation_name::generates_variable_or_animation_name,
             Crealion,
@@ -195,14 +195,18 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_themes_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let nenyr_themes = NenyrThemes {
             light_schema: Some(NenyrVariables {
                 values: mock_light_variables(),
@@ -220,14 +224,16 @@ mod tests {
             )
             .await;
 
-        let result = STYLITRON
-            .get("themes")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Themes(themes_definitions) => themes_definitions
-                    .get("myContextName1")
-                    .and_then(|context_themes| Some(context_themes.to_owned())),
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("themes")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Themes(themes_definitions) => themes_definitions
+                        .get("myContextName1")
+                        .and_then(|context_themes| Some(context_themes.to_owned())),
+                    _ => None,
+                });
 
         assert!(result.is_some());
 
@@ -243,7 +249,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_themes_to_existing_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
@@ -259,14 +265,18 @@ mod tests {
             )]),
         )]);
 
-        STYLITRON.insert("themes".to_string(), Stylitron::Themes(initial_data));
+        session
+            .stylitron
+            .insert("themes".to_string(), Stylitron::Themes(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let nenyr_themes = NenyrThemes {
             light_schema: Some(NenyrVariables {
                 values: mock_light_variables(),
@@ -284,14 +294,16 @@ mod tests {
             )
             .await;
 
-        let result = STYLITRON
-            .get("themes")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Themes(themes_definitions) => {
-                    themes_definitions.get("myContextName3").cloned()
-                }
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("themes")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Themes(themes_definitions) => {
+                        themes_definitions.get("myContextName3").cloned()
+                    }
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let themes = result.unwrap();
@@ -307,20 +319,24 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_themes_to_new_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert("themes".to_string(), Stylitron::Themes(initial_data));
+        session
+            .stylitron
+            .insert("themes".to_string(), Stylitron::Themes(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let nenyr_themes = NenyrThemes {
             light_schema: Some(NenyrVariables {
                 values: mock_light_variables(),
@@ -338,14 +354,16 @@ mod tests {
             )
             .await;
 
-        let result = STYLITRON
-            .get("themes")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Themes(themes_definitions) => {
-                    themes_definitions.get("newContextName").cloned()
-                }
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("themes")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Themes(themes_definitions) => {
+                        themes_definitions.get("newContextName").cloned()
+                    }
+                    _ => None,
+                });
 
         assert!(result.is_some());
         let themes = result.unwrap();
@@ -361,16 +379,18 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_themes_with_empty_themes_data() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let nenyr_themes = NenyrThemes {
             light_schema: Some(NenyrVariables {
                 values: IndexMap::new(),
@@ -388,14 +408,16 @@ mod tests {
             )
             .await;
 
-        let result = STYLITRON
-            .get("themes")
-            .and_then(|stylitron_data| match &*stylitron_data {
-                Stylitron::Themes(themes_definitions) => {
-                    themes_definitions.get("emptyThemesContext").cloned()
-                }
-                _ => None,
-            });
+        let result =
+            session
+                .stylitron
+                .get("themes")
+                .and_then(|stylitron_data| match &*stylitron_data {
+                    Stylitron::Themes(themes_definitions) => {
+                        themes_definitions.get("emptyThemesContext").cloned()
+                    }
+                    _ => None,
+                });
 
         assert!(result.is_some());
 
@@ -411,19 +433,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_themes_no_themes_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("themes");
+        session.stylitron.remove("themes");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let nenyr_themes = NenyrThemes {
             light_schema: Some(NenyrVariables {
                 values: mock_light_variables(),
@@ -3,7 +3,6 @@ use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Stylitron,
@@ -25,13 +24,14 @@ impl Crealion {
         typefaces_data: IndexMap<String, String>,
     ) -> JoinHandle<()> {
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
 
         // Spawn a blocking task to safely update the STYLITRON AST.
         tokio::task::spawn_blocking(move || {
             tracing::info!("Starting the process to apply typefaces to the STYLITRON AST.");
 
             // Attempt to access the `typefaces` section within the STYLITRON AST.
-            let mut stylitron_data = match STYLITRON.get_mut("typefaces") {
+            let mut stylitron_data = match stylitron.get_mut("typefaces") {
                 Some(data) => {
                     tracing::debug!(
                         "Successfully accessed the `typefaces` section in STYLITRON AST."
@@ -89,7 +89,9 @@ mod tests {
     use nenyr::types::{ast::NenyrAst, central::CentralContext};
     use tokio::sync::broadcast;
 
-    use crate::{asts::STYLITRON, crealion::Crealion, events::GaladrielAlerts, types::Stylitron};
+    use crate::{
+        asts::CompilationSession, crealion::Crealion, events::GaladrielAlerts, types::Stylitron,
+    };
 
     fn mock_typefaces() -> IndexMap<String, String> {
         IndexMap::from([
@@ -114,19 +116,24 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_typefaces_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_typefaces_to_stylitron(mock_typefaces())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("typefaces")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Typefaces(typefaces_definitions) => {
@@ -144,7 +151,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_typefaces_to_existing_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
@@ -152,19 +159,24 @@ mod tests {
         let initial_data =
             IndexMap::from([("animeName".to_string(), "animation-name".to_string())]);
 
-        STYLITRON.insert("typefaces".to_string(), Stylitron::Typefaces(initial_data));
+        session
+            .stylitron
+            .insert("typefaces".to_string(), Stylitron::Typefaces(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_typefaces_to_stylitron(mock_typefaces())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("typefaces")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Typefaces(typefaces_definitions) => Some(typefaces_definitions.clone()),
@@ -180,25 +192,30 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_typefaces_to_new_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert("typefaces".to_string(), Stylitron::Typefaces(initial_data));
+        session
+            .stylitron
+            .insert("typefaces".to_string(), Stylitron::Typefaces(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_typefaces_to_stylitron(mock_typefaces())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("typefaces")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Typefaces(typefaces_definitions) => Some(typefaces_definitions.clone()),
@@ -214,22 +231,25 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_typefaces_with_empty_typefaces_data() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let empty_typefaces: IndexMap<String, String> = IndexMap::new();
         let _ = crealion
             .apply_typefaces_to_stylitron(empty_typefaces.clone())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("typefaces")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Typefaces(typefaces_definitions) => Some(typefaces_definitions.clone()),
@@ -246,19 +266,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_typefaces_no_typefaces_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("typefaces");
+        session.stylitron.remove("typefaces");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .apply_typefaces_to_stylitron(mock_typefaces())
             .await;
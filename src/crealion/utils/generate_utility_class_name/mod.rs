@@ -1,8 +1,14 @@
-use crate::crealion::utils::generate_prefix::generate_prefix;
+use crate::{
+    configatron::get_class_name_hash_length,
+    crealion::{
+        processors::breakpoints::PRINT_BREAKPOINT_NAME, utils::generate_prefix::generate_prefix,
+    },
+};
 
 use super::generate_abbreviation::generate_abbreviation;
 
 pub fn generate_utility_class_name(
+    class_prefix: &str,
     breakpoint: &Option<String>,
     is_important: bool,
     pattern: &str,
@@ -10,6 +16,8 @@ pub fn generate_utility_class_name(
     value: &str,
 ) -> String {
     let abbr_breakpoint = match breakpoint {
+        // Print utilities keep the full name, so they are easily told apart from screen ones.
+        Some(value) if value == PRINT_BREAKPOINT_NAME => format!("{}\\.", value),
         Some(value) => format!("{}\\.", generate_abbreviation(value)),
         None => "".to_string(),
     };
@@ -25,10 +33,10 @@ pub fn generate_utility_class_name(
     };
 
     let abbr_property = generate_abbreviation(property);
-    let value_prefix = generate_prefix(value, false, 4);
+    let value_prefix = generate_prefix(value, false, get_class_name_hash_length());
 
     format!(
-        "{}{}{}{}-{}",
-        abbr_breakpoint, importance_prefix, abbr_pattern, abbr_property, value_prefix
+        "{}{}{}{}{}-{}",
+        class_prefix, abbr_breakpoint, importance_prefix, abbr_pattern, abbr_property, value_prefix
     )
 }
@@ -3,7 +3,6 @@ use indexmap::IndexMap;
 use tokio::task::JoinHandle;
 
 use crate::{
-    asts::STYLITRON,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     types::Stylitron,
@@ -28,6 +27,7 @@ impl Crealion {
         variables_data: IndexMap<String, String>,
     ) -> JoinHandle<()> {
         let sender = self.sender.clone();
+        let stylitron = self.session.stylitron.clone();
 
         // Spawn a blocking task to process the variables.
         tokio::task::spawn_blocking(move || {
@@ -38,7 +38,7 @@ impl Crealion {
             );
 
             // Attempt to retrieve the "variables" section from the STYLITRON AST.
-            let mut stylitron_data = match STYLITRON.get_mut("variables") {
+            let mut stylitron_data = match stylitron.get_mut("variables") {
                 Some(data) => {
                     tracing::debug!(
                         "Successfully accessed the `variables` section in STYLITRON AST."
@@ -147,7 +147,7 @@ mod tests {
     use tokio::sync::broadcast;
 
     use crate::{
-        asts::STYLITRON,
+        asts::CompilationSession,
         crealion::{
             utils::generates_variable_or_animation_name::generates_variable_or_animation_name,
             Crealion,
@@ -182,19 +182,24 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_variables_success() {
+        let session = CompilationSession::new();
+
         let (sender, _) = broadcast::channel(10);
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .process_variables("myContextName1".to_string(), mock_variables())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("variables")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Variables(variables_definitions) => variables_definitions
@@ -213,7 +218,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_variables_to_existing_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
@@ -226,19 +231,24 @@ mod tests {
             )]),
         )]);
 
-        STYLITRON.insert("variables".to_string(), Stylitron::Variables(initial_data));
+        session
+            .stylitron
+            .insert("variables".to_string(), Stylitron::Variables(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .process_variables("myContextName3".to_string(), mock_variables())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("variables")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Variables(variables_definitions) => {
@@ -257,25 +267,30 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_variables_to_new_context() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let session = CompilationSession::new();
 
         let (sender, _) = broadcast::channel(10);
 
         // Ensure no existing context in the STYLITRON AST.
         let initial_data = IndexMap::new();
-        STYLITRON.insert("variables".to_string(), Stylitron::Variables(initial_data));
+        session
+            .stylitron
+            .insert("variables".to_string(), Stylitron::Variables(initial_data));
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender,
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .process_variables("newContextName".to_string(), mock_variables())
             .await;
 
-        let result = STYLITRON
+        let result = session
+            .stylitron
             .get("variables")
             .and_then(|stylitron_data| match &*stylitron_data {
                 Stylitron::Variables(variables_definitions) => {
@@ -294,19 +309,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_apply_variables_no_variables_section() {
-        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        let session = CompilationSession::new();
 
         let (sender, mut receiver) = broadcast::channel(10);
 
         // Simulate an empty STYLITRON AST to trigger an error.
-        STYLITRON.remove("variables");
+        session.stylitron.remove("variables");
 
-        let crealion = Crealion::new(
+        let mut crealion = Crealion::new(
             sender.clone(),
             NenyrAst::CentralContext(CentralContext::new()),
             "".to_string(),
         );
 
+        crealion.set_session(session.clone());
+
         let _ = crealion
             .process_variables("noVariablesSection".to_string(), mock_variables())
             .await;
@@ -478,12 +478,29 @@ pub enum ErrorKind {
     CurrentWorkingDirRetrievalFailed,
     MissingGaladrielModeError,
     InvalidGaladrielModeError,
+    InvalidBuildOptionError,
+    InvalidConfigOptionError,
+    InvalidCheckOptionError,
+    InvalidSchemaOptionError,
+    InvalidDumpAstOptionError,
+    InvalidStartOptionError,
     TracingSubscriberInitializationFailed,
     ProcessInitializationFailed,
+    UnreachableImportsError,
+    InvalidGeneratedCssError,
+    MaxWarningsExceededError,
     ConfigFileReadError,
     ConfigFileParsingError,
+    ConfigValueOutOfRangeError,
+    InvalidClassPrefixError,
+    InvalidBuildJsonNameError,
+    UnknownConfigProfileError,
     ExcludeMatcherCreationError,
     ExcludeMatcherBuildFailed,
+    WatchExtraMatcherCreationError,
+    WatchExtraMatcherBuildFailed,
+    ContentMatcherCreationError,
+    ContentMatcherBuildFailed,
     TerminalRawModeActivationFailed,
     TerminalRawModeDeactivationFailed,
     EnterTerminalAltScreenMouseCaptureFailed,
@@ -494,6 +511,7 @@ pub enum ErrorKind {
     TerminalWidgetRenderingError,
     TerminalInitializationFailed,
     TerminalEventReceiveFailed,
+    ClipboardCopyFailed,
     SocketAddressBindingError,
     ServerEventReceiveFailed,
     ServerPortRegistrationFailed,
@@ -521,6 +539,7 @@ pub enum ErrorKind {
     GaladrielConfigOpenFileError,
     GaladrielConfigSerdeSerializationError,
     GaladrielConfigFileWriteError,
+    BuildCacheSerializationError,
     OpenFileError,
     FileWriteError,
     FileCreationError,
@@ -532,7 +551,10 @@ pub enum ErrorKind {
     AccessDeniedToStylitronAST,
     AccessDeniedToClassinatorAST,
     ContextNameConflict,
+    FrozenCentralNameRedefinition,
     ServerBidingError,
+    ContextExtensionDepthExceeded,
+    CyclicContextExtension,
     Other,
 }
 
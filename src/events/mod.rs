@@ -52,6 +52,12 @@ pub enum GaladrielAlerts {
         content: Vec<String>,
         kind: AlertTextType,
     },
+    /// Reports that the CSS cache was updated, along with the contexts whose styles changed.
+    /// It is not displayed, but forwarded to the clients connected to the HMR socket.
+    StylesUpdated {
+        start_time: DateTime<Local>,
+        contexts: Vec<String>,
+    },
 }
 
 #[allow(dead_code)]
@@ -112,4 +118,38 @@ impl GaladrielAlerts {
             shortcuts,
         }
     }
+
+    pub fn create_styles_updated(start_time: DateTime<Local>, contexts: Vec<String>) -> Self {
+        GaladrielAlerts::StylesUpdated {
+            start_time,
+            contexts,
+        }
+    }
+
+    /// Checks whether the message or title of the alert contains the given query, ignoring case.
+    ///
+    /// Nenyr errors are also matched against the path of the context they were raised for.
+    pub fn matches_query(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        let contains = |text: &str| text.to_lowercase().contains(&query);
+
+        match self {
+            GaladrielAlerts::Success { message, .. }
+            | GaladrielAlerts::Information { message, .. }
+            | GaladrielAlerts::Warning { message, .. } => contains(message),
+            GaladrielAlerts::NenyrError { error, .. } => {
+                contains(&error.get_error_message()) || contains(&error.get_context_path())
+            }
+            GaladrielAlerts::GaladrielError { error, .. } => contains(&error.get_message()),
+            GaladrielAlerts::Shortcuts { shortcuts, .. } => shortcuts
+                .iter()
+                .any(|(keys, description)| contains(keys) || contains(description)),
+            GaladrielAlerts::Text { title, content, .. } => {
+                contains(title) || content.iter().any(|line| contains(line))
+            }
+            GaladrielAlerts::StylesUpdated { contexts, .. } => {
+                contexts.iter().any(|context| contains(context))
+            }
+        }
+    }
 }
@@ -5,6 +5,7 @@ use nenyr::NenyrParser;
 use tokio::sync::broadcast;
 
 use crate::{
+    asts::CompilationSession,
     crealion::{Crealion, CrealionContextType},
     error::GaladrielError,
     events::GaladrielAlerts,
@@ -30,6 +31,7 @@ use crate::{
 /// - `nenyr_parser`: A mutable reference to the `NenyrParser` that will be used for parsing the file.
 /// - `starting_time`: The starting time of the parsing process, used for logging purposes.
 /// - `palantir_sender`: The `broadcast::Sender` that will be used to send notifications.
+/// - `session`: The session the parsed context is written into.
 ///
 /// # Returns
 /// A tuple with two elements:
@@ -40,6 +42,7 @@ pub async fn formera(
     nenyr_parser: &mut NenyrParser,
     starting_time: DateTime<Local>,
     palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    session: &CompilationSession,
 ) -> (Option<CrealionContextType>, Option<Vec<String>>) {
     // Convert path to a string.
     let stringified_path = current_path.to_string_lossy().to_string();
@@ -52,6 +55,7 @@ pub async fn formera(
         &stringified_path,
         nenyr_parser,
         palantir_sender.clone(),
+        session,
     )
     .await;
 
@@ -108,6 +112,7 @@ pub async fn formera(
 /// - `stringified_path`: The string representation of the file path for logging purposes.
 /// - `nenyr_parser`: A mutable reference to the `NenyrParser` that will perform the parsing.
 /// - `palantir_sender`: The `broadcast::Sender` to send any notifications.
+/// - `session`: The session the created styles are written into.
 ///
 /// # Returns
 /// A `GaladrielResult` containing a tuple:
@@ -118,6 +123,7 @@ async fn run_parsing(
     stringified_path: &str,
     nenyr_parser: &mut NenyrParser,
     palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    session: &CompilationSession,
 ) -> GaladrielResult<(Option<CrealionContextType>, Option<Vec<String>>)> {
     tracing::info!("Reading raw content of Nenyr file: {:?}", stringified_path);
 
@@ -149,6 +155,8 @@ async fn run_parsing(
         stringified_path.to_string(),
     );
 
+    crealion.set_session(session.clone());
+
     tracing::info!(
         "Creating styles from the parsed context: {:?}",
         stringified_path
@@ -73,14 +73,33 @@
 //!
 //! For further integration details, refer to the specific methods and functions documented in the module, which provide advanced features for managing contexts, variables, animations, and other styling elements within `Galadriel CSS`.
 
-use std::{io::Stdout, net::SocketAddr, path::PathBuf, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    io::Stdout,
+    net::SocketAddr,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use astroform::Astroform;
 use baraddur::Baraddur;
 use chrono::Local;
 use configatron::{
-    construct_exclude_matcher, get_minified_styles, get_port, load_galadriel_configs,
-    switch_auto_naming, switch_minified_styles, switch_reset_styles, transform_configatron_to_json,
+    carry_config_profiles, check_duplicated_config_files, construct_exclude_matcher,
+    diff_configatron_from_defaults, generate_configuration_schema, get_build_json_name,
+    get_classes_json, get_content_globs, get_idle_timeout_minutes, get_mangle_names,
+    get_minified_styles, get_port, get_reset_styles, get_source_maps, get_split_responsive,
+    get_split_themes, load_galadriel_configs, resolve_config_file, restore_configatron,
+    switch_auto_naming, switch_minified_styles, switch_plain_alerts, switch_relative_error_paths,
+    switch_reset_styles, transform_configatron_to_json, transform_configatron_to_toml,
+    ConfigFormat,
+};
+use crealion::{
+    features::enable_feature_filtering,
+    profiler::{enable_classes_profiling, take_slowest_classes},
 };
+use dashmap::DashMap;
 use error::{ErrorAction, ErrorKind, GaladrielError};
 use events::{GaladrielAlerts, GaladrielEvents};
 use ignore::overrides;
@@ -89,12 +108,13 @@ use palantir::Palantir;
 use ratatui::prelude::CrosstermBackend;
 use shellscape::{
     app::ShellscapeApp, commands::ShellscapeCommands, events::ShellscapeTerminalEvents,
-    ui::ShellscapeInterface, Shellscape,
+    status_layer::ShellscapeStatusLayer, ui::ShellscapeInterface, Shellscape,
 };
 use synthesizer::Synthesizer;
 use tokio::{
     net::TcpListener,
-    sync::{broadcast, RwLock},
+    sync::{broadcast, oneshot, RwLock},
+    task::JoinHandle,
 };
 use tracing::Level;
 use tracing_appender::rolling;
@@ -102,9 +122,35 @@ use tracing_subscriber::{
     fmt, layer::SubscriberExt, util::SubscriberInitExt, FmtSubscriber, Layer,
 };
 use utils::{
-    get_updated_css::get_updated_css, replace_file::replace_file,
+    activity_tracker::{elapsed_since_last_activity, record_activity},
+    build_json_name::render_build_json_name,
+    build_progress::{
+        emit_progress_event, enable_json_progress, get_build_phase, get_failed_files_count,
+        get_processed_files_count, is_json_progress_enabled, print_report_line, set_build_phase,
+    },
+    build_stats::{collect_build_stats, format_build_stats},
+    classes_changelog::{diff_classes_snapshots, snapshot_classes_tracking},
+    context_partitions::collect_context_partitions,
+    copy_to_clipboard::copy_to_clipboard,
+    css_data_uri::css_data_uri,
+    find_unused_classes::{find_unused_classes, warn_unused_classes},
+    get_updated_css::{
+        get_split_responsive_sheets, get_split_theme_sheets, get_updated_css,
+        get_updated_source_map, take_context_sheet, take_context_source_map,
+    },
+    notify_build_webhook::notify_build_webhook,
+    replace_file::replace_file,
     restore_abstract_syntax_trees::restore_abstract_syntax_trees,
-    serialize_classes_tracking::serialize_classes_tracking, write_file::write_file,
+    send_palantir_notification::send_palantir_notification,
+    serialize_classes_js_styles::serialize_classes_js_styles,
+    serialize_classes_tracking::serialize_classes_tracking,
+    serialize_classes_typings::serialize_classes_typings,
+    serialize_stylitron_ast::serialize_stylitron_ast,
+    serialize_variable_map::serialize_variable_map,
+    stats_badge::stats_badge,
+    validate_css::validate_css,
+    verify_imports::find_unreachable_imports,
+    write_file::write_file,
 };
 
 mod astroform;
@@ -126,175 +172,1487 @@ mod trailblazer;
 mod types;
 mod utils;
 
+pub use asts::CompilationSession;
+pub use utils::collect_css_rules::{collect_css_rules, CssDeclaration, CssRule};
+pub use utils::compile_str::{compile_str, compile_str_in_session, CompileOptions};
+pub use utils::variable_dependents::{
+    collect_variable_dependents, collect_variable_dependents_in_session,
+};
+
 /// Represents the runtime modes of Galadriel CSS.
 #[derive(Clone, PartialEq, Debug)]
 pub enum GaladrielRuntimeKind {
     /// Development mode for real-time updates and testing.
-    Development,
+    Development {
+        /// The changes are only reported, without writing any file or updating the CSS cache.
+        dry_run: bool,
+        /// The latest log message is displayed in the footer of the terminal UI.
+        verbose: bool,
+        /// Every Nenyr file is parsed at startup, ignoring the build cache.
+        no_cache: bool,
+    },
     /// Build mode for compiling and generating production-ready outputs.
     Build,
+    /// Config diff mode for listing the configurations that differ from their defaults.
+    ConfigDiff,
+    /// Schema mode for printing the JSON Schema of the configuration file.
+    Schema,
+    /// Dump AST mode for writing the STYLITRON AST of the processed project to `.galadrielcss/ast.json`.
+    DumpAst,
+    /// Check mode for processing the project without writing any output.
+    Check {
+        /// The imported URLs are verified to be reachable, and the local imports to exist.
+        verify_imports: bool,
+    },
+}
+
+/// Represents the options accepted by the build mode.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GaladrielBuildOptions {
+    /// Only the Nenyr classes tracking maps are written, skipping the CSS generation.
+    pub classes_only: bool,
+    /// Name of the configuration profile merged over the base configurations, if requested.
+    pub profile: Option<String>,
+    /// Path where the generated CSS is written as a plain stylesheet, if requested.
+    pub emit_css: Option<PathBuf>,
+    /// Path where the TypeScript typings of the Nenyr classes are written, if requested.
+    pub emit_dts: Option<PathBuf>,
+    /// Path where the resolved styles of the Nenyr classes are written as a CSS-in-JS module, if requested.
+    pub emit_js_styles: Option<PathBuf>,
+    /// The slowest Nenyr classes to resolve are reported at the end of the build.
+    pub profile_classes: bool,
+    /// Path where the minified CSS is written as a base64 data URI, if requested. A `-` path prints it to stdout.
+    pub data_uri: Option<PathBuf>,
+    /// The Nenyr and utility classes changed since the previous build are written to `.galadrielcss/changes.json`.
+    pub changelog: bool,
+    /// The generated CSS is parsed before being written, failing the build on any syntax error.
+    pub validate_css: bool,
+    /// Path where a shields.io badge with the CSS size and the Nenyr classes count is written, if requested.
+    pub emit_badge: Option<PathBuf>,
+    /// A stylesheet is also written per context into `.galadrielcss`, along with a manifest mapping each context to its file.
+    pub split_contexts: bool,
+    /// Path of a Nenyr file processed as the central context instead of the project's one, if requested.
+    pub central: Option<PathBuf>,
+    /// Project directories built in sequence by a single invocation. When empty, only the working directory is built.
+    pub project_dirs: Vec<PathBuf>,
+    /// Highest number of warnings allowed across the build before it fails, if requested.
+    pub max_warnings: Option<usize>,
+    /// Feature flags enabled for the build. The contexts and classes depending on any other flag are left out.
+    pub features: Vec<String>,
+    /// A report of the CSS size and the utility classes and animations counts is printed and written to `.galadrielcss/stats.json`.
+    pub stats: bool,
+    /// Seconds after which a hung build is aborted, if requested.
+    pub timeout: Option<u64>,
+    /// Path where the theme variables are written as a standalone stylesheet, if requested.
+    pub emit_themes: Option<PathBuf>,
+    /// The build progress is streamed to stdout as newline-delimited JSON events, for IDE integrations.
+    pub json_progress: bool,
+    /// The variables of every context are written to `.galadrielcss/variables.json`, along with their resolved values.
+    pub emit_variable_map: bool,
+}
+
+/// A result type specific to Galadriel CSS operations, wrapping standard Rust results with `GaladrielError`.
+pub type GaladrielResult<T> = Result<T, GaladrielError>;
+
+/// Exit code of a build aborted by the `--timeout` option, the same one used by the `timeout` command.
+const BUILD_TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Number of Nenyr classes listed by the `--profile-classes` build report.
+const PROFILED_CLASSES_LIMIT: usize = 10;
+
+/// Represents the runtime environment for Galadriel CSS.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GaladrielRuntime {
+    /// The mode in which the runtime is operating.
+    runtime_mode: GaladrielRuntimeKind,
+    /// The working directory for file operations and configurations.
+    working_dir: PathBuf,
+    /// The options applied when running in build mode.
+    build_options: GaladrielBuildOptions,
 }
 
-/// A result type specific to Galadriel CSS operations, wrapping standard Rust results with `GaladrielError`.
-pub type GaladrielResult<T> = Result<T, GaladrielError>;
+impl GaladrielRuntime {
+    /// Creates a new Galadriel runtime instance with the specified mode and working directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `runtime_mode` - The runtime mode (Development, Build, ConfigDiff, Schema, DumpAst, or Check).
+    /// * `working_dir` - The directory used for runtime operations.
+    /// * `build_options` - The options applied when running in build mode.
+    pub fn new(
+        runtime_mode: GaladrielRuntimeKind,
+        working_dir: PathBuf,
+        build_options: GaladrielBuildOptions,
+    ) -> Self {
+        Self {
+            runtime_mode,
+            working_dir,
+            build_options,
+        }
+    }
+
+    /// Executes the runtime logic based on the current runtime mode.
+    ///
+    /// # Returns
+    ///
+    /// A `GaladrielResult` indicating success or failure.
+    pub async fn run(&mut self) -> GaladrielResult<()> {
+        match self.runtime_mode {
+            GaladrielRuntimeKind::Development {
+                dry_run,
+                verbose,
+                no_cache,
+            } => {
+                self.start_development_mode(dry_run, verbose, no_cache)
+                    .await
+            }
+            GaladrielRuntimeKind::Build => self.start_build_mode().await,
+            GaladrielRuntimeKind::ConfigDiff => self.start_config_diff_mode().await,
+            GaladrielRuntimeKind::Schema => self.start_schema_mode(),
+            GaladrielRuntimeKind::DumpAst => self.start_dump_ast_mode().await,
+            GaladrielRuntimeKind::Check { verify_imports } => {
+                self.start_check_mode(verify_imports).await
+            }
+        }
+    }
+
+    /// Prints the JSON Schema of the `galadriel.config.json` file to the standard output.
+    fn start_schema_mode(&self) -> GaladrielResult<()> {
+        println!("{}", generate_configuration_schema()?);
+
+        Ok(())
+    }
+
+    /// Processes the Nenyr files of the working directory, then writes every section of the STYLITRON
+    /// AST to `.galadrielcss/ast.json`, so the resolved styles can be inspected or attached to bug reports.
+    async fn start_dump_ast_mode(&mut self) -> GaladrielResult<()> {
+        self.init_stderr_logs_subscriber()?;
+
+        tracing::info!("Starting dump AST mode.");
+
+        let palantir_alerts = Palantir::new();
+        let palantir_sender = palantir_alerts.get_palantir_sender();
+        let _start_alert_watcher = palantir_alerts.start_alert_watcher(true);
+
+        load_galadriel_configs(&self.working_dir, None).await?;
+
+        let matcher = construct_exclude_matcher(&self.working_dir)?;
+        let atomically_matcher = Arc::new(RwLock::new(matcher));
+
+        // The CSS is not needed, since only the ASTs are dumped.
+        Synthesizer::new(
+            true,
+            atomically_matcher,
+            palantir_sender,
+            CompilationSession::global(),
+        )
+        .set_styles_emission(false)
+        .process(true, &self.working_dir)
+        .await;
+
+        let folder_path = self.working_dir.join(".galadrielcss");
+        let ast_path = folder_path.join("ast.json");
+
+        write_file(
+            folder_path,
+            ast_path.clone(),
+            serialize_stylitron_ast(),
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        print_report_line(&format!("The STYLITRON AST was written to {:?}.", ast_path));
+
+        Ok(())
+    }
+
+    /// Prints the configurations of the working directory that differ from their defaults,
+    /// with the default and current values side by side.
+    async fn start_config_diff_mode(&mut self) -> GaladrielResult<()> {
+        // Load the galadriel configurations.
+        load_galadriel_configs(&self.working_dir, None).await?;
+
+        let differences = diff_configatron_from_defaults()?;
+
+        if differences.is_empty() {
+            print_report_line("No configuration differs from its default.");
+
+            return Ok(());
+        }
+
+        let rows = differences
+            .into_iter()
+            .map(|(config_name, default_value, current_value)| {
+                (
+                    config_name,
+                    default_value.to_string(),
+                    current_value.to_string(),
+                )
+            })
+            .collect::<Vec<(String, String, String)>>();
+
+        // Align the columns to the widest entry of each one.
+        let name_width = rows
+            .iter()
+            .map(|(config_name, _, _)| config_name.len())
+            .fold("Configuration".len(), usize::max);
+        let default_width = rows
+            .iter()
+            .map(|(_, default_value, _)| default_value.len())
+            .fold("Default".len(), usize::max);
+
+        print_report_line(&format!(
+            "{:<name_width$}  {:<default_width$}  {}",
+            "Configuration", "Default", "Current"
+        ));
+
+        for (config_name, default_value, current_value) in rows {
+            print_report_line(&format!(
+                "{:<name_width$}  {:<default_width$}  {}",
+                config_name, default_value, current_value
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Starts the development mode, setting up the environment and logging.
+    async fn start_development_mode(
+        &mut self,
+        dry_run: bool,
+        verbose: bool,
+        no_cache: bool,
+    ) -> GaladrielResult<()> {
+        // ===================================================================================================================
+        // Creates the development logs subscriber.
+        // Generates a subscriber for logging events to a file.
+        //
+        // This creates a log subscriber that writes logs to a file using the `tracing` library.
+        // It sets up a rolling file appender with a log filename generated from `generate_log_filename`.
+        // The subscriber is configured to log events with a severity level of `TRACE` or higher.
+        // ===================================================================================================================
+        let file_name = self.generate_log_filename(); // Generate the log filename.
+        let file_appender = rolling::never("logs", file_name); // Create a rolling file appender that writes logs to the specified file.
+        let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender); // Set up non-blocking log writing.
+
+        // In verbose mode, the log messages are also forwarded to the footer of the terminal UI.
+        let (status_layer, status_receiver) = if verbose {
+            let (status_layer, status_receiver) = ShellscapeStatusLayer::new();
+
+            (Some(status_layer), Some(status_receiver))
+        } else {
+            (None, None)
+        };
+
+        // Build and return the log subscriber.
+        let subscriber = FmtSubscriber::builder()
+            .with_max_level(Level::TRACE) // Set the maximum log level to TRACE.
+            .with_writer(non_blocking) // Use the non-blocking writer.
+            .finish() // Finalize the subscriber configuration.
+            .with(status_layer);
+
+        tracing::debug!("Log subscriber built and configured.");
+
+        // Set logs subscriber.
+        tracing::subscriber::set_global_default(subscriber).map_err(|err| {
+            tracing::error!("Failed to set log subscriber: {:?}", err.to_string());
+
+            GaladrielError::raise_critical_runtime_error(
+                ErrorKind::TracingSubscriberInitializationFailed,
+                &err.to_string(),
+                ErrorAction::Exit,
+            )
+        })?;
+
+        tracing::info!("Log subscriber set successfully.");
+
+        // Configure the development runtime environment.
+        self.configure_development_environment(dry_run, no_cache, status_receiver)
+            .await
+    }
+
+    /// Processes the Nenyr files of the working directory without writing any output, so their
+    /// issues are reported before a build. When requested, the imports are verified as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_imports` - Whether the remote imports are requested and the local ones existence-checked.
+    async fn start_check_mode(&mut self, verify_imports: bool) -> GaladrielResult<()> {
+        self.init_stderr_logs_subscriber()?;
+
+        tracing::info!("Starting check mode.");
+
+        let palantir_alerts = Palantir::new();
+        let palantir_sender = palantir_alerts.get_palantir_sender();
+        let _start_alert_watcher = palantir_alerts.start_alert_watcher(true);
+
+        load_galadriel_configs(&self.working_dir, None).await?;
+
+        if let Some(notification) = check_duplicated_config_files(&self.working_dir) {
+            send_palantir_notification(notification, palantir_sender.clone());
+        }
+
+        let matcher = construct_exclude_matcher(&self.working_dir)?;
+        let atomically_matcher = Arc::new(RwLock::new(matcher));
+
+        // The styles are not emitted, as nothing is written by the check.
+        Synthesizer::new(
+            true,
+            atomically_matcher,
+            palantir_sender,
+            CompilationSession::global(),
+        )
+        .set_styles_emission(false)
+        .process(true, &self.working_dir)
+        .await;
+
+        tracing::info!("Nenyr file processing finished.");
+
+        if !verify_imports {
+            return Ok(());
+        }
+
+        let failed_imports = find_unreachable_imports(&self.working_dir).await;
+
+        if failed_imports.is_empty() {
+            print_report_line("Every import is reachable.");
+
+            return Ok(());
+        }
+
+        for (import_url, reason) in &failed_imports {
+            eprintln!("Error: The `{}` import {}", import_url, reason);
+        }
+
+        Err(GaladrielError::raise_general_runtime_error(
+            ErrorKind::UnreachableImportsError,
+            &format!("{} imports could not be verified.", failed_imports.len()),
+            ErrorAction::Exit,
+        ))
+    }
+
+    /// Sets a logs subscriber writing only the errors to the standard error, used by the non-interactive modes.
+    fn init_stderr_logs_subscriber(&self) -> GaladrielResult<()> {
+        let subscriber = tracing_subscriber::registry().with(
+            fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_filter(tracing_subscriber::filter::LevelFilter::ERROR),
+        );
+
+        subscriber.try_init().map_err(|err| {
+            tracing::error!("Failed to set log subscriber: {:?}", err.to_string());
+
+            GaladrielError::raise_critical_runtime_error(
+                ErrorKind::TracingSubscriberInitializationFailed,
+                &err.to_string(),
+                ErrorAction::Exit,
+            )
+        })
+    }
+
+    /// Starts the build mode, processing styles and generating the final output.
+    async fn start_build_mode(&mut self) -> GaladrielResult<()> {
+        // Creates and starts the build logs subscriber.
+        self.init_stderr_logs_subscriber()?;
+
+        tracing::info!("Starting build mode.");
+
+        // Initialize the Palantir alerts system.
+        let palantir_alerts = Palantir::new();
+        let palantir_sender = palantir_alerts.get_palantir_sender(); // Retrieve the Palantir sender from the palantir_alerts instance. This sender is used to send alerts to Palantir.
+        let _start_alert_watcher = palantir_alerts.start_alert_watcher(true); // Start the alert watcher using the palantir_alerts instance. This likely begins observing for new alerts or events.
+        let _start_alert_log = palantir_alerts.start_alert_log(self.get_alert_log_path()); // Append every alert to the alert log, for post-mortem inspection.
+
+        tracing::info!("Initialized Palantir alerts system.");
+
+        // Builds the working directory, unless a list of project directories was provided.
+        let project_dirs = if self.build_options.project_dirs.is_empty() {
+            vec![self.working_dir.clone()]
+        } else {
+            self.build_options.project_dirs.clone()
+        };
+
+        if self.build_options.profile_classes {
+            enable_classes_profiling();
+        }
+
+        // Only the flagged styles whose feature flags were all enabled are shipped by the build.
+        enable_feature_filtering(self.build_options.features.clone());
+
+        if self.build_options.json_progress {
+            enable_json_progress();
+        }
+
+        let build_started_at = Instant::now();
+
+        emit_progress_event(serde_json::json!({
+            "event": "start",
+            "projects": project_dirs,
+        }));
+
+        let build_result = self.run_build(&project_dirs, palantir_sender).await;
+
+        emit_progress_event(serde_json::json!({
+            "event": "finish",
+            "status": if build_result.is_ok() { "ok" } else { "error" },
+            "durationMs": build_started_at.elapsed().as_millis() as u64,
+            "filesProcessed": get_processed_files_count(),
+            "filesFailed": get_failed_files_count(),
+            "cssBytes": get_updated_css().len(),
+        }));
+
+        build_result
+    }
+
+    /// Builds the project directories, within the timeout and the warnings threshold when requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_dirs` - The project directories to build.
+    /// * `palantir_sender` - The sender used to broadcast alerts during the build.
+    async fn run_build(
+        &self,
+        project_dirs: &[PathBuf],
+        palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    ) -> GaladrielResult<()> {
+        // The warnings of every project count towards the threshold, when one is requested.
+        let warnings_counter = self
+            .build_options
+            .max_warnings
+            .map(|max_warnings| (max_warnings, Self::start_warnings_counter(&palantir_sender)));
+
+        let build_projects = self.build_projects(project_dirs, palantir_sender.clone());
+
+        // A hung build is aborted once the timeout elapses, when one is requested.
+        match self.build_options.timeout {
+            Some(timeout) => {
+                match tokio::time::timeout(Duration::from_secs(timeout), build_projects).await {
+                    Ok(build_result) => build_result?,
+                    Err(_) => Self::abort_timed_out_build(timeout),
+                }
+            }
+            None => build_projects.await?,
+        }
+
+        if let Some((max_warnings, (stop_sender, warnings_counter))) = warnings_counter {
+            // Every warning has been broadcast by now, so the counter only drains the ones still queued.
+            let _ = stop_sender.send(());
+            let warnings_count = warnings_counter.await.unwrap_or_default();
+
+            eprintln!(
+                "{} warnings raised during the build, out of {} allowed.",
+                warnings_count, max_warnings
+            );
+
+            if warnings_count > max_warnings {
+                eprintln!(
+                    "Error: The build raised {} warnings, exceeding the maximum of {}.",
+                    warnings_count, max_warnings
+                );
+
+                return Err(GaladrielError::raise_general_runtime_error(
+                    ErrorKind::MaxWarningsExceededError,
+                    &format!(
+                        "The build raised {} warnings, exceeding the maximum of {}.",
+                        warnings_count, max_warnings
+                    ),
+                    ErrorAction::Exit,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds every project directory in sequence, stopping at the first failing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_dirs` - The project directories to build.
+    /// * `palantir_sender` - The sender used to broadcast alerts during the build.
+    async fn build_projects(
+        &self,
+        project_dirs: &[PathBuf],
+        palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    ) -> GaladrielResult<()> {
+        for (idx, project_dir) in project_dirs.iter().enumerate() {
+            // Every project starts from pristine ASTs and configurations, so no state leaks between them.
+            if idx > 0 {
+                restore_abstract_syntax_trees();
+                restore_configatron();
+            }
+
+            tracing::info!("Building project directory: {:?}", project_dir);
+
+            let build_result = self
+                .build_project(project_dir, palantir_sender.clone())
+                .await;
+
+            // The webhook is best-effort, so its outcome never changes the outcome of the build.
+            notify_build_webhook(build_result.is_ok()).await;
+
+            build_result?;
+
+            if self.build_options.profile_classes {
+                self.print_slowest_classes(project_dir);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reports the phase a timed out build was going through, then exits with the timeout exit code.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The seconds the build was allowed to run for.
+    fn abort_timed_out_build(timeout: u64) -> ! {
+        tracing::error!("Build aborted after {} seconds.", timeout);
+
+        eprintln!(
+            "Error: The build was aborted after exceeding the timeout of {} seconds.",
+            timeout
+        );
+        eprintln!("    Active phase: {}", get_build_phase());
+        eprintln!("    Nenyr files processed: {}", get_processed_files_count());
+
+        emit_progress_event(serde_json::json!({
+            "event": "finish",
+            "status": "timeout",
+            "durationMs": timeout * 1000,
+            "filesProcessed": get_processed_files_count(),
+            "filesFailed": get_failed_files_count(),
+        }));
+
+        std::process::exit(BUILD_TIMEOUT_EXIT_CODE);
+    }
+
+    /// Starts counting the warnings broadcast to Palantir, until signaled to stop.
+    ///
+    /// # Arguments
+    ///
+    /// * `palantir_sender` - The sender the warnings are broadcast through.
+    ///
+    /// # Returns
+    ///
+    /// * The sender stopping the counter, and the handle resolving to the number of warnings counted.
+    fn start_warnings_counter(
+        palantir_sender: &broadcast::Sender<GaladrielAlerts>,
+    ) -> (oneshot::Sender<()>, JoinHandle<usize>) {
+        let mut palantir_receiver = palantir_sender.subscribe();
+        let (stop_sender, mut stop_receiver) = oneshot::channel::<()>();
+
+        let is_warning = |notification: &GaladrielAlerts| {
+            matches!(notification, GaladrielAlerts::Warning { .. })
+        };
+
+        let warnings_counter = tokio::spawn(async move {
+            let mut warnings_count = 0;
+
+            loop {
+                tokio::select! {
+                    palantir_event = palantir_receiver.recv() => match palantir_event {
+                        Ok(notification) if is_warning(&notification) => warnings_count += 1,
+                        Ok(_) => {}
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            tracing::warn!("The warnings counter missed {} alerts.", skipped);
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = &mut stop_receiver => {
+                        while let Ok(notification) = palantir_receiver.try_recv() {
+                            if is_warning(&notification) {
+                                warnings_count += 1;
+                            }
+                        }
+
+                        break;
+                    }
+                }
+            }
+
+            warnings_count
+        });
+
+        (stop_sender, warnings_counter)
+    }
+
+    /// Prints the slowest Nenyr classes to resolve while building a project directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_dir` - The project directory that was just built.
+    fn print_slowest_classes(&self, project_dir: &PathBuf) {
+        let slowest_classes = take_slowest_classes(PROFILED_CLASSES_LIMIT);
+
+        if slowest_classes.is_empty() {
+            print_report_line(&format!(
+                "No Nenyr class was resolved in {}.",
+                project_dir.display()
+            ));
+
+            return;
+        }
+
+        print_report_line(&format!(
+            "Slowest Nenyr classes to resolve in {}:",
+            project_dir.display()
+        ));
+
+        for (idx, class_timing) in slowest_classes.iter().enumerate() {
+            print_report_line(&format!(
+                "{:>4}. `{}` in the `{}` context - {:.3}ms",
+                idx + 1,
+                class_timing.class_name,
+                class_timing.context_name,
+                class_timing.duration.as_secs_f64() * 1000.0
+            ));
+        }
+    }
+
+    /// Builds a single project directory, writing its final JSON into its own `.galadrielcss` folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `working_dir` - The root directory of the project being built.
+    /// * `palantir_sender` - The sender used to broadcast alerts during the build.
+    async fn build_project(
+        &self,
+        working_dir: &PathBuf,
+        palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    ) -> GaladrielResult<()> {
+        set_build_phase(
+            "config",
+            &format!("loading the configurations of {:?}", working_dir),
+        );
+
+        // Load the galadriel configurations.
+        load_galadriel_configs(working_dir, self.build_options.profile.as_deref()).await?;
+
+        tracing::debug!("Loaded Galadriel CSS configurations.");
+
+        if let Some(notification) = check_duplicated_config_files(working_dir) {
+            send_palantir_notification(notification, palantir_sender.clone());
+        }
+
+        // Exclude matcher for file system monitoring
+        let working_dir = working_dir.clone();
+        let matcher = construct_exclude_matcher(&working_dir)?; // Create an exclude matcher based on the working directory.
+        let atomically_matcher = Arc::new(RwLock::new(matcher)); // Wrap the matcher in an Arc and RwLock for thread-safe shared ownership and mutable access.
+
+        tracing::debug!("Created exclude matcher for working directory.");
+        tracing::info!("Started Nenyr file processing.");
+        set_build_phase(
+            "parse",
+            &format!("processing the Nenyr files of {:?}", working_dir),
+        );
+
+        let classes_only = self.build_options.classes_only;
+        let split_themes = !classes_only && get_split_themes(); // Themes are only split when the CSS is emitted.
+        let split_responsive = !classes_only && get_split_responsive(); // Likewise for the responsive styles.
+
+        // The session every stage of the build resolves into and writes from.
+        let session = CompilationSession::global();
+
+        // Start the build process for all Nenyr files.
+        Synthesizer::new(
+            true,
+            atomically_matcher,
+            palantir_sender.clone(),
+            session.clone(),
+        )
+        .set_styles_emission(!classes_only)
+        .set_themes_split(split_themes)
+        .set_responsive_split(split_responsive)
+        .set_names_mangling(get_mangle_names())
+        .set_central_override(self.build_options.central.to_owned())
+        .process(true, &working_dir)
+        .await;
+
+        tracing::info!("Nenyr file processing finished.");
+        set_build_phase(
+            "write",
+            &format!("writing the build outputs of {:?}", working_dir),
+        );
+
+        // Malformed CSS fails the build before anything is written, when the validation is requested.
+        if self.build_options.validate_css && !classes_only {
+            Self::validate_generated_css()?;
+        }
+
+        // The classes never referenced by the source files matched by `contentGlobs` are warned about.
+        let unused_classes = find_unused_classes(&working_dir)?;
+        warn_unused_classes(&unused_classes, palantir_sender.clone());
+
+        // Get the most up-to-dated Nenyr classes tracking maps
+        let tracking = serialize_classes_tracking();
+
+        // Formats the final json.
+        let folder_path = Self::resolve_output_folder(&working_dir, palantir_sender.clone()).await;
+        let final_json = if classes_only {
+            tracing::debug!("Retrieved updated class tracking maps, CSS generation skipped.");
+
+            format!("{{\"trackingClasses\": {}}}", tracking)
+        } else {
+            // Get the most up-to-dated CSS, without the split themes and responsive styles.
+            let css = get_updated_css();
+            let mut split_files = String::new();
+
+            if split_themes {
+                let theme_files = self.write_split_theme_sheets(&folder_path).await?;
+                split_files.push_str(&format!(", \"themes\": {}", theme_files));
+            }
+
+            if split_responsive {
+                let responsive_files = self.write_split_responsive_sheets(&folder_path).await?;
+                split_files.push_str(&format!(", \"responsive\": {}", responsive_files));
+            }
+
+            tracing::debug!("Retrieved updated CSS, split stylesheets and class tracking maps.");
+
+            format!(
+                "{{\"css\": {:?}{}, \"trackingClasses\": {}}}",
+                css, split_files, tracking
+            )
+        };
+
+        // The final json is named after the `buildJsonName` template, so the builds of several environments can sit side by side.
+        let final_json_name = render_build_json_name(
+            &get_build_json_name(),
+            self.build_options.profile.as_deref(),
+            &final_json,
+        );
+        let final_json_path = folder_path.join(&final_json_name);
+
+        // Creates the final json containing the CSS and Nenyr classes tracking map at root dir + `/.galadrielcss/galadrielcss.json` by default.
+        write_file(
+            folder_path.clone(),
+            final_json_path,
+            final_json,
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        // Writes the source map of the generated CSS next to the final json, and named after it, when enabled in the configurations.
+        if get_source_maps() && !classes_only {
+            let css_file = PathBuf::from(&final_json_name)
+                .with_extension("css")
+                .to_string_lossy()
+                .to_string();
+
+            if let Some(source_map) = get_updated_source_map(&css_file) {
+                write_file(
+                    folder_path.clone(),
+                    folder_path.join(format!("{}.map", css_file)),
+                    source_map,
+                    ErrorAction::Exit,
+                    ErrorKind::FileCreationError,
+                    ErrorKind::FileWriteError,
+                )
+                .await?;
+            }
+        }
+
+        // Writes the Nenyr classes tracking maps on their own, when enabled in the configurations.
+        if get_classes_json() {
+            write_file(
+                folder_path.clone(),
+                folder_path.join("classes.json"),
+                tracking,
+                ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
+            )
+            .await?;
+        }
+
+        // Writes the variable identifiers and their resolved values, when requested.
+        if self.build_options.emit_variable_map {
+            write_file(
+                folder_path.clone(),
+                folder_path.join("variables.json"),
+                serialize_variable_map(),
+                ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
+            )
+            .await?;
+        }
+
+        // Writes a stylesheet per context, when requested.
+        if self.build_options.split_contexts && !classes_only {
+            self.write_context_sheets(
+                &folder_path,
+                &session,
+                split_themes,
+                palantir_sender.clone(),
+            )
+            .await?;
+        }
+
+        // Writes the classes changed since the previous build, when requested.
+        if self.build_options.changelog {
+            self.write_classes_changelog(&folder_path).await?;
+        }
+
+        tracing::info!("Build process completed and final JSON file written.");
+
+        // Writes the generated CSS as a plain stylesheet at the requested path, relative to the working directory.
+        if let Some(css_path) = &self.build_options.emit_css {
+            if self.build_options.classes_only {
+                tracing::warn!(
+                    "The stylesheet is not emitted, since no CSS is generated with `--classes-only`."
+                );
+            } else {
+                let css_path = working_dir.join(css_path);
+                let css_folder = css_path
+                    .parent()
+                    .map(|parent| parent.to_path_buf())
+                    .unwrap_or_else(|| working_dir.clone());
+                let mut css = get_updated_css();
+                let css_file = css_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+
+                // The source map is written next to the stylesheet, which links to it.
+                if let Some(source_map) =
+                    get_updated_source_map(&css_file).filter(|_| get_source_maps())
+                {
+                    let map_file_name = format!("{}.map", css_file);
+
+                    css.push_str(&format!("\n/*# sourceMappingURL={} */", map_file_name));
+
+                    write_file(
+                        css_folder.clone(),
+                        css_path.with_file_name(map_file_name),
+                        source_map,
+                        ErrorAction::Exit,
+                        ErrorKind::FileCreationError,
+                        ErrorKind::FileWriteError,
+                    )
+                    .await?;
+                }
+
+                write_file(
+                    css_folder,
+                    css_path,
+                    css,
+                    ErrorAction::Exit,
+                    ErrorKind::FileCreationError,
+                    ErrorKind::FileWriteError,
+                )
+                .await?;
+
+                tracing::info!("Generated CSS written as a plain stylesheet.");
+            }
+        }
+
+        // Writes the TypeScript typings of the Nenyr classes at the requested path, relative to the working directory.
+        if let Some(dts_path) = &self.build_options.emit_dts {
+            let dts_path = working_dir.join(dts_path);
+            let dts_folder = dts_path
+                .parent()
+                .map(|parent| parent.to_path_buf())
+                .unwrap_or_else(|| working_dir.clone());
+
+            write_file(
+                dts_folder,
+                dts_path,
+                serialize_classes_typings(),
+                ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
+            )
+            .await?;
+
+            tracing::info!("TypeScript typings of the Nenyr classes written.");
+        }
+
+        // Writes the resolved styles of the Nenyr classes as a CSS-in-JS module at the requested path, relative to the working directory.
+        if let Some(js_styles_path) = &self.build_options.emit_js_styles {
+            let js_styles_path = working_dir.join(js_styles_path);
+            let js_styles_folder = js_styles_path
+                .parent()
+                .map(|parent| parent.to_path_buf())
+                .unwrap_or_else(|| working_dir.clone());
+
+            write_file(
+                js_styles_folder,
+                js_styles_path,
+                serialize_classes_js_styles(),
+                ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
+            )
+            .await?;
+
+            tracing::info!("CSS-in-JS styles of the Nenyr classes written.");
+        }
+
+        // Writes the minified CSS as a data URI at the requested path, relative to the working directory.
+        if let Some(data_uri_path) = &self.build_options.data_uri {
+            self.write_css_data_uri(&working_dir, data_uri_path).await?;
+        }
+
+        // Writes the stats badge of the build at the requested path, relative to the working directory.
+        if let Some(badge_path) = &self.build_options.emit_badge {
+            self.write_stats_badge(&working_dir, badge_path).await?;
+        }
+
+        // Writes the theme variables as a standalone stylesheet at the requested path, relative to the working directory.
+        if let Some(themes_path) = &self.build_options.emit_themes {
+            self.write_themes_sheet(&working_dir, themes_path, palantir_sender.clone())
+                .await?;
+        }
+
+        // Reports what the build generated, when requested.
+        if self.build_options.stats {
+            self.write_build_stats(&folder_path, &unused_classes)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the folder the build outputs are written to, which is the `.galadrielcss` folder of the project.
+    ///
+    /// When that folder cannot be created, e.g. on a read-only file system, a folder in the system temp
+    /// directory is used instead, and a warning reports its location.
+    ///
+    /// # Arguments
+    ///
+    /// * `working_dir` - The root directory of the project being built.
+    /// * `palantir_sender` - The sender used to broadcast alerts during the build.
+    async fn resolve_output_folder(
+        working_dir: &PathBuf,
+        palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    ) -> PathBuf {
+        let folder_path = working_dir.join(".galadrielcss");
+
+        let err = match tokio::fs::create_dir_all(&folder_path).await {
+            Ok(()) => return folder_path,
+            Err(err) => err,
+        };
+
+        let project_name = working_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "project".to_string());
+        let fallback_path = std::env::temp_dir().join("galadrielcss").join(project_name);
+
+        // The original folder is kept when the fallback fails too, so the write reports the original error.
+        if let Err(fallback_err) = tokio::fs::create_dir_all(&fallback_path).await {
+            tracing::error!(
+                "Failed to create the fallback output folder {:?}: {:?}",
+                fallback_path,
+                fallback_err
+            );
+
+            return folder_path;
+        }
+
+        tracing::warn!(
+            "Failed to create the output folder {:?}, falling back to {:?}: {:?}",
+            folder_path,
+            fallback_path,
+            err
+        );
+
+        let notification = GaladrielAlerts::create_warning(
+            Local::now(),
+            &format!(
+                "The {:?} output folder could not be created: {}. The build outputs are written to {:?} instead.",
+                folder_path, err, fallback_path
+            ),
+        );
+
+        if let Err(err) = palantir_sender.send(notification) {
+            tracing::error!("Failed to send alert: {:?}", err);
+        }
+
+        fallback_path
+    }
+
+    /// Parses the generated stylesheet and every split theme or responsive stylesheet with a real CSS parser.
+    ///
+    /// # Returns
+    ///
+    /// * `GaladrielResult<()>` - An error reporting the offending rule, if any stylesheet fails to parse.
+    fn validate_generated_css() -> GaladrielResult<()> {
+        let stylesheets = std::iter::once(("main".to_string(), get_updated_css()))
+            .chain(get_split_theme_sheets())
+            .chain(get_split_responsive_sheets())
+            .collect::<Vec<_>>();
+
+        for (stylesheet_name, css) in stylesheets {
+            if let Err(reason) = validate_css(&css) {
+                eprintln!(
+                    "Error: The generated `{}` stylesheet is not valid CSS: {}",
+                    stylesheet_name, reason
+                );
+
+                return Err(GaladrielError::raise_general_runtime_error(
+                    ErrorKind::InvalidGeneratedCssError,
+                    &format!(
+                        "The generated `{}` stylesheet is not valid CSS: {}",
+                        stylesheet_name, reason
+                    ),
+                    ErrorAction::Exit,
+                ));
+            }
+        }
+
+        tracing::info!("Generated CSS validated successfully.");
+
+        Ok(())
+    }
+
+    /// Compares the classes tracking maps against the snapshot of the previous build, writing the
+    /// changes into `.galadrielcss/changes.json` and storing the current snapshot for the next build.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_path` - The `.galadrielcss` folder of the project being built.
+    async fn write_classes_changelog(&self, folder_path: &PathBuf) -> GaladrielResult<()> {
+        let snapshot_path = folder_path.join("snapshot.json");
+
+        // Without a readable snapshot, every class is reported as added.
+        let previous_snapshot = match tokio::fs::read_to_string(&snapshot_path).await {
+            Ok(content) => serde_json::from_str::<BTreeMap<String, String>>(&content)
+                .unwrap_or_else(|err| {
+                    tracing::warn!("Failed to parse the previous classes snapshot: {}", err);
+
+                    BTreeMap::new()
+                }),
+            Err(_) => {
+                tracing::info!(
+                    "No previous classes snapshot found, reporting every class as added."
+                );
+
+                BTreeMap::new()
+            }
+        };
+
+        let current_snapshot = snapshot_classes_tracking();
+        let changes = diff_classes_snapshots(&previous_snapshot, &current_snapshot);
+
+        write_file(
+            folder_path.clone(),
+            folder_path.join("changes.json"),
+            format!("{:#}", changes),
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        write_file(
+            folder_path.clone(),
+            snapshot_path,
+            serde_json::json!(current_snapshot).to_string(),
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        tracing::info!("Classes changelog and snapshot written.");
 
-/// Represents the runtime environment for Galadriel CSS.
-#[derive(Clone, PartialEq, Debug)]
-pub struct GaladrielRuntime {
-    /// The mode in which the runtime is operating.
-    runtime_mode: GaladrielRuntimeKind,
-    /// The working directory for file operations and configurations.
-    working_dir: PathBuf,
-}
+        Ok(())
+    }
 
-impl GaladrielRuntime {
-    /// Creates a new Galadriel runtime instance with the specified mode and working directory.
+    /// Writes the minified CSS of the project as a single-line `data:text/css;base64,...` URI.
     ///
     /// # Arguments
     ///
-    /// * `runtime_mode` - The runtime mode (Development, or Build).
-    /// * `working_dir` - The directory used for runtime operations.
-    pub fn new(runtime_mode: GaladrielRuntimeKind, working_dir: PathBuf) -> Self {
-        Self {
-            runtime_mode,
-            working_dir,
+    /// * `working_dir` - The root directory of the project being built.
+    /// * `data_uri_path` - The path where the data URI is written, or `-` to print it to stdout.
+    async fn write_css_data_uri(
+        &self,
+        working_dir: &PathBuf,
+        data_uri_path: &PathBuf,
+    ) -> GaladrielResult<()> {
+        if self.build_options.classes_only {
+            tracing::warn!(
+                "The CSS data URI is skipped, since no CSS is generated with `--classes-only`."
+            );
+
+            return Ok(());
+        }
+
+        // The build process always emits minified styles, so the CSS is encoded as is.
+        let data_uri = css_data_uri(&get_updated_css());
+
+        if data_uri_path.as_os_str() == "-" {
+            // The progress events own the stdout, so the data URI is streamed as one of them.
+            if is_json_progress_enabled() {
+                emit_progress_event(serde_json::json!({
+                    "event": "dataUri",
+                    "dataUri": data_uri,
+                }));
+            } else {
+                println!("{}", data_uri);
+            }
+
+            return Ok(());
         }
+
+        let data_uri_path = working_dir.join(data_uri_path);
+        let data_uri_folder = data_uri_path
+            .parent()
+            .map(|parent| parent.to_path_buf())
+            .unwrap_or_else(|| working_dir.clone());
+
+        write_file(
+            data_uri_folder,
+            data_uri_path,
+            data_uri,
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        tracing::info!("CSS data URI written.");
+
+        Ok(())
     }
 
-    /// Executes the runtime logic based on the current runtime mode.
+    /// Writes a shields.io endpoint badge reporting the generated CSS size and the Nenyr classes count.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// A `GaladrielResult` indicating success or failure.
-    pub async fn run(&mut self) -> GaladrielResult<()> {
-        match self.runtime_mode {
-            GaladrielRuntimeKind::Development => self.start_development_mode().await,
-            GaladrielRuntimeKind::Build => self.start_build_mode().await,
+    /// * `working_dir` - The root directory of the project being built.
+    /// * `badge_path` - The path where the badge JSON is written.
+    async fn write_stats_badge(
+        &self,
+        working_dir: &PathBuf,
+        badge_path: &PathBuf,
+    ) -> GaladrielResult<()> {
+        if self.build_options.classes_only {
+            tracing::warn!(
+                "The stats badge is skipped, since no CSS is generated with `--classes-only`."
+            );
+
+            return Ok(());
         }
-    }
 
-    /// Starts the development mode, setting up the environment and logging.
-    async fn start_development_mode(&mut self) -> GaladrielResult<()> {
-        // ===================================================================================================================
-        // Creates the development logs subscriber.
-        // Generates a subscriber for logging events to a file.
-        //
-        // This creates a log subscriber that writes logs to a file using the `tracing` library.
-        // It sets up a rolling file appender with a log filename generated from `generate_log_filename`.
-        // The subscriber is configured to log events with a severity level of `TRACE` or higher.
-        // ===================================================================================================================
-        let file_name = self.generate_log_filename(); // Generate the log filename.
-        let file_appender = rolling::never("logs", file_name); // Create a rolling file appender that writes logs to the specified file.
-        let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender); // Set up non-blocking log writing.
+        let badge = stats_badge(&get_updated_css(), snapshot_classes_tracking().len());
+        let badge_path = working_dir.join(badge_path);
+        let badge_folder = badge_path
+            .parent()
+            .map(|parent| parent.to_path_buf())
+            .unwrap_or_else(|| working_dir.clone());
 
-        // Build and return the log subscriber.
-        let subscriber = FmtSubscriber::builder()
-            .with_max_level(Level::TRACE) // Set the maximum log level to TRACE.
-            .with_writer(non_blocking) // Use the non-blocking writer.
-            .finish(); // Finalize the subscriber configuration.
+        write_file(
+            badge_folder,
+            badge_path,
+            format!("{:#}", badge),
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
 
-        tracing::debug!("Log subscriber built and configured.");
+        tracing::info!("Stats badge written.");
 
-        // Set logs subscriber.
-        tracing::subscriber::set_global_default(subscriber).map_err(|err| {
-            tracing::error!("Failed to set log subscriber: {:?}", err.to_string());
+        Ok(())
+    }
 
-            GaladrielError::raise_critical_runtime_error(
-                ErrorKind::TracingSubscriberInitializationFailed,
-                &err.to_string(),
-                ErrorAction::Exit,
-            )
-        })?;
+    /// Writes the light and dark theme variables as a standalone stylesheet, so the theming can be
+    /// shipped apart from the utility classes.
+    ///
+    /// # Arguments
+    ///
+    /// * `working_dir` - The root directory of the project being built.
+    /// * `themes_path` - The path where the themes stylesheet is written.
+    /// * `palantir_sender` - The sender used to broadcast alerts during the transformation.
+    async fn write_themes_sheet(
+        &self,
+        working_dir: &PathBuf,
+        themes_path: &PathBuf,
+        palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    ) -> GaladrielResult<()> {
+        let themes_sheet = Astroform::new(true, false, palantir_sender)
+            .transform_themes_sheet()
+            .await
+            .map_err(|err| {
+                GaladrielError::raise_general_runtime_error(
+                    ErrorKind::TaskFailure,
+                    &format!("Failed to generate the themes stylesheet: {}", err),
+                    ErrorAction::Exit,
+                )
+            })?;
 
-        tracing::info!("Log subscriber set successfully.");
+        let themes_path = working_dir.join(themes_path);
+        let themes_folder = themes_path
+            .parent()
+            .map(|parent| parent.to_path_buf())
+            .unwrap_or_else(|| working_dir.clone());
 
-        // Configure the development runtime environment.
-        self.configure_development_environment().await
+        write_file(
+            themes_folder,
+            themes_path,
+            themes_sheet,
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        tracing::info!("Themes stylesheet written.");
+
+        Ok(())
     }
 
-    /// Starts the build mode, processing styles and generating the final output.
-    async fn start_build_mode(&mut self) -> GaladrielResult<()> {
-        // Creates the build logs subscriber.
-        let subscriber = tracing_subscriber::registry().with(
-            fmt::layer()
-                .with_writer(std::io::stderr)
-                .with_filter(tracing_subscriber::filter::LevelFilter::ERROR),
-        );
+    /// Prints the build stats to stderr and writes them into `.galadrielcss/stats.json`.
+    ///
+    /// The build always generates the minified stylesheet, so the unminified one is generated
+    /// apart, into a scratch stylesheets cache, only to be measured.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_path` - The `.galadrielcss` folder of the project being built.
+    /// * `unused_classes` - The Nenyr classes never referenced by the files matched by `contentGlobs`.
+    async fn write_build_stats(
+        &self,
+        folder_path: &PathBuf,
+        unused_classes: &[String],
+    ) -> GaladrielResult<()> {
+        if self.build_options.classes_only {
+            tracing::warn!(
+                "The build stats are skipped, since no CSS is generated with `--classes-only`."
+            );
 
-        // Starts the build subscriber.
-        subscriber.try_init().map_err(|err| {
-            tracing::error!("Failed to set log subscriber: {:?}", err.to_string());
+            return Ok(());
+        }
 
-            GaladrielError::raise_critical_runtime_error(
-                ErrorKind::TracingSubscriberInitializationFailed,
-                &err.to_string(),
+        let scratch_session = CompilationSession {
+            cascadex: Arc::new(DashMap::new()),
+            ..CompilationSession::global()
+        };
+        // The alerts were already raised by the build, so the ones of the scratch transformation are dropped.
+        let (scratch_sender, _scratch_receiver) = broadcast::channel(100);
+
+        Astroform::new(false, get_reset_styles(), scratch_sender)
+            .set_themes_split(get_split_themes())
+            .set_responsive_split(get_split_responsive())
+            .set_session(scratch_session.clone())
+            .transform()
+            .await;
+
+        let unminified_css = scratch_session
+            .cascadex
+            .get("cascading_sheet")
+            .map(|sheet| sheet.value().to_owned())
+            .unwrap_or_default();
+        let mut build_stats = collect_build_stats(&get_updated_css(), &unminified_css);
+
+        // The unused classes are only known when the source files were scanned.
+        if !get_content_globs().is_empty() {
+            build_stats["unusedClasses"] = serde_json::json!(unused_classes);
+        }
+
+        eprintln!("{}", format_build_stats(&build_stats));
+
+        write_file(
+            folder_path.clone(),
+            folder_path.join("stats.json"),
+            format!("{:#}", build_stats),
+            ErrorAction::Exit,
+            ErrorKind::FileCreationError,
+            ErrorKind::FileWriteError,
+        )
+        .await?;
+
+        tracing::info!("Build stats written.");
+
+        Ok(())
+    }
+
+    /// Writes every split theme stylesheet into `.galadrielcss/themes/<theme>.css`.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_path` - The `.galadrielcss` folder of the project being built.
+    ///
+    /// # Returns
+    ///
+    /// * `GaladrielResult<serde_json::Value>` - A JSON object mapping each theme to its file, relative to the `.galadrielcss` folder.
+    async fn write_split_theme_sheets(
+        &self,
+        folder_path: &PathBuf,
+    ) -> GaladrielResult<serde_json::Value> {
+        let themes_folder = folder_path.join("themes");
+        let mut theme_files = serde_json::Map::new();
+
+        for (theme, css) in get_split_theme_sheets() {
+            let file_name = format!("{}.css", theme);
+
+            write_file(
+                themes_folder.clone(),
+                themes_folder.join(&file_name),
+                css,
                 ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
             )
-        })?;
+            .await?;
 
-        tracing::info!("Starting build mode.");
+            tracing::info!("Split stylesheet of the `{}` theme written.", theme);
 
-        // Load the galadriel configurations.
-        load_galadriel_configs(&self.working_dir).await?;
+            theme_files.insert(
+                theme,
+                serde_json::Value::String(format!("themes/{}", file_name)),
+            );
+        }
 
-        tracing::debug!("Loaded Galadriel CSS configurations.");
+        Ok(serde_json::Value::Object(theme_files))
+    }
 
-        // Exclude matcher for file system monitoring
-        let working_dir = self.working_dir.clone();
-        let matcher = construct_exclude_matcher(&working_dir)?; // Create an exclude matcher based on the working directory.
-        let atomically_matcher = Arc::new(RwLock::new(matcher)); // Wrap the matcher in an Arc and RwLock for thread-safe shared ownership and mutable access.
+    /// Writes the split mobile-first and desktop-first stylesheets into `.galadrielcss/responsive/<approach>.css`.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_path` - The `.galadrielcss` folder of the project being built.
+    ///
+    /// # Returns
+    ///
+    /// * `GaladrielResult<serde_json::Value>` - A JSON object mapping each responsive approach to its file, relative to the `.galadrielcss` folder.
+    async fn write_split_responsive_sheets(
+        &self,
+        folder_path: &PathBuf,
+    ) -> GaladrielResult<serde_json::Value> {
+        let responsive_folder = folder_path.join("responsive");
+        let mut responsive_files = serde_json::Map::new();
+
+        for (approach, css) in get_split_responsive_sheets() {
+            let file_name = format!("{}.css", approach);
+
+            write_file(
+                responsive_folder.clone(),
+                responsive_folder.join(&file_name),
+                css,
+                ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
+            )
+            .await?;
 
-        tracing::debug!("Created exclude matcher for working directory.");
+            tracing::info!("Split {} responsive stylesheet written.", approach);
 
-        // Initialize the Palantir alerts system.
-        let palantir_alerts = Palantir::new();
-        let palantir_sender = palantir_alerts.get_palantir_sender(); // Retrieve the Palantir sender from the palantir_alerts instance. This sender is used to send alerts to Palantir.
-        let _start_alert_watcher = palantir_alerts.start_alert_watcher(true); // Start the alert watcher using the palantir_alerts instance. This likely begins observing for new alerts or events.
+            responsive_files.insert(
+                approach,
+                serde_json::Value::String(format!("responsive/{}", file_name)),
+            );
+        }
 
-        tracing::info!("Initialized Palantir alerts system.");
-        tracing::info!("Started Nenyr file processing.");
+        Ok(serde_json::Value::Object(responsive_files))
+    }
 
-        // Start the build process for all Nenyr files.
-        Synthesizer::new(true, atomically_matcher, palantir_sender.clone())
-            .process(true, &working_dir)
-            .await;
+    /// Writes the stylesheet of every context into the `.galadrielcss` folder, along with the
+    /// `contexts.json` manifest mapping each context to its stylesheet file.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_path` - The `.galadrielcss` folder of the project being built.
+    /// * `session` - The session whose resolved styles are partitioned.
+    /// * `split_themes` - Whether the theme schemas were split from the main stylesheet.
+    /// * `palantir_sender` - The sender used to broadcast alerts during the transformation.
+    async fn write_context_sheets(
+        &self,
+        folder_path: &PathBuf,
+        session: &CompilationSession,
+        split_themes: bool,
+        palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    ) -> GaladrielResult<()> {
+        let mut context_files = serde_json::Map::new();
+
+        for partition in collect_context_partitions(&session.clastrack) {
+            let context_name = partition.context_name.to_owned();
+            let file_name = format!("{}.css", context_name);
+            // Only the central partition, which is always loaded, carries the reset styles.
+            let set_reset_styles = get_reset_styles() && partition.include_shared_rules;
+
+            // The themes are split the same way as in the main stylesheet.
+            Astroform::new(true, set_reset_styles, palantir_sender.clone())
+                .set_themes_split(split_themes)
+                .set_context_partition(partition)
+                .set_session(session.clone())
+                .transform()
+                .await;
 
-        tracing::info!("Nenyr file processing finished.");
+            let mut context_css = take_context_sheet(&session.cascadex, &context_name);
 
-        // Get the most up-to-dated CSS.
-        let css = get_updated_css();
-        // Get the most up-to-dated Nenyr classes tracking maps
-        let tracking = serialize_classes_tracking();
+            // The source map of the context is written next to its stylesheet, which links to it.
+            if let Some(source_map) =
+                take_context_source_map(&session.cascadex, &context_name, &file_name)
+            {
+                let map_file_name = format!("{}.map", file_name);
 
-        tracing::debug!("Retrieved updated CSS and class tracking maps.");
+                context_css.push_str(&format!("\n/*# sourceMappingURL={} */", map_file_name));
 
-        // Formats the final json.
-        let folder_path = working_dir.join(".galadrielcss");
-        let final_json_path = folder_path.join("galadrielcss.json");
-        let final_json = format!("{{\"css\": {:?}, \"trackingClasses\": {}}}", css, tracking);
+                write_file(
+                    folder_path.clone(),
+                    folder_path.join(&map_file_name),
+                    source_map,
+                    ErrorAction::Exit,
+                    ErrorKind::FileCreationError,
+                    ErrorKind::FileWriteError,
+                )
+                .await?;
+            }
+
+            write_file(
+                folder_path.clone(),
+                folder_path.join(&file_name),
+                context_css,
+                ErrorAction::Exit,
+                ErrorKind::FileCreationError,
+                ErrorKind::FileWriteError,
+            )
+            .await?;
+
+            tracing::info!("Stylesheet of the `{}` context written.", context_name);
+
+            context_files.insert(context_name, serde_json::Value::String(file_name));
+        }
 
-        // Creates the final json containing the CSS and Nenyr classes tracking map at root dir + `/.galadrielcss/galadrielcss.json`.
         write_file(
-            folder_path,
-            final_json_path,
-            final_json,
+            folder_path.clone(),
+            folder_path.join("contexts.json"),
+            format!("{:#}", serde_json::Value::Object(context_files)),
             ErrorAction::Exit,
             ErrorKind::FileCreationError,
             ErrorKind::FileWriteError,
         )
         .await?;
 
-        tracing::info!("Build process completed and final JSON file written.");
-
         Ok(())
     }
 
+    /// Retrieves the path of the JSON lines file every alert is appended to.
+    fn get_alert_log_path(&self) -> PathBuf {
+        self.working_dir.join(".galadrielcss").join("alerts.jsonl")
+    }
+
     /// Configures the development environment for Galadriel CSS.
-    async fn configure_development_environment(&mut self) -> GaladrielResult<()> {
+    async fn configure_development_environment(
+        &mut self,
+        dry_run: bool,
+        no_cache: bool,
+        status_receiver: Option<tokio::sync::mpsc::Receiver<String>>,
+    ) -> GaladrielResult<()> {
         tracing::info!("Configuring development environment.");
 
         // Load the galadriel configurations.
-        load_galadriel_configs(&self.working_dir).await?;
+        load_galadriel_configs(&self.working_dir, None).await?;
 
         tracing::debug!("Loaded Galadriel configurations.");
 
@@ -309,6 +1667,7 @@ impl GaladrielRuntime {
         let palantir_alerts = Palantir::new();
         let palantir_sender = palantir_alerts.get_palantir_sender(); // Retrieve the Palantir sender from the palantir_alerts instance. This sender is used to send alerts to Palantir.
         let _start_alert_watcher = palantir_alerts.start_alert_watcher(false); // Start the alert watcher using the palantir_alerts instance. This likely begins observing for new alerts or events.
+        let _start_alert_log = palantir_alerts.start_alert_log(self.get_alert_log_path()); // Append every alert to the alert log, for post-mortem inspection.
 
         tracing::info!("Initialized Palantir alerts system.");
 
@@ -318,6 +1677,10 @@ impl GaladrielRuntime {
         let mut interface = shellscape.create_interface()?; // Terminal interface setup
         let mut shellscape_app = shellscape.create_app(palantir_sender.clone())?; // Application/state setup for Shellscape
 
+        if let Some(status_receiver) = status_receiver {
+            shellscape_app.reset_status_receiver(status_receiver);
+        }
+
         tracing::debug!("Initialized Shellscape UI and application state.");
 
         // Initialize the Lothlórien pipeline (axum server for Galadriel CSS).
@@ -332,6 +1695,7 @@ impl GaladrielRuntime {
 
         // Initialize the Barad-dûr file system observer.
         let mut baraddur_observer = Baraddur::new(250, working_dir, palantir_sender.clone());
+        baraddur_observer.set_dry_run(dry_run); // In a dry run, the observer only reports the changes.
         let matcher = Arc::clone(&atomically_matcher); // Clone the Arc reference to atomically_matcher for sharing it across threads safely.
         let (mut deb, deb_tx) = baraddur_observer.async_debouncer(matcher)?; // Call the async_debouncer method on the baraddur_observer instance, passing the cloned matcher. This returns a debouncer object and a sender (deb_tx).
         let matcher = Arc::clone(&atomically_matcher); // Clone the Arc reference to atomically_matcher for sharing it across threads safely.
@@ -340,6 +1704,7 @@ impl GaladrielRuntime {
         tracing::info!("Started Barad-dûr file system observer.");
 
         shellscape_app.reset_server_running_on_port(socket_port); // Set the running port.
+        shellscape_app.reset_working_dir(self.working_dir.clone()); // Set the working directory used to relativize alert paths.
         pipeline.register_server_port_in_temp(socket_port).await?; // Register the pipeline's server port in temporary storage.
         interface.invoke()?; // Start the Shellscape terminal interface rendering.
 
@@ -352,12 +1717,37 @@ impl GaladrielRuntime {
         let working_dir = self.working_dir.clone();
         let matcher = Arc::clone(&atomically_matcher);
 
+        // Warn when the TOML configuration file is shadowed by the JSON one.
+        if let Some(notification) = check_duplicated_config_files(&working_dir) {
+            send_palantir_notification(notification, palantir_sender.clone());
+        }
+
         tracing::info!("Starting initial Nenyr file processing...");
 
         // Initialize and process all Nenyr files at the beginning of the development cycle.
-        Synthesizer::new(true, matcher, palantir_sender.clone())
-            .process(get_minified_styles(), &working_dir)
-            .await;
+        // The build cache is left untouched by a dry run, which writes no file.
+        Synthesizer::new(
+            true,
+            matcher,
+            palantir_sender.clone(),
+            CompilationSession::global(),
+        )
+        .set_build_cache(!no_cache && !dry_run)
+        .process(get_minified_styles(), &working_dir)
+        .await;
+
+        // Generate the initial TypeScript typings and classes JSON, when enabled in the configurations.
+        if dry_run {
+            let notification = GaladrielAlerts::create_information(
+                Local::now(),
+                "Dry run enabled. Saved Nenyr files are reprocessed and their changes reported, but no file is written and the styles served remain unchanged.",
+            );
+
+            send_palantir_notification(notification, palantir_sender.clone());
+        } else {
+            Baraddur::regenerate_classes_typings(&working_dir, palantir_sender.clone()).await;
+            Baraddur::rewrite_classes_tracking(&working_dir, palantir_sender.clone()).await;
+        }
 
         tracing::info!("Initial Nenyr file processing finished.");
         tracing::info!("Transitioning to development runtime...");
@@ -405,7 +1795,14 @@ impl GaladrielRuntime {
     ) -> GaladrielResult<()> {
         tracing::info!("Galadriel CSS development runtime initiated.");
 
+        // The idle timeout is checked periodically, counting from the start of the runtime.
+        let mut idle_check = tokio::time::interval(tokio::time::Duration::from_secs(30));
+        record_activity();
+
         loop {
+            // The status line follows the latest log message, in verbose mode.
+            shellscape_app.refresh_status_line();
+
             // Render the Shellscape terminal interface, handle potential errors.
             if let Err(err) = interface.render(shellscape_app) {
                 tracing::error!("{:?}", err);
@@ -459,6 +1856,7 @@ impl GaladrielRuntime {
                                 shellscape,
                                 event,
                                 shellscape_app,
+                                baraddur_observer,
                                 Arc::clone(&matcher),
                                 palantir_sender.clone(),
                             ).await {
@@ -473,12 +1871,35 @@ impl GaladrielRuntime {
                         }
                     }
                 }
+                // Shut down the development server once it has been idle for too long.
+                _ = idle_check.tick() => {
+                    if Self::is_idle_timeout_elapsed() {
+                        tracing::warn!(
+                            "Shutting down the development server after {} minutes without file changes or client requests.",
+                            get_idle_timeout_minutes()
+                        );
+
+                        break;
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Checks whether the development server has been idle for longer than the configured idle timeout.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if an idle timeout is configured and has elapsed, otherwise `false`.
+    fn is_idle_timeout_elapsed() -> bool {
+        let idle_timeout_minutes = get_idle_timeout_minutes();
+
+        idle_timeout_minutes > 0
+            && elapsed_since_last_activity()
+                >= tokio::time::Duration::from_secs(idle_timeout_minutes.saturating_mul(60))
+    }
+
     /// Handles events received from the Shellscape terminal and triggers the appropriate actions.
     ///
     /// # Parameters
@@ -486,6 +1907,7 @@ impl GaladrielRuntime {
     /// - `shellscape`: A mutable reference to the Shellscape instance.
     /// - `event`: The specific event emitted by Shellscape to be handled.
     /// - `shellscape_app`: A mutable reference to the ShellscapeApp instance.
+    /// - `baraddur_observer`: A reference to the Barad-dûr file system observer.
     /// - `matcher`: A shared, thread-safe reference to the Override matcher configuration.
     /// - `palantir_sender`: Broadcast sender for sending Galadriel alerts.
     ///
@@ -497,11 +1919,19 @@ impl GaladrielRuntime {
         shellscape: &mut Shellscape,
         event: ShellscapeTerminalEvents,
         shellscape_app: &mut ShellscapeApp,
+        baraddur_observer: &Baraddur,
         matcher: Arc<RwLock<overrides::Override>>,
         palantir_sender: broadcast::Sender<GaladrielAlerts>,
     ) -> ShellscapeCommands {
-        // Match the event to its corresponding Shellscape command.
-        match shellscape.match_shellscape_event(event) {
+        // Match the event to its corresponding Shellscape command, key events typing the search query while the alerts are searched.
+        let command = match event {
+            ShellscapeTerminalEvents::Key(key) if shellscape_app.is_searching_alerts() => {
+                ShellscapeCommands::from_search_key_event(key)
+            }
+            event => shellscape.match_shellscape_event(event),
+        };
+
+        match command {
             ShellscapeCommands::Terminate => {
                 // Handle termination command.
                 return ShellscapeCommands::Terminate;
@@ -515,6 +1945,53 @@ impl GaladrielRuntime {
                 )
                 .await;
             }
+            ShellscapeCommands::ToggleWatch => {
+                // Pause the file watcher, or resume it with a single full reprocess.
+                if baraddur_observer.is_watch_paused() {
+                    baraddur_observer.resume_watch(matcher).await;
+                } else {
+                    baraddur_observer.pause_watch();
+                }
+
+                shellscape_app.reset_watch_paused(baraddur_observer.is_watch_paused());
+            }
+            ShellscapeCommands::ReloadNenyrSyntax => {
+                // Rebuild the syntax set from the Nenyr syntax definition under development.
+                shellscape_app.reload_nenyr_syntax();
+            }
+            ShellscapeCommands::ReloadConfig => {
+                // Reload the configurations and the exclude matcher, as when the configuration file changes.
+                baraddur_observer.reload_configs(matcher).await;
+            }
+            ShellscapeCommands::BenchFile => {
+                // Time the repeated processing of the latest modified Nenyr file.
+                baraddur_observer.bench_file().await;
+            }
+            ShellscapeCommands::CopyErrorPath => {
+                // Copy the context path of the topmost visible error to the system clipboard.
+                match shellscape_app.get_topmost_visible_error_path() {
+                    Some(context_path) => match copy_to_clipboard(&context_path) {
+                        Ok(()) => {
+                            shellscape_app.add_alert(GaladrielAlerts::create_information(
+                                Local::now(),
+                                &format!("Copied {:?} to the clipboard.", context_path),
+                            ));
+                        }
+                        Err(err) => {
+                            shellscape_app.add_alert(GaladrielAlerts::create_galadriel_error(
+                                Local::now(),
+                                err,
+                            ));
+                        }
+                    },
+                    None => {
+                        shellscape_app.add_alert(GaladrielAlerts::create_information(
+                            Local::now(),
+                            "There is no visible error whose context path could be copied.",
+                        ));
+                    }
+                }
+            }
             ShellscapeCommands::ScrollNotificationsUp => {
                 // Scroll notifications upwards.
                 shellscape_app.reset_alerts_scroll_down();
@@ -558,6 +2035,46 @@ impl GaladrielRuntime {
                         .add_alert(GaladrielAlerts::create_galadriel_error(Local::now(), err));
                 }
             }
+            ShellscapeCommands::ToggleRelativePaths => {
+                // Toggle relative paths in error alerts and update configurations.
+                switch_relative_error_paths();
+
+                if let Err(err) = self.replace_configurations_file().await {
+                    shellscape_app
+                        .add_alert(GaladrielAlerts::create_galadriel_error(Local::now(), err));
+                }
+            }
+            ShellscapeCommands::TogglePlainAlerts => {
+                // Toggle syntax highlighting in error alerts and update configurations.
+                switch_plain_alerts();
+
+                if let Err(err) = self.replace_configurations_file().await {
+                    shellscape_app
+                        .add_alert(GaladrielAlerts::create_galadriel_error(Local::now(), err));
+                }
+            }
+            ShellscapeCommands::NextError => {
+                // Center the next error alert in the alerts table.
+                shellscape_app.jump_to_next_error();
+            }
+            ShellscapeCommands::PrevError => {
+                // Center the previous error alert in the alerts table.
+                shellscape_app.jump_to_previous_error();
+            }
+            ShellscapeCommands::SearchAlerts => {
+                // Start filtering the alerts table by the typed query.
+                shellscape_app.start_alerts_search();
+            }
+            ShellscapeCommands::TypeAlertsSearch(character) => {
+                shellscape_app.type_alerts_search(character);
+            }
+            ShellscapeCommands::EraseAlertsSearch => {
+                shellscape_app.erase_alerts_search();
+            }
+            ShellscapeCommands::ClearAlertsSearch => {
+                // Restore the full list of alerts.
+                shellscape_app.clear_alerts_search();
+            }
             ShellscapeCommands::ClearAlertsTable => {
                 // Clear all alerts from the alerts cache.
                 shellscape_app.clear_alerts();
@@ -670,9 +2187,18 @@ impl GaladrielRuntime {
         );
 
         // Step 2: Create a `Synthesizer` to process styles with the restored ASTs.
-        Synthesizer::new(true, matcher, palantir_sender.clone())
-            .process(get_minified_styles(), working_dir)
-            .await;
+        Synthesizer::new(
+            true,
+            matcher,
+            palantir_sender.clone(),
+            CompilationSession::global(),
+        )
+        .process(get_minified_styles(), working_dir)
+        .await;
+
+        // Keep the TypeScript typings and classes JSON in sync with the repopulated classes.
+        Baraddur::regenerate_classes_typings(working_dir, palantir_sender.clone()).await;
+        Baraddur::rewrite_classes_tracking(working_dir, palantir_sender.clone()).await;
     }
 
     /// Replaces the configuration file with updated settings.
@@ -682,8 +2208,18 @@ impl GaladrielRuntime {
     async fn replace_configurations_file(&mut self) -> GaladrielResult<()> {
         tracing::info!("Starting configuration file replacement.");
 
-        let config_path = self.working_dir.join("galadriel.config.json");
-        let serialized_configs = transform_configatron_to_json()?;
+        // The configurations are written back in the format they were loaded from.
+        let (config_path, config_format) = resolve_config_file(&self.working_dir);
+        let previous_configs = tokio::fs::read_to_string(&config_path)
+            .await
+            .unwrap_or_default();
+        // The named profiles are not applied configurations, so they are carried over as they are.
+        let serialized_configs = match config_format {
+            ConfigFormat::Json => {
+                carry_config_profiles(&previous_configs, transform_configatron_to_json()?)
+            }
+            ConfigFormat::Toml => transform_configatron_to_toml(&previous_configs)?,
+        };
 
         replace_file(
             config_path,
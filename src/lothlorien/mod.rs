@@ -1,8 +1,23 @@
-use axum::{extract::Path, routing, Router};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, State,
+    },
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing, Router,
+};
 use chrono::Local;
 use rand::Rng;
 use serde::Deserialize;
-use std::{env, path::PathBuf};
+use serde_json::json;
+use std::{
+    collections::hash_map::DefaultHasher,
+    env,
+    hash::{Hash, Hasher},
+    io,
+    path::PathBuf,
+};
 
 use tokio::{
     fs,
@@ -12,12 +27,15 @@ use tokio::{
 };
 
 use crate::{
+    configatron::get_port_fallback_range,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::{GaladrielAlerts, GaladrielEvents},
     utils::{
-        get_updated_css::get_updated_css, get_utility_class_names::get_utility_class_names,
+        activity_tracker::record_activity, get_updated_css::get_updated_css,
+        get_utility_class_names::get_utility_class_names,
+        send_palantir_notification::send_palantir_notification,
         send_palantir_success_notification::send_palantir_success_notification,
-        write_file::write_file,
+        serialize_classes_tracking::serialize_classes_tracking, write_file::write_file,
     },
     GaladrielResult,
 };
@@ -134,18 +152,75 @@ impl Lothlorien {
     }
 
     // Create a TCP listener bound to the server's socket address.
+    // When its port is already in use, the next `portFallbackRange` sequential ports are tried in turn.
     pub async fn create_listener(&self) -> GaladrielResult<TcpListener> {
         tracing::info!("Creating listener...");
 
-        tokio::net::TcpListener::bind(self.socket_addr.clone())
-            .await
-            .map_err(|err| {
-                GaladrielError::raise_general_pipeline_error(
-                    ErrorKind::SocketAddressBindingError,
-                    &err.to_string(),
-                    ErrorAction::Exit,
-                )
-            })
+        let socket_addrs =
+            Self::get_fallback_socket_addrs(&self.socket_addr, get_port_fallback_range());
+        let mut last_error = String::new();
+
+        for socket_addr in &socket_addrs {
+            match tokio::net::TcpListener::bind(socket_addr).await {
+                Ok(listener) => {
+                    if *socket_addr != self.socket_addr {
+                        let notification = GaladrielAlerts::create_warning(
+                            Local::now(),
+                            &format!(
+                                "The address {} is already in use, so the Lothlórien server is listening on {} instead.",
+                                self.socket_addr, socket_addr
+                            ),
+                        );
+
+                        send_palantir_notification(notification, self.palantir_sender.clone());
+                    }
+
+                    return Ok(listener);
+                }
+                Err(err) if err.kind() == io::ErrorKind::AddrInUse => {
+                    tracing::warn!(
+                        "Address {} already in use, trying the next port.",
+                        socket_addr
+                    );
+
+                    last_error = err.to_string();
+                }
+                Err(err) => {
+                    return Err(GaladrielError::raise_general_pipeline_error(
+                        ErrorKind::SocketAddressBindingError,
+                        &err.to_string(),
+                        ErrorAction::Exit,
+                    ));
+                }
+            }
+        }
+
+        Err(GaladrielError::raise_general_pipeline_error(
+            ErrorKind::SocketAddressBindingError,
+            &format!(
+                "None of the {} addresses from {} is free: {}",
+                socket_addrs.len(),
+                self.socket_addr,
+                last_error
+            ),
+            ErrorAction::Exit,
+        ))
+    }
+
+    // Lists the configured socket address followed by the ones of the next sequential ports.
+    // A wildcard port (`0`) is always free, so it never falls back.
+    fn get_fallback_socket_addrs(socket_addr: &str, fallback_range: u16) -> Vec<String> {
+        let Some((host, port)) = socket_addr.rsplit_once(':') else {
+            return vec![socket_addr.to_string()];
+        };
+
+        match port.parse::<u16>() {
+            Ok(port) if port != 0 => (0..=fallback_range)
+                .filter_map(|offset| port.checked_add(offset))
+                .map(|port| format!("{}:{}", host, port))
+                .collect(),
+            _ => vec![socket_addr.to_string()],
+        }
     }
 
     // Start the server's main event stream and set up routes.
@@ -161,11 +236,18 @@ impl Lothlorien {
             let app = Router::new()
                 // Define a route for fetching CSS files.
                 .route("/fetch-css", routing::get(Self::fetch_css))
+                // Define a route serving the CSS as a stylesheet, cacheable through its ETag.
+                .route("/galadrielcss.css", routing::get(Self::serve_css))
+                // Define a route serving the Nenyr classes tracking maps.
+                .route("/tracking.json", routing::get(Self::serve_tracking))
                 // Define a route for collecting utility class names.
                 .route(
                     "/collect-utility-class-names/:context_type/:context_name/:class_name",
                     routing::get(Self::collect_utility_names),
-                );
+                )
+                // Define a WebSocket route pushing the contexts whose styles were updated.
+                .route("/hmr", routing::get(Self::upgrade_hmr_socket))
+                .with_state(palantir_sender.clone());
 
             tracing::info!("Starting Axum server with graceful shutdown.");
 
@@ -200,11 +282,69 @@ impl Lothlorien {
 
     // Handles a request to fetch the latest CSS.
     async fn fetch_css() -> String {
+        record_activity();
+
         get_updated_css()
     }
 
+    // Handles a request to fetch the latest CSS as a stylesheet, answering `304 Not Modified`
+    // when the client already holds the current version.
+    async fn serve_css(headers: HeaderMap) -> Response {
+        record_activity();
+
+        let css = get_updated_css();
+        let etag = Self::compute_etag(&css);
+
+        let is_not_modified = headers
+            .get(header::IF_NONE_MATCH)
+            .and_then(|if_none_match| if_none_match.to_str().ok())
+            .is_some_and(|if_none_match| Self::matches_etag(if_none_match, &etag));
+
+        if is_not_modified {
+            return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+        }
+
+        (
+            [
+                (header::CONTENT_TYPE, "text/css; charset=utf-8".to_string()),
+                (header::ETAG, etag),
+            ],
+            css,
+        )
+            .into_response()
+    }
+
+    // Handles a request to fetch the latest Nenyr classes tracking maps.
+    async fn serve_tracking() -> Response {
+        record_activity();
+
+        (
+            [(header::CONTENT_TYPE, "application/json")],
+            serialize_classes_tracking(),
+        )
+            .into_response()
+    }
+
+    // Derives a strong ETag from the hash of the content.
+    fn compute_etag(content: &str) -> String {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+
+        format!("\"{:016x}\"", hasher.finish())
+    }
+
+    // Checks whether an `If-None-Match` header lists the ETag, weak comparison included.
+    fn matches_etag(if_none_match: &str, etag: &str) -> bool {
+        if_none_match
+            .split(',')
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+    }
+
     // Handles a request to collect utility class names based on context type and parameters.
     async fn collect_utility_names(Path(params): Path<CollectUtilityParams>) -> String {
+        record_activity();
+
         // Destructure the incoming parameters.
         let CollectUtilityParams {
             context_type,
@@ -224,6 +364,54 @@ impl Lothlorien {
         get_utility_class_names(context_type, context_name, class_name)
     }
 
+    // Handles a request to open the HMR socket, subscribing it to the Palantir alerts.
+    async fn upgrade_hmr_socket(
+        socket_upgrade: WebSocketUpgrade,
+        State(palantir_sender): State<broadcast::Sender<GaladrielAlerts>>,
+    ) -> Response {
+        record_activity();
+
+        // Subscribe before upgrading, so no update sent during the handshake is missed.
+        let palantir_receiver = palantir_sender.subscribe();
+
+        socket_upgrade.on_upgrade(move |socket| Self::stream_hmr_updates(socket, palantir_receiver))
+    }
+
+    // Pushes every styles update to the HMR socket, until either the client or the alerts channel goes away.
+    async fn stream_hmr_updates(
+        mut socket: WebSocket,
+        mut palantir_receiver: broadcast::Receiver<GaladrielAlerts>,
+    ) {
+        tracing::info!("HMR socket connected.");
+
+        loop {
+            tokio::select! {
+                palantir_event = palantir_receiver.recv() => match palantir_event {
+                    Ok(GaladrielAlerts::StylesUpdated { contexts, .. }) => {
+                        let message = json!({ "type": "stylesUpdated", "contexts": contexts });
+
+                        if let Err(err) = socket.send(Message::Text(message.to_string())).await {
+                            tracing::debug!("Failed to push a styles update to the HMR socket: {:?}", err);
+                            break;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("The HMR socket missed {} alerts.", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                socket_message = socket.recv() => match socket_message {
+                    // The client messages are ignored, the socket is only read to detect disconnects.
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+
+        tracing::info!("HMR socket disconnected.");
+    }
+
     // Awaits a shutdown signal and gracefully stops the server.
     async fn shutdown_signal(
         palantir_sender: broadcast::Sender<GaladrielAlerts>,
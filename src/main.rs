@@ -1,12 +1,361 @@
-use std::env;
+use std::{env, path::PathBuf};
 
 use galadrielcss::{
     error::{ErrorAction, ErrorKind, GaladrielError},
-    GaladrielResult, GaladrielRuntime, GaladrielRuntimeKind,
+    GaladrielBuildOptions, GaladrielResult, GaladrielRuntime, GaladrielRuntimeKind,
 };
 
 fn get_usage_message() -> String {
-    "Usage:\n    galadrielcss <mode>\n\nAvailable modes:\n    'start'   - Launches the development server\n    'build'   - Compiles the project for production".to_string()
+    "Usage:\n    galadrielcss <mode> [options]\n    galadrielcss start [--dry-run] [--verbose] [--no-cache]\n    galadrielcss build [options] [<dir>...]\n    galadrielcss config --diff\n    galadrielcss schema\n    galadrielcss dump-ast\n    galadrielcss check [--verify-imports]\n\nAvailable modes:\n    'start'    - Launches the development server\n    'build'    - Compiles the project for production, or each of the given project directories\n    'config'   - With '--diff', lists the configurations that differ from their defaults\n    'schema'   - Prints the JSON Schema of 'galadriel.config.json', to be referenced by its '$schema' key\n    'dump-ast' - Processes the project and writes its STYLITRON AST to '.galadrielcss/ast.json', for inspection and bug reports\n    'check'    - Processes the project without writing any output, reporting its issues before a build\n\nStart options:\n    '--dry-run'               - Reprocesses the saved Nenyr files and reports the contexts and utility classes changed, without writing any file\n    '--verbose'               - Displays the latest log message in the footer of the terminal UI, to follow the processing without the log file\n    '--no-cache'              - Skips '.galadrielcss/cache.json' and parses every Nenyr file at startup\n\nBuild options:\n    '--classes-only'          - Writes only the Nenyr classes tracking maps, skipping the CSS generation\n    '--profile <name>'        - Merges the named profile of the 'profiles' configuration over the base configurations\n    '--emit-css <path>'       - Writes the generated CSS as a plain stylesheet at the given path\n    '--emit-themes <path>'    - Writes the light and dark theme variables as a standalone stylesheet at the given path, under ':root' and '[data-theme=\"dark\"]'\n    '--emit-dts <path>'       - Writes the TypeScript typings of the Nenyr classes at the given path\n    '--emit-js-styles <path>' - Writes the resolved styles of the Nenyr classes as a CSS-in-JS module at the given path\n    '--profile-classes'       - Reports the slowest Nenyr classes to resolve at the end of the build\n    '--data-uri <path>'       - Writes the minified CSS as a base64 data URI at the given path, or to stdout with '-'\n    '--changelog'             - Writes the classes added, removed, or modified since the previous build to '.galadrielcss/changes.json'\n    '--validate-css'          - Parses the generated CSS and fails the build on any syntax error\n    '--emit-badge <path>'     - Writes a shields.io badge JSON with the CSS size and the classes count at the given path\n    '--split-contexts'        - Also writes a stylesheet per context into '.galadrielcss', along with a 'contexts.json' manifest\n    '--central <path>'        - Processes the given Nenyr file as the central context, instead of the project's one\n    '--max-warnings <count>'  - Fails the build when more warnings than the given count are raised\n    '--timeout <seconds>'     - Aborts the build when it runs for longer than the given seconds, exiting with code 124\n    '--stats'                 - Prints the CSS size and the utility classes and animations counts, also writing them to '.galadrielcss/stats.json'\n    '--json-progress'         - Streams the build progress to stdout as one JSON event per line, for IDE integrations\n    '--emit-variable-map'     - Writes the variables of every context, with their identifiers and resolved values, to '.galadrielcss/variables.json'\n    '--features <flags>'      - Ships the styles of the feature-flagged contexts and classes only when all their flags are in the given comma-separated list\n\nCheck options:\n    '--verify-imports'        - Verifies that the remote imports are reachable and the local ones exist".to_string()
+}
+
+/// Takes the path expected after a build option from the command-line arguments.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the option.
+/// * `option` - The name of the option requiring the path.
+///
+/// # Returns
+///
+/// * `GaladrielResult<PathBuf>` - The path given to the option, or an error if it is missing.
+fn take_option_path(args: &mut env::Args, option: &str) -> GaladrielResult<PathBuf> {
+    match args.next() {
+        Some(path) if !path.starts_with("--") => Ok(PathBuf::from(path)),
+        _ => {
+            eprintln!("Error: The `{}` option requires a path", option);
+            eprintln!();
+            eprintln!("{}", get_usage_message());
+
+            Err(GaladrielError::raise_general_runtime_error(
+                ErrorKind::InvalidBuildOptionError,
+                &format!("The `{}` option requires a path.", option),
+                ErrorAction::Exit,
+            ))
+        }
+    }
+}
+
+/// Takes the name expected after a build option from the command-line arguments.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the option.
+/// * `option` - The name of the option requiring the name.
+///
+/// # Returns
+///
+/// * `GaladrielResult<String>` - The name given to the option, or an error if it is missing.
+fn take_option_name(args: &mut env::Args, option: &str) -> GaladrielResult<String> {
+    match args.next() {
+        Some(name) if !name.starts_with("--") => Ok(name),
+        _ => {
+            eprintln!("Error: The `{}` option requires a name", option);
+            eprintln!();
+            eprintln!("{}", get_usage_message());
+
+            Err(GaladrielError::raise_general_runtime_error(
+                ErrorKind::InvalidBuildOptionError,
+                &format!("The `{}` option requires a name.", option),
+                ErrorAction::Exit,
+            ))
+        }
+    }
+}
+
+/// Takes the count expected after a build option from the command-line arguments.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments, positioned right after the option.
+/// * `option` - The name of the option expecting the count, used in the error message.
+///
+/// # Returns
+///
+/// * `GaladrielResult<usize>` - The count, or an error if it is missing or not a non-negative integer.
+fn take_option_count(args: &mut env::Args, option: &str) -> GaladrielResult<usize> {
+    match args.next().and_then(|count| count.parse::<usize>().ok()) {
+        Some(count) => Ok(count),
+        None => {
+            eprintln!(
+                "Error: The `{}` option requires a non-negative integer",
+                option
+            );
+            eprintln!();
+            eprintln!("{}", get_usage_message());
+
+            Err(GaladrielError::raise_general_runtime_error(
+                ErrorKind::InvalidBuildOptionError,
+                &format!("The `{}` option requires a non-negative integer.", option),
+                ErrorAction::Exit,
+            ))
+        }
+    }
+}
+
+/// Parses the remaining command-line arguments into the build mode options.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the runtime mode.
+/// * `current_dir` - The current working directory, used to resolve relative project directories.
+///
+/// # Returns
+///
+/// * `GaladrielResult<GaladrielBuildOptions>` - The parsed build options, or an error if an unknown option is found.
+fn parse_build_options(
+    mut args: env::Args,
+    current_dir: &PathBuf,
+) -> GaladrielResult<GaladrielBuildOptions> {
+    let mut build_options = GaladrielBuildOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--classes-only" => build_options.classes_only = true,
+            "--profile-classes" => build_options.profile_classes = true,
+            "--changelog" => build_options.changelog = true,
+            "--validate-css" => build_options.validate_css = true,
+            "--split-contexts" => build_options.split_contexts = true,
+            "--stats" => build_options.stats = true,
+            "--json-progress" => build_options.json_progress = true,
+            "--emit-variable-map" => build_options.emit_variable_map = true,
+            "--profile" => {
+                build_options.profile = Some(take_option_name(&mut args, "--profile")?);
+            }
+            "--features" => {
+                build_options.features = take_option_name(&mut args, "--features")?
+                    .split(',')
+                    .map(|feature| feature.trim().to_string())
+                    .filter(|feature| !feature.is_empty())
+                    .collect();
+            }
+            "--timeout" => {
+                build_options.timeout = Some(take_option_count(&mut args, "--timeout")? as u64);
+            }
+            "--max-warnings" => {
+                build_options.max_warnings = Some(take_option_count(&mut args, "--max-warnings")?);
+            }
+            "--emit-css" => {
+                build_options.emit_css = Some(take_option_path(&mut args, "--emit-css")?);
+            }
+            "--emit-themes" => {
+                build_options.emit_themes = Some(take_option_path(&mut args, "--emit-themes")?);
+            }
+            "--emit-dts" => {
+                build_options.emit_dts = Some(take_option_path(&mut args, "--emit-dts")?);
+            }
+            "--emit-js-styles" => {
+                build_options.emit_js_styles =
+                    Some(take_option_path(&mut args, "--emit-js-styles")?);
+            }
+            "--data-uri" => {
+                build_options.data_uri = Some(take_option_path(&mut args, "--data-uri")?);
+            }
+            "--emit-badge" => {
+                build_options.emit_badge = Some(take_option_path(&mut args, "--emit-badge")?);
+            }
+            "--central" => {
+                let central_path = current_dir.join(take_option_path(&mut args, "--central")?);
+
+                if !central_path.is_file() {
+                    eprintln!("Error: `{}` is not a file", central_path.display());
+
+                    return Err(GaladrielError::raise_general_runtime_error(
+                        ErrorKind::InvalidBuildOptionError,
+                        &format!(
+                            "`{}` is not a valid central context file.",
+                            central_path.display()
+                        ),
+                        ErrorAction::Exit,
+                    ));
+                }
+
+                build_options.central = Some(central_path);
+            }
+            // Any argument that is not an option is a project directory to be built.
+            project_dir if !project_dir.starts_with("--") => {
+                let project_dir = current_dir.join(project_dir);
+
+                if !project_dir.is_dir() {
+                    eprintln!("Error: `{}` is not a directory", project_dir.display());
+
+                    return Err(GaladrielError::raise_general_runtime_error(
+                        ErrorKind::InvalidBuildOptionError,
+                        &format!(
+                            "`{}` is not a valid project directory.",
+                            project_dir.display()
+                        ),
+                        ErrorAction::Exit,
+                    ));
+                }
+
+                build_options.project_dirs.push(project_dir);
+            }
+            _ => {
+                eprintln!("Error: Invalid build option `{}`", arg);
+                eprintln!();
+                eprintln!("{}", get_usage_message());
+
+                return Err(GaladrielError::raise_general_runtime_error(
+                    ErrorKind::InvalidBuildOptionError,
+                    &format!("`{}` is not a valid Galadriel CSS build option.", arg),
+                    ErrorAction::Exit,
+                ));
+            }
+        }
+    }
+
+    Ok(build_options)
+}
+
+/// Parses the remaining command-line arguments of the config mode.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the runtime mode.
+///
+/// # Returns
+///
+/// * `GaladrielResult<GaladrielRuntimeKind>` - The config runtime mode, or an error if the arguments are invalid.
+fn parse_config_options(mut args: env::Args) -> GaladrielResult<GaladrielRuntimeKind> {
+    match (args.next(), args.next()) {
+        (Some(option), None) if option == "--diff" => Ok(GaladrielRuntimeKind::ConfigDiff),
+        _ => {
+            eprintln!("Error: The `config` mode requires the `--diff` option");
+            eprintln!();
+            eprintln!("{}", get_usage_message());
+
+            Err(GaladrielError::raise_general_runtime_error(
+                ErrorKind::InvalidConfigOptionError,
+                "The `config` mode only accepts the `--diff` option.",
+                ErrorAction::Exit,
+            ))
+        }
+    }
+}
+
+/// Parses the remaining command-line arguments of the schema mode, which accepts none.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the runtime mode.
+///
+/// # Returns
+///
+/// * `GaladrielResult<GaladrielRuntimeKind>` - The schema runtime mode, or an error if any argument is given.
+fn parse_schema_options(mut args: env::Args) -> GaladrielResult<GaladrielRuntimeKind> {
+    match args.next() {
+        None => Ok(GaladrielRuntimeKind::Schema),
+        Some(arg) => {
+            eprintln!("Error: Invalid schema option `{}`", arg);
+            eprintln!();
+            eprintln!("{}", get_usage_message());
+
+            Err(GaladrielError::raise_general_runtime_error(
+                ErrorKind::InvalidSchemaOptionError,
+                "The `schema` mode accepts no option.",
+                ErrorAction::Exit,
+            ))
+        }
+    }
+}
+
+/// Parses the remaining command-line arguments of the dump AST mode, which accepts no option.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the runtime mode.
+///
+/// # Returns
+///
+/// * `GaladrielResult<GaladrielRuntimeKind>` - The dump AST runtime mode, or an error if any argument is given.
+fn parse_dump_ast_options(mut args: env::Args) -> GaladrielResult<GaladrielRuntimeKind> {
+    match args.next() {
+        None => Ok(GaladrielRuntimeKind::DumpAst),
+        Some(arg) => {
+            eprintln!("Error: Invalid dump-ast option `{}`", arg);
+            eprintln!();
+            eprintln!("{}", get_usage_message());
+
+            Err(GaladrielError::raise_general_runtime_error(
+                ErrorKind::InvalidDumpAstOptionError,
+                "The `dump-ast` mode accepts no option.",
+                ErrorAction::Exit,
+            ))
+        }
+    }
+}
+
+/// Parses the remaining command-line arguments of the start mode.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the runtime mode.
+///
+/// # Returns
+///
+/// * `GaladrielResult<GaladrielRuntimeKind>` - The start runtime mode, or an error if an unknown option is found.
+fn parse_start_options(args: env::Args) -> GaladrielResult<GaladrielRuntimeKind> {
+    let mut dry_run = false;
+    let mut verbose = false;
+    let mut no_cache = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--verbose" => verbose = true,
+            "--no-cache" => no_cache = true,
+            _ => {
+                eprintln!("Error: Invalid start option `{}`", arg);
+                eprintln!();
+                eprintln!("{}", get_usage_message());
+
+                return Err(GaladrielError::raise_general_runtime_error(
+                    ErrorKind::InvalidStartOptionError,
+                    &format!("`{}` is not a valid Galadriel CSS start option.", arg),
+                    ErrorAction::Exit,
+                ));
+            }
+        }
+    }
+
+    Ok(GaladrielRuntimeKind::Development {
+        dry_run,
+        verbose,
+        no_cache,
+    })
+}
+
+/// Parses the remaining command-line arguments of the check mode.
+///
+/// # Arguments
+///
+/// * `args` - The command-line arguments following the runtime mode.
+///
+/// # Returns
+///
+/// * `GaladrielResult<GaladrielRuntimeKind>` - The check runtime mode, or an error if an unknown option is found.
+fn parse_check_options(args: env::Args) -> GaladrielResult<GaladrielRuntimeKind> {
+    let mut verify_imports = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--verify-imports" => verify_imports = true,
+            _ => {
+                eprintln!("Error: Invalid check option `{}`", arg);
+                eprintln!();
+                eprintln!("{}", get_usage_message());
+
+                return Err(GaladrielError::raise_general_runtime_error(
+                    ErrorKind::InvalidCheckOptionError,
+                    &format!("`{}` is not a valid Galadriel CSS check option.", arg),
+                    ErrorAction::Exit,
+                ));
+            }
+        }
+    }
+
+    Ok(GaladrielRuntimeKind::Check { verify_imports })
 }
 
 /// Main asynchronous function serving as the entry point for Galadriel CSS runtime.
@@ -27,7 +376,14 @@ async fn main() -> GaladrielResult<()> {
     // Match on the next argument to determine the runtime mode (`start` or `build`).
     match args.next() {
         // Check if the mode is valid (either "start" or "build").
-        Some(runtime_kind) if runtime_kind == "start" || runtime_kind == "build" => {
+        Some(runtime_kind)
+            if runtime_kind == "start"
+                || runtime_kind == "build"
+                || runtime_kind == "config"
+                || runtime_kind == "schema"
+                || runtime_kind == "dump-ast"
+                || runtime_kind == "check" =>
+        {
             // Get the current working directory to use as the runtime base directory.
             let current_dir = std::env::current_dir().map_err(|err| {
                 GaladrielError::raise_general_runtime_error(
@@ -38,14 +394,29 @@ async fn main() -> GaladrielResult<()> {
             })?;
 
             // Determine runtime mode based on the argument received.
-            let runtime_mode = if runtime_kind == "start" {
-                GaladrielRuntimeKind::Development
-            } else {
-                GaladrielRuntimeKind::Build
+            let (runtime_mode, build_options) = match runtime_kind.as_str() {
+                "start" => (parse_start_options(args)?, GaladrielBuildOptions::default()),
+                "config" => (
+                    parse_config_options(args)?,
+                    GaladrielBuildOptions::default(),
+                ),
+                "check" => (parse_check_options(args)?, GaladrielBuildOptions::default()),
+                "schema" => (
+                    parse_schema_options(args)?,
+                    GaladrielBuildOptions::default(),
+                ),
+                "dump-ast" => (
+                    parse_dump_ast_options(args)?,
+                    GaladrielBuildOptions::default(),
+                ),
+                _ => (
+                    GaladrielRuntimeKind::Build,
+                    parse_build_options(args, &current_dir)?,
+                ),
             };
 
             // Determine runtime mode based on the argument received.
-            let mut runtime = GaladrielRuntime::new(runtime_mode, current_dir);
+            let mut runtime = GaladrielRuntime::new(runtime_mode, current_dir, build_options);
 
             // Run the runtime asynchronously and await completion.
             runtime.run().await
@@ -73,7 +444,7 @@ async fn main() -> GaladrielResult<()> {
 
             Err(GaladrielError::raise_general_runtime_error(
                 ErrorKind::MissingGaladrielModeError,
-                "No mode specified. Please provide a valid mode ('start', or 'build').",
+                "No mode specified. Please provide a valid mode ('start', 'build', 'config', 'schema', or 'check').",
                 ErrorAction::Exit,
             ))
         }
@@ -1,8 +1,16 @@
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
 use chrono::Local;
+use serde_json::{json, Value};
 use tokio::{sync, task::JoinHandle};
 
 use crate::{
     asts::PALANTIR_ALERTS,
+    configatron::get_notify_on_error,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts, utils::pretty_print::pretty_print,
 };
@@ -66,12 +74,19 @@ impl Palantir {
                     // Await the next alert event from the receiver.
                     palantir_event = palantir_receiver.recv() => {
                         match palantir_event {
+                            // The styles updates are only meant for the clients of the HMR socket.
+                            Ok(GaladrielAlerts::StylesUpdated { .. }) => {}
                             Ok(notification) => {
                                 tracing::info!("Received a new alert: {:?}", notification);
 
                                 if is_build {
                                     pretty_print(notification);
                                 } else {
+                                    // Errors ring the terminal bell, so they are noticed while away from the screen.
+                                    if Self::is_error_alert(&notification) && get_notify_on_error() {
+                                        Self::ring_terminal_bell();
+                                    }
+
                                     // Push valid notifications to the top of the cache.
                                     Self::push_top(notification);
                                 }
@@ -109,6 +124,170 @@ impl Palantir {
         })
     }
 
+    /// Starts the alert log, which appends every incoming alert as a JSON line to the given file.
+    ///
+    /// The log is kept for post-mortem inspection, after the alerts cache is gone. When the file
+    /// cannot be opened or written, a single warning is raised and the log stops.
+    ///
+    /// # Parameters
+    /// - `log_path`: The path of the JSON lines file the alerts are appended to.
+    ///
+    /// # Returns
+    /// A `JoinHandle` representing the spawned Tokio task.
+    pub fn start_alert_log(&self, log_path: PathBuf) -> JoinHandle<()> {
+        let palantir_sender = self.get_palantir_sender();
+        // Subscribe before spawning, so no alert sent in the meantime is missed.
+        let mut palantir_receiver = palantir_sender.subscribe();
+
+        tracing::info!("Starting the alert log at {:?}.", log_path);
+
+        tokio::spawn(async move {
+            let log_file = log_path
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|_| OpenOptions::new().create(true).append(true).open(&log_path));
+
+            let mut log_file = match log_file {
+                Ok(log_file) => log_file,
+                Err(err) => {
+                    Self::raise_alert_log_warning(&palantir_sender, &log_path, &err);
+                    return;
+                }
+            };
+
+            loop {
+                match palantir_receiver.recv().await {
+                    Ok(notification) => {
+                        let entry = Self::alert_log_entry(&notification);
+
+                        if let Err(err) = writeln!(log_file, "{}", entry) {
+                            Self::raise_alert_log_warning(&palantir_sender, &log_path, &err);
+                            break;
+                        }
+                    }
+                    Err(sync::broadcast::error::RecvError::Closed) => {
+                        tracing::info!("Alert log channel closed, stopping the task.");
+                        break;
+                    }
+                    Err(err) => {
+                        tracing::error!("Error receiving alert for the alert log: {:?}", err);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Serializes an alert as an entry of the alert log, along with its timestamp.
+    fn alert_log_entry(notification: &GaladrielAlerts) -> Value {
+        match notification {
+            GaladrielAlerts::Success {
+                start_time,
+                ending_time,
+                duration,
+                message,
+            } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "success",
+                "message": message,
+                "endingTime": ending_time.to_rfc3339(),
+                "durationMs": duration.num_milliseconds(),
+            }),
+            GaladrielAlerts::Information {
+                start_time,
+                message,
+            } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "information",
+                "message": message,
+            }),
+            GaladrielAlerts::Warning {
+                start_time,
+                message,
+            } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "warning",
+                "message": message,
+            }),
+            GaladrielAlerts::NenyrError { start_time, error } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "nenyrError",
+                "message": error.get_error_message(),
+                "kind": format!("{:?}", error.get_error_kind()),
+                "contextPath": error.get_context_path(),
+                "contextName": error.get_context_name(),
+                "line": error.get_line(),
+                "column": error.get_column(),
+                "suggestion": error.get_suggestion(),
+            }),
+            GaladrielAlerts::GaladrielError { start_time, error } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "galadrielError",
+                "message": error.get_message(),
+                "kind": format!("{:?}", error.get_kind()),
+                "errorType": format!("{:?}", error.get_type()),
+            }),
+            GaladrielAlerts::Shortcuts { start_time, .. } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "shortcuts",
+            }),
+            GaladrielAlerts::Text {
+                start_time, title, ..
+            } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "text",
+                "message": title,
+            }),
+            GaladrielAlerts::StylesUpdated {
+                start_time,
+                contexts,
+            } => json!({
+                "timestamp": start_time.to_rfc3339(),
+                "type": "stylesUpdated",
+                "contexts": contexts,
+            }),
+        }
+    }
+
+    /// Raises the warning reporting that the alert log could not be written.
+    fn raise_alert_log_warning(
+        palantir_sender: &sync::broadcast::Sender<GaladrielAlerts>,
+        log_path: &Path,
+        err: &std::io::Error,
+    ) {
+        tracing::error!("Failed to write the alert log at {:?}: {:?}", log_path, err);
+
+        let notification = GaladrielAlerts::create_warning(
+            Local::now(),
+            &format!(
+                "The alerts could not be logged to {:?}: {}. The remaining alerts of this session will not be logged.",
+                log_path, err
+            ),
+        );
+
+        if let Err(err) = palantir_sender.send(notification) {
+            tracing::error!("Failed to send alert: {:?}", err);
+        }
+    }
+
+    /// Checks whether the alert reports a Galadriel CSS or Nenyr error.
+    fn is_error_alert(notification: &GaladrielAlerts) -> bool {
+        matches!(
+            notification,
+            GaladrielAlerts::GaladrielError { .. } | GaladrielAlerts::NenyrError { .. }
+        )
+    }
+
+    /// Rings the terminal bell by writing the BEL character to the standard output.
+    fn ring_terminal_bell() {
+        tracing::debug!("Ringing the terminal bell for an error alert.");
+
+        let mut stdout = std::io::stdout();
+
+        if let Err(err) = stdout.write_all(b"\x07").and_then(|_| stdout.flush()) {
+            tracing::error!("Failed to ring the terminal bell: {:?}", err);
+        }
+    }
+
     /// Pushes a new alert to the top of the `PALANTIR_ALERTS` cache.
     ///
     /// # Parameters
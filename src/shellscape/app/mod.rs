@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use chrono::Local;
 use rand::Rng;
 use ratatui::widgets::ScrollbarState;
@@ -19,6 +21,9 @@ use crate::{
 
 use super::{area::ShellscapeArea, metadata::ShellscapeMetadata};
 
+/// The environment variable pointing to the Nenyr syntax definition reloaded during development.
+const NENYR_SYNTAX_PATH_ENV: &str = "GALADRIEL_NENYR_SYNTAX_PATH";
+
 // The `ShellscapeApp` struct serves as the core representation of the terminal-based application, encapsulating its configuration, UI state, alerts, and various settings that control its behavior and appearance.
 // It leverages `ratatui` for rendering the UI, which enables managing interactive terminal-based UIs efficiently.
 #[allow(dead_code)]
@@ -27,6 +32,11 @@ pub struct ShellscapeApp {
     palantir_sender: sync::broadcast::Sender<GaladrielAlerts>,
     pub metadata: ShellscapeMetadata,
     pub server_running_on_port: u16,
+    pub working_dir: PathBuf,
+    pub watch_paused: bool,
+    pub search_query: Option<String>,
+    pub status_receiver: Option<sync::mpsc::Receiver<String>>,
+    pub status_line: Option<String>,
 
     pub table_scroll_state: ScrollbarState,
     pub dock_scroll_state: ScrollbarState,
@@ -34,6 +44,8 @@ pub struct ShellscapeApp {
     pub dock_vertical_axis: u16,
     pub table_scroll_len: usize,
     pub dock_scroll_len: usize,
+    pub table_error_positions: Vec<usize>,
+    pub table_error_context_paths: Vec<(usize, String)>,
     pub table_area: ShellscapeArea,
     pub dock_area: ShellscapeArea,
 
@@ -76,35 +88,10 @@ impl ShellscapeApp {
             "\u{00A9} 2024 Galadriel CSS. Crafting modular, efficient, and scalable styles with precision. Built with Rust.".to_string(),
         );
 
-        // Create a new syntax set builder
-        let mut ssb = SyntaxSetBuilder::new();
-        let nenyr_syntax = SyntaxDefinition::load_from_str(&get_nenyr_syntax(), true, None)
-            .map_err(|err| {
-                GaladrielError::raise_general_interface_error(
-                    ErrorKind::NenyrSyntaxIntegrationFailed,
-                    &err.to_string(),
-                    ErrorAction::Exit,
-                )
-            })?;
-
-        // Add syntax.
-        ssb.add(nenyr_syntax);
-
-        // Build the syntax set
-        let syntax_set = ssb.build();
+        // Build the syntax set holding the embedded Nenyr syntax
+        let (syntax_set, syntax) = build_nenyr_syntax(&get_nenyr_syntax(), ErrorAction::Exit)?;
         // Load default theme set
         let theme_set = ThemeSet::load_defaults();
-        // Find the "Nenyr" syntax in the syntax set
-        let syntax = syntax_set
-            .find_syntax_by_name("Nenyr")
-            .ok_or_else(|| {
-                GaladrielError::raise_general_interface_error(
-                    ErrorKind::NenyrSyntaxMissing,
-                    "The Nenyr syntax could not be found in the syntax set.",
-                    ErrorAction::Exit,
-                )
-            })?
-            .to_owned();
 
         // Return a new ShellscapeApp instance with the configured values
         Ok(Self {
@@ -113,10 +100,17 @@ impl ShellscapeApp {
             table_area: ShellscapeArea::new(0, 0, 0, 0),
             dock_area: ShellscapeArea::new(0, 0, 0, 0),
             server_running_on_port: 0,
+            working_dir: PathBuf::new(),
+            watch_paused: false,
+            search_query: None,
+            status_receiver: None,
+            status_line: None,
             table_vertical_axis: 0,
             dock_vertical_axis: 0,
             table_scroll_len: 0,
             dock_scroll_len: 0,
+            table_error_positions: vec![],
+            table_error_context_paths: vec![],
             palantir_sender,
             metadata,
             syntax_set,
@@ -168,6 +162,65 @@ impl ShellscapeApp {
         lines
     }
 
+    /// Reloads the Nenyr syntax definition from the file set in the `GALADRIEL_NENYR_SYNTAX_PATH`
+    /// environment variable and rebuilds the syntax set used for highlighting.
+    ///
+    /// This is meant for developing the syntax definition itself, so highlighting tweaks can be
+    /// tested without restarting the development session. If the file cannot be read or parsed,
+    /// an error alert is added and the current syntax is kept.
+    pub fn reload_nenyr_syntax(&mut self) {
+        let syntax_path = match std::env::var(NENYR_SYNTAX_PATH_ENV) {
+            Ok(syntax_path) if !syntax_path.trim().is_empty() => PathBuf::from(syntax_path.trim()),
+            _ => {
+                self.add_alert(GaladrielAlerts::create_information(
+                    Local::now(),
+                    &format!(
+                        "No Nenyr syntax definition to reload. Set the `{}` environment variable to the path of the definition being developed.",
+                        NENYR_SYNTAX_PATH_ENV
+                    ),
+                ));
+
+                return;
+            }
+        };
+
+        info!(
+            "Reloading the Nenyr syntax definition from: {:?}",
+            syntax_path
+        );
+
+        let reloaded_syntax = std::fs::read_to_string(&syntax_path)
+            .map_err(|err| {
+                GaladrielError::raise_general_interface_error(
+                    ErrorKind::NenyrSyntaxIntegrationFailed,
+                    &format!(
+                        "Failed to read the Nenyr syntax definition at {:?}: {}",
+                        syntax_path, err
+                    ),
+                    ErrorAction::Notify,
+                )
+            })
+            .and_then(|definition| build_nenyr_syntax(&definition, ErrorAction::Notify));
+
+        match reloaded_syntax {
+            Ok((syntax_set, syntax)) => {
+                self.syntax_set = syntax_set;
+                self.syntax = syntax;
+
+                self.add_alert(GaladrielAlerts::create_information(
+                    Local::now(),
+                    &format!(
+                        "Reloaded the Nenyr syntax definition from {:?}.",
+                        syntax_path
+                    ),
+                ));
+            }
+            Err(err) => {
+                self.add_alert(GaladrielAlerts::create_galadriel_error(Local::now(), err));
+            }
+        }
+    }
+
     pub fn add_alert(&self, notification: GaladrielAlerts) {
         let palantir_sender = self.palantir_sender.clone();
 
@@ -190,6 +243,19 @@ impl ShellscapeApp {
         self.table_scroll_len = 0;
     }
 
+    /// Resets the working directory used to relativize paths displayed in alerts.
+    ///
+    /// # Arguments
+    /// * `working_dir` - The working directory of the current Galadriel CSS runtime.
+    pub fn reset_working_dir(&mut self, working_dir: PathBuf) {
+        self.working_dir = working_dir;
+    }
+
+    /// Retrieves the working directory used to relativize paths displayed in alerts.
+    pub fn get_working_dir(&self) -> PathBuf {
+        self.working_dir.to_owned()
+    }
+
     /// Resets the port on which the server is running.
     ///
     /// # Arguments
@@ -198,6 +264,105 @@ impl ShellscapeApp {
         self.server_running_on_port = port;
     }
 
+    /// Resets whether the file watcher is paused, as displayed in the footer.
+    ///
+    /// # Arguments
+    /// * `paused` - Whether the processing of file changes is paused.
+    pub fn reset_watch_paused(&mut self, paused: bool) {
+        self.watch_paused = paused;
+    }
+
+    /// Checks whether the file watcher is paused.
+    pub fn is_watch_paused(&self) -> bool {
+        self.watch_paused
+    }
+
+    /// Sets the receiver of the log messages displayed by the status line, in verbose mode.
+    ///
+    /// # Arguments
+    /// * `status_receiver` - The receiver fed by the `ShellscapeStatusLayer`.
+    pub fn reset_status_receiver(&mut self, status_receiver: sync::mpsc::Receiver<String>) {
+        self.status_receiver = Some(status_receiver);
+    }
+
+    /// Updates the status line with the latest log message received, skipping the ones queued before it.
+    pub fn refresh_status_line(&mut self) {
+        let Some(status_receiver) = self.status_receiver.as_mut() else {
+            return;
+        };
+
+        while let Ok(message) = status_receiver.try_recv() {
+            self.status_line = Some(message);
+        }
+    }
+
+    /// Returns the latest log message displayed by the status line, if any was received.
+    pub fn get_status_line(&self) -> Option<String> {
+        self.status_line.clone()
+    }
+
+    /// Starts searching the alerts, keeping the query of a search already in progress.
+    pub fn start_alerts_search(&mut self) {
+        if self.search_query.is_none() {
+            self.search_query = Some(String::new());
+        }
+    }
+
+    /// Appends a character to the query of the alerts search in progress.
+    pub fn type_alerts_search(&mut self, character: char) {
+        if let Some(query) = self.search_query.as_mut() {
+            query.push(character);
+            self.reset_table_axis();
+        }
+    }
+
+    /// Erases the last character of the query of the alerts search in progress.
+    pub fn erase_alerts_search(&mut self) {
+        if let Some(query) = self.search_query.as_mut() {
+            query.pop();
+            self.reset_table_axis();
+        }
+    }
+
+    /// Clears the alerts search, restoring the full list of alerts.
+    pub fn clear_alerts_search(&mut self) {
+        self.search_query = None;
+        self.reset_table_axis();
+    }
+
+    /// Checks whether the alerts are being searched.
+    pub fn is_searching_alerts(&self) -> bool {
+        self.search_query.is_some()
+    }
+
+    /// Retrieves the query of the alerts search in progress, if any.
+    pub fn get_search_query(&self) -> Option<String> {
+        self.search_query.to_owned()
+    }
+
+    /// Keeps only the alerts matching the query of the alerts search in progress.
+    ///
+    /// # Arguments
+    /// * `alerts` - The alerts to be filtered.
+    ///
+    /// # Returns
+    /// The matching alerts, or all of them when no search is in progress or its query is empty.
+    pub fn filter_alerts(&self, alerts: Vec<GaladrielAlerts>) -> Vec<GaladrielAlerts> {
+        match self.search_query.as_deref() {
+            Some(query) if !query.is_empty() => alerts
+                .into_iter()
+                .filter(|alert| alert.matches_query(query))
+                .collect(),
+            _ => alerts,
+        }
+    }
+
+    /// Scrolls the alerts table back to its top, as its content changed.
+    fn reset_table_axis(&mut self) {
+        self.table_vertical_axis = 0;
+        self.table_scroll_state = self.table_scroll_state.position(0);
+    }
+
     /// Resets the subtitle in the metadata.
     ///
     /// # Arguments
@@ -328,6 +493,91 @@ impl ShellscapeApp {
         self.dock_scroll_state = self.dock_scroll_state.content_length(len);
     }
 
+    /// Resets the line positions of the error alerts rendered in the alerts table.
+    ///
+    /// # Arguments
+    /// - `positions`: The index of the first rendered line of each error alert, in ascending order.
+    pub fn reset_table_error_positions(&mut self, positions: Vec<usize>) {
+        self.table_error_positions = positions;
+    }
+
+    /// Resets the context paths of the Nenyr error alerts rendered in the alerts table.
+    ///
+    /// # Arguments
+    /// - `context_paths`: The index of the first rendered line of each Nenyr error alert, paired with its context path.
+    pub fn reset_table_error_context_paths(&mut self, context_paths: Vec<(usize, String)>) {
+        self.table_error_context_paths = context_paths;
+    }
+
+    /// Retrieves the context path of the topmost Nenyr error alert within the visible part of the alerts table.
+    ///
+    /// # Returns
+    /// The context path, or `None` when no Nenyr error alert starts within the visible lines.
+    pub fn get_topmost_visible_error_path(&self) -> Option<String> {
+        let first_visible_line = self.table_vertical_axis as usize;
+        let visible_height = self
+            .table_area
+            .bottom()
+            .saturating_sub(self.table_area.top()) as usize;
+
+        self.table_error_context_paths
+            .iter()
+            .find(|(position, _)| {
+                *position >= first_visible_line && *position < first_visible_line + visible_height
+            })
+            .map(|(_, context_path)| context_path.to_owned())
+    }
+
+    /// Scrolls the alerts table so the next error alert below the current one is centered.
+    ///
+    /// Information, success and other non-error alerts are skipped. Nothing happens when
+    /// there is no error alert after the current scroll position.
+    pub fn jump_to_next_error(&mut self) {
+        let current_axis = self.table_vertical_axis;
+
+        let next_axis = self
+            .table_error_positions
+            .iter()
+            .map(|position| self.center_table_axis_on(*position))
+            .find(|axis| *axis > current_axis);
+
+        if let Some(axis) = next_axis {
+            self.table_vertical_axis = axis;
+            self.table_scroll_state = self.table_scroll_state.position(axis as usize);
+        }
+    }
+
+    /// Scrolls the alerts table so the previous error alert above the current one is centered.
+    ///
+    /// Information, success and other non-error alerts are skipped. Nothing happens when
+    /// there is no error alert before the current scroll position.
+    pub fn jump_to_previous_error(&mut self) {
+        let current_axis = self.table_vertical_axis;
+
+        let previous_axis = self
+            .table_error_positions
+            .iter()
+            .rev()
+            .map(|position| self.center_table_axis_on(*position))
+            .find(|axis| *axis < current_axis);
+
+        if let Some(axis) = previous_axis {
+            self.table_vertical_axis = axis;
+            self.table_scroll_state = self.table_scroll_state.position(axis as usize);
+        }
+    }
+
+    /// Computes the vertical axis that places the given line at the center of the alerts table.
+    fn center_table_axis_on(&self, position: usize) -> u16 {
+        let half_height = self
+            .table_area
+            .bottom()
+            .saturating_sub(self.table_area.top())
+            .saturating_div(2);
+
+        (position as u16).saturating_sub(half_height)
+    }
+
     pub fn tick(&self) {
         info!("ShellscapeApp tick method called.");
     }
@@ -386,7 +636,17 @@ impl ShellscapeApp {
             ("'Shift' + 'r'".to_string(), "Toggles the reset styles.".to_string()),
             ("'Shift' + 'm'".to_string(), "Toggles minified styles.".to_string()),
             ("'Shift' + 'n'".to_string(), "Toggles the auto-naming feature.".to_string()),
+            ("'Shift' + 'p'".to_string(), "Toggles relative paths in error alerts.".to_string()),
+            ("'Shift' + 'h'".to_string(), "Toggles syntax highlighting in error alerts.".to_string()),
+            ("'Shift' + 'o'".to_string(), "Pauses or resumes the file watcher.".to_string()),
             ("'Shift' + 'k'".to_string(), "Clears all alerts.".to_string()),
+            ("'Shift' + 'y'".to_string(), "Reloads the Nenyr syntax definition under development.".to_string()),
+            ("'Shift' + 'g'".to_string(), "Reloads the configurations, for the changes the watcher missed.".to_string()),
+            ("'Shift' + 'b'".to_string(), "Benchmarks the latest modified Nenyr file.".to_string()),
+            ("'Shift' + 'c'".to_string(), "Copies the context path of the topmost visible error.".to_string()),
+            ("'/'".to_string(), "Searches the alerts, filtering them while typing. 'Esc' clears the search.".to_string()),
+            ("'n'".to_string(), "Jumps to the next error alert.".to_string()),
+            ("'p'".to_string(), "Jumps to the previous error alert.".to_string()),
             ("'Ctrl' + 's'".to_string(), "Displays the shortcut guide.".to_string()),
             ("'Ctrl' + 'l'".to_string(), "Opens license information.".to_string()),
             ("'Ctrl' + 'd'".to_string(), "Displays the donation guide.".to_string()),
@@ -549,6 +809,52 @@ impl ShellscapeApp {
     }
 }
 
+/// Builds a syntax set holding the given Nenyr syntax definition and retrieves the Nenyr syntax from it.
+///
+/// # Arguments
+/// * `definition` - The Nenyr syntax definition, in the Sublime syntax format.
+/// * `action` - The action attached to the raised errors.
+///
+/// # Returns
+/// Returns a `GaladrielResult` with the built syntax set and the Nenyr syntax reference.
+///
+/// # Errors
+/// * The syntax definition could not be loaded (`NenyrSyntaxIntegrationFailed`).
+/// * The `Nenyr` syntax could not be found in the syntax set (`NenyrSyntaxMissing`).
+fn build_nenyr_syntax(
+    definition: &str,
+    action: ErrorAction,
+) -> GaladrielResult<(SyntaxSet, SyntaxReference)> {
+    // Create a new syntax set builder
+    let mut ssb = SyntaxSetBuilder::new();
+    let nenyr_syntax = SyntaxDefinition::load_from_str(definition, true, None).map_err(|err| {
+        GaladrielError::raise_general_interface_error(
+            ErrorKind::NenyrSyntaxIntegrationFailed,
+            &err.to_string(),
+            action.clone(),
+        )
+    })?;
+
+    // Add syntax.
+    ssb.add(nenyr_syntax);
+
+    // Build the syntax set
+    let syntax_set = ssb.build();
+    // Find the "Nenyr" syntax in the syntax set
+    let syntax = syntax_set
+        .find_syntax_by_name("Nenyr")
+        .ok_or_else(|| {
+            GaladrielError::raise_general_interface_error(
+                ErrorKind::NenyrSyntaxMissing,
+                "The Nenyr syntax could not be found in the syntax set.",
+                action,
+            )
+        })?
+        .to_owned();
+
+    Ok((syntax_set, syntax))
+}
+
 fn random_subtitle_message() -> String {
     let messages = [
         "Galadriel CSS was not designed to be merely simple; it was crafted to be a powerful, advanced and robust solution.",
@@ -568,9 +874,13 @@ fn random_subtitle_message() -> String {
 
 #[cfg(test)]
 mod tests {
+    use chrono::Local;
     use tokio::sync;
 
-    use crate::shellscape::app::ShellscapeApp;
+    use crate::{
+        events::GaladrielAlerts,
+        shellscape::{app::ShellscapeApp, area::ShellscapeArea},
+    };
 
     #[test]
     fn test_shellscape_app_new() {
@@ -594,4 +904,133 @@ mod tests {
 
         app.tick();
     }
+
+    #[test]
+    fn test_shellscape_app_jump_between_errors() {
+        let (sender, _) = sync::broadcast::channel(10);
+        let mut app = ShellscapeApp::new("1.0.0", sender).unwrap();
+
+        app.reset_table_area(ShellscapeArea::new(0, 100, 0, 20));
+        app.reset_table_scroll_state(200);
+        app.reset_table_error_positions(vec![5, 40, 120]);
+
+        // The first error is already visible, so the next one is centered.
+        app.jump_to_next_error();
+        assert_eq!(app.get_table_vertical_axis(), 30);
+
+        app.jump_to_next_error();
+        assert_eq!(app.get_table_vertical_axis(), 110);
+
+        // There are no errors after the last one.
+        app.jump_to_next_error();
+        assert_eq!(app.get_table_vertical_axis(), 110);
+
+        app.jump_to_previous_error();
+        assert_eq!(app.get_table_vertical_axis(), 30);
+
+        app.jump_to_previous_error();
+        assert_eq!(app.get_table_vertical_axis(), 0);
+    }
+
+    #[test]
+    fn test_shellscape_app_finds_topmost_visible_error_path() {
+        let (sender, _) = sync::broadcast::channel(10);
+        let mut app = ShellscapeApp::new("1.0.0", sender).unwrap();
+
+        app.reset_table_area(ShellscapeArea::new(0, 100, 0, 20));
+        app.reset_table_scroll_state(200);
+        app.reset_table_error_context_paths(vec![
+            (5, "src/card.nyr".to_string()),
+            (40, "src/layout.nyr".to_string()),
+        ]);
+
+        assert_eq!(
+            app.get_topmost_visible_error_path(),
+            Some("src/card.nyr".to_string())
+        );
+
+        // The first error is scrolled out of view once the second one is centered.
+        app.reset_table_error_positions(vec![5, 40]);
+        app.jump_to_next_error();
+        assert_eq!(
+            app.get_topmost_visible_error_path(),
+            Some("src/layout.nyr".to_string())
+        );
+
+        app.reset_table_error_context_paths(vec![]);
+        assert_eq!(app.get_topmost_visible_error_path(), None);
+    }
+
+    #[test]
+    fn test_shellscape_app_keeps_the_latest_status_line() {
+        let (sender, _) = sync::broadcast::channel(10);
+        let mut app = ShellscapeApp::new("1.0.0", sender).unwrap();
+        let (status_sender, status_receiver) = sync::mpsc::channel(10);
+
+        app.refresh_status_line();
+        assert_eq!(app.get_status_line(), None);
+
+        app.reset_status_receiver(status_receiver);
+        status_sender
+            .try_send("Parsing context file.".to_string())
+            .unwrap();
+        status_sender
+            .try_send("Applying inheritance for Nenyr classes.".to_string())
+            .unwrap();
+
+        app.refresh_status_line();
+        assert_eq!(
+            app.get_status_line(),
+            Some("Applying inheritance for Nenyr classes.".to_string())
+        );
+
+        // The status line is kept until a newer message arrives.
+        app.refresh_status_line();
+        assert_eq!(
+            app.get_status_line(),
+            Some("Applying inheritance for Nenyr classes.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shellscape_app_filters_searched_alerts() {
+        let (sender, _) = sync::broadcast::channel(10);
+        let mut app = ShellscapeApp::new("1.0.0", sender).unwrap();
+
+        let alerts = vec![
+            GaladrielAlerts::create_information(Local::now(), "Layout context updated."),
+            GaladrielAlerts::create_warning(Local::now(), "Unknown alias in module context."),
+        ];
+
+        app.start_alerts_search();
+        assert_eq!(app.filter_alerts(alerts.clone()).len(), 2);
+
+        for character in "MODULE".chars() {
+            app.type_alerts_search(character);
+        }
+        assert_eq!(app.filter_alerts(alerts.clone()), vec![alerts[1].clone()]);
+
+        app.erase_alerts_search();
+        assert_eq!(app.get_search_query(), Some("MODUL".to_string()));
+
+        app.clear_alerts_search();
+        assert!(!app.is_searching_alerts());
+        assert_eq!(app.filter_alerts(alerts.clone()).len(), 2);
+    }
+
+    #[test]
+    fn test_shellscape_app_keeps_syntax_on_failed_reload() {
+        let (sender, _) = sync::broadcast::channel(10);
+        let mut app = ShellscapeApp::new("1.0.0", sender).unwrap();
+
+        std::env::set_var(
+            "GALADRIEL_NENYR_SYNTAX_PATH",
+            "missing/nenyr.sublime-syntax",
+        );
+        app.reload_nenyr_syntax();
+        std::env::remove_var("GALADRIEL_NENYR_SYNTAX_PATH");
+
+        assert_eq!(app.syntax.name, "Nenyr");
+        assert!(app.syntax_set.find_syntax_by_name("Nenyr").is_some());
+    }
 }
@@ -21,6 +21,8 @@ pub enum ShellscapeCommands {
         row: u16,
     },
     ClearAlertsTable,
+    NextError,
+    PrevError,
     VewShortcuts,
     ViewLicense,
     MakeDonation,
@@ -29,9 +31,20 @@ pub enum ShellscapeCommands {
     ToggleResetStyles,
     ToggleMinifiedStyles,
     ToggleAutoNaming,
+    ToggleRelativePaths,
+    TogglePlainAlerts,
     ModifyVersion,
     AdjustExclude,
     ResetAllAsts,
+    ToggleWatch,
+    ReloadNenyrSyntax,
+    ReloadConfig,
+    BenchFile,
+    CopyErrorPath,
+    SearchAlerts,
+    TypeAlertsSearch(char),
+    EraseAlertsSearch,
+    ClearAlertsSearch,
 }
 
 impl ShellscapeCommands {
@@ -77,6 +90,14 @@ impl ShellscapeCommands {
                 info!("Toggling auto-naming feature...");
                 ShellscapeCommands::ToggleAutoNaming
             }
+            KeyCode::Char('p') | KeyCode::Char('P') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Toggling relative paths in error alerts...");
+                ShellscapeCommands::ToggleRelativePaths
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Toggling syntax highlighting in error alerts...");
+                ShellscapeCommands::TogglePlainAlerts
+            }
             KeyCode::Char('v') | KeyCode::Char('V') if event.modifiers == KeyModifiers::SHIFT => {
                 info!("Modifying version configuration...");
                 ShellscapeCommands::ModifyVersion
@@ -85,10 +106,42 @@ impl ShellscapeCommands {
                 info!("Adjusting exclusion settings...");
                 ShellscapeCommands::AdjustExclude
             }
+            KeyCode::Char('o') | KeyCode::Char('O') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Toggling the file watcher...");
+                ShellscapeCommands::ToggleWatch
+            }
+            KeyCode::Char('y') | KeyCode::Char('Y') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Reloading the Nenyr syntax definition...");
+                ShellscapeCommands::ReloadNenyrSyntax
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Reloading the Galadriel CSS configurations...");
+                ShellscapeCommands::ReloadConfig
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Benchmarking the latest modified Nenyr file...");
+                ShellscapeCommands::BenchFile
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') if event.modifiers == KeyModifiers::SHIFT => {
+                info!("Copying the context path of the topmost visible error...");
+                ShellscapeCommands::CopyErrorPath
+            }
+            KeyCode::Char('/') => {
+                info!("Searching the alerts...");
+                ShellscapeCommands::SearchAlerts
+            }
             KeyCode::Char('k') | KeyCode::Char('K') if event.modifiers == KeyModifiers::SHIFT => {
                 info!("Clearing all alerts...");
                 ShellscapeCommands::ClearAlertsTable
             }
+            KeyCode::Char('n') if event.modifiers == KeyModifiers::NONE => {
+                info!("Jumping to the next error alert...");
+                ShellscapeCommands::NextError
+            }
+            KeyCode::Char('p') if event.modifiers == KeyModifiers::NONE => {
+                info!("Jumping to the previous error alert...");
+                ShellscapeCommands::PrevError
+            }
             KeyCode::Char('s') | KeyCode::Char('S') if event.modifiers == KeyModifiers::CONTROL => {
                 info!("Displaying shortcut guide...");
                 ShellscapeCommands::VewShortcuts
@@ -125,6 +178,33 @@ impl ShellscapeCommands {
         }
     }
 
+    /// Converts a `KeyEvent` received while searching the alerts into a `ShellscapeCommands` variant.
+    ///
+    /// Typed characters extend the search query and `Backspace` erases its last character, while
+    /// `Esc` clears the search. Any other event is converted as outside the search.
+    ///
+    /// # Arguments
+    /// - `event`: A `KeyEvent` representing the key press event to be processed.
+    ///
+    /// # Returns
+    /// Returns a `ShellscapeCommands` variant based on the `KeyEvent` passed.
+    pub fn from_search_key_event(event: KeyEvent) -> ShellscapeCommands {
+        match event.code {
+            KeyCode::Esc => {
+                info!("Clearing the alerts search...");
+                ShellscapeCommands::ClearAlertsSearch
+            }
+            KeyCode::Backspace => ShellscapeCommands::EraseAlertsSearch,
+            KeyCode::Char(character)
+                if event.modifiers == KeyModifiers::NONE
+                    || event.modifiers == KeyModifiers::SHIFT =>
+            {
+                ShellscapeCommands::TypeAlertsSearch(character)
+            }
+            _ => Self::from_key_event(event),
+        }
+    }
+
     pub fn from_mouse_event(event: MouseEvent) -> ShellscapeCommands {
         match event.kind {
             MouseEventKind::ScrollDown => ShellscapeCommands::ScrollDown {
@@ -174,6 +254,104 @@ mod tests {
         assert_eq!(result, ShellscapeCommands::AboutAuthor);
     }
 
+    #[test]
+    fn test_from_key_event_toggle_relative_paths_with_shift_p() {
+        let event = KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::ToggleRelativePaths);
+    }
+
+    #[test]
+    fn test_from_key_event_toggle_plain_alerts_with_shift_h() {
+        let event = KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::TogglePlainAlerts);
+    }
+
+    #[test]
+    fn test_from_key_event_toggle_watch_with_shift_o() {
+        let event = KeyEvent::new(KeyCode::Char('O'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::ToggleWatch);
+    }
+
+    #[test]
+    fn test_from_key_event_reload_nenyr_syntax_with_shift_y() {
+        let event = KeyEvent::new(KeyCode::Char('Y'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::ReloadNenyrSyntax);
+    }
+
+    #[test]
+    fn test_from_key_event_reload_config_with_shift_g() {
+        let event = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::ReloadConfig);
+    }
+
+    #[test]
+    fn test_from_key_event_bench_file_with_shift_b() {
+        let event = KeyEvent::new(KeyCode::Char('B'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::BenchFile);
+    }
+
+    #[test]
+    fn test_from_key_event_copy_error_path_with_shift_c() {
+        let event = KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT);
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::CopyErrorPath);
+    }
+
+    #[test]
+    fn test_from_key_event_search_alerts_with_slash() {
+        let event = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::empty());
+        let result = ShellscapeCommands::from_key_event(event);
+        assert_eq!(result, ShellscapeCommands::SearchAlerts);
+    }
+
+    #[test]
+    fn test_from_search_key_event() {
+        let event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty());
+        assert_eq!(
+            ShellscapeCommands::from_search_key_event(event),
+            ShellscapeCommands::TypeAlertsSearch('q')
+        );
+
+        let event = KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty());
+        assert_eq!(
+            ShellscapeCommands::from_search_key_event(event),
+            ShellscapeCommands::EraseAlertsSearch
+        );
+
+        let event = KeyEvent::new(KeyCode::Esc, KeyModifiers::empty());
+        assert_eq!(
+            ShellscapeCommands::from_search_key_event(event),
+            ShellscapeCommands::ClearAlertsSearch
+        );
+
+        let event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(
+            ShellscapeCommands::from_search_key_event(event),
+            ShellscapeCommands::Terminate
+        );
+    }
+
+    #[test]
+    fn test_from_key_event_jump_between_errors() {
+        let event = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(
+            ShellscapeCommands::from_key_event(event),
+            ShellscapeCommands::NextError
+        );
+
+        let event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::empty());
+        assert_eq!(
+            ShellscapeCommands::from_key_event(event),
+            ShellscapeCommands::PrevError
+        );
+    }
+
     #[test]
     fn test_from_key_event_none_with_non_control() {
         let event = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::empty());
@@ -21,6 +21,7 @@ mod area;
 pub mod commands;
 pub mod events;
 mod metadata;
+pub mod status_layer;
 pub mod ui;
 mod widgets;
 
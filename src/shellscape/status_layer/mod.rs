@@ -0,0 +1,118 @@
+use std::fmt::Debug;
+
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+/// Number of log messages queued for the status line before the newer ones are dropped.
+const STATUS_LINE_CAPACITY: usize = 64;
+
+/// A `tracing` layer forwarding the message of the `INFO` or more severe log events to the status
+/// line of the terminal UI.
+///
+/// The messages are queued without ever waiting, so a render loop that falls behind only
+/// loses messages, instead of stalling the code that logs them.
+#[derive(Debug)]
+pub struct ShellscapeStatusLayer {
+    status_sender: Sender<String>,
+}
+
+impl ShellscapeStatusLayer {
+    /// Creates the status layer along with the receiver of the messages it forwards.
+    ///
+    /// # Returns
+    /// A tuple with the layer and the receiver to be drained by the terminal UI.
+    pub fn new() -> (Self, Receiver<String>) {
+        let (status_sender, status_receiver) = mpsc::channel(STATUS_LINE_CAPACITY);
+
+        (Self { status_sender }, status_receiver)
+    }
+}
+
+impl<S: Subscriber> Layer<S> for ShellscapeStatusLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+
+        // The more verbose levels compare as greater. The logs of the terminal UI itself, such as
+        // its ticks, would hide the ones describing the processing, so they are skipped as well.
+        if *metadata.level() > Level::INFO || metadata.target().contains("::shellscape") {
+            return;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        if let Some(message) = visitor.message {
+            // A full queue means the terminal UI is behind, so the message is dropped.
+            let _ = self.status_sender.try_send(message);
+        }
+    }
+}
+
+/// Extracts the message field of a log event.
+#[derive(Default)]
+struct MessageVisitor {
+    message: Option<String>,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{ShellscapeStatusLayer, STATUS_LINE_CAPACITY};
+
+    #[test]
+    fn status_layer_forwards_info_messages() {
+        let (status_layer, mut status_receiver) = ShellscapeStatusLayer::new();
+        let subscriber = tracing_subscriber::registry().with(status_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!(target: "galadrielcss::synthesizer", "Skipped debug message.");
+            tracing::info!(target: "galadrielcss::synthesizer", "Processing {} contexts.", 3);
+            tracing::info!("Skipped terminal UI message.");
+        });
+
+        assert_eq!(
+            status_receiver.try_recv().ok(),
+            Some("Processing 3 contexts.".to_string())
+        );
+        assert!(status_receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn status_layer_drops_messages_when_full() {
+        let (status_layer, mut status_receiver) = ShellscapeStatusLayer::new();
+        let subscriber = tracing_subscriber::registry().with(status_layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            for idx in 0..STATUS_LINE_CAPACITY + 10 {
+                tracing::info!(target: "galadrielcss::synthesizer", "Message {}", idx);
+            }
+        });
+
+        let mut received = 0;
+
+        while status_receiver.try_recv().is_ok() {
+            received += 1;
+        }
+
+        assert_eq!(received, STATUS_LINE_CAPACITY);
+    }
+}
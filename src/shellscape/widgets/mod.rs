@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use chrono::{DateTime, Local, TimeDelta};
 use nenyr::error::NenyrError;
 use ratatui::{
@@ -12,7 +14,10 @@ use textwrap::Options;
 
 use crate::{
     asts::PALANTIR_ALERTS,
-    configatron::{get_auto_naming, get_exclude, get_minified_styles, get_reset_styles},
+    configatron::{
+        get_auto_naming, get_exclude, get_minified_styles, get_plain_alerts,
+        get_relative_error_paths, get_reset_styles,
+    },
     error::GaladrielError,
     events::{AlertTextType, GaladrielAlerts},
 };
@@ -369,6 +374,24 @@ impl ShellscapeWidgets {
 
         lines.append(&mut auto_naming);
 
+        let mut relative_paths = self.format_config_label(
+            "\u{1F7E9}".to_string(),
+            "Relative Paths".to_string(),
+            format!("{}", get_relative_error_paths()),
+            dock_width,
+        );
+
+        lines.append(&mut relative_paths);
+
+        let mut plain_alerts = self.format_config_label(
+            "\u{1F7E6}".to_string(),
+            "Plain Alerts".to_string(),
+            format!("{}", get_plain_alerts()),
+            dock_width,
+        );
+
+        lines.append(&mut plain_alerts);
+
         let mut port_element = self.format_config_label(
             "\u{2B1B}".to_string(),
             "Port".to_string(),
@@ -418,12 +441,19 @@ impl ShellscapeWidgets {
             dock_width,
         );
 
+        let toggle_paths = self.format_dock_option(
+            "Relative Paths".to_string(),
+            "'Shift' + 'P'".to_string(),
+            dock_width,
+        );
+
         // Add the formatted title and options to the list of lines.
         lines.push(configs_title);
         lines.push(Line::from(Span::raw("")));
         lines.push(toggle_reset);
         lines.push(toggle_minified);
         lines.push(toggle_naming);
+        lines.push(toggle_paths);
 
         lines
     }
@@ -447,6 +477,8 @@ impl ShellscapeWidgets {
 
         let options = vec![
             ("Clear All Alerts".to_string(), "'Shift' + 'K'".to_string()),
+            ("Next Error".to_string(), "'n'".to_string()),
+            ("Previous Error".to_string(), "'p'".to_string()),
             ("Reset All ASTs".to_string(), "'Shift' + 'W'".to_string()),
             ("View Shortcuts".to_string(), "'Ctrl' + 'S'".to_string()),
             ("View License".to_string(), "'Ctrl' + 'L'".to_string()),
@@ -671,28 +703,37 @@ impl ShellscapeWidgets {
     /// - `app`: A mutable reference to the `ShellscapeApp` for syntax highlighting.
     ///
     /// # Returns
-    /// A `Line` object with syntax highlighting applied.
+    /// A `Line` object with syntax highlighting applied, unless the `plainAlerts` configuration is enabled.
     fn format_alert_line(
         &self,
         line_text: String,
         line_num: usize,
         app: &mut ShellscapeApp,
     ) -> Line {
-        // Obtain syntax-highlighted ranges for the line.
-        let ranges = app.highlighter(&line_text);
-        let mut spans: Vec<Span> = ranges
-            .iter()
-            .map(|(style, line)| {
-                Span::styled(
-                    line.to_string(),
-                    Style::default().fg(Color::Rgb(
-                        style.foreground.r,
-                        style.foreground.g,
-                        style.foreground.b,
-                    )),
-                )
-            })
-            .collect();
+        let mut spans: Vec<Span> = if get_plain_alerts() {
+            // Skip the highlighter entirely, rendering the line in a single color.
+            vec![Span::styled(
+                line_text,
+                Style::default().fg(self.off_white_color),
+            )]
+        } else {
+            // Obtain syntax-highlighted ranges for the line.
+            let ranges = app.highlighter(&line_text);
+
+            ranges
+                .iter()
+                .map(|(style, line)| {
+                    Span::styled(
+                        line.to_string(),
+                        Style::default().fg(Color::Rgb(
+                            style.foreground.r,
+                            style.foreground.g,
+                            style.foreground.b,
+                        )),
+                    )
+                })
+                .collect()
+        };
 
         // Add decorative and numerical indicators.
         spans.insert(
@@ -958,7 +999,7 @@ impl ShellscapeWidgets {
         let error_path = self.format_alert_label(
             "\u{1F534}".to_string(),
             "Path           ".to_string(),
-            format!("{:?}", error.get_context_path()),
+            self.format_context_path(PathBuf::from(error.get_context_path()), app),
         );
 
         let error_kind = self.format_alert_label(
@@ -1038,6 +1079,27 @@ impl ShellscapeWidgets {
         lines
     }
 
+    /// Formats the context path of an error for display in the alerts table.
+    ///
+    /// When the `relativeErrorPaths` configuration is enabled, the path is shown relative to
+    /// the working directory, falling back to the original path when it cannot be relativized.
+    ///
+    /// # Parameters
+    /// - `context_path`: The path of the file where the error occurred.
+    /// - `app`: The reference to the `ShellscapeApp` instance holding the working directory.
+    ///
+    /// # Returns
+    /// A `String` containing the quoted path ready to be displayed.
+    fn format_context_path(&self, context_path: PathBuf, app: &ShellscapeApp) -> String {
+        if get_relative_error_paths() {
+            if let Ok(relative_path) = context_path.strip_prefix(app.get_working_dir()) {
+                return format!("{:?}", relative_path.to_string_lossy());
+            }
+        }
+
+        format!("{:?}", context_path.to_string_lossy())
+    }
+
     /// Creates a success alert with a formatted title, message, and duration information.
     ///
     /// # Arguments
@@ -1259,6 +1321,10 @@ impl ShellscapeWidgets {
         app: &mut ShellscapeApp,
     ) -> Vec<Line> {
         let mut lines: Vec<Line> = vec![];
+        // Index of the first line of each error alert, used to jump between errors.
+        let mut error_positions: Vec<usize> = vec![];
+        // Index of the first line of each Nenyr error alert with its context path, used to copy the path.
+        let mut error_context_paths: Vec<(usize, String)> = vec![];
 
         // Check if there are no alerts to display, and if so, show a default message
         if alerts.len() == 0 {
@@ -1276,6 +1342,8 @@ impl ShellscapeWidgets {
             match alert {
                 // Process a GaladrielError alert (likely related to a Galadriel-related process)
                 GaladrielAlerts::GaladrielError { start_time, error } => {
+                    error_positions.push(lines.len());
+
                     let mut elements =
                         self.create_galadriel_error_alert(start_time, error, textwrap_width);
 
@@ -1293,6 +1361,9 @@ impl ShellscapeWidgets {
                 }
                 // Process a NenyrError alert (likely related to the Nenyr parsing)
                 GaladrielAlerts::NenyrError { start_time, error } => {
+                    error_positions.push(lines.len());
+                    error_context_paths.push((lines.len(), error.get_context_path()));
+
                     let mut elements =
                         self.create_nenyr_error_alert(start_time, error, textwrap_width, app);
 
@@ -1347,9 +1418,14 @@ impl ShellscapeWidgets {
 
                     lines.append(&mut elements);
                 }
+                // The styles updates are never pushed to the alerts cache.
+                GaladrielAlerts::StylesUpdated { .. } => {}
             }
         }
 
+        app.reset_table_error_positions(error_positions);
+        app.reset_table_error_context_paths(error_context_paths);
+
         lines
     }
 
@@ -1377,6 +1453,8 @@ impl ShellscapeWidgets {
             None => vec![],
         };
 
+        // Keep only the alerts matching the alerts search in progress
+        let alerts = app.filter_alerts(alerts);
         // Process the alerts to get a vector of formatted lines
         let lines = self.process_alerts(textwrap_width, alerts, app);
         // Get the total number of lines
@@ -1479,17 +1557,41 @@ impl ShellscapeWidgets {
         );
     }
 
-    /// Creates a footer widget for the terminal UI, which displays some footer text.
+    /// Creates a footer widget for the terminal UI, which displays some footer text,
+    /// a warning while the file watcher is paused, or the latest log message in verbose mode.
     ///
     /// # Arguments
     /// * `app` - A mutable reference to the `ShellscapeApp` where the footer text is retrieved from.
     ///
     /// Returns a `Paragraph` widget displaying the footer text in the terminal.
     fn create_footer(&self, app: &mut ShellscapeApp) -> Paragraph {
-        // Retrieve the footer text from the app state.
-        let footer_text = app.get_footer();
-        // Apply styling to the footer text (light cream color).
-        let footer = Span::styled(footer_text, Style::default().fg(self.light_cream_color));
+        // While the alerts are searched, the footer displays the search query instead of its text.
+        let footer = if let Some(query) = app.get_search_query() {
+            Span::styled(
+                format!("SEARCH \u{25E6} /{}  ('Esc' clears the search)", query),
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(self.light_cream_color),
+            )
+        } else if app.is_watch_paused() {
+            Span::styled(
+                "WATCHER PAUSED \u{25E6} File changes are ignored. Press 'Shift' + 'o' to resume.",
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(self.dark_mustard_color),
+            )
+        } else if let Some(status_line) = app.get_status_line() {
+            Span::styled(
+                format!("LOG \u{25E6} {}", status_line),
+                Style::default().fg(self.light_cream_color),
+            )
+        } else {
+            // Apply styling to the footer text (light cream color).
+            Span::styled(
+                app.get_footer(),
+                Style::default().fg(self.light_cream_color),
+            )
+        };
 
         // Create a Paragraph widget with the footer text and apply styling (alignment, background, padding).
         Paragraph::new(footer)
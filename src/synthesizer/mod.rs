@@ -1,4 +1,8 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use chrono::Local;
 use ignore::{overrides, WalkBuilder};
@@ -6,8 +10,26 @@ use nenyr::NenyrParser;
 use tokio::sync::{broadcast, RwLock};
 
 use crate::{
-    astroform::Astroform, configatron::get_reset_styles, events::GaladrielAlerts, formera::formera,
-    trailblazer::Trailblazer, utils::is_nenyr_event::is_nenyr_event,
+    astroform::Astroform,
+    asts::CompilationSession,
+    configatron::get_reset_styles,
+    events::GaladrielAlerts,
+    formera::formera,
+    trailblazer::Trailblazer,
+    utils::{
+        build_cache::{
+            hash_context_files, restore_build_cache, store_build_cache, CachedAlert, CachedFile,
+        },
+        build_progress::{is_json_progress_enabled, record_processed_file},
+        is_nenyr_event::is_nenyr_event,
+        send_palantir_error_notification::send_palantir_error_notification,
+        send_palantir_notification::send_palantir_notification,
+        send_palantir_success_notification::send_palantir_success_notification,
+        warn_crowded_properties::warn_crowded_properties,
+        warn_dangling_derivations::warn_dangling_derivations,
+        warn_important_overrides::warn_important_overrides,
+        warn_unused_variables::warn_unused_variables,
+    },
 };
 
 /// `Synthesizer` is responsible for reprocessing all Nenyr contexts in the application.
@@ -21,14 +43,27 @@ use crate::{
 /// - `module_contexts`: A vector holding paths to module context files.
 /// - `matcher`: A reference to the matcher used for context filtering.,
 /// - `palantir_sender`: A broadcast sender used for sending alerts.
+/// - `emit_styles`: A flag indicating whether the CSS should be generated after parsing.
+/// - `split_themes`: A flag indicating whether the non-default themes are split from the main stylesheet.
+/// - `split_responsive`: A flag indicating whether the mobile-first and desktop-first responsive styles are split from the main stylesheet.
+/// - `build_cache`: A flag indicating whether the parsed ASTs are restored from and stored into the build cache.
+/// - `names_mangling`: A flag indicating whether the generated identifiers are renamed to the shortest unique names.
+/// - `session`: The session the contexts are compiled into.
 #[derive(Clone, Debug)]
 pub struct Synthesizer {
     include_central: bool,
+    emit_styles: bool,
+    split_themes: bool,
+    split_responsive: bool,
+    build_cache: bool,
+    names_mangling: bool,
     central_context: PathBuf,
+    central_override: Option<PathBuf>,
     layout_contexts: Vec<PathBuf>,
     module_contexts: Vec<PathBuf>,
     matcher: Arc<RwLock<overrides::Override>>,
     palantir_sender: broadcast::Sender<GaladrielAlerts>,
+    session: CompilationSession,
 }
 
 impl Synthesizer {
@@ -38,6 +73,7 @@ impl Synthesizer {
     /// - `include_central`: A flag to specify whether the central context should be included in the processing.
     /// - `matcher`: A reference to the matcher used for context filtering.
     /// - `palantir_sender`: A sender used to broadcast alerts.
+    /// - `session`: The session the contexts are compiled into, forwarded to `Crealion`, `Trailblazer` and `Astroform`.
     ///
     /// # Returns
     /// Returns an instance of `Synthesizer`.
@@ -45,17 +81,88 @@ impl Synthesizer {
         include_central: bool,
         matcher: Arc<RwLock<overrides::Override>>,
         palantir_sender: broadcast::Sender<GaladrielAlerts>,
+        session: CompilationSession,
     ) -> Self {
         Self {
             central_context: PathBuf::new(),
+            central_override: None,
             layout_contexts: vec![],
             module_contexts: vec![],
             palantir_sender,
             include_central,
+            emit_styles: true,
+            split_themes: false,
+            split_responsive: false,
+            build_cache: false,
+            names_mangling: false,
             matcher,
+            session,
         }
     }
 
+    /// Sets whether the CSS should be generated once all the contexts have been parsed.
+    ///
+    /// Disabling it leaves the CSS cache untouched, which is useful when only the
+    /// Nenyr classes tracking maps are needed.
+    ///
+    /// # Arguments
+    /// - `emit_styles`: A flag indicating whether `Astroform` should run after parsing.
+    pub fn set_styles_emission(&mut self, emit_styles: bool) -> &mut Self {
+        self.emit_styles = emit_styles;
+        self
+    }
+
+    /// Sets whether the non-default theme schemas are split from the main stylesheet,
+    /// so they can be written into their own files.
+    ///
+    /// # Arguments
+    /// - `split_themes`: A flag forwarded to `Astroform`.
+    pub fn set_themes_split(&mut self, split_themes: bool) -> &mut Self {
+        self.split_themes = split_themes;
+        self
+    }
+
+    /// Sets whether the mobile-first and desktop-first responsive styles are split from the main
+    /// stylesheet, so they can be written into their own files.
+    ///
+    /// # Arguments
+    /// - `split_responsive`: A flag forwarded to `Astroform`.
+    pub fn set_responsive_split(&mut self, split_responsive: bool) -> &mut Self {
+        self.split_responsive = split_responsive;
+        self
+    }
+
+    /// Sets whether the parsed ASTs are restored from `.galadrielcss/cache.json`, so only the Nenyr
+    /// files that changed since they were stored are parsed again.
+    ///
+    /// # Arguments
+    /// - `build_cache`: A flag indicating whether the build cache is read and written.
+    pub fn set_build_cache(&mut self, build_cache: bool) -> &mut Self {
+        self.build_cache = build_cache;
+        self
+    }
+
+    /// Sets whether the utility classes, variables and animations are renamed to the shortest unique
+    /// names before the CSS is generated. Only the builds enable it, keeping the names stable while developing.
+    ///
+    /// # Arguments
+    /// - `names_mangling`: A flag forwarded to `Astroform`.
+    pub fn set_names_mangling(&mut self, names_mangling: bool) -> &mut Self {
+        self.names_mangling = names_mangling;
+        self
+    }
+
+    /// Sets a Nenyr file processed as the central context, instead of the one found in the working directory.
+    ///
+    /// Every layout and module context resolves its variables, themes and aliases against it.
+    ///
+    /// # Arguments
+    /// - `central_override`: The path of the central context to use, or `None` to keep the project's one.
+    pub fn set_central_override(&mut self, central_override: Option<PathBuf>) -> &mut Self {
+        self.central_override = central_override;
+        self
+    }
+
     /// Processes the Nenyr contexts within the provided working directory.
     ///
     /// This function traverses the directory to find Nenyr context files (i.e., `central.nyr`, `layout.nyr`, and others),
@@ -93,6 +200,11 @@ impl Synthesizer {
             // Get the path of the current directory entry.
             let current_path = dir_entry.path().to_path_buf();
 
+            // The overriding central context is never processed as a project file.
+            if self.central_override.as_ref() == Some(&current_path) {
+                continue;
+            }
+
             // Check if the current path corresponds to a Nenyr event based on the matcher logic.
             if is_nenyr_event(&current_path, &matcher) {
                 if let Some(file_name) = current_path.file_name() {
@@ -101,7 +213,7 @@ impl Synthesizer {
 
                     // If the file is a central context file and the flag is set, store its path.
                     if file_name.ends_with("central.nyr") {
-                        if self.include_central {
+                        if self.include_central && self.central_override.is_none() {
                             tracing::info!("Identified central context: {:?}", current_path);
 
                             self.central_context = current_path;
@@ -121,22 +233,67 @@ impl Synthesizer {
             }
         }
 
+        // The overriding central context replaces the project's one.
+        if let Some(central_override) = &self.central_override {
+            if self.include_central {
+                tracing::info!(
+                    "Overriding the central context with: {:?}",
+                    central_override
+                );
+
+                self.central_context = central_override.to_owned();
+            }
+        }
+
         // After identifying all the relevant context files, start the parsing process.
-        self.run_parsing(is_minified).await;
+        self.run_parsing(is_minified, working_dir).await;
 
         tracing::info!("Finished parsing and transforming all contexts.");
     }
 
+    /// Takes the alerts broadcast since the last call, splitting the messages of the errors from the
+    /// alerts kept in the build cache. Every other alert is skipped.
+    ///
+    /// # Arguments
+    /// - `receiver`: The receiver subscribed to the Palantir alerts.
+    fn drain_file_alerts(
+        receiver: &mut broadcast::Receiver<GaladrielAlerts>,
+    ) -> (Vec<String>, Vec<CachedAlert>) {
+        let mut error_messages: Vec<String> = vec![];
+        let mut cached_alerts: Vec<CachedAlert> = vec![];
+
+        loop {
+            match receiver.try_recv() {
+                Ok(GaladrielAlerts::NenyrError { error, .. }) => {
+                    error_messages.push(error.get_error_message());
+                }
+                Ok(GaladrielAlerts::GaladrielError { error, .. }) => {
+                    error_messages.push(error.get_message());
+                }
+                Ok(notification) => {
+                    cached_alerts.extend(CachedAlert::from_alert(&notification));
+                }
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    tracing::warn!("The progress stream missed {} alerts.", skipped);
+                }
+                Err(_) => break,
+            }
+        }
+
+        (error_messages, cached_alerts)
+    }
+
     /// Runs the actual parsing for all the identified contexts (central, layout, and modules).
     ///
     /// This function processes the contexts in the order: central, then layout, and then modules.
-    /// After parsing, it triggers a final transformation using `Astroform`.
+    /// After parsing, it triggers a final transformation using `Astroform`, unless the styles emission is disabled.
     ///
     /// - `is_minified`: If the styles is to be minified.
+    /// - `working_dir`: The directory where the build cache is stored.
     ///
     /// # Returns
     /// This function is asynchronous and does not return a value.
-    async fn run_parsing(&mut self, is_minified: bool) {
+    async fn run_parsing(&mut self, is_minified: bool, working_dir: &Path) {
         tracing::info!("Running parsing for contexts: central, layout, and modules.");
 
         // Create a new instance of the Nenyr parser.
@@ -155,28 +312,140 @@ impl Synthesizer {
         ordered_contexts.append(&mut self.layout_contexts);
         ordered_contexts.append(&mut self.module_contexts);
 
-        // For each context path in the ordered list, parse the corresponding Nenyr file.
-        for context_path in ordered_contexts {
-            tracing::info!("Parsing context file: {:?}", context_path);
+        // The parsed contexts of the unchanged files are restored from the build cache.
+        let file_hashes = if self.build_cache {
+            hash_context_files(&ordered_contexts).await
+        } else {
+            None
+        };
+
+        let central_context = (self.include_central
+            && !self.central_context.to_string_lossy().is_empty())
+        .then(|| self.central_context.to_string_lossy().to_string());
+
+        let restored_files = match &file_hashes {
+            Some(file_hashes) => {
+                restore_build_cache(
+                    working_dir,
+                    file_hashes,
+                    central_context.as_deref(),
+                    &self.session,
+                )
+                .await
+            }
+            None => BTreeMap::new(),
+        };
 
-            let _ = formera(
-                context_path,
-                &mut nenyr_parser,
+        if !restored_files.is_empty() {
+            send_palantir_success_notification(
+                &format!("{} of the {} Nenyr files did not change since the last run, so their parsed contexts were restored from the build cache.", restored_files.len(), ordered_contexts.len()),
                 Local::now(),
                 palantir_sender.clone(),
-            )
-            .await;
+            );
+        }
+
+        // The alerts raised by each file are only collected when they are streamed as progress events,
+        // or when they are kept in the build cache.
+        let mut progress_receiver =
+            (is_json_progress_enabled() || self.build_cache).then(|| palantir_sender.subscribe());
+        let mut cached_files: BTreeMap<String, CachedFile> = BTreeMap::new();
+        // The modules of a reparsed layout are reparsed too, since they resolve against it.
+        let mut related_modules: HashSet<String> = HashSet::new();
+        let mut has_parsed_files = false;
+
+        // For each context path in the ordered list, parse the corresponding Nenyr file.
+        for context_path in ordered_contexts {
+            let file_path = context_path.to_string_lossy().to_string();
+
+            match restored_files.get(&file_path) {
+                Some(restored_file) if !related_modules.contains(&file_path) => {
+                    tracing::info!("Restored context file: {:?}", context_path);
+
+                    // The alerts of the restored file are raised again, so they are reported and counted as on a full parse.
+                    for cached_alert in restored_file.get_alerts() {
+                        send_palantir_notification(
+                            cached_alert.to_alert(),
+                            palantir_sender.clone(),
+                        );
+                    }
+                }
+                _ => {
+                    tracing::info!("Parsing context file: {:?}", context_path);
+
+                    let (_, layout_relation) = formera(
+                        context_path.to_owned(),
+                        &mut nenyr_parser,
+                        Local::now(),
+                        palantir_sender.clone(),
+                        &self.session,
+                    )
+                    .await;
+
+                    related_modules.extend(layout_relation.unwrap_or_default());
+                    has_parsed_files = true;
+                }
+            }
+
+            let (file_errors, file_alerts) = progress_receiver
+                .as_mut()
+                .map(Self::drain_file_alerts)
+                .unwrap_or_default();
+
+            // The files raising errors are never cached, so they are parsed and report their errors again on the next run.
+            if let Some(file_hash) = file_hashes
+                .as_ref()
+                .and_then(|file_hashes| file_hashes.get(&file_path))
+                .filter(|_| file_errors.is_empty())
+            {
+                cached_files.insert(file_path, CachedFile::new(*file_hash, file_alerts));
+            }
+
+            record_processed_file(&context_path, &file_errors);
+        }
+
+        if file_hashes.is_some() && has_parsed_files {
+            if let Err(error) = store_build_cache(working_dir, cached_files, &self.session).await {
+                send_palantir_error_notification(error, Local::now(), palantir_sender.clone());
+            }
         }
 
         tracing::info!("Applying inheritance for Nenyr classes.");
 
+        let mut trailblazer = Trailblazer::default();
+        trailblazer.set_session(self.session.clone());
+
+        // Collapses the utility classes sharing identical declarations, when enabled.
+        trailblazer.dedupe_utilities(palantir_sender.clone());
+
         // Applies inheritance for Nenyr classes and their corresponding utility class names.
-        Trailblazer::default().blazer();
+        trailblazer.blazer();
+
+        // Warns about the properties generating more utility classes than the configured limit.
+        warn_crowded_properties(&self.session, palantir_sender.clone());
+
+        // Warns about the derived classes overriding a property inherited with `!important`.
+        warn_important_overrides(&self.session, palantir_sender.clone());
+
+        // Warns about the derived classes whose parent class is not declared.
+        warn_dangling_derivations(&self.session, palantir_sender.clone());
+
+        // Warns about the variables that are never referenced, when enabled.
+        warn_unused_variables(&self.session, palantir_sender.clone());
+
+        if !self.emit_styles {
+            tracing::info!("Styles emission disabled, skipping CSS utility rules transformation.");
+
+            return;
+        }
 
         tracing::info!("Transforming styles in CSS utility rules.");
 
         // Updates the CSS cache by transforming the most up-to-date styles.
         Astroform::new(is_minified, get_reset_styles(), palantir_sender.clone())
+            .set_themes_split(self.split_themes)
+            .set_responsive_split(self.split_responsive)
+            .set_names_mangling(self.names_mangling)
+            .set_session(self.session.clone())
             .transform()
             .await;
     }
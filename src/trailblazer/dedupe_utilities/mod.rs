@@ -0,0 +1,306 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Local;
+use indexmap::IndexMap;
+use tokio::sync::broadcast;
+
+use crate::{
+    configatron::get_dedupe_utilities,
+    events::GaladrielAlerts,
+    types::{Classinator, Stylitron},
+    utils::send_palantir_success_notification::send_palantir_success_notification,
+};
+
+use super::Trailblazer;
+
+/// The utility classes of a styles node, keyed by pattern, importance, CSS property and utility class name.
+type StylesDefinitions =
+    IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>;
+
+impl Trailblazer {
+    /// Collapses the utility classes sharing identical declarations into a single shared one, when the
+    /// `dedupeUtilities` configuration is enabled, and reports how many rules were merged.
+    ///
+    /// Runs before `blazer`, so the inherited class mappings only hold the shared utility class names.
+    ///
+    /// # Parameters
+    /// - `palantir_sender`: The sender used to broadcast the merged rules report.
+    pub fn dedupe_utilities(&self, palantir_sender: broadcast::Sender<GaladrielAlerts>) {
+        if !get_dedupe_utilities() {
+            return;
+        }
+
+        let starting_time = Local::now();
+        let merged_rules = self.collapse_duplicate_utilities();
+
+        tracing::info!("Merged {} duplicated utility rules.", merged_rules);
+
+        if merged_rules == 0 {
+            return;
+        }
+
+        send_palantir_success_notification(
+            &format!(
+                "Merged {} utility rules with identical declarations into shared utility classes.",
+                merged_rules
+            ),
+            starting_time,
+            palantir_sender,
+        );
+    }
+
+    /// Removes every utility class whose breakpoint, pattern, importance, property and value match
+    /// an earlier one, and points the Nenyr classes using it at the earlier one instead.
+    ///
+    /// Values are compared literally, so values referencing distinct variables are never merged, even
+    /// when those variables currently hold the same value, as each one can be overridden on its own.
+    ///
+    /// # Returns
+    /// - The number of utility classes merged into a shared one.
+    fn collapse_duplicate_utilities(&self) -> usize {
+        let mut merged_names: HashMap<String, String> = HashMap::new();
+
+        for node_name in ["styles", "responsive"] {
+            let Some(mut stylitron_data) = self.session.stylitron.get_mut(node_name) else {
+                continue;
+            };
+
+            match *stylitron_data {
+                Stylitron::Styles(ref mut styles_definitions) => {
+                    Self::collapse_styles_definitions(styles_definitions, &mut merged_names);
+                }
+                Stylitron::ResponsiveStyles(ref mut responsive_definitions) => {
+                    for styles_definitions in responsive_definitions.values_mut() {
+                        Self::collapse_styles_definitions(styles_definitions, &mut merged_names);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if merged_names.is_empty() {
+            return 0;
+        }
+
+        self.rename_classinator_utilities(&merged_names);
+
+        // The merged utility classes are no longer emitted, so they have no origin to map back to.
+        for merged_name in merged_names.keys() {
+            self.session.originator.remove(merged_name);
+        }
+
+        merged_names.len()
+    }
+
+    /// Collapses the utility classes of a styles node, recording each merged name along with the
+    /// shared name replacing it.
+    fn collapse_styles_definitions(
+        styles_definitions: &mut StylesDefinitions,
+        merged_names: &mut HashMap<String, String>,
+    ) {
+        styles_definitions
+            .values_mut()
+            .flat_map(|importance_map| importance_map.values_mut())
+            .flat_map(|properties_map| properties_map.values_mut())
+            .for_each(|utilities_map| {
+                let mut shared_names: HashMap<String, String> = HashMap::new();
+
+                utilities_map.retain(|utility_name, value| match shared_names.get(value) {
+                    Some(shared_name) => {
+                        tracing::debug!(
+                            "Merging utility class '{}' into '{}'.",
+                            utility_name,
+                            shared_name
+                        );
+
+                        merged_names.insert(utility_name.to_owned(), shared_name.to_owned());
+
+                        false
+                    }
+                    None => {
+                        shared_names.insert(value.to_owned(), utility_name.to_owned());
+
+                        true
+                    }
+                });
+            });
+    }
+
+    /// Points the Nenyr classes of every context at the shared utility class names.
+    fn rename_classinator_utilities(&self, merged_names: &HashMap<String, String>) {
+        for mut classinator_data in self.session.classinator.iter_mut() {
+            let utility_lists: Vec<&mut Vec<String>> = match classinator_data.value_mut() {
+                Classinator::Central(central_map) => central_map
+                    .values_mut()
+                    .flat_map(|classes_map| classes_map.values_mut())
+                    .collect(),
+                Classinator::Layouts(layouts_map) => layouts_map
+                    .values_mut()
+                    .flat_map(|inherits_map| inherits_map.values_mut())
+                    .flat_map(|classes_map| classes_map.values_mut())
+                    .collect(),
+                Classinator::Modules(modules_map) => modules_map
+                    .values_mut()
+                    .flat_map(|parent_map| parent_map.values_mut())
+                    .flat_map(|inherits_map| inherits_map.values_mut())
+                    .flat_map(|classes_map| classes_map.values_mut())
+                    .collect(),
+            };
+
+            for utility_names in utility_lists {
+                for utility_name in utility_names.iter_mut() {
+                    if let Some(shared_name) = merged_names.get(utility_name) {
+                        *utility_name = shared_name.to_owned();
+                    }
+                }
+
+                // A Nenyr class may now hold the same shared name twice.
+                let mut seen_names: HashSet<String> = HashSet::new();
+                utility_names.retain(|utility_name| seen_names.insert(utility_name.to_owned()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::{
+        asts::CompilationSession,
+        trailblazer::Trailblazer,
+        types::{Classinator, Stylitron},
+    };
+
+    #[test]
+    fn identical_declarations_share_a_utility_class() {
+        let session = CompilationSession::new();
+
+        session.stylitron.insert(
+            "variables".to_string(),
+            Stylitron::Variables(IndexMap::from([
+                (
+                    "dynamicLayout".to_string(),
+                    IndexMap::from([(
+                        "primary".to_string(),
+                        vec!["--a1B2".to_string(), "#fff".to_string()],
+                    )]),
+                ),
+                (
+                    "cardModule".to_string(),
+                    IndexMap::from([(
+                        "main".to_string(),
+                        vec!["--c3D4".to_string(), "#fff".to_string()],
+                    )]),
+                ),
+            ])),
+        );
+        session.stylitron.insert(
+            "styles".to_string(),
+            Stylitron::Styles(IndexMap::from([(
+                "_".to_string(),
+                IndexMap::from([(
+                    "_".to_string(),
+                    IndexMap::from([(
+                        "color".to_string(),
+                        IndexMap::from([
+                            ("clr-aaaa".to_string(), "var(--a1B2)".to_string()),
+                            ("clr-bbbb".to_string(), "var(--c3D4)".to_string()),
+                            ("clr-cccc".to_string(), "red".to_string()),
+                            ("clr-dddd".to_string(), "red".to_string()),
+                        ]),
+                    )]),
+                )]),
+            )])),
+        );
+        session.stylitron.insert(
+            "responsive".to_string(),
+            Stylitron::ResponsiveStyles(IndexMap::from([(
+                "min-width:640px".to_string(),
+                IndexMap::from([(
+                    "_".to_string(),
+                    IndexMap::from([(
+                        "_".to_string(),
+                        IndexMap::from([(
+                            "display".to_string(),
+                            IndexMap::from([
+                                ("sm\\.dpy-S4vd".to_string(), "flex".to_string()),
+                                ("tablet\\.dpy-S4vd".to_string(), "flex".to_string()),
+                            ]),
+                        )]),
+                    )]),
+                )]),
+            )])),
+        );
+        session.classinator.insert(
+            "layouts".to_string(),
+            Classinator::Layouts(IndexMap::from([(
+                "dynamicLayout".to_string(),
+                IndexMap::from([(
+                    "_".to_string(),
+                    IndexMap::from([(
+                        "wrapper".to_string(),
+                        vec!["clr-aaaa".to_string(), "sm\\.dpy-S4vd".to_string()],
+                    )]),
+                )]),
+            )])),
+        );
+        session.classinator.insert(
+            "modules".to_string(),
+            Classinator::Modules(IndexMap::from([(
+                "dynamicLayout".to_string(),
+                IndexMap::from([(
+                    "cardModule".to_string(),
+                    IndexMap::from([(
+                        "_".to_string(),
+                        IndexMap::from([(
+                            "box".to_string(),
+                            vec![
+                                "clr-bbbb".to_string(),
+                                "tablet\\.dpy-S4vd".to_string(),
+                                "clr-dddd".to_string(),
+                            ],
+                        )]),
+                    )]),
+                )]),
+            )])),
+        );
+
+        let mut trailblazer = Trailblazer::default();
+        trailblazer.set_session(session.clone());
+
+        assert_eq!(trailblazer.collapse_duplicate_utilities(), 2);
+
+        let Some(stylitron_data) = session.stylitron.get("styles") else {
+            panic!("The styles node is missing.");
+        };
+
+        let Stylitron::Styles(ref styles_definitions) = *stylitron_data else {
+            panic!("The styles node has an unexpected type.");
+        };
+
+        assert_eq!(
+            styles_definitions["_"]["_"]["color"]
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["clr-aaaa", "clr-bbbb", "clr-cccc"]
+        );
+
+        let Some(classinator_data) = session.classinator.get("modules") else {
+            panic!("The modules node is missing.");
+        };
+
+        let Classinator::Modules(ref modules_map) = *classinator_data else {
+            panic!("The modules node has an unexpected type.");
+        };
+
+        assert_eq!(
+            modules_map["dynamicLayout"]["cardModule"]["_"]["box"],
+            vec![
+                "clr-bbbb".to_string(),
+                "sm\\.dpy-S4vd".to_string(),
+                "clr-cccc".to_string()
+            ]
+        );
+    }
+}
@@ -1,16 +1,23 @@
 use indexmap::IndexMap;
 
 use crate::{
-    asts::{CLASSINATOR, CLASTRACK},
+    asts::CompilationSession,
     types::{Classinator, Clastrack},
 };
 
+mod dedupe_utilities;
+
 #[derive(Clone, Debug)]
-pub struct Trailblazer {}
+pub struct Trailblazer {
+    /// The session whose Nenyr classes are inherited.
+    session: CompilationSession,
+}
 
 impl Default for Trailblazer {
     fn default() -> Self {
-        Self {}
+        Self {
+            session: CompilationSession::global(),
+        }
     }
 }
 
@@ -20,6 +27,15 @@ impl Default for Trailblazer {
 /// This implementation handles the mapping and inheritance between
 /// different contextual categories: "central", "layouts", and "modules."
 impl Trailblazer {
+    /// Sets the session whose Nenyr classes are inherited, instead of the global one.
+    ///
+    /// # Parameters
+    /// - `session`: The session whose `CLASSINATOR` is read and whose `CLASTRACK` is updated.
+    pub fn set_session(&mut self, session: CompilationSession) -> &mut Self {
+        self.session = session;
+        self
+    }
+
     /// Processes and updates the `CLASTRACK` with the transformed and inherited
     /// mappings for central, layouts, and modules contexts.
     pub fn blazer(&self) {
@@ -46,9 +62,15 @@ impl Trailblazer {
         tracing::info!("Updating CLASTRACK with processed mappings.");
 
         // Update the CLASTRACK with the processed mappings for each context.
-        CLASTRACK.insert("central".to_string(), Clastrack::Central(inherited_central));
-        CLASTRACK.insert("layouts".to_string(), Clastrack::Layouts(inherited_layouts));
-        CLASTRACK.insert("modules".to_string(), Clastrack::Modules(inherited_modules));
+        self.session
+            .clastrack
+            .insert("central".to_string(), Clastrack::Central(inherited_central));
+        self.session
+            .clastrack
+            .insert("layouts".to_string(), Clastrack::Layouts(inherited_layouts));
+        self.session
+            .clastrack
+            .insert("modules".to_string(), Clastrack::Modules(inherited_modules));
 
         tracing::info!("Completed processing of class mappings in Trailblazer.");
     }
@@ -252,7 +274,7 @@ impl Trailblazer {
     fn extract_central_node(&self) -> IndexMap<String, IndexMap<String, Vec<String>>> {
         tracing::debug!("Extracting central node...");
 
-        match CLASSINATOR.get("central") {
+        match self.session.classinator.get("central") {
             Some(classinator_data) => match &*classinator_data {
                 Classinator::Central(ref central_data) => central_data.to_owned(),
                 _ => IndexMap::new(),
@@ -271,7 +293,7 @@ impl Trailblazer {
     ) -> IndexMap<String, IndexMap<String, IndexMap<String, Vec<String>>>> {
         tracing::debug!("Extracting layouts node...");
 
-        match CLASSINATOR.get("layouts") {
+        match self.session.classinator.get("layouts") {
             Some(classinator_data) => match &*classinator_data {
                 Classinator::Layouts(ref layouts_data) => layouts_data.to_owned(),
                 _ => IndexMap::new(),
@@ -290,7 +312,7 @@ impl Trailblazer {
     ) -> IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, Vec<String>>>>> {
         tracing::debug!("Extracting modules node...");
 
-        match CLASSINATOR.get("modules") {
+        match self.session.classinator.get("modules") {
             Some(classinator_data) => match &*classinator_data {
                 Classinator::Modules(ref modules_data) => modules_data.to_owned(),
                 _ => IndexMap::new(),
@@ -1,6 +1,7 @@
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Stylitron {
     // -> Aliases          => context name: nickname: value.
     // -> Breakpoints      => schema: breakpoint name: breakpoint value.
@@ -30,18 +31,32 @@ pub enum Stylitron {
     ),
 }
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Classinator {
     // -> Central      => inherits name: class name: value
     // -> Layouts      => layout name: inherits name: class name: value
     // -> Modules      => parent name: module name: inherits name: class name: value
+    // The values hold the utility class names in their CSS-selector form (e.g. `hvr\.bgd-clr-DA0P`).
     Central(IndexMap<String, IndexMap<String, Vec<String>>>),
     Layouts(IndexMap<String, IndexMap<String, IndexMap<String, Vec<String>>>>),
     Modules(IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, Vec<String>>>>>),
 }
 
-#[derive(Clone, PartialEq, Debug)]
+/// The Nenyr class a utility class was generated for, used to map the generated CSS back to its source.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ClassOrigin {
+    /// The path of the Nenyr file declaring the class.
+    pub file_path: String,
+    /// The name of the Nenyr class.
+    pub class_name: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Clastrack {
+    // -> Central      => class name: space-separated utility class names
+    // -> Layouts      => layout name: class name: space-separated utility class names
+    // -> Modules      => module name: class name: space-separated utility class names
+    // The utility class names keep their CSS escaping, which must be removed before applying them to the markup.
     Central(IndexMap<String, String>),
     Layouts(IndexMap<String, IndexMap<String, String>>),
     Modules(IndexMap<String, IndexMap<String, String>>),
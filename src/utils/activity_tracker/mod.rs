@@ -0,0 +1,29 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// The moment of the last file change or client request handled by the development server.
+    static ref LAST_ACTIVITY: Mutex<Instant> = Mutex::new(Instant::now());
+}
+
+/// Records a file change or client request as the latest activity of the development server.
+pub fn record_activity() {
+    if let Ok(mut last_activity) = LAST_ACTIVITY.lock() {
+        *last_activity = Instant::now();
+    }
+}
+
+/// Retrieves the time elapsed since the latest recorded activity.
+///
+/// # Returns
+/// - `Duration`: The idle time of the development server.
+pub fn elapsed_since_last_activity() -> Duration {
+    LAST_ACTIVITY
+        .lock()
+        .map(|last_activity| last_activity.elapsed())
+        .unwrap_or_default()
+}
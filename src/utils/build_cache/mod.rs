@@ -0,0 +1,290 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use chrono::Local;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    asts::CompilationSession,
+    configatron::{get_minified_styles, get_reset_styles, get_resolution_config_hash},
+    error::{ErrorAction, ErrorKind, GaladrielError},
+    events::GaladrielAlerts,
+    types::{ClassOrigin, Classinator, Clastrack, Stylitron},
+    GaladrielResult,
+};
+
+use super::write_file::write_file;
+
+/// Name of the build cache file, inside the `.galadrielcss` folder.
+const BUILD_CACHE_FILE_NAME: &str = "cache.json";
+
+/// The parsed ASTs of a project, along with what they were parsed from.
+#[derive(Serialize, Deserialize)]
+struct BuildCache {
+    /// The Galadriel CSS version that stored the cache, since the ASTs layout may change between versions.
+    version: String,
+    /// The hash of the configurations the contexts were resolved with.
+    config_hash: u64,
+    /// The cache entry of every Nenyr file parsed without errors, keyed by its path.
+    files: BTreeMap<String, CachedFile>,
+    /// The ASTs of the session once every Nenyr file was parsed, before the classes inheritance.
+    stylitron: Vec<(String, Stylitron)>,
+    classinator: Vec<(String, Classinator)>,
+    clastrack: Vec<(String, Clastrack)>,
+    intaker: Vec<(String, String)>,
+    gatekeeper: Vec<(String, Vec<String>)>,
+    originator: Vec<(String, ClassOrigin)>,
+}
+
+/// The cache entry of a Nenyr file parsed without errors.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct CachedFile {
+    /// The content hash of the file when it was parsed.
+    hash: u64,
+    /// The alerts raised while parsing the file, replayed when its parsed context is restored.
+    alerts: Vec<CachedAlert>,
+}
+
+impl CachedFile {
+    /// Creates the cache entry of a Nenyr file.
+    ///
+    /// # Arguments
+    /// - `hash`: The content hash of the file when it was parsed.
+    /// - `alerts`: The alerts raised while parsing the file.
+    pub fn new(hash: u64, alerts: Vec<CachedAlert>) -> Self {
+        Self { hash, alerts }
+    }
+
+    /// Retrieves the alerts raised while parsing the file.
+    pub fn get_alerts(&self) -> &[CachedAlert] {
+        &self.alerts
+    }
+}
+
+/// An alert raised while parsing a Nenyr file, kept so it is raised again when the file is restored.
+///
+/// The files raising errors are never cached, and their success alerts are not kept, since they
+/// report the parsing itself.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub enum CachedAlert {
+    Information(String),
+    Warning(String),
+}
+
+impl CachedAlert {
+    /// Keeps an alert raised while parsing a Nenyr file, when it is an information or a warning.
+    pub fn from_alert(notification: &GaladrielAlerts) -> Option<Self> {
+        match notification {
+            GaladrielAlerts::Information { message, .. } => {
+                Some(Self::Information(message.to_owned()))
+            }
+            GaladrielAlerts::Warning { message, .. } => Some(Self::Warning(message.to_owned())),
+            _ => None,
+        }
+    }
+
+    /// Recreates the alert, raised now.
+    pub fn to_alert(&self) -> GaladrielAlerts {
+        match self {
+            Self::Information(message) => {
+                GaladrielAlerts::create_information(Local::now(), message)
+            }
+            Self::Warning(message) => GaladrielAlerts::create_warning(Local::now(), message),
+        }
+    }
+}
+
+/// Hashes the content of every Nenyr file, keyed by its path.
+///
+/// # Arguments
+/// - `context_paths`: The paths of the Nenyr files processed by the build.
+///
+/// # Returns
+/// - The content hash of every file, or `None` when any of them cannot be read.
+pub async fn hash_context_files(context_paths: &[PathBuf]) -> Option<BTreeMap<String, u64>> {
+    let mut file_hashes = BTreeMap::new();
+
+    for context_path in context_paths {
+        let content = tokio::fs::read(context_path).await.ok()?;
+        let mut hasher = DefaultHasher::new();
+
+        content.hash(&mut hasher);
+        file_hashes.insert(context_path.to_string_lossy().to_string(), hasher.finish());
+    }
+
+    Some(file_hashes)
+}
+
+/// Restores the session ASTs stored by a previous run, returning the cache entries of the Nenyr files
+/// that did not change since then. Only the other files have to be parsed again, on top of the restored ASTs.
+///
+/// Nothing is restored when the configurations changed, when a cached file was removed, since its
+/// contexts cannot be taken out of the cached ASTs, or when the central context changed, since every
+/// layout and module resolves against it.
+///
+/// # Arguments
+/// - `working_dir`: The working directory where the `.galadrielcss` folder is located.
+/// - `file_hashes`: The content hash of every Nenyr file to process.
+/// - `central_context`: The path of the central context, if it is processed.
+/// - `session`: The session the cached ASTs are restored into.
+///
+/// # Returns
+/// - The cache entries of the unchanged files, keyed by their path, or an empty map when nothing was restored.
+pub async fn restore_build_cache(
+    working_dir: &Path,
+    file_hashes: &BTreeMap<String, u64>,
+    central_context: Option<&str>,
+    session: &CompilationSession,
+) -> BTreeMap<String, CachedFile> {
+    let cache_path = get_build_cache_path(working_dir);
+
+    let build_cache = match tokio::fs::read_to_string(&cache_path).await {
+        Ok(content) => match serde_json::from_str::<BuildCache>(&content) {
+            Ok(build_cache) => build_cache,
+            Err(err) => {
+                tracing::warn!("Failed to parse the build cache: {}", err);
+
+                return BTreeMap::new();
+            }
+        },
+        Err(_) => {
+            tracing::info!("No build cache found at {:?}.", cache_path);
+
+            return BTreeMap::new();
+        }
+    };
+
+    let is_file_removed = build_cache
+        .files
+        .keys()
+        .any(|file_path| !file_hashes.contains_key(file_path));
+    let is_central_changed = central_context.is_some_and(|central_context| {
+        build_cache
+            .files
+            .get(central_context)
+            .map(|cached_file| cached_file.hash)
+            != file_hashes.get(central_context).copied()
+    });
+
+    if build_cache.version != env!("CARGO_PKG_VERSION")
+        || build_cache.config_hash != get_build_config_hash()
+        || is_file_removed
+        || is_central_changed
+    {
+        tracing::info!("The build cache is stale, parsing every Nenyr file.");
+
+        return BTreeMap::new();
+    }
+
+    let unchanged_files = build_cache
+        .files
+        .into_iter()
+        .filter(|(file_path, cached_file)| file_hashes.get(file_path) == Some(&cached_file.hash))
+        .collect::<BTreeMap<String, CachedFile>>();
+
+    if unchanged_files.is_empty() {
+        tracing::info!("Every Nenyr file changed since the build cache was stored.");
+
+        return unchanged_files;
+    }
+
+    restore_map(&session.stylitron, build_cache.stylitron);
+    restore_map(&session.classinator, build_cache.classinator);
+    restore_map(&session.clastrack, build_cache.clastrack);
+    restore_map(&session.intaker, build_cache.intaker);
+    restore_map(&session.gatekeeper, build_cache.gatekeeper);
+    restore_map(&session.originator, build_cache.originator);
+
+    tracing::info!(
+        "Restored the ASTs of {} unchanged Nenyr files out of {} from the build cache.",
+        unchanged_files.len(),
+        file_hashes.len()
+    );
+
+    unchanged_files
+}
+
+/// Stores the session ASTs into `.galadrielcss/cache.json`, so the next run only parses the changed files.
+///
+/// # Arguments
+/// - `working_dir`: The working directory where the `.galadrielcss` folder is located.
+/// - `cached_files`: The cache entry of every Nenyr file parsed without errors, keyed by its path.
+/// - `session`: The session whose ASTs are stored, once every Nenyr file was parsed.
+pub async fn store_build_cache(
+    working_dir: &Path,
+    cached_files: BTreeMap<String, CachedFile>,
+    session: &CompilationSession,
+) -> GaladrielResult<()> {
+    let build_cache = BuildCache {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        config_hash: get_build_config_hash(),
+        files: cached_files,
+        stylitron: snapshot_map(&session.stylitron),
+        classinator: snapshot_map(&session.classinator),
+        clastrack: snapshot_map(&session.clastrack),
+        intaker: snapshot_map(&session.intaker),
+        gatekeeper: snapshot_map(&session.gatekeeper),
+        originator: snapshot_map(&session.originator),
+    };
+
+    let content = serde_json::to_string(&build_cache).map_err(|err| {
+        GaladrielError::raise_general_other_error(
+            ErrorKind::BuildCacheSerializationError,
+            &err.to_string(),
+            ErrorAction::Notify,
+        )
+    })?;
+
+    write_file(
+        working_dir.join(".galadrielcss"),
+        get_build_cache_path(working_dir),
+        content,
+        ErrorAction::Notify,
+        ErrorKind::FileCreationError,
+        ErrorKind::FileWriteError,
+    )
+    .await?;
+
+    tracing::info!("Stored the build cache.");
+
+    Ok(())
+}
+
+/// Retrieves the path of the build cache file.
+fn get_build_cache_path(working_dir: &Path) -> PathBuf {
+    working_dir
+        .join(".galadrielcss")
+        .join(BUILD_CACHE_FILE_NAME)
+}
+
+/// Computes a hash of the configurations the cached ASTs depend on, including the minification
+/// and reset styles flags the Nenyr files are processed with.
+fn get_build_config_hash() -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    get_resolution_config_hash().hash(&mut hasher);
+    get_minified_styles().hash(&mut hasher);
+    get_reset_styles().hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// Copies the entries of a session map.
+fn snapshot_map<V: Clone>(map: &DashMap<String, V>) -> Vec<(String, V)> {
+    map.iter()
+        .map(|entry| (entry.key().to_owned(), entry.value().to_owned()))
+        .collect()
+}
+
+/// Replaces the entries of a session map with the cached ones.
+fn restore_map<V>(map: &DashMap<String, V>, entries: Vec<(String, V)>) {
+    map.clear();
+
+    for (key, value) in entries {
+        map.insert(key, value);
+    }
+}
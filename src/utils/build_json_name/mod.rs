@@ -0,0 +1,32 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use chrono::Local;
+
+/// Name of the environment substituted for `{env}` when the build runs without a profile.
+const DEFAULT_BUILD_ENV: &str = "default";
+
+/// Renders the file name of the build JSON from its configured template.
+///
+/// The `{env}` variable is replaced by the name of the build profile, or `default` without one,
+/// `{hash}` by a hash of the build JSON content and `{date}` by the current date (`YYYY-MM-DD`).
+///
+/// # Arguments
+/// - `template`: The `buildJsonName` template, e.g. `galadrielcss.{env}.json`.
+/// - `profile`: The name of the configuration profile the build runs with, if any.
+/// - `content`: The content of the build JSON.
+///
+/// # Returns
+/// - The file name the build JSON is written to.
+pub fn render_build_json_name(template: &str, profile: Option<&str>, content: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+
+    content.hash(&mut hasher);
+
+    template
+        .replace("{env}", profile.unwrap_or(DEFAULT_BUILD_ENV))
+        .replace("{hash}", &format!("{:016x}", hasher.finish()))
+        .replace("{date}", &Local::now().format("%Y-%m-%d").to_string())
+}
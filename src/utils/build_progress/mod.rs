@@ -0,0 +1,112 @@
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use lazy_static::lazy_static;
+use serde_json::json;
+
+lazy_static! {
+    /// The phase the build is currently going through, reported when the build times out.
+    static ref BUILD_PHASE: Mutex<String> = Mutex::new("starting".to_string());
+}
+
+/// The number of Nenyr files processed so far.
+static PROCESSED_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of processed Nenyr files that raised an error.
+static FAILED_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the progress events are streamed to stdout as newline-delimited JSON.
+static JSON_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// Enables the streaming of the progress events to stdout, one JSON object per line.
+pub fn enable_json_progress() {
+    JSON_PROGRESS.store(true, Ordering::Relaxed);
+}
+
+/// Checks whether the progress events are streamed to stdout.
+pub fn is_json_progress_enabled() -> bool {
+    JSON_PROGRESS.load(Ordering::Relaxed)
+}
+
+/// Writes a progress event to stdout as a single JSON line, when the progress stream is enabled.
+///
+/// # Arguments
+/// - `event`: The progress event, whose `event` entry names its kind, e.g. `start` or `finish`.
+pub fn emit_progress_event(event: serde_json::Value) {
+    if is_json_progress_enabled() {
+        println!("{}", event);
+    }
+}
+
+/// Prints a human-readable line to stdout, or to stderr while the progress events are streamed to stdout.
+///
+/// # Arguments
+/// - `line`: The line to print.
+pub fn print_report_line(line: &str) {
+    if is_json_progress_enabled() {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+/// Records the phase the build is entering.
+///
+/// # Arguments
+/// - `phase`: The stable identifier of the phase streamed to the progress events: `config`, `parse` or `write`.
+/// - `description`: A short description of the phase, e.g. `processing the Nenyr files`.
+pub fn set_build_phase(phase: &str, description: &str) {
+    if let Ok(mut build_phase) = BUILD_PHASE.lock() {
+        *build_phase = description.to_string();
+    }
+
+    emit_progress_event(json!({
+        "event": "phase",
+        "phase": phase,
+        "description": description,
+    }));
+}
+
+/// Records a Nenyr file as processed.
+///
+/// # Arguments
+/// - `file_path`: The path of the processed Nenyr file.
+/// - `errors`: The messages of the errors raised while processing the file.
+pub fn record_processed_file(file_path: &Path, errors: &[String]) {
+    PROCESSED_FILES.fetch_add(1, Ordering::Relaxed);
+
+    if !errors.is_empty() {
+        FAILED_FILES.fetch_add(1, Ordering::Relaxed);
+    }
+
+    emit_progress_event(json!({
+        "event": "file",
+        "phase": "parse",
+        "file": file_path,
+        "status": if errors.is_empty() { "ok" } else { "error" },
+        "errors": errors,
+    }));
+}
+
+/// Retrieves the phase the build is currently going through.
+pub fn get_build_phase() -> String {
+    BUILD_PHASE
+        .lock()
+        .map(|build_phase| build_phase.to_owned())
+        .unwrap_or_default()
+}
+
+/// Retrieves the number of Nenyr files processed so far.
+pub fn get_processed_files_count() -> usize {
+    PROCESSED_FILES.load(Ordering::Relaxed)
+}
+
+/// Retrieves the number of processed Nenyr files that raised an error.
+pub fn get_failed_files_count() -> usize {
+    FAILED_FILES.load(Ordering::Relaxed)
+}
@@ -0,0 +1,167 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::json;
+
+use crate::{
+    asts::{CLASSINATOR, STYLITRON},
+    crealion::CENTRAL_CONTEXT_NAME,
+    types::{Classinator, Stylitron},
+};
+
+/// Number of contexts listed in the largest contexts section of the build stats.
+const LARGEST_CONTEXTS_LIMIT: usize = 5;
+
+/// Builds the build stats report, with the sizes of the stylesheet and the counts of the
+/// generated utility classes and animations.
+///
+/// The rules of a context are its utility classes plus its `@keyframes` animations.
+///
+/// # Arguments
+/// - `minified_css`: The generated stylesheet, minified.
+/// - `unminified_css`: The same stylesheet, without minification.
+///
+/// # Returns
+/// - A JSON value with the `bytes`, `utilityClasses`, `animations` and `largestContexts` entries.
+pub fn collect_build_stats(minified_css: &str, unminified_css: &str) -> serde_json::Value {
+    let utility_classes = count_utility_classes_per_context();
+    let animations = count_animations_per_context();
+
+    let mut context_rules: BTreeMap<String, usize> = utility_classes.clone();
+
+    for (context_name, animations_count) in &animations {
+        *context_rules.entry(context_name.to_owned()).or_default() += animations_count;
+    }
+
+    let mut largest_contexts = context_rules.into_iter().collect::<Vec<_>>();
+
+    largest_contexts.sort_by(|first, second| second.1.cmp(&first.1).then(first.0.cmp(&second.0)));
+    largest_contexts.truncate(LARGEST_CONTEXTS_LIMIT);
+
+    json!({
+        "bytes": {
+            "minified": minified_css.len(),
+            "unminified": unminified_css.len(),
+        },
+        "utilityClasses": utility_classes,
+        "animations": animations.values().sum::<usize>(),
+        "largestContexts": largest_contexts
+            .into_iter()
+            .map(|(context_name, rules)| json!({ "context": context_name, "rules": rules }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Formats the build stats report as human-readable lines.
+///
+/// # Arguments
+/// - `build_stats`: The report built by `collect_build_stats`.
+pub fn format_build_stats(build_stats: &serde_json::Value) -> String {
+    let mut lines = vec![
+        "Build stats:".to_string(),
+        format!(
+            "    Size: {} bytes minified, {} bytes unminified",
+            build_stats["bytes"]["minified"], build_stats["bytes"]["unminified"]
+        ),
+        format!("    Animations: {}", build_stats["animations"]),
+        "    Utility classes per context:".to_string(),
+    ];
+
+    if let Some(utility_classes) = build_stats["utilityClasses"].as_object() {
+        for (context_name, count) in utility_classes {
+            lines.push(format!("        {}: {}", context_name, count));
+        }
+    }
+
+    lines.push("    Largest contexts by rule count:".to_string());
+
+    if let Some(largest_contexts) = build_stats["largestContexts"].as_array() {
+        for context in largest_contexts {
+            lines.push(format!(
+                "        {}: {} rules",
+                context["context"].as_str().unwrap_or_default(),
+                context["rules"]
+            ));
+        }
+    }
+
+    if let Some(unused_classes) = build_stats["unusedClasses"].as_array() {
+        lines.push(format!("    Unused classes: {}", unused_classes.len()));
+
+        for unused_class in unused_classes {
+            lines.push(format!(
+                "        {}",
+                unused_class.as_str().unwrap_or_default()
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Counts the distinct utility classes used by the Nenyr classes of every context in `CLASSINATOR`.
+fn count_utility_classes_per_context() -> BTreeMap<String, usize> {
+    let mut contexts_classes: BTreeMap<String, HashSet<String>> = BTreeMap::new();
+
+    for classinator_data in CLASSINATOR.iter() {
+        match &*classinator_data {
+            Classinator::Central(central_node) => {
+                let utility_names = contexts_classes.entry("central".to_string()).or_default();
+
+                utility_names.extend(
+                    central_node
+                        .values()
+                        .flat_map(|classes| classes.values().flatten().cloned()),
+                );
+            }
+            Classinator::Layouts(layouts_node) => {
+                for (layout_name, inherits_node) in layouts_node {
+                    let utility_names = contexts_classes.entry(layout_name.to_owned()).or_default();
+
+                    utility_names.extend(
+                        inherits_node
+                            .values()
+                            .flat_map(|classes| classes.values().flatten().cloned()),
+                    );
+                }
+            }
+            Classinator::Modules(parents_node) => {
+                for (module_name, inherits_node) in parents_node.values().flatten() {
+                    let utility_names = contexts_classes.entry(module_name.to_owned()).or_default();
+
+                    utility_names.extend(
+                        inherits_node
+                            .values()
+                            .flat_map(|classes| classes.values().flatten().cloned()),
+                    );
+                }
+            }
+        }
+    }
+
+    contexts_classes
+        .into_iter()
+        .map(|(context_name, utility_names)| (context_name, utility_names.len()))
+        .collect()
+}
+
+/// Counts the animations declared by every context in `STYLITRON`.
+fn count_animations_per_context() -> BTreeMap<String, usize> {
+    let Some(stylitron_data) = STYLITRON.get("animations") else {
+        return BTreeMap::new();
+    };
+
+    match &*stylitron_data {
+        Stylitron::Animation(animations_node) => animations_node
+            .iter()
+            .map(|(context_name, animations)| {
+                let context_name = match context_name.as_str() {
+                    CENTRAL_CONTEXT_NAME => "central".to_string(),
+                    context_name => context_name.to_string(),
+                };
+
+                (context_name, animations.len())
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
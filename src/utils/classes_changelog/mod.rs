@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::json;
+
+use crate::asts::CLASTRACK;
+
+use super::serialize_classes_tracking::{
+    get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+};
+
+/// Flattens the Nenyr classes tracking maps into a snapshot keyed by the qualified class name.
+///
+/// Central classes are qualified as `central.<class>`, while layout and module classes are
+/// qualified by their context, e.g. `layouts.<layout>.<class>` or `modules.<module>.<class>`.
+///
+/// # Returns
+/// - A `BTreeMap` mapping each qualified Nenyr class name to its utility class names.
+pub fn snapshot_classes_tracking() -> BTreeMap<String, String> {
+    let mut snapshot: BTreeMap<String, String> = get_tracking_map_from_central(&CLASTRACK)
+        .into_iter()
+        .map(|(class_name, utility_names)| (format!("central.{}", class_name), utility_names))
+        .collect();
+
+    for (kind, contexts_map) in [
+        ("layouts", get_tracking_map_from_layouts(&CLASTRACK)),
+        ("modules", get_tracking_map_from_modules(&CLASTRACK)),
+    ] {
+        for (context_name, classes_map) in contexts_map {
+            snapshot.extend(classes_map.into_iter().map(|(class_name, utility_names)| {
+                (
+                    format!("{}.{}.{}", kind, context_name, class_name),
+                    utility_names,
+                )
+            }));
+        }
+    }
+
+    snapshot
+}
+
+/// Compares two classes tracking snapshots, listing the changes of the Nenyr and utility classes.
+///
+/// # Arguments
+/// - `previous`: The snapshot stored by the previous build.
+/// - `current`: The snapshot of the current build.
+///
+/// # Returns
+/// - A JSON value with the added, removed, and modified Nenyr classes, and the added and removed utility classes.
+pub fn diff_classes_snapshots(
+    previous: &BTreeMap<String, String>,
+    current: &BTreeMap<String, String>,
+) -> serde_json::Value {
+    let added_classes = current
+        .keys()
+        .filter(|class_name| !previous.contains_key(*class_name))
+        .collect::<Vec<_>>();
+    let removed_classes = previous
+        .keys()
+        .filter(|class_name| !current.contains_key(*class_name))
+        .collect::<Vec<_>>();
+    let modified_classes = current
+        .iter()
+        .filter(|(class_name, utility_names)| {
+            previous
+                .get(*class_name)
+                .is_some_and(|previous_names| previous_names != *utility_names)
+        })
+        .map(|(class_name, _)| class_name)
+        .collect::<Vec<_>>();
+
+    let previous_utilities = collect_utility_names(previous);
+    let current_utilities = collect_utility_names(current);
+
+    json!({
+        "nenyrClasses": {
+            "added": added_classes,
+            "removed": removed_classes,
+            "modified": modified_classes,
+        },
+        "utilityClasses": {
+            "added": current_utilities.difference(&previous_utilities).collect::<Vec<_>>(),
+            "removed": previous_utilities.difference(&current_utilities).collect::<Vec<_>>(),
+        },
+    })
+}
+
+/// Collects every distinct utility class name referenced by a snapshot.
+fn collect_utility_names(snapshot: &BTreeMap<String, String>) -> BTreeSet<&str> {
+    snapshot
+        .values()
+        .flat_map(|utility_names| utility_names.split_whitespace())
+        .collect()
+}
@@ -0,0 +1,96 @@
+use indexmap::IndexMap;
+
+use crate::{asts::STYLITRON, types::Stylitron};
+
+/// A single CSS declaration of a generated rule.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CssDeclaration {
+    /// The CSS property, e.g. `background-color`.
+    pub property: String,
+    /// The resolved value of the property.
+    pub value: String,
+}
+
+/// A generated CSS rule, as structured data instead of CSS text.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CssRule {
+    /// The selector of the rule, made of the escaped utility class name and its pseudo-selector.
+    pub selector: String,
+    /// The declarations applied by the rule.
+    pub declarations: Vec<CssDeclaration>,
+    /// The breakpoint condition wrapping the rule (e.g. `min-width:740px`), if it is responsive.
+    pub media_condition: Option<String>,
+    /// Whether the declarations are flagged as `!important`.
+    pub is_important: bool,
+}
+
+/// Collects every CSS rule generated from the `styles` and `responsive` sections of the STYLITRON AST.
+///
+/// The rules are listed in the order they are emitted: the global styles first, followed by
+/// the responsive styles of each breakpoint.
+///
+/// # Returns
+/// - `Vec<CssRule>`: The generated rules.
+pub fn collect_css_rules() -> Vec<CssRule> {
+    let mut css_rules: Vec<CssRule> = vec![];
+
+    if let Some(stylitron_data) = STYLITRON.get("styles") {
+        if let Stylitron::Styles(ref styles_definitions) = *stylitron_data {
+            collect_pattern_rules(styles_definitions, None, &mut css_rules);
+        }
+    }
+
+    if let Some(stylitron_data) = STYLITRON.get("responsive") {
+        if let Stylitron::ResponsiveStyles(ref responsive_definitions) = *stylitron_data {
+            for (media_condition, styles_definitions) in responsive_definitions {
+                collect_pattern_rules(
+                    styles_definitions,
+                    Some(media_condition.to_owned()),
+                    &mut css_rules,
+                );
+            }
+        }
+    }
+
+    tracing::debug!("Collected {} CSS rules from STYLITRON.", css_rules.len());
+
+    css_rules
+}
+
+/// Collects the rules of a styles node, keyed by pattern, importance, property and utility class name.
+///
+/// # Arguments
+/// - `styles_definitions`: The styles node being walked.
+/// - `media_condition`: The breakpoint condition wrapping the node, if any.
+/// - `css_rules`: The collected rules, extended in place.
+fn collect_pattern_rules(
+    styles_definitions: &IndexMap<
+        String,
+        IndexMap<String, IndexMap<String, IndexMap<String, String>>>,
+    >,
+    media_condition: Option<String>,
+    css_rules: &mut Vec<CssRule>,
+) {
+    for (pattern, importance_map) in styles_definitions {
+        // The `_` pattern holds the styles without any pseudo-selector.
+        let pseudo_selector = pattern.trim_start_matches('_');
+
+        for (importance, properties_map) in importance_map {
+            let is_important = importance == "!important";
+
+            for (property, class_map) in properties_map {
+                for (class_name, value) in class_map {
+                    css_rules.push(CssRule {
+                        selector: format!(".{}{}", class_name, pseudo_selector),
+                        declarations: vec![CssDeclaration {
+                            property: property.to_owned(),
+                            value: value.to_owned(),
+                        }],
+                        media_condition: media_condition.clone(),
+                        is_important,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::Local;
+use nenyr::NenyrParser;
+use tokio::sync::broadcast;
+
+use crate::{
+    astroform::Astroform, asts::CompilationSession, crealion::Crealion, error::GaladrielError,
+    trailblazer::Trailblazer, GaladrielResult,
+};
+
+/// Name reported by the errors of a compiled Nenyr source, since it does not come from a file.
+const INLINE_SOURCE_NAME: &str = "inline.nyr";
+
+/// Options of an in-memory compilation, used instead of the `galadriel.config.json` configurations.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CompileOptions {
+    /// Whether the generated CSS is minified.
+    pub minified_styles: bool,
+    /// Whether the reset styles are prepended to the generated CSS.
+    pub reset_styles: bool,
+}
+
+/// Compiles a Nenyr context into CSS, without reading or writing any file.
+///
+/// Every call compiles into its own `CompilationSession`, so the generated CSS only holds the given
+/// context and compilations may run concurrently with each other or with a Galadriel CSS runtime.
+///
+/// # Arguments
+/// - `nenyr_source`: The Nenyr context to compile.
+/// - `options`: Whether the generated CSS is minified and includes the reset styles.
+///
+/// # Returns
+/// - `GaladrielResult<String>`: The generated CSS, or the error raised while parsing or processing the context.
+pub async fn compile_str(nenyr_source: &str, options: CompileOptions) -> GaladrielResult<String> {
+    compile_str_in_session(&CompilationSession::new(), nenyr_source, options).await
+}
+
+/// Compiles a Nenyr context into CSS within the given session.
+///
+/// The contexts compiled earlier into the same session stay available, so a layout or module
+/// context can be compiled after the central context it relies on. The returned CSS holds every
+/// context of the session.
+///
+/// # Arguments
+/// - `session`: The session the context is compiled into.
+/// - `nenyr_source`: The Nenyr context to compile.
+/// - `options`: Whether the generated CSS is minified and includes the reset styles.
+///
+/// # Returns
+/// - `GaladrielResult<String>`: The generated CSS, or the error raised while parsing or processing the context.
+pub async fn compile_str_in_session(
+    session: &CompilationSession,
+    nenyr_source: &str,
+    options: CompileOptions,
+) -> GaladrielResult<String> {
+    // Nothing displays the alerts raised while compiling, but the receiver keeps the channel open.
+    let (palantir_sender, _palantir_receiver) = broadcast::channel(100);
+
+    let start_time = Local::now();
+    let parsed_ast = NenyrParser::new()
+        .parse(nenyr_source.to_string(), INLINE_SOURCE_NAME.to_string())
+        .map_err(|error| GaladrielError::raise_nenyr_error(start_time, error))?;
+
+    tracing::info!("Compiling an inline Nenyr context.");
+
+    Crealion::new(
+        palantir_sender.clone(),
+        parsed_ast,
+        INLINE_SOURCE_NAME.to_string(),
+    )
+    .set_session(session.clone())
+    .create()
+    .await?;
+
+    let mut trailblazer = Trailblazer::default();
+    trailblazer.set_session(session.clone());
+
+    // Collapses the utility classes sharing identical declarations, when enabled.
+    trailblazer.dedupe_utilities(palantir_sender.clone());
+
+    // Applies inheritance for Nenyr classes and their corresponding utility class names.
+    trailblazer.blazer();
+
+    Astroform::new(
+        options.minified_styles,
+        options.reset_styles,
+        palantir_sender,
+    )
+    .set_session(session.clone())
+    .transform()
+    .await;
+
+    Ok(session
+        .cascadex
+        .get("cascading_sheet")
+        .map(|latest_css| latest_css.value().to_owned())
+        .unwrap_or_default())
+}
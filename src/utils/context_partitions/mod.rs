@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use dashmap::DashMap;
+use indexmap::IndexMap;
+
+use crate::{astroform::ContextPartition, types::Clastrack};
+
+use super::serialize_classes_tracking::{
+    get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+};
+
+/// Name of the partition holding the central context, along with the rules shared by every context.
+pub const CENTRAL_PARTITION_NAME: &str = "central";
+
+/// Splits the tracked utility classes into one partition per context.
+///
+/// The central partition carries the shared rules and the central utility classes, so it must always
+/// be loaded. Every layout and module partition only carries the utility classes its own Nenyr
+/// classes use that are not already in the central partition.
+///
+/// # Arguments
+/// - `clastrack`: The classes tracking map to read from.
+///
+/// # Returns
+/// - `Vec<ContextPartition>`: The central partition, followed by the layouts and modules ones.
+pub fn collect_context_partitions(clastrack: &DashMap<String, Clastrack>) -> Vec<ContextPartition> {
+    let central_utilities = collect_tracked_utilities(&get_tracking_map_from_central(clastrack));
+    let mut partitions = vec![ContextPartition {
+        context_name: CENTRAL_PARTITION_NAME.to_string(),
+        include_shared_rules: true,
+        utility_names: central_utilities.clone(),
+    }];
+
+    let contexts_tracking = get_tracking_map_from_layouts(clastrack)
+        .into_iter()
+        .chain(get_tracking_map_from_modules(clastrack));
+
+    for (context_name, context_tracking) in contexts_tracking {
+        let utility_names = collect_tracked_utilities(&context_tracking)
+            .difference(&central_utilities)
+            .cloned()
+            .collect::<HashSet<String>>();
+
+        tracing::debug!(
+            "Partition of context '{}' holds {} utility classes.",
+            context_name,
+            utility_names.len()
+        );
+
+        partitions.push(ContextPartition {
+            context_name,
+            include_shared_rules: false,
+            utility_names,
+        });
+    }
+
+    partitions
+}
+
+/// Collects the utility class names used by the Nenyr classes of a context tracking map.
+fn collect_tracked_utilities(context_tracking: &IndexMap<String, String>) -> HashSet<String> {
+    context_tracking
+        .values()
+        .flat_map(|utility_names| utility_names.split_whitespace())
+        .map(|utility_name| utility_name.replace('\\', "")) // Removes escape characters if found.
+        .collect()
+}
@@ -0,0 +1,26 @@
+use crate::{
+    error::{ErrorAction, ErrorKind, GaladrielError},
+    GaladrielResult,
+};
+
+/// Copies the given text to the system clipboard.
+///
+/// # Arguments
+/// - `text`: The text to be copied.
+///
+/// # Returns
+/// - `GaladrielResult<()>`: Returns `Ok(())` if the text was copied, or a `ClipboardCopyFailed` error
+///   when the clipboard is unavailable, e.g. in a headless session.
+pub fn copy_to_clipboard(text: &str) -> GaladrielResult<()> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|err| {
+            tracing::error!("Failed to copy text to the clipboard: {:?}", err);
+
+            GaladrielError::raise_general_interface_error(
+                ErrorKind::ClipboardCopyFailed,
+                &format!("Failed to copy to the clipboard: {}", err),
+                ErrorAction::Notify,
+            )
+        })
+}
@@ -0,0 +1,39 @@
+/// The standard base64 alphabet (RFC 4648).
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Wraps a stylesheet into a `data:text/css;base64,...` URI, ready to be used in a `<link>` or `@import`.
+///
+/// # Arguments
+/// - `css`: The stylesheet to be encoded.
+///
+/// # Returns
+/// - A single-line `String` with the data URI.
+pub fn css_data_uri(css: &str) -> String {
+    format!("data:text/css;base64,{}", encode_base64(css.as_bytes()))
+}
+
+/// Encodes the given bytes with the standard, padded base64 alphabet.
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let triple = chunk.iter().enumerate().fold(0u32, |triple, (idx, byte)| {
+            triple | ((*byte as u32) << (16 - idx * 8))
+        });
+
+        // A chunk of N bytes produces N + 1 significant characters, padded up to 4.
+        for idx in 0..4 {
+            match idx <= chunk.len() {
+                true => {
+                    let sextet = (triple >> (18 - idx * 6)) & 0b11_1111;
+
+                    encoded.push(BASE64_ALPHABET[sextet as usize] as char);
+                }
+                false => encoded.push('='),
+            }
+        }
+    }
+
+    encoded
+}
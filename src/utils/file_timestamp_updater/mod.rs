@@ -1,5 +1,9 @@
 use core::str;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use chrono::Local;
 use ignore::{overrides, WalkBuilder};
@@ -8,6 +12,7 @@ use regex::Regex;
 use tokio::sync::{broadcast, RwLock};
 
 use crate::{
+    configatron::get_reload_throttle_ms,
     error::{ErrorAction, ErrorKind, GaladrielError},
     events::GaladrielAlerts,
     utils::{
@@ -22,6 +27,18 @@ const PATTERN: &str = r"@(?:class|layout|module):([a-zA-Z0-9_-]+)(?:::([a-zA-Z0-
 lazy_static! {
     // Compile the regex pattern at runtime and store it in a static variable for reuse.
     pub static ref MARKUP_RE: Regex = Regex::new(PATTERN).unwrap();
+
+    // Reload requests waiting for the throttle window to elapse, keyed by the CSS processing flag and folder path.
+    static ref PENDING_RELOADS: Mutex<PendingReloads> = Mutex::new(PendingReloads::default());
+}
+
+// Reload requests coalesced within the current throttle window.
+#[derive(Default)]
+struct PendingReloads {
+    // Whether a flush of the pending requests is already scheduled.
+    is_scheduled: bool,
+    // The requested folders, along with their CSS processing flag and exclude matcher.
+    requests: Vec<(bool, PathBuf, Arc<RwLock<overrides::Override>>)>,
 }
 
 // Structure to handle file timestamp updates, using a broadcast channel for notifications.
@@ -36,12 +53,112 @@ impl FileTimestampUpdater {
         Self { palantir_sender }
     }
 
-    // Asynchronous method to process files in a folder and update their timestamps.
+    // Asynchronous method to request the timestamps update of the files in a folder, which triggers a client reload.
+    // Requests made within the `reloadThrottleMs` window are coalesced, so a burst of updates results in a single reload.
     pub async fn process_from_folder(
         &self,
         is_css_processing: bool,
         path: PathBuf, // Path to the folder to be processed.
         matcher: Arc<RwLock<overrides::Override>>, // Clone the sender for thread safety.
+    ) {
+        let throttle_ms = get_reload_throttle_ms();
+
+        // Without a throttle window, the timestamps are updated right away.
+        if throttle_ms == 0 {
+            return self
+                .update_folder_timestamps(is_css_processing, path, matcher)
+                .await;
+        }
+
+        let should_schedule = match PENDING_RELOADS.lock() {
+            Ok(mut pending_reloads) => {
+                Self::coalesce_reload_request(
+                    &mut pending_reloads.requests,
+                    is_css_processing,
+                    path,
+                    matcher,
+                );
+
+                // Only the first request of the window schedules the flush.
+                !std::mem::replace(&mut pending_reloads.is_scheduled, true)
+            }
+            Err(err) => {
+                tracing::error!("Failed to access the pending reloads: {:?}", err);
+
+                return;
+            }
+        };
+
+        if should_schedule {
+            tracing::debug!(
+                "Scheduling pending reloads flush in {} milliseconds.",
+                throttle_ms
+            );
+
+            let updater = self.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(throttle_ms)).await;
+
+                updater.flush_pending_reloads().await;
+            });
+        }
+    }
+
+    // Adds a reload request to the pending ones, skipping it when an already pending folder covers it
+    // and replacing the pending folders it covers.
+    fn coalesce_reload_request(
+        requests: &mut Vec<(bool, PathBuf, Arc<RwLock<overrides::Override>>)>,
+        is_css_processing: bool,
+        path: PathBuf,
+        matcher: Arc<RwLock<overrides::Override>>,
+    ) {
+        let is_covered = requests.iter().any(|(pending_is_css, pending_path, _)| {
+            *pending_is_css == is_css_processing && path.starts_with(pending_path)
+        });
+
+        if is_covered {
+            tracing::debug!("Reload request for {} coalesced.", path.display());
+
+            return;
+        }
+
+        requests.retain(|(pending_is_css, pending_path, _)| {
+            *pending_is_css != is_css_processing || !pending_path.starts_with(&path)
+        });
+
+        requests.push((is_css_processing, path, matcher));
+    }
+
+    // Drains the pending reload requests and updates the timestamps of their folders.
+    async fn flush_pending_reloads(&self) {
+        let requests = match PENDING_RELOADS.lock() {
+            Ok(mut pending_reloads) => {
+                pending_reloads.is_scheduled = false;
+
+                std::mem::take(&mut pending_reloads.requests)
+            }
+            Err(err) => {
+                tracing::error!("Failed to access the pending reloads: {:?}", err);
+
+                return;
+            }
+        };
+
+        tracing::info!("Flushing {} coalesced reload requests.", requests.len());
+
+        for (is_css_processing, path, matcher) in requests {
+            self.update_folder_timestamps(is_css_processing, path, matcher)
+                .await;
+        }
+    }
+
+    // Asynchronous method to process files in a folder and update their timestamps.
+    async fn update_folder_timestamps(
+        &self,
+        is_css_processing: bool,
+        path: PathBuf, // Path to the folder to be processed.
+        matcher: Arc<RwLock<overrides::Override>>, // Clone the sender for thread safety.
     ) {
         tracing::info!("Started processing files from folder: {}", path.display());
 
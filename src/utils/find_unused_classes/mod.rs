@@ -0,0 +1,114 @@
+use std::{collections::HashSet, path::Path};
+
+use chrono::Local;
+use ignore::WalkBuilder;
+use tokio::sync::broadcast;
+
+use crate::{
+    asts::CLASTRACK,
+    configatron::{construct_content_matcher, get_content_globs},
+    events::GaladrielAlerts,
+    GaladrielResult,
+};
+
+use super::{
+    send_palantir_notification::send_palantir_notification,
+    serialize_classes_tracking::{
+        get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+    },
+};
+
+/// Finds the Nenyr classes never referenced by the source files matched by `contentGlobs`.
+///
+/// The scanned files are split into words, and a class is referenced when its name is one of
+/// them, whatever the syntax the components reference it with. Nothing is scanned when
+/// `contentGlobs` is empty.
+///
+/// # Arguments
+/// - `working_dir`: The root directory of the project, the globs are relative to.
+///
+/// # Returns
+/// - `GaladrielResult<Vec<String>>`: The unreferenced classes, named as `contextName::className`.
+pub fn find_unused_classes(working_dir: &Path) -> GaladrielResult<Vec<String>> {
+    if get_content_globs().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let matcher = construct_content_matcher(working_dir)?;
+    let mut referenced_words: HashSet<String> = HashSet::new();
+
+    // The git ignore rules apply, so dependencies and generated files are not scanned.
+    let walker = WalkBuilder::new(working_dir).hidden(true).build();
+
+    for dir_entry in walker.filter_map(|entry| entry.ok()) {
+        let current_path = dir_entry.path();
+
+        if !current_path.is_file() || !matcher.matched(current_path, false).is_whitelist() {
+            continue;
+        }
+
+        match std::fs::read_to_string(current_path) {
+            Ok(content) => {
+                referenced_words.extend(
+                    content
+                        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                        .filter(|word| !word.is_empty())
+                        .map(|word| word.to_string()),
+                );
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to scan the content file {:?}: {}",
+                    current_path,
+                    err
+                );
+            }
+        }
+    }
+
+    let mut defined_classes: Vec<(String, String)> = get_tracking_map_from_central(&CLASTRACK)
+        .into_keys()
+        .map(|class_name| ("central".to_string(), class_name))
+        .collect();
+
+    for (context_name, classes_map) in get_tracking_map_from_layouts(&CLASTRACK)
+        .into_iter()
+        .chain(get_tracking_map_from_modules(&CLASTRACK))
+    {
+        defined_classes.extend(
+            classes_map
+                .into_keys()
+                .map(|class_name| (context_name.to_owned(), class_name)),
+        );
+    }
+
+    Ok(defined_classes
+        .into_iter()
+        .filter(|(_, class_name)| !referenced_words.contains(class_name))
+        .map(|(context_name, class_name)| format!("{}::{}", context_name, class_name))
+        .collect())
+}
+
+/// Warns about every Nenyr class never referenced by the scanned source files.
+///
+/// # Arguments
+/// - `unused_classes`: The unreferenced classes, named as `contextName::className`.
+/// - `palantir_sender`: The sender used to broadcast the warnings.
+pub fn warn_unused_classes(
+    unused_classes: &[String],
+    palantir_sender: broadcast::Sender<GaladrielAlerts>,
+) {
+    for unused_class in unused_classes {
+        tracing::warn!("Class '{}' is never referenced.", unused_class);
+
+        let notification = GaladrielAlerts::create_warning(
+            Local::now(),
+            &format!(
+                "The `{}` class is never referenced by the files matched by `contentGlobs`. Consider removing it to keep the generated CSS lean.",
+                unused_class
+            ),
+        );
+
+        send_palantir_notification(notification, palantir_sender.clone());
+    }
+}
@@ -0,0 +1,28 @@
+/// The media condition resolved for the reserved `print` breakpoint.
+pub const PRINT_MEDIA_CONDITION: &str = "print";
+
+/// The prefix of the conditions resolved for the breakpoints of the `containerBreakpoints`
+/// configuration, e.g. `container:min-width:480px`.
+pub const CONTAINER_CONDITION_PREFIX: &str = "container:";
+
+/// Formats the `@media` prelude of a resolved breakpoint condition.
+///
+/// The `print` condition targets the print media type, a condition prefixed by
+/// `CONTAINER_CONDITION_PREFIX` is a container query, while every other condition
+/// (e.g. `min-width:740px`) is a screen feature query.
+///
+/// # Arguments
+/// - `media_condition`: The resolved breakpoint condition.
+///
+/// # Returns
+/// - A `String` with the `@media` or `@container` prelude, without the rule block.
+pub fn format_media_query(media_condition: &str) -> String {
+    if media_condition == PRINT_MEDIA_CONDITION {
+        return "@media print".to_string();
+    }
+
+    match media_condition.strip_prefix(CONTAINER_CONDITION_PREFIX) {
+        Some(container_condition) => format!("@container ({})", container_condition),
+        None => format!("@media screen and ({})", media_condition),
+    }
+}
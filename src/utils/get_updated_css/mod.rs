@@ -1,4 +1,12 @@
-use crate::asts::CASCADEX;
+use dashmap::DashMap;
+
+use crate::{
+    astroform::{
+        CONTEXT_SHEET_KEY_PREFIX, CONTEXT_SOURCE_MAP_KEY_PREFIX, RESPONSIVE_SHEET_KEY_PREFIX,
+        SOURCE_MAP_KEY, THEME_SHEET_KEY_PREFIX,
+    },
+    asts::CASCADEX,
+};
 
 /// Retrieves the latest updated CSS content from the CASCADEX cache.
 ///
@@ -15,3 +23,99 @@ pub fn get_updated_css() -> String {
     // Returns an empty string if the "cascading_sheet" entry is not found in the cache.
     String::new()
 }
+
+/// Retrieves the source map of the latest CSS content from the CASCADEX cache.
+///
+/// # Arguments
+/// - `css_file`: The name of the file the CSS content is written to, referenced by the source map.
+///
+/// # Returns
+/// - `Some(String)` with the source map as JSON, or `None` if no source map was generated.
+pub fn get_updated_source_map(css_file: &str) -> Option<String> {
+    CASCADEX
+        .get(SOURCE_MAP_KEY)
+        .and_then(|source_map| name_source_map_file(source_map.value(), css_file))
+}
+
+/// Retrieves the theme stylesheets split from the main stylesheet.
+///
+/// # Returns
+/// - A `Vec<(String, String)>` with the theme schema name and its CSS, sorted by the schema name.
+pub fn get_split_theme_sheets() -> Vec<(String, String)> {
+    let mut theme_sheets = CASCADEX
+        .iter()
+        .filter_map(|entry| {
+            entry
+                .key()
+                .strip_prefix(THEME_SHEET_KEY_PREFIX)
+                .map(|schema| (schema.to_string(), entry.value().to_owned()))
+        })
+        .collect::<Vec<(String, String)>>();
+
+    theme_sheets.sort_by(|first, second| first.0.cmp(&second.0));
+
+    theme_sheets
+}
+
+/// Retrieves the mobile-first and desktop-first responsive stylesheets split from the main stylesheet.
+///
+/// # Returns
+/// - A `Vec<(String, String)>` with the responsive approach (`mobile-first` or `desktop-first`) and its CSS.
+pub fn get_split_responsive_sheets() -> Vec<(String, String)> {
+    ["mobile-first", "desktop-first"]
+        .into_iter()
+        .filter_map(|approach| {
+            CASCADEX
+                .get(&format!("{}{}", RESPONSIVE_SHEET_KEY_PREFIX, approach))
+                .map(|sheet| (approach.to_string(), sheet.value().to_owned()))
+        })
+        .collect()
+}
+
+/// Takes the stylesheet generated for a single context out of a CASCADEX cache.
+///
+/// # Arguments
+/// - `cascadex`: The stylesheets cache of the session the context stylesheet was generated in.
+/// - `context_name`: The name of the context the stylesheet was generated for.
+///
+/// # Returns
+/// - A `String` containing the context CSS, or an empty `String` if it was not generated.
+pub fn take_context_sheet(cascadex: &DashMap<String, String>, context_name: &str) -> String {
+    cascadex
+        .remove(&format!("{}{}", CONTEXT_SHEET_KEY_PREFIX, context_name))
+        .map(|(_, context_css)| context_css)
+        .unwrap_or_default()
+}
+
+/// Takes the source map of the stylesheet generated for a single context out of a CASCADEX cache.
+///
+/// # Arguments
+/// - `cascadex`: The stylesheets cache of the session the context stylesheet was generated in.
+/// - `context_name`: The name of the context the stylesheet was generated for.
+/// - `css_file`: The name of the file the context stylesheet is written to, referenced by the source map.
+///
+/// # Returns
+/// - `Some(String)` with the source map as JSON, or `None` if no source map was generated.
+pub fn take_context_source_map(
+    cascadex: &DashMap<String, String>,
+    context_name: &str,
+    css_file: &str,
+) -> Option<String> {
+    cascadex
+        .remove(&format!(
+            "{}{}",
+            CONTEXT_SOURCE_MAP_KEY_PREFIX, context_name
+        ))
+        .and_then(|(_, source_map)| name_source_map_file(&source_map, css_file))
+}
+
+/// Sets the `file` entry of a source map to the name of the stylesheet it describes.
+fn name_source_map_file(source_map: &str, css_file: &str) -> Option<String> {
+    let mut source_map = serde_json::from_str::<serde_json::Value>(source_map).ok()?;
+
+    source_map
+        .as_object_mut()?
+        .insert("file".to_string(), serde_json::Value::from(css_file));
+
+    Some(source_map.to_string())
+}
@@ -1,4 +1,17 @@
+pub mod activity_tracker;
+pub mod build_cache;
+pub mod build_json_name;
+pub mod build_progress;
+pub mod build_stats;
+pub mod classes_changelog;
+pub mod collect_css_rules;
+pub mod compile_str;
+pub mod context_partitions;
+pub mod copy_to_clipboard;
+pub mod css_data_uri;
 pub mod file_timestamp_updater;
+pub mod find_unused_classes;
+pub mod format_media_query;
 pub mod generates_node_styles;
 pub mod generates_words;
 pub mod get_nenyr_syntax;
@@ -6,6 +19,7 @@ pub mod get_updated_css;
 pub mod get_utility_class_names;
 pub mod inject_names;
 pub mod is_nenyr_event;
+pub mod notify_build_webhook;
 pub mod pretty_print;
 pub mod replace_file;
 pub mod resilient_reader;
@@ -13,6 +27,18 @@ pub mod restore_abstract_syntax_trees;
 pub mod send_palantir_error_notification;
 pub mod send_palantir_notification;
 pub mod send_palantir_success_notification;
+pub mod serialize_classes_js_styles;
 pub mod serialize_classes_tracking;
+pub mod serialize_classes_typings;
+pub mod serialize_stylitron_ast;
+pub mod serialize_variable_map;
 pub mod set_file_times;
+pub mod stats_badge;
+pub mod validate_css;
+pub mod variable_dependents;
+pub mod verify_imports;
+pub mod warn_crowded_properties;
+pub mod warn_dangling_derivations;
+pub mod warn_important_overrides;
+pub mod warn_unused_variables;
 pub mod write_file;
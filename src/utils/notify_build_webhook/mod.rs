@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use chrono::Local;
+use serde_json::json;
+
+use crate::configatron::get_build_webhook;
+
+use super::{classes_changelog::snapshot_classes_tracking, get_updated_css::get_updated_css};
+
+/// The maximum time the build waits for the webhook to answer.
+const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Notifies the configured build webhook that a build has completed.
+///
+/// The webhook receives a `POST` request with the outcome of the build, the size of the generated
+/// CSS, the number of Nenyr classes and a timestamp. The notification is best-effort: any failure
+/// is only logged, and nothing is sent when no webhook is configured.
+///
+/// # Arguments
+/// - `succeeded`: Whether the build completed successfully.
+pub async fn notify_build_webhook(succeeded: bool) {
+    let webhook_url = get_build_webhook();
+
+    if webhook_url.trim().is_empty() {
+        return;
+    }
+
+    let payload = json!({
+        "success": succeeded,
+        "cssSize": get_updated_css().len(),
+        "classCount": snapshot_classes_tracking().len(),
+        "timestamp": Local::now().to_rfc3339(),
+    });
+
+    tracing::info!("Notifying the build webhook: {}", webhook_url);
+
+    let client = match reqwest::Client::builder()
+        .timeout(WEBHOOK_REQUEST_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(err) => {
+            tracing::error!("Failed to build the webhook HTTP client: {:?}", err);
+
+            return;
+        }
+    };
+
+    let response = client
+        .post(webhook_url.trim())
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(payload.to_string())
+        .send()
+        .await;
+
+    match response {
+        Ok(response) if response.status().is_success() => {
+            tracing::info!("Build webhook notified with status {}.", response.status());
+        }
+        Ok(response) => {
+            tracing::warn!(
+                "The build webhook answered with status {}.",
+                response.status()
+            );
+        }
+        Err(err) => {
+            tracing::warn!("Failed to notify the build webhook: {}", err);
+        }
+    }
+}
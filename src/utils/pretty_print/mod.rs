@@ -2,7 +2,9 @@ use chrono::{DateTime, Local, TimeDelta};
 use crossterm::style::Stylize;
 use nenyr::error::NenyrError;
 
-use crate::{error::GaladrielError, events::GaladrielAlerts};
+use crate::{
+    error::GaladrielError, events::GaladrielAlerts, utils::build_progress::print_report_line,
+};
 
 /// The `pretty_print` function is the main entry point for displaying different types of notifications
 /// in a formatted way. It takes a `GaladrielAlerts` enum, matches it to its variant, and delegates the
@@ -161,7 +163,7 @@ fn pretty_print_nenyr_error(start_time: DateTime<Local>, error: NenyrError) {
 
         apply_textwrap(&formatted_message, true);
     } else {
-        println!("");
+        print_report_line("");
     }
 }
 
@@ -257,7 +259,7 @@ fn apply_textwrap(message: &str, is_error: bool) {
             if is_error {
                 eprintln!("{}{}", tab, msg.to_string());
             } else {
-                println!("{}{}", tab, msg.to_string());
+                print_report_line(&format!("{}{}", tab, msg));
             }
         }
     } else {
@@ -265,9 +267,9 @@ fn apply_textwrap(message: &str, is_error: bool) {
         if is_error {
             eprintln!("{}", message);
         } else {
-            println!("{}", message);
+            print_report_line(message);
         }
     }
 
-    println!("");
+    print_report_line("");
 }
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 
 use crate::{
-    asts::{CLASSINATOR, GATEKEEPER, INTAKER, STYLITRON},
+    asts::{CLASSINATOR, GATEKEEPER, INTAKER, ORIGINATOR, STYLITRON},
     types::{Classinator, Stylitron},
 };
 
@@ -10,6 +10,7 @@ use super::generates_node_styles::generates_node_styles;
 pub fn restore_abstract_syntax_trees() {
     INTAKER.clear();
     GATEKEEPER.clear();
+    ORIGINATOR.clear();
 
     CLASSINATOR.insert("central".to_string(), Classinator::Central(IndexMap::new()));
     CLASSINATOR.insert("layouts".to_string(), Classinator::Layouts(IndexMap::new()));
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use crate::{
+    asts::{CLASTRACK, STYLITRON},
+    types::Stylitron,
+    utils::{
+        format_media_query::format_media_query,
+        serialize_classes_tracking::{
+            get_tracking_map_from_central, get_tracking_map_from_layouts,
+            get_tracking_map_from_modules,
+        },
+    },
+};
+
+/// A single CSS declaration held by a utility class in the `STYLITRON` AST.
+#[derive(Clone, PartialEq, Debug)]
+struct UtilityDeclaration {
+    /// The media condition of the declaration, when it comes from the responsive styles.
+    media_condition: Option<String>,
+    /// The pseudo-selector of the declaration, or an empty string when there is none.
+    pseudo_selector: String,
+    /// The CSS property name.
+    property: String,
+    /// The CSS value, including the `!important` flag when present.
+    value: String,
+}
+
+/// A node of the CSS-in-JS style object: either a declaration value or a nested style object.
+#[derive(Clone, PartialEq, Debug)]
+enum JsStyleNode {
+    Value(String),
+    Nested(IndexMap<String, JsStyleNode>),
+}
+
+/// Serializes the resolved styles of every Nenyr class into a JavaScript module for CSS-in-JS runtimes.
+///
+/// The styles are resolved from the utility classes tracked for each Nenyr class, so they carry
+/// exactly the declarations written into the `STYLITRON` AST. Pseudo-selectors are nested under
+/// `&<pseudo>` keys, and responsive styles under their `@media` rule.
+///
+/// # Returns
+/// Returns a `String` with the content of a `.js` module whose default export maps `central`,
+/// `layouts` and `modules` to the style objects of their Nenyr classes.
+pub fn serialize_classes_js_styles() -> String {
+    tracing::info!("Starting to serialize the Nenyr classes styles as a CSS-in-JS module.");
+
+    let declarations = collect_utility_declarations();
+
+    // Resolve the style objects of the central context classes.
+    let central_styles =
+        resolve_classes_styles(get_tracking_map_from_central(&CLASTRACK), &declarations);
+    // Resolve the style objects of the classes of every layout context.
+    let layouts_styles =
+        resolve_contexts_styles(get_tracking_map_from_layouts(&CLASTRACK), &declarations);
+    // Resolve the style objects of the classes of every module context.
+    let modules_styles =
+        resolve_contexts_styles(get_tracking_map_from_modules(&CLASTRACK), &declarations);
+
+    let styles = JsStyleNode::Nested(IndexMap::from([
+        ("central".to_string(), central_styles),
+        ("layouts".to_string(), layouts_styles),
+        ("modules".to_string(), modules_styles),
+    ]));
+
+    format!(
+        "// This file is generated by Galadriel CSS. Do not edit it manually.\n\nconst nenyrStyles = {};\n\nexport default nenyrStyles;\n",
+        format_js_style_node(&styles, 0)
+    )
+}
+
+/// Collects the declaration held by each utility class of the `STYLITRON` AST.
+///
+/// # Returns
+/// - `HashMap<String, UtilityDeclaration>`: The declarations keyed by the utility class name.
+fn collect_utility_declarations() -> HashMap<String, UtilityDeclaration> {
+    let mut declarations: HashMap<String, UtilityDeclaration> = HashMap::new();
+
+    if let Some(stylitron_data) = STYLITRON.get("styles") {
+        if let Stylitron::Styles(ref styles_map) = *stylitron_data {
+            insert_declarations(None, styles_map, &mut declarations);
+        }
+    }
+
+    if let Some(stylitron_data) = STYLITRON.get("responsive") {
+        if let Stylitron::ResponsiveStyles(ref responsive_map) = *stylitron_data {
+            for (media_condition, styles_map) in responsive_map {
+                insert_declarations(
+                    Some(media_condition.to_owned()),
+                    styles_map,
+                    &mut declarations,
+                );
+            }
+        }
+    }
+
+    declarations
+}
+
+/// Inserts the declarations of a styles node into the declarations map.
+///
+/// # Arguments
+/// - `media_condition`: The media condition of the styles node, if it is a responsive one.
+/// - `styles_map`: The styles node, keyed by pseudo-selector, importance, property and utility class name.
+/// - `declarations`: The map receiving the declaration of each utility class.
+fn insert_declarations(
+    media_condition: Option<String>,
+    styles_map: &IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+    declarations: &mut HashMap<String, UtilityDeclaration>,
+) {
+    for (pseudo_selector, importance_map) in styles_map {
+        // Remove any leading underscores from the pseudo-selector and importance names.
+        let pseudo_selector = pseudo_selector.trim_start_matches('_');
+
+        for (importance, properties_map) in importance_map {
+            let importance = importance.trim_start_matches('_');
+
+            for (property, class_map) in properties_map {
+                for (utility_name, value) in class_map {
+                    let value = match importance.is_empty() {
+                        true => value.to_owned(),
+                        false => format!("{} {}", value, importance),
+                    };
+
+                    declarations.insert(
+                        utility_name.to_owned(),
+                        UtilityDeclaration {
+                            media_condition: media_condition.to_owned(),
+                            pseudo_selector: pseudo_selector.to_string(),
+                            property: property.to_owned(),
+                            value,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Resolves the style objects of the Nenyr classes of several contexts.
+///
+/// # Arguments
+/// - `contexts_map`: The tracking maps keyed by the context name.
+/// - `declarations`: The declaration held by each utility class.
+///
+/// # Returns
+/// - `JsStyleNode`: An object mapping each context name to the style objects of its classes.
+fn resolve_contexts_styles(
+    contexts_map: IndexMap<String, IndexMap<String, String>>,
+    declarations: &HashMap<String, UtilityDeclaration>,
+) -> JsStyleNode {
+    JsStyleNode::Nested(
+        contexts_map
+            .into_iter()
+            .map(|(context_name, classes_map)| {
+                (
+                    context_name,
+                    resolve_classes_styles(classes_map, declarations),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Resolves the style objects of the Nenyr classes of a single context.
+///
+/// # Arguments
+/// - `classes_map`: The tracking map of the context, keyed by the Nenyr class name.
+/// - `declarations`: The declaration held by each utility class.
+///
+/// # Returns
+/// - `JsStyleNode`: An object mapping each Nenyr class name to its style object.
+fn resolve_classes_styles(
+    classes_map: IndexMap<String, String>,
+    declarations: &HashMap<String, UtilityDeclaration>,
+) -> JsStyleNode {
+    let mut classes_styles: IndexMap<String, JsStyleNode> = IndexMap::new();
+
+    for (class_name, utility_names) in classes_map {
+        let mut class_styles: IndexMap<String, JsStyleNode> = IndexMap::new();
+
+        for utility_name in utility_names.split_whitespace() {
+            let declaration = match declarations.get(utility_name) {
+                Some(declaration) => declaration,
+                None => {
+                    tracing::warn!(
+                        "Utility class '{}' of the '{}' class was not found in STYLITRON.",
+                        utility_name,
+                        class_name
+                    );
+
+                    continue;
+                }
+            };
+
+            let mut target_styles = &mut class_styles;
+
+            // Responsive declarations are nested under their `@media` rule.
+            if let Some(media_condition) = &declaration.media_condition {
+                target_styles =
+                    get_nested_styles(target_styles, format_media_query(media_condition));
+            }
+
+            // Pseudo-selector declarations are nested under the `&<pseudo>` key.
+            if !declaration.pseudo_selector.is_empty() {
+                target_styles =
+                    get_nested_styles(target_styles, format!("&{}", declaration.pseudo_selector));
+            }
+
+            target_styles.insert(
+                camelize_property(&declaration.property),
+                JsStyleNode::Value(declaration.value.to_owned()),
+            );
+        }
+
+        classes_styles.insert(class_name, JsStyleNode::Nested(class_styles));
+    }
+
+    JsStyleNode::Nested(classes_styles)
+}
+
+/// Retrieves the nested style object stored under the given key, creating it when missing.
+///
+/// # Arguments
+/// - `styles`: The style object holding the nested one.
+/// - `key`: The key of the nested style object.
+///
+/// # Returns
+/// - `&mut IndexMap<String, JsStyleNode>`: The nested style object.
+fn get_nested_styles(
+    styles: &mut IndexMap<String, JsStyleNode>,
+    key: String,
+) -> &mut IndexMap<String, JsStyleNode> {
+    let node = styles
+        .entry(key)
+        .or_insert_with(|| JsStyleNode::Nested(IndexMap::new()));
+
+    // A declaration can never share its key with a nested object, but it is replaced if it does.
+    if let JsStyleNode::Value(_) = node {
+        *node = JsStyleNode::Nested(IndexMap::new());
+    }
+
+    match node {
+        JsStyleNode::Nested(nested_styles) => nested_styles,
+        JsStyleNode::Value(_) => unreachable!(),
+    }
+}
+
+/// Converts a CSS property name into the camel case name used by CSS-in-JS style objects.
+///
+/// Custom properties are kept as they are, and vendor prefixes follow the usual convention,
+/// e.g. `-webkit-transition` becomes `WebkitTransition` and `-ms-transform` becomes `msTransform`.
+///
+/// # Arguments
+/// - `property`: The CSS property name.
+///
+/// # Returns
+/// - `String`: The camel case property name.
+fn camelize_property(property: &str) -> String {
+    if property.starts_with("--") {
+        return property.to_string();
+    }
+
+    let property = match property.strip_prefix("-ms-") {
+        Some(unprefixed_property) => format!("ms-{}", unprefixed_property),
+        None => property.to_string(),
+    };
+
+    property
+        .split('-')
+        .enumerate()
+        .map(|(idx, word)| {
+            if idx == 0 {
+                return word.to_string();
+            }
+
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .map(|c| c.to_uppercase().collect::<String>())
+                .unwrap_or_default()
+                + chars.as_str()
+        })
+        .collect()
+}
+
+/// Formats a style node as a JavaScript object literal.
+///
+/// # Arguments
+/// - `node`: The style node to be formatted.
+/// - `depth`: The nesting depth of the node, used for indentation.
+///
+/// # Returns
+/// - `String`: The formatted object literal or string value.
+fn format_js_style_node(node: &JsStyleNode, depth: usize) -> String {
+    match node {
+        JsStyleNode::Value(value) => format!("{:?}", value),
+        JsStyleNode::Nested(styles) if styles.is_empty() => "{}".to_string(),
+        JsStyleNode::Nested(styles) => {
+            let indentation = "    ".repeat(depth + 1);
+            let entries = styles
+                .iter()
+                .map(|(key, nested_node)| {
+                    format!(
+                        "{}{:?}: {}",
+                        indentation,
+                        key,
+                        format_js_style_node(nested_node, depth + 1)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(",\n");
+
+            format!("{{\n{}\n{}}}", entries, "    ".repeat(depth))
+        }
+    }
+}
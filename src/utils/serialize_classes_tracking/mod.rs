@@ -1,3 +1,4 @@
+use dashmap::DashMap;
 use indexmap::IndexMap;
 
 use crate::{asts::CLASTRACK, types::Clastrack};
@@ -5,37 +6,97 @@ use crate::{asts::CLASTRACK, types::Clastrack};
 /// Serializes the tracking data from three different contexts (`central`, `layouts`, and `modules`).
 /// It collects the tracking maps from each context and formats them into a JSON-like string.
 ///
+/// The utility class names are stored in their CSS-selector form, escaping characters such as
+/// `!` and `.` (e.g. `mMd\.\!bgd-clr-a1Ib`), which is not the string expected by a `class`
+/// attribute. Hence, the same maps are serialized a second time under the `domSafe` key, holding
+/// the class names without the CSS escaping (e.g. `mMd.!bgd-clr-a1Ib`).
+///
 /// # Returns
 /// Returns a `String` representing the serialized tracking data from all contexts.
 ///
 /// The string is formatted as a JSON object with keys "central", "layouts", and "modules", each containing
-/// the corresponding tracking map data.
+/// the corresponding tracking map data in the CSS-selector form, and the key "domSafe", containing the
+/// same three keys with the class names to be applied to the markup.
 pub fn serialize_classes_tracking() -> String {
+    serialize_classes_tracking_from(&CLASTRACK)
+}
+
+/// Serializes the tracking data of the given classes tracking map, in the same format
+/// as `serialize_classes_tracking`.
+///
+/// # Arguments
+/// - `clastrack`: The classes tracking map to serialize.
+pub fn serialize_classes_tracking_from(clastrack: &DashMap<String, Clastrack>) -> String {
     tracing::info!("Starting to serialize class tracking data.");
 
     // Fetch the tracking data from the central context
-    let central_map = get_tracking_map_from_central();
+    let central_map = get_tracking_map_from_central(clastrack);
     // Fetch the tracking data from the layouts context
-    let layouts_map = get_tracking_map_from_layouts();
+    let layouts_map = get_tracking_map_from_layouts(clastrack);
     // Fetch the tracking data from the modules context
-    let modules_map = get_tracking_map_from_modules();
+    let modules_map = get_tracking_map_from_modules(clastrack);
+
+    // Removes the CSS escaping from the class names, so they can be used in the markup
+    let dom_safe_central = to_dom_safe_classes(&central_map);
+    let dom_safe_layouts = to_dom_safe_contexts(&layouts_map);
+    let dom_safe_modules = to_dom_safe_contexts(&modules_map);
 
     // Return the serialized string in a JSON-like format containing all three tracking maps
     format!(
-        "{{ \"central\": {:?}, \"layouts\": {:?}, \"modules\": {:?}}}",
-        central_map, layouts_map, modules_map
+        "{{ \"central\": {:?}, \"layouts\": {:?}, \"modules\": {:?}, \"domSafe\": {{ \"central\": {:?}, \"layouts\": {:?}, \"modules\": {:?}}}}}",
+        central_map, layouts_map, modules_map, dom_safe_central, dom_safe_layouts, dom_safe_modules
     )
 }
 
+/// Converts the utility class names of a context tracking map from their CSS-selector form into
+/// the form applied to a `class` attribute, by removing the escape characters.
+///
+/// # Arguments
+/// - `classes_map`: The tracking map of the context, keyed by the Nenyr class name.
+///
+/// # Returns
+/// - `IndexMap<String, String>`: The same map holding the unescaped utility class names.
+fn to_dom_safe_classes(classes_map: &IndexMap<String, String>) -> IndexMap<String, String> {
+    classes_map
+        .iter()
+        .map(|(class_name, utility_names)| {
+            (class_name.to_owned(), utility_names.replace('\\', "")) // Removes escape characters if found.
+        })
+        .collect()
+}
+
+/// Converts the utility class names of several contexts tracking maps into their DOM-safe form.
+///
+/// # Arguments
+/// - `contexts_map`: The tracking maps keyed by the context name.
+///
+/// # Returns
+/// - `IndexMap<String, IndexMap<String, String>>`: The same maps holding the unescaped utility class names.
+fn to_dom_safe_contexts(
+    contexts_map: &IndexMap<String, IndexMap<String, String>>,
+) -> IndexMap<String, IndexMap<String, String>> {
+    contexts_map
+        .iter()
+        .map(|(context_name, classes_map)| {
+            (context_name.to_owned(), to_dom_safe_classes(classes_map))
+        })
+        .collect()
+}
+
 /// Retrieves the tracking map for the "central" context. If no valid data is found in `CLASTRACK` for
 /// the "central" key, an empty `IndexMap` is returned.
 ///
+/// # Arguments
+/// - `clastrack`: The classes tracking map to read from.
+///
 /// # Returns
 /// - `IndexMap<String, String>`: A map representing the tracking data from the central context.
-fn get_tracking_map_from_central() -> IndexMap<String, String> {
+pub fn get_tracking_map_from_central(
+    clastrack: &DashMap<String, Clastrack>,
+) -> IndexMap<String, String> {
     tracing::info!("Fetching tracking map for central context.");
 
-    match CLASTRACK.get("central") {
+    match clastrack.get("central") {
         Some(clastrack_data) => match &*clastrack_data {
             Clastrack::Central(ref central_node) => {
                 return central_node.to_owned();
@@ -51,12 +112,17 @@ fn get_tracking_map_from_central() -> IndexMap<String, String> {
 /// Retrieves the tracking map for the "layouts" context. If no valid data is found in `CLASTRACK` for
 /// the "layouts" key, an empty `IndexMap` is returned.
 ///
+/// # Arguments
+/// - `clastrack`: The classes tracking map to read from.
+///
 /// # Returns
 /// - `IndexMap<String, IndexMap<String, String>>`: A map representing the tracking data from the layouts context.
-fn get_tracking_map_from_layouts() -> IndexMap<String, IndexMap<String, String>> {
+pub fn get_tracking_map_from_layouts(
+    clastrack: &DashMap<String, Clastrack>,
+) -> IndexMap<String, IndexMap<String, String>> {
     tracing::info!("Fetching tracking map for layout contexts.");
 
-    match CLASTRACK.get("layouts") {
+    match clastrack.get("layouts") {
         Some(clastrack_data) => match &*clastrack_data {
             Clastrack::Layouts(ref layouts_node) => {
                 return layouts_node.to_owned();
@@ -72,12 +138,17 @@ fn get_tracking_map_from_layouts() -> IndexMap<String, IndexMap<String, String>>
 /// Retrieves the tracking map for the "modules" context. If no valid data is found in `CLASTRACK` for
 /// the "modules" key, an empty `IndexMap` is returned.
 ///
+/// # Arguments
+/// - `clastrack`: The classes tracking map to read from.
+///
 /// # Returns
 /// - `IndexMap<String, IndexMap<String, String>>`: A map representing the tracking data from the modules context.
-fn get_tracking_map_from_modules() -> IndexMap<String, IndexMap<String, String>> {
+pub fn get_tracking_map_from_modules(
+    clastrack: &DashMap<String, Clastrack>,
+) -> IndexMap<String, IndexMap<String, String>> {
     tracing::info!("Fetching tracking map for module contexts.");
 
-    match CLASTRACK.get("modules") {
+    match clastrack.get("modules") {
         Some(clastrack_data) => match &*clastrack_data {
             Clastrack::Modules(ref modules_node) => {
                 return modules_node.to_owned();
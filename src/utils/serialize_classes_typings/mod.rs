@@ -0,0 +1,80 @@
+use indexmap::IndexMap;
+
+use crate::{
+    asts::CLASTRACK,
+    utils::serialize_classes_tracking::{
+        get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+    },
+};
+
+/// Serializes the Nenyr class names available in each context into TypeScript typings.
+///
+/// The typings are generated from the classes tracking maps, so they describe exactly the
+/// Nenyr classes that can be applied through the tracking JSON.
+///
+/// # Returns
+/// Returns a `String` with the content of a `.d.ts` file, declaring:
+/// - `CentralClasses`: A union of the class names of the central context.
+/// - `LayoutClasses`: An object type mapping each layout context to the union of its class names.
+/// - `ModuleClasses`: An object type mapping each module context to the union of its class names.
+pub fn serialize_classes_typings() -> String {
+    tracing::info!("Starting to serialize class typings.");
+
+    // Fetch the class names of the central context
+    let central_union = format_class_names_union(get_tracking_map_from_central(&CLASTRACK));
+    // Fetch the class names of every layout context
+    let layouts_object = format_contexts_object(get_tracking_map_from_layouts(&CLASTRACK));
+    // Fetch the class names of every module context
+    let modules_object = format_contexts_object(get_tracking_map_from_modules(&CLASTRACK));
+
+    format!(
+        "// This file is generated by Galadriel CSS. Do not edit it manually.\n\nexport type CentralClasses = {};\n\nexport type LayoutClasses = {};\n\nexport type ModuleClasses = {};\n",
+        central_union, layouts_object, modules_object
+    )
+}
+
+/// Formats the class names of a single context into a TypeScript string literal union.
+///
+/// # Arguments
+/// - `classes_map`: The tracking map of the context, keyed by the Nenyr class name.
+///
+/// # Returns
+/// - `String`: The union of class names, or `never` when the context has no classes.
+fn format_class_names_union(classes_map: IndexMap<String, String>) -> String {
+    if classes_map.is_empty() {
+        return "never".to_string();
+    }
+
+    classes_map
+        .keys()
+        .map(|class_name| format!("{:?}", class_name))
+        .collect::<Vec<String>>()
+        .join(" | ")
+}
+
+/// Formats the tracking maps of several contexts into a TypeScript object type.
+///
+/// # Arguments
+/// - `contexts_map`: The tracking maps keyed by the context name.
+///
+/// # Returns
+/// - `String`: The object type mapping each context name to the union of its class names.
+fn format_contexts_object(contexts_map: IndexMap<String, IndexMap<String, String>>) -> String {
+    if contexts_map.is_empty() {
+        return "{}".to_string();
+    }
+
+    let entries = contexts_map
+        .into_iter()
+        .map(|(context_name, classes_map)| {
+            format!(
+                "    {:?}: {};",
+                context_name,
+                format_class_names_union(classes_map)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!("{{\n{}\n}}", entries)
+}
@@ -0,0 +1,55 @@
+use serde_json::{json, Map, Value};
+
+use crate::{asts::STYLITRON, types::Stylitron};
+
+/// The sections of `STYLITRON`, in the order they are dumped.
+const STYLITRON_SECTIONS: [&str; 9] = [
+    "imports",
+    "aliases",
+    "breakpoints",
+    "typefaces",
+    "variables",
+    "themes",
+    "animations",
+    "styles",
+    "responsive",
+];
+
+/// Serializes every section of `STYLITRON` into a pretty-printed JSON object keyed by the section name.
+///
+/// The sections are dumped as they are stored, e.g. the `styles` section is keyed by pattern,
+/// importance, property and utility class name, while the `imports` section is the list of the
+/// imported URLs.
+///
+/// # Returns
+/// - `String`: The STYLITRON AST as a pretty-printed JSON object.
+pub fn serialize_stylitron_ast() -> String {
+    let mut sections = Map::new();
+
+    for section_name in STYLITRON_SECTIONS {
+        let Some(stylitron_data) = STYLITRON.get(section_name) else {
+            tracing::warn!(
+                "The {} section is missing from STYLITRON AST.",
+                section_name
+            );
+
+            continue;
+        };
+
+        let section = match &*stylitron_data {
+            Stylitron::Imports(imports) => json!(imports.keys().collect::<Vec<_>>()),
+            Stylitron::Aliases(aliases) => json!(aliases),
+            Stylitron::Breakpoints(breakpoints) => json!(breakpoints),
+            Stylitron::Typefaces(typefaces) => json!(typefaces),
+            Stylitron::Variables(variables) => json!(variables),
+            Stylitron::Themes(themes) => json!(themes),
+            Stylitron::Animation(animations) => json!(animations),
+            Stylitron::Styles(styles) => json!(styles),
+            Stylitron::ResponsiveStyles(responsive_styles) => json!(responsive_styles),
+        };
+
+        sections.insert(section_name.to_string(), section);
+    }
+
+    format!("{:#}", Value::Object(sections))
+}
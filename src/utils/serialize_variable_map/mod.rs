@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use serde_json::{json, Map, Value};
+
+use crate::{asts::STYLITRON, crealion::CENTRAL_CONTEXT_NAME, types::Stylitron};
+
+lazy_static! {
+    /// Matches a reference to a generated variable, e.g. `var(--gW1yAqTMgoH)`.
+    static ref VARIABLE_REFERENCE_RE: Regex = Regex::new(r"var\(\s*(--[\w-]+)\s*\)").unwrap();
+}
+
+/// Number of nested variable references followed before a value is left partially resolved,
+/// which also stops the cyclic references.
+const MAX_RESOLUTION_DEPTH: usize = 16;
+
+/// Serializes the variables of every context in `STYLITRON`, mapping each one to its unique
+/// identifier, its declared value and its value once the referenced variables are resolved.
+///
+/// The output is keyed by the context name, `central` for the central context, then by the
+/// relative name of the variable, e.g.
+/// `{"central": {"primaryColor": {"identifier": "--gW1yAqTMgoH", "value": "var(--gNKGUE7AAmy)", "resolved": "#FF6677"}}}`.
+///
+/// # Returns
+/// - `String`: The variable map as a pretty-printed JSON object.
+pub fn serialize_variable_map() -> String {
+    let Some(stylitron_data) = STYLITRON.get("variables") else {
+        return "{}".to_string();
+    };
+
+    let Stylitron::Variables(ref variables_map) = *stylitron_data else {
+        return "{}".to_string();
+    };
+
+    let declared_values = variables_map
+        .values()
+        .flat_map(|context_variables| context_variables.values())
+        .filter_map(|variable| match variable.as_slice() {
+            [unique_name, value, ..] => Some((unique_name.as_str(), value.as_str())),
+            _ => None,
+        })
+        .collect::<HashMap<&str, &str>>();
+
+    let mut contexts = Map::new();
+
+    for (context_name, context_variables) in variables_map {
+        let context_name = match context_name.as_str() {
+            CENTRAL_CONTEXT_NAME => "central".to_string(),
+            context_name => context_name.to_string(),
+        };
+
+        let variables = context_variables
+            .iter()
+            .filter_map(|(variable_name, variable)| match variable.as_slice() {
+                [unique_name, value, ..] => Some((
+                    variable_name.to_owned(),
+                    json!({
+                        "identifier": unique_name,
+                        "value": value,
+                        "resolved": resolve_variable_references(value, &declared_values, 0),
+                    }),
+                )),
+                _ => None,
+            })
+            .collect::<Map<String, Value>>();
+
+        contexts.insert(context_name, Value::Object(variables));
+    }
+
+    format!("{:#}", Value::Object(contexts))
+}
+
+/// Replaces every reference to a generated variable with the value it resolves to.
+///
+/// The references to unknown variables, e.g. theme variables, are kept as they are.
+///
+/// # Arguments
+/// - `value`: The value holding the variable references.
+/// - `declared_values`: The declared value of every generated variable, keyed by its identifier.
+/// - `depth`: The number of nested references already followed.
+fn resolve_variable_references(
+    value: &str,
+    declared_values: &HashMap<&str, &str>,
+    depth: usize,
+) -> String {
+    if depth >= MAX_RESOLUTION_DEPTH {
+        return value.to_string();
+    }
+
+    VARIABLE_REFERENCE_RE
+        .replace_all(value, |captures: &Captures| {
+            match declared_values.get(&captures[1]) {
+                Some(declared_value) => {
+                    resolve_variable_references(declared_value, declared_values, depth + 1)
+                }
+                None => captures[0].to_string(),
+            }
+        })
+        .to_string()
+}
@@ -0,0 +1,38 @@
+use serde_json::json;
+
+/// Stylesheet sizes, in kilobytes, up to which the badge is colored green or yellow. Larger ones are red.
+const BADGE_SIZE_THRESHOLDS: (f64, f64) = (50.0, 100.0);
+
+/// Builds a shields.io endpoint badge reporting the size of the generated stylesheet and the number of Nenyr classes.
+///
+/// # Arguments
+/// - `css`: The generated stylesheet.
+/// - `classes_count`: The number of Nenyr classes tracked by the build.
+///
+/// # Returns
+/// - A JSON value following the shields.io endpoint schema, e.g.
+///   `{ "schemaVersion": 1, "label": "css size", "message": "42.1 KB, 120 classes", "color": "green" }`.
+pub fn stats_badge(css: &str, classes_count: usize) -> serde_json::Value {
+    let size_in_kb = css.len() as f64 / 1024.0;
+    let (green_threshold, yellow_threshold) = BADGE_SIZE_THRESHOLDS;
+
+    let color = if size_in_kb <= green_threshold {
+        "green"
+    } else if size_in_kb <= yellow_threshold {
+        "yellow"
+    } else {
+        "red"
+    };
+
+    let classes_label = match classes_count {
+        1 => "class",
+        _ => "classes",
+    };
+
+    json!({
+        "schemaVersion": 1,
+        "label": "css size",
+        "message": format!("{:.1} KB, {} {}", size_in_kb, classes_count, classes_label),
+        "color": color,
+    })
+}
@@ -0,0 +1,46 @@
+use lightningcss::stylesheet::{ParserOptions, StyleSheet};
+
+/// Number of characters shown around the location of a syntax error.
+const SNIPPET_RADIUS: usize = 40;
+
+/// Parses a generated stylesheet with a real CSS parser, catching malformed output before it reaches the browser.
+///
+/// # Arguments
+/// - `css`: The generated stylesheet.
+///
+/// # Returns
+/// - `Ok(())` if the stylesheet parses, or `Err(String)` describing the syntax error and the offending rule.
+pub fn validate_css(css: &str) -> Result<(), String> {
+    let err = match StyleSheet::parse(css, ParserOptions::default()) {
+        Ok(_) => return Ok(()),
+        Err(err) => err,
+    };
+
+    tracing::error!("Generated CSS failed to parse: {}", err);
+
+    match &err.loc {
+        Some(location) => {
+            // Lines are reported from zero, while columns start at one.
+            let line = css.lines().nth(location.line as usize).unwrap_or_default();
+            let column = (location.column as usize).saturating_sub(1);
+
+            Err(format!(
+                "{} at line {}, column {}, near `{}`",
+                err.kind,
+                location.line + 1,
+                location.column,
+                snippet_around(line, column)
+            ))
+        }
+        None => Err(err.kind.to_string()),
+    }
+}
+
+/// Extracts the characters around a column of a line, where the syntax error was found.
+fn snippet_around(line: &str, column: usize) -> String {
+    let chars = line.chars().collect::<Vec<char>>();
+    let start = column.saturating_sub(SNIPPET_RADIUS).min(chars.len());
+    let end = column.saturating_add(SNIPPET_RADIUS).min(chars.len());
+
+    chars[start..end].iter().collect()
+}
@@ -0,0 +1,205 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{asts::CompilationSession, crealion::CENTRAL_CONTEXT_NAME, types::Stylitron};
+
+use super::serialize_classes_tracking::{
+    get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+};
+
+/// Lists the contexts, along with their Nenyr classes, depending on a variable of the global session.
+///
+/// See `collect_variable_dependents_in_session` for the details.
+///
+/// # Arguments
+/// - `context_name`: The context declaring the variable, `central` for the central context.
+/// - `variable_name`: The relative name of the variable or theme variable, as written in Nenyr.
+///
+/// # Returns
+/// - `BTreeMap<String, BTreeSet<String>>`: The dependent Nenyr classes of each dependent context.
+pub fn collect_variable_dependents(
+    context_name: &str,
+    variable_name: &str,
+) -> BTreeMap<String, BTreeSet<String>> {
+    collect_variable_dependents_in_session(
+        &CompilationSession::global(),
+        context_name,
+        variable_name,
+    )
+}
+
+/// Lists the contexts, along with their Nenyr classes, depending on a variable of the given session,
+/// so a change to the variable only invalidates them instead of the whole project.
+///
+/// A context depends on the variable when one of its Nenyr classes, including the ones inherited
+/// from the central or layout contexts, applies a utility class referencing the variable. A context
+/// whose animations reference the variable is listed as well, without any Nenyr class.
+///
+/// # Arguments
+/// - `session`: The session whose abstract syntax trees are searched.
+/// - `context_name`: The context declaring the variable, `central` for the central context.
+/// - `variable_name`: The relative name of the variable or theme variable, as written in Nenyr.
+///
+/// # Returns
+/// - `BTreeMap<String, BTreeSet<String>>`: The dependent Nenyr classes of each dependent context.
+pub fn collect_variable_dependents_in_session(
+    session: &CompilationSession,
+    context_name: &str,
+    variable_name: &str,
+) -> BTreeMap<String, BTreeSet<String>> {
+    let mut dependents: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let variable_references = collect_variable_references(session, context_name, variable_name);
+
+    if variable_references.is_empty() {
+        tracing::debug!(
+            "Variable '{}' is not declared in the '{}' context.",
+            variable_name,
+            context_name
+        );
+
+        return dependents;
+    }
+
+    let references_variable = |value: &str| {
+        variable_references
+            .iter()
+            .any(|reference| value.contains(reference))
+    };
+    let dependent_utilities = collect_dependent_utilities(session, &references_variable);
+
+    let contexts_tracking = [(
+        "central".to_string(),
+        get_tracking_map_from_central(&session.clastrack),
+    )]
+    .into_iter()
+    .chain(get_tracking_map_from_layouts(&session.clastrack))
+    .chain(get_tracking_map_from_modules(&session.clastrack));
+
+    for (tracked_context, context_tracking) in contexts_tracking {
+        let dependent_classes = context_tracking
+            .into_iter()
+            .filter(|(_, utility_names)| {
+                utility_names
+                    .split_whitespace()
+                    .any(|utility_name| dependent_utilities.contains(utility_name))
+            })
+            .map(|(class_name, _)| class_name)
+            .collect::<BTreeSet<String>>();
+
+        if !dependent_classes.is_empty() {
+            dependents.insert(tracked_context, dependent_classes);
+        }
+    }
+
+    // The keyframes of the animations are emitted per context, outside of any Nenyr class.
+    if let Some(stylitron_data) = session.stylitron.get("animations") {
+        if let Stylitron::Animation(ref animations_definitions) = *stylitron_data {
+            for (animation_context, context_animations) in animations_definitions {
+                let references_in_keyframes = context_animations
+                    .values()
+                    .flat_map(|animation_entry| animation_entry.values())
+                    .flat_map(|stops_map| stops_map.values())
+                    .flat_map(|properties_map| properties_map.values())
+                    .any(|value| references_variable(value));
+
+                if references_in_keyframes {
+                    dependents
+                        .entry(resolve_context_name(animation_context))
+                        .or_default();
+                }
+            }
+        }
+    }
+
+    tracing::info!(
+        "Variable '{}' of the '{}' context has {} dependent contexts.",
+        variable_name,
+        context_name,
+        dependents.len()
+    );
+
+    dependents
+}
+
+/// Collects the `var()` references emitted for a variable, one per theme schema for a theme variable.
+fn collect_variable_references(
+    session: &CompilationSession,
+    context_name: &str,
+    variable_name: &str,
+) -> Vec<String> {
+    let stylitron_context = match context_name {
+        "central" => CENTRAL_CONTEXT_NAME,
+        _ => context_name,
+    };
+
+    let mut unique_names: Vec<String> = vec![];
+
+    if let Some(stylitron_data) = session.stylitron.get("variables") {
+        if let Stylitron::Variables(ref variables_definitions) = *stylitron_data {
+            if let Some(variable_entry) = variables_definitions
+                .get(stylitron_context)
+                .and_then(|context_variables| context_variables.get(variable_name))
+            {
+                unique_names.extend(variable_entry.first().cloned());
+            }
+        }
+    }
+
+    if let Some(stylitron_data) = session.stylitron.get("themes") {
+        if let Stylitron::Themes(ref themes_definitions) = *stylitron_data {
+            if let Some(context_themes) = themes_definitions.get(stylitron_context) {
+                context_themes
+                    .values()
+                    .filter_map(|schema_variables| schema_variables.get(variable_name))
+                    .filter_map(|variable_entry| variable_entry.first().cloned())
+                    .for_each(|unique_name| unique_names.push(unique_name));
+            }
+        }
+    }
+
+    unique_names
+        .into_iter()
+        .map(|unique_name| format!("var({})", unique_name))
+        .collect()
+}
+
+/// Collects the names of the utility classes whose value references the variable.
+fn collect_dependent_utilities(
+    session: &CompilationSession,
+    references_variable: &dyn Fn(&str) -> bool,
+) -> BTreeSet<String> {
+    let mut dependent_utilities: BTreeSet<String> = BTreeSet::new();
+
+    for node_name in ["styles", "responsive"] {
+        let Some(stylitron_data) = session.stylitron.get(node_name) else {
+            continue;
+        };
+
+        let styles_maps = match &*stylitron_data {
+            Stylitron::Styles(styles_map) => vec![styles_map],
+            Stylitron::ResponsiveStyles(responsive_map) => responsive_map.values().collect(),
+            _ => vec![],
+        };
+
+        styles_maps
+            .iter()
+            .flat_map(|styles_map| styles_map.values())
+            .flat_map(|importance_map| importance_map.values())
+            .flat_map(|properties_map| properties_map.values())
+            .flat_map(|utilities_map| utilities_map.iter())
+            .filter(|(_, value)| references_variable(value))
+            .for_each(|(utility_name, _)| {
+                dependent_utilities.insert(utility_name.to_owned());
+            });
+    }
+
+    dependent_utilities
+}
+
+/// Resolves the name of a context as listed in the tracking maps.
+fn resolve_context_name(context_name: &str) -> String {
+    if context_name == CENTRAL_CONTEXT_NAME {
+        return "central".to_string();
+    }
+
+    context_name.to_string()
+}
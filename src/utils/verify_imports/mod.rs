@@ -0,0 +1,111 @@
+use std::{path::PathBuf, time::Duration};
+
+use futures::future::join_all;
+
+use crate::{asts::STYLITRON, types::Stylitron};
+
+/// Maximum time a remote import is given to answer its `HEAD` request.
+const IMPORT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Verifies every import held by the `imports` section of the STYLITRON AST.
+///
+/// Remote imports (`http://` or `https://`) are requested with `HEAD` and must answer with a
+/// 2xx status within the request timeout. Any other import is treated as a local file, resolved
+/// relative to the working directory, and must exist.
+///
+/// # Arguments
+/// - `working_dir`: The root directory of the project, used to resolve local imports.
+///
+/// # Returns
+/// - `Vec<(String, String)>`: The imports that failed the verification, each with the reason of the failure.
+pub async fn find_unreachable_imports(working_dir: &PathBuf) -> Vec<(String, String)> {
+    let imports = match STYLITRON.get("imports") {
+        Some(stylitron_data) => match &*stylitron_data {
+            Stylitron::Imports(ref imports_definitions) => {
+                imports_definitions.keys().cloned().collect::<Vec<String>>()
+            }
+            _ => vec![],
+        },
+        None => vec![],
+    };
+
+    tracing::info!("Verifying {} imports.", imports.len());
+
+    let client = match reqwest::Client::builder()
+        .timeout(IMPORT_REQUEST_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(err) => {
+            tracing::error!("Failed to build the imports HTTP client: {:?}", err);
+
+            return imports
+                .into_iter()
+                .map(|import_url| (import_url, format!("could not be requested: {}", err)))
+                .collect();
+        }
+    };
+
+    let verifications = imports.into_iter().map(|import_url| {
+        let client = client.clone();
+
+        async move {
+            let failure = if is_remote_import(&import_url) {
+                verify_remote_import(&client, &import_url).await
+            } else {
+                verify_local_import(working_dir, &import_url)
+            };
+
+            failure.map(|reason| (import_url, reason))
+        }
+    });
+
+    join_all(verifications)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Checks whether an import points to a remote resource.
+fn is_remote_import(import_url: &str) -> bool {
+    let import_url = import_url.to_lowercase();
+
+    import_url.starts_with("http://") || import_url.starts_with("https://")
+}
+
+/// Requests a remote import with `HEAD`, returning the reason of the failure, if any.
+async fn verify_remote_import(client: &reqwest::Client, import_url: &str) -> Option<String> {
+    match client.head(import_url).send().await {
+        Ok(response) if response.status().is_success() => {
+            tracing::debug!("Remote import '{}' is reachable.", import_url);
+
+            None
+        }
+        Ok(response) => Some(format!("answered with status {}", response.status())),
+        Err(err) if err.is_timeout() => Some(format!(
+            "did not answer within {} seconds",
+            IMPORT_REQUEST_TIMEOUT.as_secs()
+        )),
+        Err(err) => Some(format!("could not be requested: {}", err)),
+    }
+}
+
+/// Checks that a local import exists, returning the reason of the failure, if any.
+fn verify_local_import(working_dir: &PathBuf, import_url: &str) -> Option<String> {
+    // The query string and fragment are not part of the file path.
+    let file_path = import_url
+        .split(|c| c == '?' || c == '#')
+        .next()
+        .unwrap_or(import_url);
+    let file_path = working_dir.join(file_path.trim_start_matches('/'));
+
+    match file_path.is_file() {
+        true => {
+            tracing::debug!("Local import '{}' exists.", import_url);
+
+            None
+        }
+        false => Some(format!("was not found at {}", file_path.display())),
+    }
+}
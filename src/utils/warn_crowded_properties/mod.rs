@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Local;
+use tokio::sync::broadcast;
+
+use crate::{
+    asts::CompilationSession, configatron::get_max_classes_per_property, events::GaladrielAlerts,
+    types::Stylitron,
+};
+
+use super::send_palantir_notification::send_palantir_notification;
+
+/// Warns about every CSS property whose distinct utility classes exceed the `maxClassesPerProperty` limit.
+///
+/// Many distinct values for a single property usually mean arbitrary values are used where design
+/// tokens would fit, so the warning suggests moving them into variables. Nothing is emitted when the
+/// limit is disabled (`0`).
+///
+/// # Arguments
+/// - `session`: The session whose styles are checked.
+/// - `palantir_sender`: The sender used to broadcast the warnings.
+pub fn warn_crowded_properties(
+    session: &CompilationSession,
+    palantir_sender: broadcast::Sender<GaladrielAlerts>,
+) {
+    let max_classes = get_max_classes_per_property();
+
+    if max_classes == 0 {
+        return;
+    }
+
+    let mut property_classes: HashMap<String, HashSet<String>> = HashMap::new();
+
+    // Collects the utility class names of every property, from the base and the responsive styles.
+    if let Some(stylitron_data) = session.stylitron.get("styles") {
+        if let Stylitron::Styles(ref styles_definitions) = *stylitron_data {
+            styles_definitions
+                .values()
+                .flat_map(|importance_map| importance_map.values())
+                .flat_map(|properties_map| properties_map.iter())
+                .for_each(|(property, class_map)| {
+                    property_classes
+                        .entry(property.to_owned())
+                        .or_default()
+                        .extend(class_map.keys().cloned());
+                });
+        }
+    }
+
+    if let Some(stylitron_data) = session.stylitron.get("responsive") {
+        if let Stylitron::ResponsiveStyles(ref responsive_definitions) = *stylitron_data {
+            responsive_definitions
+                .values()
+                .flat_map(|styles_map| styles_map.values())
+                .flat_map(|importance_map| importance_map.values())
+                .flat_map(|properties_map| properties_map.iter())
+                .for_each(|(property, class_map)| {
+                    property_classes
+                        .entry(property.to_owned())
+                        .or_default()
+                        .extend(class_map.keys().cloned());
+                });
+        }
+    }
+
+    let mut crowded_properties = property_classes
+        .into_iter()
+        .map(|(property, class_names)| (property, class_names.len()))
+        .filter(|(_, classes_count)| *classes_count > max_classes)
+        .collect::<Vec<_>>();
+
+    crowded_properties.sort();
+
+    for (property, classes_count) in crowded_properties {
+        tracing::warn!(
+            "Property '{}' has {} utility classes, above the limit of {}.",
+            property,
+            classes_count,
+            max_classes
+        );
+
+        let notification = GaladrielAlerts::create_warning(
+            Local::now(),
+            &format!(
+                "The `{}` property generates {} distinct utility classes, above the limit of {}. Consider defining its recurring values as variables instead of arbitrary values.",
+                property, classes_count, max_classes
+            ),
+        );
+
+        send_palantir_notification(notification, palantir_sender.clone());
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::Local;
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use tokio::sync::broadcast;
+
+use crate::{
+    asts::CompilationSession,
+    events::GaladrielAlerts,
+    types::{ClassOrigin, Classinator},
+};
+
+use super::send_palantir_notification::send_palantir_notification;
+
+/// A Nenyr class deriving from a class that none of the contexts it inherits from declares.
+struct DanglingDerivation {
+    /// The name of the class context.
+    context_name: String,
+    class_name: String,
+    derived_from: String,
+    utility_names: Vec<String>,
+}
+
+/// Warns about every Nenyr class whose `deriving_from` names a class missing from its context hierarchy.
+///
+/// The parent class is searched in the class own context, then in its layout (for modules) and in the
+/// central context. When it is not found, the inheritance silently adds no styles, which usually means
+/// the parent name has a typo.
+///
+/// # Arguments
+/// - `session`: The session whose classes are checked.
+/// - `palantir_sender`: The sender used to broadcast the warnings.
+pub fn warn_dangling_derivations(
+    session: &CompilationSession,
+    palantir_sender: broadcast::Sender<GaladrielAlerts>,
+) {
+    for dangling in collect_dangling_derivations(&session.classinator) {
+        let file_path = find_class_file_path(&session.originator, &dangling);
+
+        tracing::warn!(
+            "Class '{}' in context '{}' derives from the missing class '{}' ({}).",
+            dangling.class_name,
+            dangling.context_name,
+            dangling.derived_from,
+            file_path.as_deref().unwrap_or("unknown file")
+        );
+
+        let location = match file_path {
+            Some(file_path) => format!(" (declared in `{}`)", file_path),
+            None => String::new(),
+        };
+
+        let notification = GaladrielAlerts::create_warning(
+            Local::now(),
+            &format!(
+                "The `{}` class of the `{}` context{} derives from the `{}` class, which is not declared in this context or in any context it inherits from, so no styles are inherited. Please check the name of the parent class.",
+                dangling.class_name, dangling.context_name, location, dangling.derived_from
+            ),
+        );
+
+        send_palantir_notification(notification, palantir_sender.clone());
+    }
+}
+
+/// Retrieves the path of the Nenyr file declaring the class, from the origin of its utility classes.
+fn find_class_file_path(
+    originator: &DashMap<String, ClassOrigin>,
+    dangling: &DanglingDerivation,
+) -> Option<String> {
+    dangling.utility_names.iter().find_map(|utility_name| {
+        originator
+            .get(utility_name)
+            .filter(|origin| origin.class_name == dangling.class_name)
+            .map(|origin| origin.file_path.to_owned())
+    })
+}
+
+/// Collects the derived classes of the central, layouts and modules contexts whose parent class is not declared
+/// in the context chain they inherit from.
+fn collect_dangling_derivations(
+    classinator: &DashMap<String, Classinator>,
+) -> Vec<DanglingDerivation> {
+    let central_map = match classinator.get("central") {
+        Some(classinator_data) => match &*classinator_data {
+            Classinator::Central(central_data) => central_data.to_owned(),
+            _ => IndexMap::new(),
+        },
+        None => IndexMap::new(),
+    };
+
+    let layouts_map = match classinator.get("layouts") {
+        Some(classinator_data) => match &*classinator_data {
+            Classinator::Layouts(layouts_data) => layouts_data.to_owned(),
+            _ => IndexMap::new(),
+        },
+        None => IndexMap::new(),
+    };
+
+    let mut dangling_derivations = vec![];
+
+    collect_context_dangling(
+        &mut dangling_derivations,
+        "central",
+        &central_map,
+        &[&central_map],
+    );
+
+    for (layout_name, inherited_map) in &layouts_map {
+        collect_context_dangling(
+            &mut dangling_derivations,
+            layout_name,
+            inherited_map,
+            &[inherited_map, &central_map],
+        );
+    }
+
+    if let Some(classinator_data) = classinator.get("modules") {
+        if let Classinator::Modules(ref modules_data) = *classinator_data {
+            let empty_layout = IndexMap::new();
+
+            for (layout_name, modules_map) in modules_data {
+                let layout_map = layouts_map.get(layout_name).unwrap_or(&empty_layout);
+
+                for (module_name, inherited_map) in modules_map {
+                    collect_context_dangling(
+                        &mut dangling_derivations,
+                        module_name,
+                        inherited_map,
+                        &[inherited_map, layout_map, &central_map],
+                    );
+                }
+            }
+        }
+    }
+
+    dangling_derivations
+}
+
+/// Collects the derived classes of a single context whose parent class is missing from the context chain.
+///
+/// # Arguments
+/// - `dangling_derivations`: The list receiving the dangling derivations.
+/// - `context_name`: The name of the context being checked.
+/// - `inherited_map`: The classes of the context, grouped by the class they derive from.
+/// - `context_chain`: The classes of the context itself and of every context it inherits from.
+fn collect_context_dangling(
+    dangling_derivations: &mut Vec<DanglingDerivation>,
+    context_name: &str,
+    inherited_map: &IndexMap<String, IndexMap<String, Vec<String>>>,
+    context_chain: &[&IndexMap<String, IndexMap<String, Vec<String>>>],
+) {
+    for (derived_from, class_map) in inherited_map {
+        // Classes that derive from nothing are stored under `_`.
+        if derived_from == "_" {
+            continue;
+        }
+
+        let is_declared = context_chain.iter().any(|context_map| {
+            context_map
+                .values()
+                .any(|class_map| class_map.contains_key(derived_from))
+        });
+
+        if is_declared {
+            continue;
+        }
+
+        for (class_name, utility_names) in class_map {
+            dangling_derivations.push(DanglingDerivation {
+                context_name: context_name.to_string(),
+                class_name: class_name.to_owned(),
+                derived_from: derived_from.to_owned(),
+                utility_names: utility_names.to_owned(),
+            });
+        }
+    }
+}
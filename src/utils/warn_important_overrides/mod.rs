@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use chrono::Local;
+use dashmap::DashMap;
+use indexmap::IndexMap;
+use tokio::sync::broadcast;
+
+use crate::{
+    asts::CompilationSession,
+    events::GaladrielAlerts,
+    types::{Classinator, Stylitron},
+};
+
+use super::{
+    send_palantir_notification::send_palantir_notification,
+    serialize_classes_tracking::{
+        get_tracking_map_from_central, get_tracking_map_from_layouts, get_tracking_map_from_modules,
+    },
+};
+
+/// Where a utility class applies its declaration: the media condition and the pseudo selector
+/// (both `_` when absent), followed by the CSS property.
+type DeclarationTarget = (String, String, String);
+
+/// The kind of context a derived class belongs to, which defines where its parent class is searched.
+#[derive(Clone)]
+enum ContextKind {
+    Central,
+    Layout,
+    /// A module, along with the layout it belongs to.
+    Module(String),
+}
+
+/// A Nenyr class deriving from another class, with the utility class names it declares itself.
+struct DerivedClass {
+    context_kind: ContextKind,
+    /// The name of the class context.
+    context_name: String,
+    class_name: String,
+    derived_from: String,
+    utility_names: Vec<String>,
+}
+
+/// Warns about every derived Nenyr class that sets a property its parent class sets with `!important`.
+///
+/// Once the inheritance is applied, both declarations end up in the derived class. A plain declaration
+/// never wins over the inherited `!important` one, while two `!important` declarations are decided by
+/// the order of the rules in the stylesheet, so either case rarely does what the author expects.
+///
+/// # Arguments
+/// - `session`: The session whose classes are checked.
+/// - `palantir_sender`: The sender used to broadcast the warnings.
+pub fn warn_important_overrides(
+    session: &CompilationSession,
+    palantir_sender: broadcast::Sender<GaladrielAlerts>,
+) {
+    let derived_classes = collect_derived_classes(&session.classinator);
+
+    if derived_classes.is_empty() {
+        return;
+    }
+
+    let declarations = collect_utility_declarations(&session.stylitron);
+    let central_tracking = get_tracking_map_from_central(&session.clastrack);
+    let layouts_tracking = get_tracking_map_from_layouts(&session.clastrack);
+    let modules_tracking = get_tracking_map_from_modules(&session.clastrack);
+
+    for derived_class in derived_classes {
+        // The parent class is searched from the class own context up to the central context, as in the inheritance.
+        let context_chain = match &derived_class.context_kind {
+            ContextKind::Central => vec![Some(&central_tracking)],
+            ContextKind::Layout => vec![
+                layouts_tracking.get(&derived_class.context_name),
+                Some(&central_tracking),
+            ],
+            ContextKind::Module(layout_name) => vec![
+                modules_tracking.get(&derived_class.context_name),
+                layouts_tracking.get(layout_name),
+                Some(&central_tracking),
+            ],
+        };
+
+        let Some(inherited_utilities) = context_chain
+            .into_iter()
+            .flatten()
+            .find_map(|tracking| tracking.get(&derived_class.derived_from))
+        else {
+            continue;
+        };
+
+        // The `!important` declarations inherited from the parent class.
+        let inherited_important = inherited_utilities
+            .split_whitespace()
+            .filter_map(|utility_name| declarations.get(utility_name))
+            .filter(|(_, is_important)| *is_important)
+            .map(|(target, _)| target)
+            .collect::<Vec<_>>();
+
+        for utility_name in &derived_class.utility_names {
+            let Some((target, is_important)) = declarations.get(utility_name) else {
+                continue;
+            };
+
+            if !inherited_important.contains(&target) {
+                continue;
+            }
+
+            let (_, _, property) = target;
+
+            tracing::warn!(
+                "Class '{}' in context '{}' overrides the `!important` property '{}' inherited from class '{}'.",
+                derived_class.class_name,
+                derived_class.context_name,
+                property,
+                derived_class.derived_from
+            );
+
+            let outcome = match *is_important {
+                true => "Since both declarations are `!important`, the winner depends only on the order of the generated rules.",
+                false => "Since the inherited declaration is `!important`, the value set by the derived class never applies.",
+            };
+
+            let notification = GaladrielAlerts::create_warning(
+                Local::now(),
+                &format!(
+                    "The `{}` class of the `{}` context sets the `{}` property, which it also inherits with `!important` from the `{}` class. {} Please remove one of the declarations or adjust their importance.",
+                    derived_class.class_name, derived_class.context_name, property, derived_class.derived_from, outcome
+                ),
+            );
+
+            send_palantir_notification(notification, palantir_sender.clone());
+        }
+    }
+}
+
+/// Maps every utility class name to its declaration target and whether it is `!important`,
+/// from both the base and the responsive styles.
+fn collect_utility_declarations(
+    stylitron: &DashMap<String, Stylitron>,
+) -> HashMap<String, (DeclarationTarget, bool)> {
+    let mut declarations = HashMap::new();
+
+    if let Some(stylitron_data) = stylitron.get("styles") {
+        if let Stylitron::Styles(ref styles_definitions) = *stylitron_data {
+            collect_styles_declarations(&mut declarations, "_", styles_definitions);
+        }
+    }
+
+    if let Some(stylitron_data) = stylitron.get("responsive") {
+        if let Stylitron::ResponsiveStyles(ref responsive_definitions) = *stylitron_data {
+            for (media_condition, styles_map) in responsive_definitions {
+                collect_styles_declarations(&mut declarations, media_condition, styles_map);
+            }
+        }
+    }
+
+    declarations
+}
+
+/// Maps the utility class names of a styles node (pseudo selector, importance, property and utility)
+/// to their declaration targets under the given media condition.
+fn collect_styles_declarations(
+    declarations: &mut HashMap<String, (DeclarationTarget, bool)>,
+    media_condition: &str,
+    styles_map: &IndexMap<String, IndexMap<String, IndexMap<String, IndexMap<String, String>>>>,
+) {
+    for (pseudo_selector, importance_map) in styles_map {
+        for (importance, properties_map) in importance_map {
+            for (property, class_map) in properties_map {
+                for utility_name in class_map.keys() {
+                    let target = (
+                        media_condition.to_string(),
+                        pseudo_selector.to_owned(),
+                        property.to_owned(),
+                    );
+
+                    declarations.insert(
+                        utility_name.to_owned(),
+                        (target, importance == "!important"),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Collects every Nenyr class that derives from another class, from the central, layouts and modules contexts.
+fn collect_derived_classes(classinator: &DashMap<String, Classinator>) -> Vec<DerivedClass> {
+    let mut derived_classes = vec![];
+
+    let mut collect_context =
+        |context_kind: ContextKind,
+         context_name: &str,
+         inherited_map: &IndexMap<String, IndexMap<String, Vec<String>>>| {
+            for (derived_from, class_map) in inherited_map {
+                // Classes that derive from nothing are stored under `_`.
+                if derived_from == "_" {
+                    continue;
+                }
+
+                for (class_name, utility_names) in class_map {
+                    derived_classes.push(DerivedClass {
+                        context_kind: context_kind.clone(),
+                        context_name: context_name.to_string(),
+                        class_name: class_name.to_owned(),
+                        derived_from: derived_from.to_owned(),
+                        utility_names: utility_names.to_owned(),
+                    });
+                }
+            }
+        };
+
+    if let Some(classinator_data) = classinator.get("central") {
+        if let Classinator::Central(ref central_data) = *classinator_data {
+            collect_context(ContextKind::Central, "central", central_data);
+        }
+    }
+
+    if let Some(classinator_data) = classinator.get("layouts") {
+        if let Classinator::Layouts(ref layouts_data) = *classinator_data {
+            for (layout_name, inherited_map) in layouts_data {
+                collect_context(ContextKind::Layout, layout_name, inherited_map);
+            }
+        }
+    }
+
+    if let Some(classinator_data) = classinator.get("modules") {
+        if let Classinator::Modules(ref modules_data) = *classinator_data {
+            for (layout_name, modules_map) in modules_data {
+                for (module_name, inherited_map) in modules_map {
+                    collect_context(
+                        ContextKind::Module(layout_name.to_owned()),
+                        module_name,
+                        inherited_map,
+                    );
+                }
+            }
+        }
+    }
+
+    derived_classes
+}
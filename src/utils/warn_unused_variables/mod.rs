@@ -0,0 +1,137 @@
+use chrono::Local;
+use tokio::sync::broadcast;
+
+use crate::{
+    asts::CompilationSession, configatron::get_warn_unused_variables,
+    crealion::CENTRAL_CONTEXT_NAME, events::GaladrielAlerts, types::Stylitron,
+};
+
+use super::send_palantir_notification::send_palantir_notification;
+
+/// Warns about every variable that is never referenced once the project is processed.
+///
+/// A variable is referenced when its `var()` reference appears in the value of a utility class,
+/// in the keyframes of an animation, or in the value of another variable or theme variable. Since
+/// the unique names are only reachable from the context declaring them and its descendants, a
+/// single search across the whole project covers them. Nothing is emitted unless `warnUnusedVariables` is enabled.
+///
+/// # Arguments
+/// - `session`: The session whose variables are checked.
+/// - `palantir_sender`: The sender used to broadcast the warnings.
+pub fn warn_unused_variables(
+    session: &CompilationSession,
+    palantir_sender: broadcast::Sender<GaladrielAlerts>,
+) {
+    if !get_warn_unused_variables() {
+        return;
+    }
+
+    let referencing_values = collect_referencing_values(session);
+
+    let Some(stylitron_data) = session.stylitron.get("variables") else {
+        return;
+    };
+
+    let Stylitron::Variables(ref variables_definitions) = *stylitron_data else {
+        return;
+    };
+
+    for (context_name, context_variables) in variables_definitions {
+        let context_name = match context_name.as_str() {
+            CENTRAL_CONTEXT_NAME => "central",
+            context_name => context_name,
+        };
+
+        for (variable_name, variable_entry) in context_variables {
+            let Some(unique_name) = variable_entry.first() else {
+                continue;
+            };
+
+            let reference = format!("var({})", unique_name);
+
+            if referencing_values
+                .iter()
+                .any(|value| value.contains(&reference))
+            {
+                continue;
+            }
+
+            tracing::warn!(
+                "Variable '{}' of context '{}' is never referenced.",
+                variable_name,
+                context_name
+            );
+
+            let notification = GaladrielAlerts::create_warning(
+                Local::now(),
+                &format!(
+                    "The `{}` variable of the `{}` context is never referenced by any class, animation or variable. Consider removing it if the design token is no longer needed.",
+                    variable_name, context_name
+                ),
+            );
+
+            send_palantir_notification(notification, palantir_sender.clone());
+        }
+    }
+}
+
+/// Collects every value that may reference a variable: the values of the utility classes, the
+/// properties of the animations keyframes, and the values of the variables and theme variables.
+fn collect_referencing_values(session: &CompilationSession) -> Vec<String> {
+    let mut values: Vec<String> = vec![];
+
+    for node_name in ["styles", "responsive"] {
+        let Some(stylitron_data) = session.stylitron.get(node_name) else {
+            continue;
+        };
+
+        let styles_maps = match &*stylitron_data {
+            Stylitron::Styles(styles_map) => vec![styles_map],
+            Stylitron::ResponsiveStyles(responsive_map) => responsive_map.values().collect(),
+            _ => vec![],
+        };
+
+        styles_maps
+            .iter()
+            .flat_map(|styles_map| styles_map.values())
+            .flat_map(|importance_map| importance_map.values())
+            .flat_map(|properties_map| properties_map.values())
+            .flat_map(|utilities_map| utilities_map.values())
+            .for_each(|value| values.push(value.to_owned()));
+    }
+
+    if let Some(stylitron_data) = session.stylitron.get("animations") {
+        if let Stylitron::Animation(ref animations_definitions) = *stylitron_data {
+            animations_definitions
+                .values()
+                .flat_map(|context_animations| context_animations.values())
+                .flat_map(|animation_entry| animation_entry.values())
+                .flat_map(|stops_map| stops_map.values())
+                .flat_map(|properties_map| properties_map.values())
+                .for_each(|value| values.push(value.to_owned()));
+        }
+    }
+
+    if let Some(stylitron_data) = session.stylitron.get("variables") {
+        if let Stylitron::Variables(ref variables_definitions) = *stylitron_data {
+            variables_definitions
+                .values()
+                .flat_map(|context_variables| context_variables.values())
+                .filter_map(|variable_entry| variable_entry.get(1))
+                .for_each(|value| values.push(value.to_owned()));
+        }
+    }
+
+    if let Some(stylitron_data) = session.stylitron.get("themes") {
+        if let Stylitron::Themes(ref themes_definitions) = *stylitron_data {
+            themes_definitions
+                .values()
+                .flat_map(|context_themes| context_themes.values())
+                .flat_map(|schema_variables| schema_variables.values())
+                .filter_map(|variable_entry| variable_entry.get(1))
+                .for_each(|value| values.push(value.to_owned()));
+        }
+    }
+
+    values
+}